@@ -0,0 +1,81 @@
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::binary::BinaryReduction;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    word_to_encode: Option<String>,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [-o output_file] [--input-alphabet letters] [--encode word] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./binarize".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let word_to_encode = cli::take_option(&mut cmd_args, "--encode", print_usage_message);
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        output_path,
+        input_alphabet,
+        word_to_encode,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let transitions = loader::transitions_from_file(&args.machine_path)?;
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => alphabet,
+        None => loader::input_alphabet_from_file(&args.machine_path)?.unwrap_or_default(),
+    };
+
+    let reduction = BinaryReduction::new(transitions, &input_alphabet);
+    if let Some(word) = args.word_to_encode {
+        println!("{}", reduction.encode_word(&word)?);
+        return Ok(());
+    }
+
+    eprintln!(
+        "letters take {} cells; {} states became {} ({} extra), each step takes at most {} steps",
+        reduction.block_width(),
+        reduction.original_state_count(),
+        reduction.state_count(),
+        reduction.state_count() - reduction.original_state_count(),
+        reduction.step_overhead()
+    );
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    for trans in reduction.transitions() {
+        writeln!(out, "{}", trans)?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::canonical::CanonicalForm;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_paths: Vec<String>,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    hash: bool,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--hash] [-o output_file] [--input-alphabet letters] [machine_description_file...]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./canonicalize".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let hash = cli::take_flag(&mut cmd_args, "--hash");
+
+    // only hashes are short enough to print for several machines at once
+    if cmd_args.is_empty() || (!hash && cmd_args.len() != 1) {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_paths: cmd_args,
+        output_path,
+        input_alphabet,
+        hash,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    for path in args.machine_paths.iter() {
+        let transitions = loader::transitions_from_file(path)?;
+        let input_alphabet = match &args.input_alphabet {
+            Some(alphabet) => alphabet.clone(),
+            None => loader::input_alphabet_from_file(path)?.unwrap_or_default(),
+        };
+        let canonical = CanonicalForm::new(&transitions, &input_alphabet);
+        if args.hash {
+            // the layout of `sha256sum`, so that `sort` and `uniq -w 16` group equal machines
+            writeln!(out, "{:016x}  {}", canonical.hash(), path)?;
+        } else {
+            write!(out, "{}", canonical)?;
+        }
+    }
+    out.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, loader};
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    multi_tape: bool,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--multi-tape] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./check_determinism".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let multi_tape = cli::take_flag(&mut cmd_args, "--multi-tape");
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        multi_tape,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+
+    let conflicts: Vec<String> = if args.multi_tape {
+        let transitions = loader::multi_tape_transitions_from_file(&args.machine_path)?;
+        determinism::conflicting_transitions(&transitions)
+            .iter()
+            .map(|group| group.to_string())
+            .collect()
+    } else {
+        let transitions = loader::transitions_from_file(&args.machine_path)?;
+        determinism::conflicting_transitions(&transitions)
+            .iter()
+            .map(|group| group.to_string())
+            .collect()
+    };
+
+    if conflicts.is_empty() {
+        println!("deterministic");
+        return Ok(());
+    }
+
+    println!("nondeterministic");
+    for group in conflicts.iter() {
+        println!("{}", group);
+    }
+    std::process::exit(1);
+}
@@ -0,0 +1,74 @@
+// command line parsing shared by the binaries, each of which takes a handful of options
+use crate::machine::logic::{differential, loader};
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+
+pub const DEFAULT_MAX_LENGTH: usize = 6;
+pub const DEFAULT_STEP_LIMIT: Number = 1000;
+
+// removes `flag` along with the value following it
+pub fn take_option(
+    cmd_args: &mut Vec<String>,
+    flag: &str,
+    print_usage_message: fn(),
+) -> Option<String> {
+    let flag_position = cmd_args.iter().position(|arg| arg == flag)?;
+    if flag_position + 1 >= cmd_args.len() {
+        eprintln!("error: expected a value after {}", flag);
+        print_usage_message();
+        std::process::exit(1);
+    }
+    let value = cmd_args.remove(flag_position + 1);
+    cmd_args.remove(flag_position);
+    Some(value)
+}
+
+// removes `flag`, telling whether it was there
+pub fn take_flag(cmd_args: &mut Vec<String>, flag: &str) -> bool {
+    let given = cmd_args.iter().any(|arg| arg == flag);
+    cmd_args.retain(|arg| arg != flag);
+    given
+}
+
+// the options of the binaries running two machines on every word up to some length
+pub struct WordOptions {
+    pub input_alphabet: Option<Vec<TapeEntry>>,
+    pub max_length: usize,
+    pub step_limit: Number,
+}
+impl WordOptions {
+    // removes `--input-alphabet`, `--max-length` and `--steps` along with their values
+    pub fn take(cmd_args: &mut Vec<String>, print_usage_message: fn()) -> AppResult<Self> {
+        let input_alphabet = match take_option(cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+        let max_length = match take_option(cmd_args, "--max-length", print_usage_message) {
+            Some(length) => length.parse::<usize>()?,
+            None => DEFAULT_MAX_LENGTH,
+        };
+        let step_limit = match take_option(cmd_args, "--steps", print_usage_message) {
+            Some(limit) => limit.parse::<Number>()?,
+            None => DEFAULT_STEP_LIMIT,
+        };
+        Ok(Self {
+            input_alphabet,
+            max_length,
+            step_limit,
+        })
+    }
+
+    /* The letters of the words: declared on the command line or in either file, otherwise
+    the letters the machines in `paths` read, which `transitions` holds all of. */
+    pub fn letters(&self, paths: &[&str], transitions: &[MultiTransition]) -> AppResult<Vec<char>> {
+        if let Some(alphabet) = &self.input_alphabet {
+            return Ok(differential::alphabet_letters(alphabet));
+        }
+        for path in paths {
+            if let Some(alphabet) = loader::input_alphabet_from_file(path)? {
+                return Ok(differential::alphabet_letters(&alphabet));
+            }
+        }
+        Ok(differential::input_alphabet(transitions))
+    }
+}
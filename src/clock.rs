@@ -0,0 +1,100 @@
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::clock::{Clock, TimeBound};
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    bound: TimeBound,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    multi_tape: bool,
+    strategy: Strategy,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} --bound f(n) [--multi-tape] [--strategy head-to-head|start-marker|one-sweep] [-o output_file] [--input-alphabet letters] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./clock".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let bound = match cli::take_option(&mut cmd_args, "--bound", print_usage_message) {
+        Some(description) => TimeBound::from_description(&description)?,
+        None => {
+            eprintln!("error: expected a time bound");
+            print_usage_message();
+            std::process::exit(1);
+        }
+    };
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let strategy = match cli::take_option(&mut cmd_args, "--strategy", print_usage_message) {
+        Some(name) => Strategy::from_name(&name)?,
+        None => Strategy::HeadToHead,
+    };
+    let multi_tape = cli::take_flag(&mut cmd_args, "--multi-tape");
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        bound,
+        output_path,
+        input_alphabet,
+        multi_tape,
+        strategy,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let transitions = loader::transitions_from_file(&args.machine_path)?;
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => alphabet,
+        None => loader::input_alphabet_from_file(&args.machine_path)?.unwrap_or_default(),
+    };
+
+    let clock = Clock::new(transitions, args.bound, &input_alphabet);
+    eprintln!(
+        "runs get {} steps of the original machine, then reject",
+        clock.bound()
+    );
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    if args.multi_tape {
+        for trans in clock.multi_tape_transitions().iter() {
+            writeln!(out, "{}", trans)?;
+        }
+        out.flush()?;
+        return Ok(());
+    }
+
+    MachineTranslator::new(clock.multi_tape_transitions(), args.strategy)?
+        .with_input_alphabet(&input_alphabet)?
+        .translation()
+        .write_to(&mut out)?;
+    out.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::format::MachineFormat;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    input_path: String,
+    output_path: String,
+    multi_tape: bool,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--multi-tape] [input_machine_file] [output_machine_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./convert".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> Arguments {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let multi_tape = cli::take_flag(&mut cmd_args, "--multi-tape");
+
+    if cmd_args.len() != 2 {
+        eprintln!("error: expected 2 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Arguments {
+        output_path: cmd_args.remove(1),
+        input_path: cmd_args.remove(0),
+        multi_tape,
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments();
+    let output_format = MachineFormat::from_path(&args.output_path);
+
+    let input_alphabet = loader::input_alphabet_from_file(&args.input_path)?.unwrap_or_default();
+    let output = if args.multi_tape {
+        let transitions = loader::multi_tape_transitions_from_file(&args.input_path)?;
+        loader::multi_tape_transitions_to_format(&transitions, &input_alphabet, output_format)?
+    } else {
+        let transitions = loader::transitions_from_file(&args.input_path)?;
+        loader::transitions_to_format(&transitions, &input_alphabet, output_format)?
+    };
+    std::fs::write(&args.output_path, output + "\n")?;
+
+    Ok(())
+}
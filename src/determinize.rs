@@ -0,0 +1,110 @@
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, loader};
+use turinglike_oversophisticated_calculator::machine::translation::determinizer::Determinizer;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    multi_tape: bool,
+    show_branching: bool,
+    strategy: Strategy,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--multi-tape] [--show-branching] [--strategy head-to-head|start-marker|one-sweep] [-o output_file] [--input-alphabet letters] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./determinize".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let strategy = match cli::take_option(&mut cmd_args, "--strategy", print_usage_message) {
+        Some(name) => Strategy::from_name(&name)?,
+        None => Strategy::HeadToHead,
+    };
+    let multi_tape = cli::take_flag(&mut cmd_args, "--multi-tape");
+    let show_branching = cli::take_flag(&mut cmd_args, "--show-branching");
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        output_path,
+        input_alphabet,
+        multi_tape,
+        show_branching,
+        strategy,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let transitions = loader::transitions_from_file(&args.machine_path)?;
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => alphabet,
+        None => loader::input_alphabet_from_file(&args.machine_path)?.unwrap_or_default(),
+    };
+
+    let determinizer = Determinizer::new(transitions, &input_alphabet);
+    let multi_tape_transitions = determinizer.multi_tape_transitions();
+    let conflicts = determinism::conflicting_transitions(&multi_tape_transitions);
+    if !conflicts.is_empty() {
+        for group in conflicts.iter() {
+            eprintln!("{}", group);
+        }
+        return Err("the two-tape machine came out nondeterministic".into());
+    }
+    if args.show_branching {
+        eprintln!(
+            "choices are sequences over 1..{}, tried in breadth-first order",
+            determinizer.branching()
+        );
+    }
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    if args.multi_tape {
+        for trans in multi_tape_transitions.iter() {
+            writeln!(out, "{}", trans)?;
+        }
+        out.flush()?;
+        return Ok(());
+    }
+
+    let translation = MachineTranslator::new(multi_tape_transitions, args.strategy)?
+        .with_input_alphabet(&input_alphabet)?
+        .translation();
+    let conflicts = translation.conflicting_transitions();
+    if !conflicts.is_empty() {
+        for group in conflicts.iter() {
+            eprintln!("{}", group);
+        }
+        return Err("the deterministic machine was translated into a nondeterministic one".into());
+    }
+    translation.write_to(&mut out)?;
+    out.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use turinglike_oversophisticated_calculator::cli::{self, WordOptions};
+use turinglike_oversophisticated_calculator::machine::logic::canonical::CanonicalForm;
+use turinglike_oversophisticated_calculator::machine::logic::diff::{self, Change};
+use turinglike_oversophisticated_calculator::machine::logic::{differential, loader};
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    first_path: String,
+    second_path: String,
+    canonical: bool,
+    options: WordOptions,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--canonical] [--input-alphabet letters] [--max-length n] [--steps limit] [first_machine_file] [second_machine_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./diff".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = WordOptions::take(&mut cmd_args, print_usage_message)?;
+    let canonical = cli::take_flag(&mut cmd_args, "--canonical");
+
+    if cmd_args.len() != 2 {
+        eprintln!("error: expected 2 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        second_path: cmd_args.remove(1),
+        first_path: cmd_args.remove(0),
+        canonical,
+        options,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let first = loader::transitions_from_file(&args.first_path)?;
+    let second = loader::transitions_from_file(&args.second_path)?;
+    let both: Vec<MultiTransition> = first
+        .iter()
+        .chain(second.iter())
+        .cloned()
+        .map(MultiTransition::from)
+        .collect();
+    let letters = args
+        .options
+        .letters(&[&args.first_path, &args.second_path], &both)?;
+
+    let changes = if args.canonical {
+        // the input letters keep their numbers, so the words still mean the same
+        let alphabet: Vec<TapeEntry> = letters
+            .iter()
+            .map(|letter| TapeEntry(letter.to_digit(10).unwrap().into()))
+            .collect();
+        diff::transition_changes(
+            CanonicalForm::new(&first, &alphabet).transitions(),
+            CanonicalForm::new(&second, &alphabet).transitions(),
+        )
+    } else {
+        diff::transition_changes(&first, &second)
+    };
+    for change in changes.iter() {
+        println!("{}", change);
+    }
+    let count = |kind: fn(&Change) -> bool| changes.iter().filter(|&change| kind(change)).count();
+    println!(
+        "{} transitions added, {} removed, {} changed",
+        count(|change| matches!(change, Change::Added(_))),
+        count(|change| matches!(change, Change::Removed(_))),
+        count(|change| matches!(change, Change::Changed { .. }))
+    );
+
+    let options = &args.options;
+    let words = differential::words_up_to(&letters, options.max_length);
+    let check = diff::first_disagreement(&first, &second, &words, options.step_limit)?;
+    if let Some(disagreement) = &check.disagreement {
+        print!("{}", disagreement);
+    }
+    for comparison in check.undecided.iter() {
+        println!("undecided on {}", comparison);
+    }
+    let decided = words.len() - check.undecided.len();
+    match (&check.disagreement, check.undecided.len()) {
+        (Some(_), _) => (),
+        (None, 0) => println!(
+            "both accept the same of the {} words up to length {}",
+            decided, options.max_length
+        ),
+        (None, undecided) => println!(
+            "both accept the same of the {} words up to length {} where they finished within {} steps, {} undecided",
+            decided, options.max_length, options.step_limit, undecided
+        ),
+    }
+
+    Ok(())
+}
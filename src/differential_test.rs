@@ -0,0 +1,109 @@
+use std::io::BufRead;
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::differential::{
+    self, DifferentialTest,
+};
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::translator::Strategy;
+use turinglike_oversophisticated_calculator::types::*;
+
+const DEFAULT_STEP_LIMIT: Number = 1000;
+
+struct Arguments {
+    machine_path: String,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    strategy: Strategy,
+    max_length: Option<usize>,
+    step_limit: Number,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--input-alphabet letters] [--strategy head-to-head|start-marker|one-sweep] [--max-length n] [--steps limit] [multi_tape_machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./differential_test".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let strategy = match cli::take_option(&mut cmd_args, "--strategy", print_usage_message) {
+        Some(name) => Strategy::from_name(&name)?,
+        None => Strategy::HeadToHead,
+    };
+    let max_length = match cli::take_option(&mut cmd_args, "--max-length", print_usage_message) {
+        Some(length) => Some(length.parse::<usize>()?),
+        None => None,
+    };
+    let step_limit = match cli::take_option(&mut cmd_args, "--steps", print_usage_message) {
+        Some(limit) => limit.parse::<Number>()?,
+        None => DEFAULT_STEP_LIMIT,
+    };
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        input_alphabet,
+        strategy,
+        max_length,
+        step_limit,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let transitions = loader::multi_tape_transitions_from_file(&args.machine_path)?;
+    // declared on the command line or in the file, otherwise only the letters being read
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => Some(alphabet),
+        None => loader::input_alphabet_from_file(&args.machine_path)?,
+    };
+
+    // without `--max-length` the words come from STDIN, one per line
+    let words: Vec<String> = match args.max_length {
+        Some(length) => {
+            let letters = match &input_alphabet {
+                Some(alphabet) => differential::alphabet_letters(alphabet),
+                None => differential::input_alphabet(&transitions),
+            };
+            differential::words_up_to(&letters, length)
+        }
+        None => std::io::stdin()
+            .lock()
+            .lines()
+            .map(|line| Ok(line?.trim().to_string()))
+            .collect::<AppResult<Vec<String>>>()?,
+    };
+
+    let test = DifferentialTest::new(
+        transitions,
+        &input_alphabet.unwrap_or_default(),
+        args.strategy,
+        args.step_limit,
+    )?;
+    let mut finding_count = 0;
+    for word in words.iter() {
+        if let Some(finding) = test.check(word) {
+            println!("{}", finding);
+            finding_count += 1;
+        }
+    }
+    println!("checked {} words, {} findings", words.len(), finding_count);
+
+    if finding_count > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use turinglike_oversophisticated_calculator::cli::WordOptions;
+use turinglike_oversophisticated_calculator::machine::logic::equivalence::{
+    BoundedEquivalence, Verdict,
+};
+use turinglike_oversophisticated_calculator::machine::logic::{differential, loader};
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    first_path: String,
+    second_path: String,
+    options: WordOptions,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--input-alphabet letters] [--max-length n] [--steps limit] [first_machine_file] [second_machine_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./equivalence".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = WordOptions::take(&mut cmd_args, print_usage_message)?;
+
+    if cmd_args.len() != 2 {
+        eprintln!("error: expected 2 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        second_path: cmd_args.remove(1),
+        first_path: cmd_args.remove(0),
+        options,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let first = loader::multi_tape_transitions_from_file(&args.first_path)?;
+    let second = loader::multi_tape_transitions_from_file(&args.second_path)?;
+    let both: Vec<MultiTransition> = first.iter().chain(second.iter()).cloned().collect();
+    let letters = args
+        .options
+        .letters(&[&args.first_path, &args.second_path], &both)?;
+
+    let words = differential::words_up_to(&letters, args.options.max_length);
+    let check = BoundedEquivalence::new(first, second, args.options.step_limit)?;
+    let mut disagreements = 0;
+    let mut undecided = vec![];
+    for word in words.iter() {
+        let comparison = check.compare(word);
+        match comparison.verdict() {
+            Verdict::Agree => (),
+            Verdict::Disagree => {
+                println!("disagreement on {}", comparison);
+                disagreements += 1;
+            }
+            Verdict::Undecided => undecided.push(comparison),
+        }
+    }
+    // printed after the disagreements, as raising the limit may settle them
+    for comparison in undecided.iter() {
+        println!("undecided on {}", comparison);
+    }
+
+    println!(
+        "checked {} words up to length {}: {} disagreements, {} undecided",
+        words.len(),
+        args.options.max_length,
+        disagreements,
+        undecided.len()
+    );
+    if disagreements > 0 {
+        println!("the languages differ");
+        std::process::exit(1);
+    }
+    if undecided.is_empty() {
+        println!("the languages agree on these words");
+    } else {
+        println!(
+            "the languages agree where both machines finished within {} steps",
+            args.options.step_limit
+        );
+    }
+    Ok(())
+}
@@ -1,45 +1,164 @@
-#[macro_use]
-extern crate scan_fmt;
-extern crate derive_more;
-
-mod machine;
-mod types;
-
-use crate::types::*;
-use machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::classic::{ClassicMachine, Config};
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::decoded::DecodedConfig;
+use turinglike_oversophisticated_calculator::machine::translation::legend::SymbolLegend;
+use turinglike_oversophisticated_calculator::machine::translation::speedup::LinearSpeedup;
+use turinglike_oversophisticated_calculator::machine::translation::state_map::StateMap;
+use turinglike_oversophisticated_calculator::types::*;
 
 struct Arguments {
-    machine_description: String,
+    transitions: Vec<Transition>,
     execution_limit: Number,
+    trace: bool,
+    state_map: Option<StateMap>,
+    legend: Option<SymbolLegend>,
+    speedup: Option<LinearSpeedup>,
 }
 
 fn print_usage_message() {
     println!(
-        "Usage: {} [machine_description_file] [execution_limit]",
+        "Usage: {} [--trace] [--state-map state_map_file] [--legend legend_file] [--speedup factor] [machine_description_file] [execution_limit]",
         std::env::args()
-            .nth(0)
-            .unwrap_or("./interpreter".to_string())
+            .next()
+            .unwrap_or_else(|| "./interpreter".to_string())
     )
 }
 
 fn parse_cmd_arguments() -> AppResult<Arguments> {
-    let cmd_args: Vec<String> = std::env::args().collect();
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
 
-    if cmd_args.len() != 3 {
-        eprintln!(
-            "error: expected 2 arguments but found {}",
-            cmd_args.len() - 1
-        );
+    let state_map = match cli::take_option(&mut cmd_args, "--state-map", print_usage_message) {
+        Some(path) => Some(StateMap::from_description(std::fs::read_to_string(path)?)?),
+        None => None,
+    };
+    let legend = match cli::take_option(&mut cmd_args, "--legend", print_usage_message) {
+        Some(path) => Some(SymbolLegend::from_description(std::fs::read_to_string(
+            path,
+        )?)?),
+        None => None,
+    };
+    let speedup_factor = match cli::take_option(&mut cmd_args, "--speedup", print_usage_message) {
+        Some(factor) => Some(factor.parse::<usize>()?),
+        None => None,
+    };
+    // a state map or a legend is only good for traces
+    let trace = cli::take_flag(&mut cmd_args, "--trace") || state_map.is_some() || legend.is_some();
+
+    if cmd_args.len() != 2 {
+        eprintln!("error: expected 2 arguments but found {}", cmd_args.len());
         print_usage_message();
         std::process::exit(1);
     }
 
+    let transitions = loader::transitions_from_file(&cmd_args[0])?;
+    let speedup = match speedup_factor {
+        Some(factor) => {
+            let input_alphabet =
+                loader::input_alphabet_from_file(&cmd_args[0])?.unwrap_or_default();
+            Some(LinearSpeedup::new(
+                transitions.clone(),
+                factor,
+                &input_alphabet,
+            )?)
+        }
+        None => None,
+    };
+
     Ok(Arguments {
-        machine_description: std::fs::read_to_string(&cmd_args[1])?,
-        execution_limit: cmd_args[2].parse::<Number>()?,
+        transitions,
+        execution_limit: cmd_args[1].parse::<Number>()?,
+        trace,
+        state_map,
+        legend,
+        speedup,
     })
 }
 
+fn describe_config(cfg: &Config, state_map: &Option<StateMap>) -> String {
+    let meaning = state_map
+        .as_ref()
+        .and_then(|map| map.meaning(&cfg.state))
+        .map_or(String::new(), |meaning| format!("  # {}", meaning));
+    format!("{} {}{}", cfg.state, cfg.tape, meaning)
+}
+
+// the config as one of the machine that was translated, one line per simulated tape
+fn describe_decoded_config(
+    cfg: &Config,
+    legend: &SymbolLegend,
+    state_map: &Option<StateMap>,
+) -> String {
+    match DecodedConfig::decode(&cfg.state, &cfg.tape, legend, state_map.as_ref()) {
+        Ok(decoded) => decoded.to_string(),
+        Err(err) => format!("{} {}  # can't decode: {}", cfg.state, cfg.tape, err),
+    }
+}
+
+// one entry per config on STDERR, so that STDOUT still only gets the answer
+fn print_trace(
+    step: Number,
+    configs: &[&Config],
+    legend: &Option<SymbolLegend>,
+    state_map: &Option<StateMap>,
+) {
+    let mut lines: Vec<String> = configs
+        .iter()
+        .map(|cfg| {
+            let description = match legend {
+                Some(legend) => describe_decoded_config(cfg, legend, state_map),
+                None => describe_config(cfg, state_map),
+            };
+            format!("{}: {}", step, description)
+        })
+        .collect();
+    lines.sort();
+    for line in lines {
+        eprintln!("{}", line);
+    }
+}
+
+fn describe_run_length(length: Option<Number>) -> String {
+    match length {
+        Some(steps) => format!("{} steps", steps),
+        None => "no accepting run within the limit".to_string(),
+    }
+}
+
+/* Runs the machine both as it is and sped up, reporting the lengths of the shortest accepting
+runs on STDERR. The sped-up machine packs its input first, which is counted separately. */
+fn compare_with_speedup(
+    transitions: Vec<Transition>,
+    speedup: &LinearSpeedup,
+    execution_limit: Number,
+    input: String,
+) -> AppResult<()> {
+    let original =
+        ClassicMachine::new(transitions, execution_limit, input.clone())?.accepting_run_length();
+
+    let mut packing_steps = None;
+    let sped_up = ClassicMachine::new(speedup.transitions(), execution_limit, input)?
+        .traced_accepting_run_length(|step, configs| {
+            let packed = configs
+                .iter()
+                .any(|cfg| LinearSpeedup::is_block_step_start(&cfg.state));
+            if packed && packing_steps.is_none() {
+                packing_steps = Some(step);
+            }
+        });
+
+    eprintln!("original: {}", describe_run_length(original));
+    eprintln!(
+        "blocks of {} cells: {}, {} of them packing the input",
+        speedup.factor(),
+        describe_run_length(sped_up),
+        packing_steps.map_or("?".to_string(), |steps| steps.to_string())
+    );
+    println!("{}", if sped_up.is_some() { "YES" } else { "NO" });
+    Ok(())
+}
+
 fn main() -> Result<(), AppError> {
     let args = parse_cmd_arguments()?;
     let mut stdin = String::new();
@@ -47,10 +166,29 @@ fn main() -> Result<(), AppError> {
 
     let tape_content = stdin.trim().to_string();
 
-    let machine =
-        ClassicMachine::new(args.machine_description, args.execution_limit, tape_content)?;
+    if let Some(speedup) = &args.speedup {
+        return compare_with_speedup(
+            args.transitions,
+            speedup,
+            args.execution_limit,
+            tape_content,
+        );
+    }
+
+    let machine = ClassicMachine::new(args.transitions, args.execution_limit, tape_content)?;
+
+    if !args.trace {
+        machine.run_with_limit();
+        return Ok(());
+    }
 
-    machine.run_with_limit();
+    let (legend, state_map) = (args.legend, args.state_map);
+    let accepted = machine
+        .traced_accepting_run_length(|step, configs| {
+            print_trace(step, configs, &legend, &state_map)
+        })
+        .is_some();
+    println!("{}", if accepted { "YES" } else { "NO" });
 
     Ok(())
 }
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate scan_fmt;
+extern crate derive_more;
+
+pub mod cli;
+pub mod machine;
+pub mod types;
@@ -0,0 +1,41 @@
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::{lint, loader};
+use turinglike_oversophisticated_calculator::types::*;
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./lint".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Vec<Transition>> {
+    let cmd_args: Vec<String> = std::env::args().collect();
+
+    if cmd_args.len() != 2 {
+        eprintln!(
+            "error: expected 1 arguments but found {}",
+            cmd_args.len() - 1
+        );
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    loader::transitions_from_file(&cmd_args[1])
+}
+
+fn main() -> Result<(), AppError> {
+    let transitions = parse_cmd_arguments()?;
+
+    let warnings = lint::lint(&transitions);
+    for warning in warnings.iter() {
+        println!("warning: {}", warning);
+    }
+
+    if !warnings.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
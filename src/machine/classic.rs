@@ -1,8 +1,10 @@
 use crate::machine::component::tape::Tape;
 use crate::machine::component::transition::Transition;
-use crate::machine::logic::loader;
 use crate::types::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+type TransitionIndex = HashMap<(State, TapeEntry), Vec<Transition>>;
 
 #[derive(Debug)]
 struct Run {
@@ -11,15 +13,18 @@ struct Run {
     current_configs: HashSet<Config>,
 }
 impl Run {
-    pub fn apply_transitions(mut self, transitions: &Vec<Transition>) -> Run {
+    pub fn apply_transitions(mut self, transitions: &TransitionIndex) -> Run {
         let mut new_configs = HashSet::new();
-        for trans in transitions.iter() {
-            for cfg in self.current_configs.iter() {
-                if trans.applicable_to(cfg) {
-                    new_configs.insert(cfg.get_config_from_after_transition(trans));
+        for cfg in self.current_configs.iter() {
+            let key = (cfg.state.clone(), cfg.tape.read_from_head().clone());
+            for trans in transitions.get(&key).into_iter().flatten() {
+                let new_config = cfg.get_config_from_after_transition(trans);
+                // a config visited earlier can't lead anywhere new
+                if !self.visited_configs.contains(&new_config) {
+                    new_configs.insert(new_config);
                 }
             }
-        } // TODO OPTIMIZATION drop new_configs that have already been visited earlier
+        }
 
         self.visited_configs.extend(new_configs.iter().cloned());
         Run {
@@ -28,12 +33,11 @@ impl Run {
             current_step_no: self.current_step_no + 1,
         }
     }
+    // every visited config was current at some step, and we stop at the first accepting one
     pub fn is_accepting_run_reached(&self) -> bool {
-        // TODO OPTIMIZATION just check current_configs instead to avoid multichecks on same configs
-        self.visited_configs
+        self.current_configs
             .iter()
-            .find(|cfg| cfg.state.value() == std_states::ACCEPT)
-            .is_some()
+            .any(|cfg| cfg.state.value() == std_states::ACCEPT)
     }
 }
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -59,44 +63,81 @@ impl Config {
 #[derive(Debug)]
 pub struct ClassicMachine {
     run: Run,
-    transitions: Vec<Transition>,
+    transitions: Rc<TransitionIndex>,
     execution_limit: Number,
 }
 
 impl ClassicMachine {
     pub fn new(
-        machine_description: String,
+        transitions: Vec<Transition>,
         execution_limit: Number,
         input_word: String,
     ) -> AppResult<ClassicMachine> {
+        let mut transition_index = TransitionIndex::new();
+        for trans in transitions {
+            transition_index
+                .entry((trans.state_before.clone(), trans.tape_value_before.clone()))
+                .or_default()
+                .push(trans);
+        }
+
+        Ok(ClassicMachine {
+            run: Self::initial_run(input_word),
+            transitions: Rc::new(transition_index),
+            execution_limit,
+        })
+    }
+
+    fn initial_run(input_word: String) -> Run {
         let mut step_configs = HashSet::new();
         step_configs.insert(Config {
             tape: Tape::new(input_word),
             state: State(std_states::START.to_string()),
         });
 
-        let initial_run_data = Run {
+        Run {
             visited_configs: step_configs.clone(),
             current_configs: step_configs,
             current_step_no: 0,
-        };
+        }
+    }
 
-        Ok(ClassicMachine {
-            run: initial_run_data,
-            transitions: loader::transitions_from_description(machine_description)?,
+    // a fresh machine on another word, sharing the transitions with this one
+    pub fn with_input(&self, execution_limit: Number, input_word: String) -> ClassicMachine {
+        ClassicMachine {
+            run: Self::initial_run(input_word),
+            transitions: Rc::clone(&self.transitions),
             execution_limit,
-        })
+        }
     }
 
     fn time_limit_reached(&self) -> bool {
         self.run.current_step_no >= self.execution_limit
     }
 
-    pub fn run_with_limit(mut self) -> () {
-        while !self.time_limit_reached()
-            && !self.run.is_accepting_run_reached()
-            && self.run.current_configs.len() > 0
-        {
+    pub fn accepts(self) -> bool {
+        self.accepting_run_length().is_some()
+    }
+
+    // number of steps of the shortest accepting run, if there is one within the limit
+    pub fn accepting_run_length(self) -> Option<Number> {
+        self.traced_accepting_run_length(|_, _| {})
+    }
+
+    // same as `accepting_run_length`, showing `trace` the new configs of every step
+    pub fn traced_accepting_run_length(
+        mut self,
+        mut trace: impl FnMut(Number, &[&Config]),
+    ) -> Option<Number> {
+        loop {
+            let configs: Vec<&Config> = self.run.current_configs.iter().collect();
+            trace(self.run.current_step_no, &configs);
+            if self.time_limit_reached()
+                || self.run.is_accepting_run_reached()
+                || self.run.current_configs.is_empty()
+            {
+                break;
+            }
             self.run = self.run.apply_transitions(&self.transitions);
             // dbg!(self.run.current_step_no);
             // dbg!(self.run.visited_configs.len());
@@ -104,6 +145,48 @@ impl ClassicMachine {
             // dbg!(&self.run.current_configs);
         }
         if self.run.is_accepting_run_reached() {
+            Some(self.run.current_step_no)
+        } else {
+            None
+        }
+    }
+
+    /* The configs of a shortest accepting run, from the initial one on. Every step's configs
+    are kept to walk back from the accepting one, so this takes more memory than finding the
+    length alone. */
+    pub fn accepting_run(self) -> Option<Vec<Config>> {
+        let transitions = Rc::clone(&self.transitions);
+        let mut steps: Vec<Vec<Config>> = vec![];
+        self.traced_accepting_run_length(|_, configs| {
+            steps.push(configs.iter().map(|&cfg| cfg.clone()).collect())
+        })?;
+
+        let accepting = steps
+            .pop()?
+            .into_iter()
+            .find(|cfg| cfg.state.value() == std_states::ACCEPT)?;
+        let mut run = vec![accepting];
+        while let Some(configs) = steps.pop() {
+            let next = run.last().unwrap();
+            let previous = configs
+                .into_iter()
+                .find(|cfg| {
+                    let key = (cfg.state.clone(), cfg.tape.read_from_head().clone());
+                    transitions
+                        .get(&key)
+                        .into_iter()
+                        .flatten()
+                        .any(|trans| &cfg.clone().apply(trans) == next)
+                })
+                .unwrap();
+            run.push(previous);
+        }
+        run.reverse();
+        Some(run)
+    }
+
+    pub fn run_with_limit(self) {
+        if self.accepts() {
             println!("YES")
         } else {
             println!("NO")
@@ -1,4 +1,5 @@
 use crate::types::*;
+use std::fmt;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub struct Tape {
@@ -36,6 +37,10 @@ impl Tape {
         &self.content[self.head_idx]
     }
 
+    pub fn cells(&self) -> &[TapeEntry] {
+        &self.content
+    }
+
     fn trim_single_trailing_blank(&mut self) {
         // leave at least one entry on the tape
         if self.content.len() > 1 && self.content.last().unwrap() == &TapeEntry::BLANK {
@@ -48,7 +53,7 @@ impl Tape {
             self.trim_single_trailing_blank();
         }
         if self.head_idx > 0 {
-            self.head_idx = self.head_idx - 1 // prevent fall off of the tape
+            self.head_idx -= 1 // prevent fall off of the tape
         }
     }
 
@@ -68,3 +73,21 @@ impl Tape {
         }
     }
 }
+// the head's cell is put in brackets
+impl fmt::Display for Tape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<String> = self
+            .content
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                if idx == self.head_idx {
+                    format!("[{}]", entry)
+                } else {
+                    entry.to_string()
+                }
+            })
+            .collect();
+        write!(f, "{}", cells.join(" "))
+    }
+}
@@ -1,7 +1,8 @@
 use crate::machine::classic::Config;
 use crate::types::*;
+use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub struct Transition {
     pub state_before: State,
     pub state_after: State,
@@ -40,18 +41,25 @@ impl Transition {
         })
     }
 
-    pub fn to_string(&self) -> String {
-        format!(
-            "{} {} {} {} {}",
-            self.state_before.to_string(),
-            self.tape_value_before.to_string(),
-            self.state_after.to_string(),
-            self.tape_value_after.to_string(),
-            direction_to_string(&self.tape_head_move_direction),
-        )
+    // runs end as soon as they accept, so such a transition never fires
+    pub fn leaves_accept(&self) -> bool {
+        self.state_before.value() == std_states::ACCEPT
     }
 
     pub fn applicable_to(&self, cfg: &Config) -> bool {
         self.state_before == cfg.state && &self.tape_value_before == cfg.tape.read_from_head()
     }
 }
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.state_before,
+            self.tape_value_before,
+            self.state_after,
+            self.tape_value_after,
+            direction_to_string(&self.tape_head_move_direction),
+        )
+    }
+}
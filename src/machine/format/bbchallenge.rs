@@ -0,0 +1,184 @@
+/* bbchallenge.org standard text format, e.g. `1RB1LB_1LA1RZ`: one `_`-separated group per
+state (A, B, ...) with a `<write><direction><next state>` triple per symbol. A machine halts
+on an undefined transition `---` and in any state letter past the defined ones - we read
+both as going to `accept`, the first one staying put, and export `accept` as `Z`. With no
+way to reject, a machine lacking a transition can't be exported. */
+use crate::machine::component::transition::Transition;
+use crate::machine::format::conventional_state_names;
+use crate::types::*;
+use std::collections::BTreeSet;
+
+const UNDEFINED: &str = "---";
+const HALT_STATE: char = 'Z';
+const MAX_SYMBOLS: usize = 10;
+const MAX_STATES: usize = 26;
+
+fn state_letter(idx: usize) -> char {
+    (b'A' + idx as u8) as char
+}
+
+fn parse_direction(ch: char) -> AppResult<HeadMoveDirection> {
+    match ch {
+        'L' => Ok(HeadMoveDirection::Left),
+        'R' => Ok(HeadMoveDirection::Right),
+        other => Err(format!("bad bbchallenge direction `{}`", other).into()),
+    }
+}
+
+pub fn parse(content: &str) -> AppResult<Vec<Transition>> {
+    let rows: Vec<Vec<char>> = content
+        .trim()
+        .split('_')
+        .map(|row| row.chars().collect())
+        .collect();
+    let symbol_count = rows[0].len() / 3;
+    if symbol_count == 0 || rows.iter().any(|row| row.len() != 3 * symbol_count) {
+        return Err(format!("malformed bbchallenge machine `{}`", content.trim()).into());
+    }
+    if rows.len() > MAX_STATES {
+        return Err(format!(
+            "{} states don't fit in the bbchallenge format, which names them A to Z",
+            rows.len()
+        )
+        .into());
+    }
+
+    let mut states: Vec<String> = (0..rows.len())
+        .map(|idx| state_letter(idx).to_string())
+        .collect();
+    let halting: Vec<String> = rows
+        .iter()
+        .flat_map(|row| row.chunks(3).map(|entry| entry[2]))
+        .filter(|&letter| letter != '-' && !states.contains(&letter.to_string()))
+        .map(|letter| letter.to_string())
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect();
+    states.extend(halting.iter().cloned());
+    let names = conventional_state_names(&states, "A", &halting, &[]);
+
+    let mut transitions = vec![];
+    for (idx, row) in rows.iter().enumerate() {
+        for (symbol, entry) in row.chunks(3).enumerate() {
+            let entry: String = entry.iter().collect();
+            if entry == UNDEFINED {
+                transitions.push(Transition {
+                    state_before: names[&state_letter(idx).to_string()].clone(),
+                    state_after: State(std_states::ACCEPT.to_string()),
+                    tape_value_before: TapeEntry(symbol as Number),
+                    tape_value_after: TapeEntry(symbol as Number),
+                    tape_head_move_direction: HeadMoveDirection::Stay,
+                });
+                continue;
+            }
+            let mut chars = entry.chars();
+            let (write, direction, next) = (chars.next(), chars.next(), chars.next());
+            let write = write
+                .and_then(|ch| ch.to_digit(10))
+                .ok_or_else(|| format!("bad bbchallenge transition `{}`", entry))?;
+            transitions.push(Transition {
+                state_before: names[&state_letter(idx).to_string()].clone(),
+                state_after: names
+                    .get(&next.unwrap_or('-').to_string())
+                    .cloned()
+                    .ok_or_else(|| format!("bad bbchallenge transition `{}`", entry))?,
+                tape_value_before: TapeEntry(symbol as Number),
+                tape_value_after: TapeEntry(write.into()),
+                tape_head_move_direction: parse_direction(direction.unwrap_or('-'))?,
+            });
+        }
+    }
+    Ok(transitions)
+}
+
+pub fn render(transitions: &[Transition]) -> AppResult<String> {
+    let mut states: Vec<State> = vec![State(std_states::START.to_string())];
+    let other_states: BTreeSet<&State> = transitions
+        .iter()
+        .flat_map(|trans| vec![&trans.state_before, &trans.state_after])
+        .filter(|state| state.value() != std_states::START && state.value() != std_states::ACCEPT)
+        .collect();
+    states.extend(other_states.into_iter().cloned());
+    if states
+        .iter()
+        .any(|state| state.value() == std_states::REJECT)
+    {
+        return Err("the bbchallenge format has no reject state".into());
+    }
+    if states.len() > (HALT_STATE as usize - 'A' as usize) {
+        return Err(format!(
+            "{} states don't fit in the bbchallenge format",
+            states.len()
+        )
+        .into());
+    }
+
+    let symbol_count = transitions
+        .iter()
+        .flat_map(|trans| vec![trans.tape_value_before.0, trans.tape_value_after.0])
+        .max()
+        .unwrap_or(0) as usize
+        + 1;
+    if symbol_count > MAX_SYMBOLS {
+        return Err(format!(
+            "{} symbols don't fit in the bbchallenge format",
+            symbol_count
+        )
+        .into());
+    }
+    let letter_of = |state: &State| -> char {
+        match states.iter().position(|other| other == state) {
+            Some(idx) => state_letter(idx),
+            None => HALT_STATE,
+        }
+    };
+
+    let mut rows = vec![];
+    for state in states.iter() {
+        let mut row = String::new();
+        for symbol in 0..symbol_count {
+            let matching: Vec<&Transition> = transitions
+                .iter()
+                .filter(|trans| {
+                    &trans.state_before == state && trans.tape_value_before.0 == symbol as Number
+                })
+                .collect();
+            match matching.as_slice() {
+                [] => {
+                    return Err(format!(
+                        "state `{}` has no transition on {}, and the bbchallenge format can't reject",
+                        state, symbol
+                    )
+                    .into())
+                }
+                // halting right away is what an undefined transition does
+                [trans]
+                    if trans.state_after.value() == std_states::ACCEPT
+                        && trans.tape_head_move_direction == HeadMoveDirection::Stay =>
+                {
+                    row.push_str(UNDEFINED)
+                }
+                [trans] => {
+                    row.push_str(&trans.tape_value_after.to_string());
+                    row.push(match trans.tape_head_move_direction {
+                        HeadMoveDirection::Left => 'L',
+                        HeadMoveDirection::Right => 'R',
+                        HeadMoveDirection::Stay => {
+                            return Err("the bbchallenge format has no stay moves".into())
+                        }
+                    });
+                    row.push(letter_of(&trans.state_after));
+                }
+                _ => {
+                    return Err(format!(
+                        "the bbchallenge format only holds deterministic machines, but state `{}` has several transitions on {}",
+                        state, symbol
+                    )
+                    .into())
+                }
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows.join("_"))
+}
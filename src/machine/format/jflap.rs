@@ -0,0 +1,401 @@
+/* JFLAP `.jff` Turing machines, including multi-tape ones. An empty `<read/>` or `<write/>`
+is the blank; the initial state becomes `start` and every final state becomes `accept`.
+JFLAP names its symbols with single characters, so anything we can't name with a digit
+or letter is written as a private character and listed in a legend comment. */
+use crate::machine::component::transition::Transition;
+use crate::machine::format::{conventional_state_names, symbol_name, SymbolTable};
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+const BLANK_NAME: &str = "";
+const JFLAP_BLANK: &str = "\u{25a1}";
+const LEGEND_PREFIX: &str = "symbols:";
+const FIRST_PRIVATE_SYMBOL: u32 = 0xe000;
+const LAYOUT_COLUMN_WIDTH: usize = 150;
+const LAYOUT_ROW_HEIGHT: usize = 100;
+
+struct RawTransition {
+    from: State,
+    to: State,
+    read: Vec<TapeEntry>,
+    write: Vec<TapeEntry>,
+    moves: Vec<HeadMoveDirection>,
+}
+
+fn parse_direction(text: &str) -> AppResult<HeadMoveDirection> {
+    match text.trim() {
+        "L" => Ok(HeadMoveDirection::Left),
+        "R" => Ok(HeadMoveDirection::Right),
+        "S" => Ok(HeadMoveDirection::Stay),
+        other => Err(format!("bad JFLAP move `{}`", other).into()),
+    }
+}
+
+fn child_text<'a>(node: &roxmltree::Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(tag))
+        .map(|child| child.text().unwrap_or(""))
+}
+
+// reads the `<read>`, `<write>` or `<move>` of every tape, in tape order
+fn per_tape<'a>(
+    node: &roxmltree::Node<'a, '_>,
+    tag: &str,
+    tapes: usize,
+) -> AppResult<Vec<&'a str>> {
+    let mut values = vec![None; tapes];
+    for (idx, child) in node
+        .children()
+        .filter(|child| child.has_tag_name(tag))
+        .enumerate()
+    {
+        let tape = match child.attribute("tape") {
+            Some(tape) => tape.parse::<usize>()? - 1,
+            None => idx,
+        };
+        if tape >= tapes {
+            return Err(format!("JFLAP transition refers to tape {}", tape + 1).into());
+        }
+        values[tape] = Some(child.text().unwrap_or(""));
+    }
+    values
+        .into_iter()
+        .map(|value| value.ok_or_else(|| format!("JFLAP transition is missing a `{}`", tag).into()))
+        .collect()
+}
+
+// without an expected tape count we take whatever the file declares
+fn parse_raw(content: &str, expected_tapes: Option<usize>) -> AppResult<Vec<RawTransition>> {
+    let document = roxmltree::Document::parse(content)?;
+    let root = document.root_element();
+
+    let machine_type = child_text(&root, "type").unwrap_or("");
+    if machine_type.trim() != "turing" {
+        return Err(format!(
+            "expected a JFLAP Turing machine but found `{}`",
+            machine_type
+        )
+        .into());
+    }
+    let tapes = match child_text(&root, "tapes") {
+        Some(count) => count.trim().parse::<usize>()?,
+        None => 1,
+    };
+    if expected_tapes.is_some_and(|expected| expected != tapes) {
+        return Err(format!(
+            "expected a {}-tape machine but found {} tapes",
+            expected_tapes.unwrap_or(tapes),
+            tapes
+        )
+        .into());
+    }
+
+    let mut symbols = SymbolTable::new(BLANK_NAME);
+    symbols.define(JFLAP_BLANK, TapeEntry::BLANK);
+    for comment in document.descendants().filter(|node| node.is_comment()) {
+        let legend = comment.text().unwrap_or("").trim();
+        if let Some(legend) = legend.strip_prefix(LEGEND_PREFIX) {
+            for definition in legend.split_whitespace() {
+                let (name, number) = definition
+                    .split('=')
+                    .collect_tuple()
+                    .ok_or_else(|| format!("bad JFLAP symbol legend `{}`", definition))?;
+                symbols.define(name, TapeEntry(number.parse()?));
+            }
+        }
+    }
+
+    let automaton = root
+        .children()
+        .find(|child| child.has_tag_name("automaton"))
+        .unwrap_or(root);
+    let state_nodes: Vec<roxmltree::Node> = automaton
+        .children()
+        .filter(|child| child.has_tag_name("state") || child.has_tag_name("block"))
+        .collect();
+
+    // JFLAP only requires ids to be unique, names can repeat
+    let names: Vec<&str> = state_nodes
+        .iter()
+        .map(|node| {
+            node.attribute("name")
+                .or_else(|| node.attribute("id"))
+                .unwrap_or("")
+        })
+        .collect();
+    let mut labels: HashMap<&str, String> = HashMap::new();
+    for (node, name) in state_nodes.iter().zip(names.iter()) {
+        let id = node.attribute("id").ok_or("JFLAP state without an id")?;
+        let label = if names.iter().filter(|other| other == &name).count() > 1 {
+            format!("{}_{}", name, id)
+        } else {
+            name.to_string()
+        };
+        labels.insert(id, label);
+    }
+    let label_of = |node: &roxmltree::Node| labels[node.attribute("id").unwrap_or("")].clone();
+
+    let all_labels: Vec<String> = state_nodes.iter().map(label_of).collect();
+    let start = state_nodes
+        .iter()
+        .find(|node| node.children().any(|child| child.has_tag_name("initial")))
+        .map(label_of)
+        .ok_or("JFLAP machine has no initial state")?;
+    let accepting: Vec<String> = state_nodes
+        .iter()
+        .filter(|node| node.children().any(|child| child.has_tag_name("final")))
+        .map(label_of)
+        .collect();
+    let conventional = conventional_state_names(&all_labels, &start, &accepting, &[]);
+    let state_of = |id: &str| -> AppResult<State> {
+        labels
+            .get(id.trim())
+            .map(|label| conventional[label].clone())
+            .ok_or_else(|| format!("JFLAP transition refers to unknown state {}", id).into())
+    };
+
+    automaton
+        .children()
+        .filter(|child| child.has_tag_name("transition"))
+        .map(|node| {
+            Ok(RawTransition {
+                from: state_of(child_text(&node, "from").unwrap_or(""))?,
+                to: state_of(child_text(&node, "to").unwrap_or(""))?,
+                read: per_tape(&node, "read", tapes)?
+                    .into_iter()
+                    .map(|name| symbols.number_of(name))
+                    .collect(),
+                write: per_tape(&node, "write", tapes)?
+                    .into_iter()
+                    .map(|name| symbols.number_of(name))
+                    .collect(),
+                moves: per_tape(&node, "move", tapes)?
+                    .into_iter()
+                    .map(parse_direction)
+                    .collect::<AppResult<Vec<HeadMoveDirection>>>()?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse(content: &str) -> AppResult<Vec<Transition>> {
+    Ok(parse_raw(content, Some(1))?
+        .into_iter()
+        .map(|raw| Transition {
+            state_before: raw.from,
+            state_after: raw.to,
+            tape_value_before: raw.read[0].clone(),
+            tape_value_after: raw.write[0].clone(),
+            tape_head_move_direction: raw.moves[0].clone(),
+        })
+        .collect())
+}
+
+pub fn parse_multi_tape(content: &str) -> AppResult<Vec<MultiTransition>> {
+    Ok(parse_raw(content, None)?
+        .into_iter()
+        .map(|raw| MultiTransition {
+            state_before: raw.from,
+            state_after: raw.to,
+            tape_values_before: raw.read,
+            tape_values_after: raw.write,
+            tape_head_move_directions: raw.moves,
+        })
+        .collect())
+}
+
+fn escaped(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// columns are BFS layers from `start`, with anything unreachable in one last column
+fn layout(states: &[State], raw_transitions: &[RawTransition]) -> HashMap<State, (usize, usize)> {
+    let mut layers: HashMap<&State, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+    if let Some(start) = states
+        .iter()
+        .find(|state| state.value() == std_states::START)
+    {
+        layers.insert(start, 0);
+        queue.push_back(start);
+    }
+    while let Some(state) = queue.pop_front() {
+        let layer = layers[state];
+        for raw in raw_transitions.iter().filter(|raw| &raw.from == state) {
+            if !layers.contains_key(&raw.to) {
+                layers.insert(&raw.to, layer + 1);
+                queue.push_back(&raw.to);
+            }
+        }
+    }
+    let unreachable_layer = layers.values().max().map_or(0, |layer| layer + 1);
+
+    let mut rows: HashMap<usize, usize> = HashMap::new();
+    states
+        .iter()
+        .map(|state| {
+            let layer = *layers.get(state).unwrap_or(&unreachable_layer);
+            let row = rows.entry(layer).or_insert(0);
+            *row += 1;
+            (
+                state.clone(),
+                (
+                    LAYOUT_COLUMN_WIDTH / 2 + layer * LAYOUT_COLUMN_WIDTH,
+                    LAYOUT_ROW_HEIGHT / 2 + (*row - 1) * LAYOUT_ROW_HEIGHT,
+                ),
+            )
+        })
+        .collect()
+}
+
+fn render_raw(raw_transitions: &[RawTransition], tapes: usize) -> AppResult<String> {
+    let states: Vec<State> = std::iter::once(State(std_states::START.to_string()))
+        .chain(
+            raw_transitions
+                .iter()
+                .flat_map(|raw| vec![raw.from.clone(), raw.to.clone()]),
+        )
+        .unique()
+        .collect();
+    let ids: HashMap<&State, usize> = states
+        .iter()
+        .enumerate()
+        .map(|(id, state)| (state, id))
+        .collect();
+    let positions = layout(&states, raw_transitions);
+
+    let large_symbols: BTreeSet<&TapeEntry> = raw_transitions
+        .iter()
+        .flat_map(|raw| raw.read.iter().chain(raw.write.iter()))
+        .filter(|entry| symbol_name(entry).is_err())
+        .collect();
+    let private_names: HashMap<&TapeEntry, String> = large_symbols
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let ch = std::char::from_u32(FIRST_PRIVATE_SYMBOL + idx as u32)
+                .ok_or("too many symbols for JFLAP")?;
+            Ok((*entry, ch.to_string()))
+        })
+        .collect::<AppResult<HashMap<&TapeEntry, String>>>()?;
+    let exported_symbol = |entry: &TapeEntry| -> AppResult<String> {
+        if entry == &TapeEntry::BLANK {
+            Ok(BLANK_NAME.to_string())
+        } else if let Some(name) = private_names.get(entry) {
+            Ok(name.clone())
+        } else {
+            Ok(symbol_name(entry)?.to_string())
+        }
+    };
+    let tape_attribute = |tape: usize| -> String {
+        if tapes > 1 {
+            format!(" tape=\"{}\"", tape + 1)
+        } else {
+            String::new()
+        }
+    };
+
+    let mut lines = vec![
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>".to_string(),
+        "<structure>".to_string(),
+        "\t<type>turing</type>".to_string(),
+    ];
+    if tapes > 1 {
+        lines.push(format!("\t<tapes>{}</tapes>", tapes));
+    }
+    if !private_names.is_empty() {
+        let legend = large_symbols
+            .iter()
+            .map(|entry| format!("{}={}", private_names[entry], entry))
+            .join(" ");
+        lines.push(format!("\t<!--{} {}-->", LEGEND_PREFIX, legend));
+    }
+    lines.push("\t<automaton>".to_string());
+
+    for state in states.iter() {
+        let id = ids[state];
+        let (x, y) = positions[state];
+        lines.push(format!(
+            "\t\t<block id=\"{}\" name=\"{}\">",
+            id,
+            escaped(state.value())
+        ));
+        lines.push(format!("\t\t\t<tag>Machine{}</tag>", id));
+        lines.push(format!("\t\t\t<x>{}.0</x>", x));
+        lines.push(format!("\t\t\t<y>{}.0</y>", y));
+        if state.value() == std_states::START {
+            lines.push("\t\t\t<initial/>".to_string());
+        }
+        if state.value() == std_states::ACCEPT {
+            lines.push("\t\t\t<final/>".to_string());
+        }
+        lines.push("\t\t</block>".to_string());
+    }
+
+    for raw in raw_transitions.iter() {
+        lines.push("\t\t<transition>".to_string());
+        lines.push(format!("\t\t\t<from>{}</from>", ids[&raw.from]));
+        lines.push(format!("\t\t\t<to>{}</to>", ids[&raw.to]));
+        for (tag, entries) in [("read", &raw.read), ("write", &raw.write)].iter() {
+            for (tape, entry) in entries.iter().enumerate() {
+                let symbol = exported_symbol(entry)?;
+                lines.push(if symbol.is_empty() {
+                    format!("\t\t\t<{}{}/>", tag, tape_attribute(tape))
+                } else {
+                    format!(
+                        "\t\t\t<{0}{1}>{2}</{0}>",
+                        tag,
+                        tape_attribute(tape),
+                        escaped(&symbol)
+                    )
+                });
+            }
+        }
+        for (tape, direction) in raw.moves.iter().enumerate() {
+            lines.push(format!(
+                "\t\t\t<move{}>{}</move>",
+                tape_attribute(tape),
+                direction_to_string(direction)
+            ));
+        }
+        lines.push("\t\t</transition>".to_string());
+    }
+
+    lines.push("\t</automaton>".to_string());
+    lines.push("</structure>".to_string());
+    Ok(lines.join("\n"))
+}
+
+pub fn render(transitions: &[Transition]) -> AppResult<String> {
+    let raw_transitions: Vec<RawTransition> = transitions
+        .iter()
+        .map(|trans| RawTransition {
+            from: trans.state_before.clone(),
+            to: trans.state_after.clone(),
+            read: vec![trans.tape_value_before.clone()],
+            write: vec![trans.tape_value_after.clone()],
+            moves: vec![trans.tape_head_move_direction.clone()],
+        })
+        .collect();
+    render_raw(&raw_transitions, 1)
+}
+
+pub fn render_multi_tape(transitions: &[MultiTransition]) -> AppResult<String> {
+    let tapes = transitions.first().map_or(1, |trans| trans.tape_count());
+    let raw_transitions: Vec<RawTransition> = transitions
+        .iter()
+        .map(|trans| RawTransition {
+            from: trans.state_before.clone(),
+            to: trans.state_after.clone(),
+            read: trans.tape_values_before.clone(),
+            write: trans.tape_values_after.clone(),
+            moves: trans.tape_head_move_directions.clone(),
+        })
+        .collect();
+    render_raw(&raw_transitions, tapes)
+}
@@ -0,0 +1,137 @@
+pub mod bbchallenge;
+pub mod jflap;
+pub mod morphett;
+pub mod structured;
+pub mod turingmachine_io;
+
+use crate::types::*;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MachineFormat {
+    Text,
+    Json,
+    Yaml,
+    Toml,
+    TuringMachineIo,
+    Morphett,
+    BbChallenge,
+    Jflap,
+}
+impl MachineFormat {
+    // anything we don't recognise is treated as the whitespace-separated `.tm` format
+    pub fn from_path(path: &str) -> Self {
+        let lowercase_path = path.to_lowercase();
+        if lowercase_path.ends_with(".tmio.yaml") || lowercase_path.ends_with(".tmio.yml") {
+            return MachineFormat::TuringMachineIo;
+        }
+
+        let extension = Path::new(&lowercase_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_string());
+        match extension.as_deref() {
+            Some("json") => MachineFormat::Json,
+            Some("yaml") | Some("yml") => MachineFormat::Yaml,
+            Some("toml") => MachineFormat::Toml,
+            Some("morphett") => MachineFormat::Morphett,
+            Some("bb") => MachineFormat::BbChallenge,
+            Some("jff") => MachineFormat::Jflap,
+            _ => MachineFormat::Text,
+        }
+    }
+}
+
+/* Simulators name their symbols with single characters. Digits and letters map onto
+the first 62 numbers, so our machines survive a round trip; any other name gets a fresh
+number past those. */
+const SYMBOL_CHARS: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn symbol_name(entry: &TapeEntry) -> AppResult<char> {
+    SYMBOL_CHARS
+        .chars()
+        .nth(entry.0 as usize)
+        .ok_or_else(|| format!("symbol {} can't be named with a single character", entry).into())
+}
+
+pub struct SymbolTable {
+    blank: String,
+    numbers: HashMap<String, TapeEntry>,
+    next_free: Number,
+}
+impl SymbolTable {
+    pub fn new(blank: &str) -> Self {
+        Self {
+            blank: blank.to_string(),
+            numbers: HashMap::new(),
+            next_free: SYMBOL_CHARS.len() as Number,
+        }
+    }
+
+    pub fn number_of(&mut self, name: &str) -> TapeEntry {
+        if name == self.blank {
+            return TapeEntry::BLANK;
+        }
+        if let Some(entry) = self.numbers.get(name) {
+            return entry.clone();
+        }
+        while self.numbers.values().any(|entry| entry.0 == self.next_free) {
+            self.next_free += 1;
+        }
+
+        let mut chars = name.chars();
+        let entry = match (chars.next(), chars.next()) {
+            (Some(ch), None) => SYMBOL_CHARS
+                .find(ch)
+                .filter(|&idx| idx != 0) // 0 is reserved for the blank
+                .map(|idx| TapeEntry(idx as Number)),
+            _ => None,
+        }
+        .unwrap_or_else(|| {
+            self.next_free += 1;
+            TapeEntry(self.next_free - 1)
+        });
+        self.numbers.insert(name.to_string(), entry.clone());
+        entry
+    }
+
+    // for formats that carry their own legend for symbols we can't name with a character
+    pub fn define(&mut self, name: &str, entry: TapeEntry) {
+        self.numbers.insert(name.to_string(), entry);
+    }
+}
+
+/* Renames a foreign machine's start and halting states to `start`, `accept` and `reject`.
+Any other state that happens to use one of those names is moved out of the way. */
+pub fn conventional_state_names(
+    states: &[String],
+    start: &str,
+    accepting: &[String],
+    rejecting: &[String],
+) -> HashMap<String, State> {
+    let reserved = [std_states::START, std_states::ACCEPT, std_states::REJECT];
+    let mut taken: HashSet<String> = states.iter().cloned().collect();
+    let mut names = HashMap::new();
+
+    for state in states {
+        let name = if state == start {
+            std_states::START.to_string()
+        } else if accepting.contains(state) {
+            std_states::ACCEPT.to_string()
+        } else if rejecting.contains(state) {
+            std_states::REJECT.to_string()
+        } else if reserved.contains(&state.as_str()) {
+            let mut name = format!("{}_", state);
+            while taken.contains(&name) {
+                name.push('_');
+            }
+            taken.insert(name.clone());
+            name
+        } else {
+            state.clone()
+        };
+        names.insert(state.clone(), State(name));
+    }
+    names
+}
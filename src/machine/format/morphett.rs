@@ -0,0 +1,220 @@
+/* Morphett's simulator (morphett.info/turing) text format:
+`<state> <symbol> <new symbol> <direction> <new state>` with `;` comments, `_` as the blank
+and `*` as a wildcard. It starts in state `0` and halts in any state starting with `halt`. */
+use crate::machine::component::transition::Transition;
+use crate::machine::format::{conventional_state_names, symbol_name, SymbolTable};
+use crate::types::*;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+const BLANK_NAME: &str = "_";
+const WILDCARD: &str = "*";
+const START_STATE: &str = "0";
+const HALT_PREFIX: &str = "halt";
+const ACCEPT_STATE: &str = "halt-accept";
+const REJECT_STATE: &str = "halt-reject";
+
+struct Rule {
+    state_before: String,
+    symbol_before: String,
+    symbol_after: String,
+    direction: HeadMoveDirection,
+    state_after: String,
+}
+impl Rule {
+    fn from_line(line: &str) -> AppResult<Option<Self>> {
+        let tokens: Vec<&str> = line
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .split_whitespace()
+            .collect();
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+        if tokens.len() < 5 {
+            return Err(format!("bad Morphett rule `{}`", line).into());
+        }
+
+        let direction = match tokens[3] {
+            "l" | "L" => HeadMoveDirection::Left,
+            "r" | "R" => HeadMoveDirection::Right,
+            WILDCARD => HeadMoveDirection::Stay,
+            other => return Err(format!("bad Morphett direction `{}`", other).into()),
+        };
+        Ok(Some(Self {
+            state_before: tokens[0].to_string(),
+            symbol_before: tokens[1].to_string(),
+            symbol_after: tokens[2].to_string(),
+            direction,
+            state_after: tokens[4].to_string(),
+        }))
+    }
+
+    // Morphett prefers exact matches over wildcards, and a wildcard symbol over a wildcard state
+    fn priority(&self) -> usize {
+        match (
+            self.state_before == WILDCARD,
+            self.symbol_before == WILDCARD,
+        ) {
+            (false, false) => 0,
+            (false, true) => 1,
+            (true, false) => 2,
+            (true, true) => 3,
+        }
+    }
+}
+
+fn is_halting(state: &str) -> bool {
+    state.starts_with(HALT_PREFIX)
+}
+
+pub fn parse(content: &str) -> AppResult<Vec<Transition>> {
+    let rules: Vec<Rule> = content
+        .lines()
+        .map(Rule::from_line)
+        .collect::<AppResult<Vec<Option<Rule>>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let states: Vec<String> = rules
+        .iter()
+        .flat_map(|rule| vec![rule.state_before.clone(), rule.state_after.clone()])
+        .filter(|state| state != WILDCARD)
+        .unique()
+        .collect();
+    // a wildcard symbol also has to cover input letters that the rules never mention
+    let wildcard_symbols = rules.iter().any(|rule| rule.symbol_before == WILDCARD);
+    let input_symbols = (1..=9)
+        .map(|digit: u32| digit.to_string())
+        .filter(|_| wildcard_symbols);
+    let symbols: Vec<String> = rules
+        .iter()
+        .flat_map(|rule| vec![rule.symbol_before.clone(), rule.symbol_after.clone()])
+        .chain(std::iter::once(BLANK_NAME.to_string()))
+        .chain(input_symbols)
+        .filter(|symbol| symbol != WILDCARD)
+        .unique()
+        .collect();
+
+    let (rejecting, accepting): (Vec<String>, Vec<String>) = states
+        .iter()
+        .filter(|state| is_halting(state))
+        .cloned()
+        .partition(|state| state == REJECT_STATE);
+    let names = conventional_state_names(&states, START_STATE, &accepting, &rejecting);
+    let mut symbol_table = SymbolTable::new(BLANK_NAME);
+
+    // the states and symbols each kind of rule is there for, to find the rules winning a case
+    let exact: HashSet<(&str, &str)> = rules
+        .iter()
+        .filter(|rule| rule.priority() == 0)
+        .map(|rule| (rule.state_before.as_str(), rule.symbol_before.as_str()))
+        .collect();
+    let any_symbol: HashSet<&str> = rules
+        .iter()
+        .filter(|rule| rule.priority() == 1)
+        .map(|rule| rule.state_before.as_str())
+        .collect();
+    let any_state: HashSet<&str> = rules
+        .iter()
+        .filter(|rule| rule.priority() == 2)
+        .map(|rule| rule.symbol_before.as_str())
+        .collect();
+    let winning_priority = |state: &str, symbol: &str| {
+        if exact.contains(&(state, symbol)) {
+            0
+        } else if any_symbol.contains(state) {
+            1
+        } else if any_state.contains(symbol) {
+            2
+        } else {
+            3
+        }
+    };
+
+    let mut transitions = vec![];
+    // keeping the order of the rules, expand each wildcard into the cases it actually wins
+    for rule in rules.iter() {
+        let covered_states: Vec<&String> = if rule.state_before == WILDCARD {
+            states.iter().collect()
+        } else {
+            vec![&rule.state_before]
+        };
+        let covered_symbols: Vec<&String> = if rule.symbol_before == WILDCARD {
+            symbols.iter().collect()
+        } else {
+            vec![&rule.symbol_before]
+        };
+        for &state in covered_states.iter().filter(|state| !is_halting(state)) {
+            for &symbol in covered_symbols.iter() {
+                if winning_priority(state, symbol) != rule.priority() {
+                    continue;
+                }
+                let symbol_after = if rule.symbol_after == WILDCARD {
+                    symbol
+                } else {
+                    &rule.symbol_after
+                };
+                let state_after = if rule.state_after == WILDCARD {
+                    state
+                } else {
+                    &rule.state_after
+                };
+                transitions.push(Transition {
+                    state_before: names[state].clone(),
+                    state_after: names[state_after].clone(),
+                    tape_value_before: symbol_table.number_of(symbol),
+                    tape_value_after: symbol_table.number_of(symbol_after),
+                    tape_head_move_direction: rule.direction.clone(),
+                });
+            }
+        }
+    }
+    Ok(transitions)
+}
+
+fn exported_state(state: &State) -> AppResult<String> {
+    match state.value() {
+        std_states::START => Ok(START_STATE.to_string()),
+        std_states::ACCEPT => Ok(ACCEPT_STATE.to_string()),
+        std_states::REJECT => Ok(REJECT_STATE.to_string()),
+        name if name == START_STATE || name == WILDCARD || is_halting(name) => Err(format!(
+            "state `{}` has a special meaning in the Morphett format",
+            name
+        )
+        .into()),
+        name if name.contains(';') => Err(format!("state `{}` contains a `;`", name).into()),
+        name => Ok(name.to_string()),
+    }
+}
+
+fn exported_symbol(entry: &TapeEntry) -> AppResult<String> {
+    if entry == &TapeEntry::BLANK {
+        Ok(BLANK_NAME.to_string())
+    } else {
+        Ok(symbol_name(entry)?.to_string())
+    }
+}
+
+pub fn render(transitions: &[Transition]) -> AppResult<String> {
+    transitions
+        .iter()
+        .map(|trans| {
+            Ok(format!(
+                "{} {} {} {} {}",
+                exported_state(&trans.state_before)?,
+                exported_symbol(&trans.tape_value_before)?,
+                exported_symbol(&trans.tape_value_after)?,
+                match trans.tape_head_move_direction {
+                    HeadMoveDirection::Left => "l",
+                    HeadMoveDirection::Right => "r",
+                    HeadMoveDirection::Stay => WILDCARD,
+                },
+                exported_state(&trans.state_after)?,
+            ))
+        })
+        .collect::<AppResult<Vec<String>>>()
+        .map(|lines| lines.join("\n"))
+}
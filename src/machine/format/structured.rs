@@ -0,0 +1,204 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::format::MachineFormat;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+fn default_tape_count() -> usize {
+    1
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TransitionDocument {
+    pub from: String,
+    pub read: Vec<Number>,
+    pub to: String,
+    pub write: Vec<Number>,
+    #[serde(rename = "move")]
+    pub moves: Vec<HeadMoveDirection>,
+}
+
+/* Machine as structured data. `states` and `alphabet` are optional on input, but when
+given every transition has to stay within them. States listed in `accept` / `reject`
+are renamed to our conventional `accept` / `reject` states. */
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct MachineDocument {
+    #[serde(default = "default_tape_count")]
+    pub tapes: usize,
+    #[serde(default)]
+    pub states: Vec<String>,
+    #[serde(default)]
+    pub alphabet: Vec<Number>,
+    #[serde(default)]
+    pub accept: Vec<String>,
+    #[serde(default)]
+    pub reject: Vec<String>,
+    pub transitions: Vec<TransitionDocument>,
+}
+
+impl MachineDocument {
+    fn from_transition_documents(tapes: usize, transitions: Vec<TransitionDocument>) -> Self {
+        let states: BTreeSet<String> = transitions
+            .iter()
+            .flat_map(|trans| vec![trans.from.clone(), trans.to.clone()])
+            .collect();
+        let mut alphabet: BTreeSet<Number> = transitions
+            .iter()
+            .flat_map(|trans| trans.read.iter().chain(trans.write.iter()).cloned())
+            .collect();
+        alphabet.insert(TapeEntry::BLANK.0);
+        let halting_states = |name: &str| -> Vec<String> {
+            states
+                .iter()
+                .filter(|s| s.as_str() == name)
+                .cloned()
+                .collect()
+        };
+
+        Self {
+            tapes,
+            accept: halting_states(std_states::ACCEPT),
+            reject: halting_states(std_states::REJECT),
+            states: states.iter().cloned().collect(),
+            alphabet: alphabet.into_iter().collect(),
+            transitions,
+        }
+    }
+
+    pub fn from_transitions(transitions: &[Transition]) -> Self {
+        Self::from_transition_documents(
+            1,
+            transitions
+                .iter()
+                .map(|trans| TransitionDocument {
+                    from: trans.state_before.to_string(),
+                    read: vec![trans.tape_value_before.0],
+                    to: trans.state_after.to_string(),
+                    write: vec![trans.tape_value_after.0],
+                    moves: vec![trans.tape_head_move_direction.clone()],
+                })
+                .collect(),
+        )
+    }
+
+    pub fn from_multi_tape_transitions(transitions: &[MultiTransition]) -> Self {
+        Self::from_transition_documents(
+            transitions.first().map_or(1, |trans| trans.tape_count()),
+            transitions
+                .iter()
+                .map(|trans| TransitionDocument {
+                    from: trans.state_before.to_string(),
+                    read: trans.tape_values_before.iter().map(|e| e.0).collect(),
+                    to: trans.state_after.to_string(),
+                    write: trans.tape_values_after.iter().map(|e| e.0).collect(),
+                    moves: trans.tape_head_move_directions.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    // input letters no transition mentions go into the alphabet as well
+    pub fn declaring_input(mut self, input_alphabet: &[TapeEntry]) -> Self {
+        let mut alphabet: BTreeSet<Number> = self.alphabet.into_iter().collect();
+        alphabet.extend(input_alphabet.iter().map(|letter| letter.0));
+        self.alphabet = alphabet.into_iter().collect();
+        self
+    }
+
+    fn state_renames(&self) -> HashMap<&str, &str> {
+        let accepting = self.accept.iter().map(|s| (s.as_str(), std_states::ACCEPT));
+        let rejecting = self.reject.iter().map(|s| (s.as_str(), std_states::REJECT));
+        accepting.chain(rejecting).collect()
+    }
+
+    // checks the document against its own declarations and yields transitions with renamed states
+    fn validated_transitions(&self, tapes: usize) -> AppResult<Vec<TransitionDocument>> {
+        if self.tapes != tapes {
+            return Err(format!(
+                "expected a {}-tape machine but found {} tapes",
+                tapes, self.tapes
+            )
+            .into());
+        }
+        let renames = self.state_renames();
+        let rename = |state: &str| renames.get(state).unwrap_or(&state).to_string();
+
+        self.transitions
+            .iter()
+            .map(|trans| {
+                if trans.read.len() != tapes
+                    || trans.write.len() != tapes
+                    || trans.moves.len() != tapes
+                {
+                    return Err(format!(
+                        "transition from `{}` doesn't describe {} tapes",
+                        trans.from, tapes
+                    )
+                    .into());
+                }
+                for state in [&trans.from, &trans.to].iter() {
+                    if !self.states.is_empty() && !self.states.contains(state) {
+                        return Err(format!("undeclared state `{}`", state).into());
+                    }
+                }
+                for symbol in trans.read.iter().chain(trans.write.iter()) {
+                    if !self.alphabet.is_empty() && !self.alphabet.contains(symbol) {
+                        return Err(format!("symbol {} is not in the alphabet", symbol).into());
+                    }
+                }
+                Ok(TransitionDocument {
+                    from: rename(&trans.from),
+                    to: rename(&trans.to),
+                    ..trans.clone()
+                })
+            })
+            .collect()
+    }
+
+    pub fn to_transitions(&self) -> AppResult<Vec<Transition>> {
+        Ok(self
+            .validated_transitions(1)?
+            .into_iter()
+            .map(|trans| Transition {
+                state_before: State(trans.from),
+                state_after: State(trans.to),
+                tape_value_before: TapeEntry(trans.read[0]),
+                tape_value_after: TapeEntry(trans.write[0]),
+                tape_head_move_direction: trans.moves[0].clone(),
+            })
+            .collect())
+    }
+
+    pub fn to_multi_tape_transitions(&self) -> AppResult<Vec<MultiTransition>> {
+        Ok(self
+            .validated_transitions(self.tapes)?
+            .into_iter()
+            .map(|trans| MultiTransition {
+                state_before: State(trans.from),
+                state_after: State(trans.to),
+                tape_values_before: trans.read.into_iter().map(TapeEntry).collect(),
+                tape_values_after: trans.write.into_iter().map(TapeEntry).collect(),
+                tape_head_move_directions: trans.moves,
+            })
+            .collect())
+    }
+
+    pub fn parse(content: &str, format: MachineFormat) -> AppResult<Self> {
+        Ok(match format {
+            MachineFormat::Json => serde_json::from_str(content)?,
+            MachineFormat::Yaml => serde_yaml::from_str(content)?,
+            MachineFormat::Toml => toml::from_str(content)?,
+            _ => return Err(format!("{:?} machines are not structured documents", format).into()),
+        })
+    }
+
+    pub fn render(&self, format: MachineFormat) -> AppResult<String> {
+        Ok(match format {
+            MachineFormat::Json => serde_json::to_string_pretty(self)?,
+            MachineFormat::Yaml => serde_yaml::to_string(self)?,
+            MachineFormat::Toml => toml::to_string(self)?,
+            _ => return Err(format!("{:?} machines are not structured documents", format).into()),
+        })
+    }
+}
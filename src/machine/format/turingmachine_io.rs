@@ -0,0 +1,252 @@
+/* turingmachine.io YAML. The simulator only moves left or right and halts whenever there
+is no transition, so stay moves are exported as a step right and back, and states that
+are dead ends in our machine are exported as `reject`. Its tape is infinite in both
+directions, which we can't do anything about. */
+use crate::machine::component::transition::Transition;
+use crate::machine::format::{conventional_state_names, symbol_name, SymbolTable};
+use crate::types::*;
+use itertools::Itertools;
+use serde_yaml::Value;
+use std::collections::BTreeSet;
+
+const BLANK_NAME: &str = "0";
+const TABLE_KEY: &str = "table";
+
+fn scalar_name(value: &Value) -> AppResult<String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        _ => Err(format!("expected a symbol or state name but found {:?}", value).into()),
+    }
+}
+
+fn direction_from_key(key: &str) -> Option<HeadMoveDirection> {
+    match key {
+        "L" => Some(HeadMoveDirection::Left),
+        "R" => Some(HeadMoveDirection::Right),
+        _ => None,
+    }
+}
+
+struct RawTransition {
+    state_before: String,
+    symbol_before: String,
+    state_after: String,
+    symbol_after: String,
+    direction: HeadMoveDirection,
+}
+
+fn parse_action(state: &str, symbol: &str, action: &Value) -> AppResult<RawTransition> {
+    let (symbol_after, direction, state_after) = match action {
+        Value::String(key) => (
+            symbol.to_string(),
+            direction_from_key(key),
+            state.to_string(),
+        ),
+        Value::Mapping(mapping) => {
+            let symbol_after = match mapping.get(&Value::String("write".to_string())) {
+                Some(write) => scalar_name(write)?,
+                None => symbol.to_string(),
+            };
+            let (direction, state_after) = mapping
+                .iter()
+                .filter_map(|(key, value)| {
+                    let direction = direction_from_key(key.as_str()?)?;
+                    Some((Some(direction), value))
+                })
+                .next()
+                .ok_or_else(|| format!("transition from `{}` doesn't move", state))?;
+            let state_after = match state_after {
+                Value::Null => state.to_string(),
+                other => scalar_name(other)?,
+            };
+            (symbol_after, direction, state_after)
+        }
+        _ => (symbol.to_string(), None, state.to_string()),
+    };
+
+    Ok(RawTransition {
+        state_before: state.to_string(),
+        symbol_before: symbol.to_string(),
+        state_after,
+        symbol_after,
+        direction: direction
+            .ok_or_else(|| format!("bad action {:?} in state `{}`", action, state))?,
+    })
+}
+
+pub fn looks_like(content: &str) -> bool {
+    serde_yaml::from_str::<Value>(content)
+        .map(|document| document.get(TABLE_KEY).is_some())
+        .unwrap_or(false)
+}
+
+pub fn parse(content: &str) -> AppResult<Vec<Transition>> {
+    let document: Value = serde_yaml::from_str(content)?;
+    let blank = scalar_name(document.get("blank").ok_or("missing `blank`")?)?;
+    let start = scalar_name(document.get("start state").ok_or("missing `start state`")?)?;
+    let table = document
+        .get(TABLE_KEY)
+        .and_then(|table| table.as_mapping())
+        .ok_or("missing `table`")?;
+
+    let mut states = vec![];
+    let mut halting_states = vec![];
+    let mut raw_transitions = vec![];
+    for (state, body) in table.iter() {
+        let state = scalar_name(state)?;
+        states.push(state.clone());
+        let body = match body {
+            Value::Mapping(body) if !body.is_empty() => body,
+            _ => {
+                halting_states.push(state);
+                continue;
+            }
+        };
+        for (symbols, action) in body.iter() {
+            let symbols = match symbols {
+                Value::Sequence(symbols) => symbols.iter().map(scalar_name).collect(),
+                symbol => scalar_name(symbol).map(|symbol| vec![symbol]),
+            }?;
+            for symbol in symbols {
+                raw_transitions.push(parse_action(&state, &symbol, action)?);
+            }
+        }
+    }
+
+    let (rejecting, accepting): (Vec<String>, Vec<String>) = halting_states
+        .into_iter()
+        .partition(|state| state == std_states::REJECT);
+    let names = conventional_state_names(&states, &start, &accepting, &rejecting);
+    let rename = |state: &str| -> AppResult<State> {
+        names
+            .get(state)
+            .cloned()
+            .ok_or_else(|| format!("state `{}` is missing from the table", state).into())
+    };
+
+    let mut symbols = SymbolTable::new(&blank);
+    raw_transitions
+        .into_iter()
+        .map(|raw| {
+            Ok(Transition {
+                state_before: rename(&raw.state_before)?,
+                state_after: rename(&raw.state_after)?,
+                tape_value_before: symbols.number_of(&raw.symbol_before),
+                tape_value_after: symbols.number_of(&raw.symbol_after),
+                tape_head_move_direction: raw.direction,
+            })
+        })
+        .collect()
+}
+
+fn quoted(name: &str) -> String {
+    format!("'{}'", name.replace('\'', "''"))
+}
+
+fn quoted_symbol(entry: &TapeEntry) -> AppResult<String> {
+    Ok(quoted(&symbol_name(entry)?.to_string()))
+}
+
+fn stay_return_state(state: &State) -> State {
+    State(format!("{}~back", state.value()))
+}
+
+pub fn render(transitions: &[Transition]) -> AppResult<String> {
+    let states_with_transitions: BTreeSet<&State> = transitions
+        .iter()
+        .map(|trans| &trans.state_before)
+        .collect();
+    let is_halting =
+        |state: &State| state.value() == std_states::ACCEPT || state.value() == std_states::REJECT;
+    let exported_target = |state: &State| -> State {
+        if is_halting(state) || states_with_transitions.contains(state) {
+            state.clone()
+        } else {
+            State(std_states::REJECT.to_string())
+        }
+    };
+
+    let mut alphabet: BTreeSet<TapeEntry> = transitions
+        .iter()
+        .flat_map(|trans| {
+            vec![
+                trans.tape_value_before.clone(),
+                trans.tape_value_after.clone(),
+            ]
+        })
+        .collect();
+    alphabet.insert(TapeEntry::BLANK);
+    let alphabet_key = format!(
+        "[{}]",
+        alphabet
+            .iter()
+            .map(quoted_symbol)
+            .collect::<AppResult<Vec<String>>>()?
+            .join(", ")
+    );
+
+    let mut lines = vec![
+        format!("blank: {}", quoted(BLANK_NAME)),
+        format!("start state: {}", quoted(std_states::START)),
+        "table:".to_string(),
+    ];
+    let mut halting_targets: BTreeSet<State> = BTreeSet::new();
+    let mut return_targets: BTreeSet<State> = BTreeSet::new();
+
+    for state in transitions.iter().map(|trans| &trans.state_before).unique() {
+        lines.push(format!("  {}:", quoted(state.value())));
+        let mut seen_symbols = BTreeSet::new();
+        for trans in transitions
+            .iter()
+            .filter(|trans| &trans.state_before == state)
+        {
+            if !seen_symbols.insert(&trans.tape_value_before) {
+                return Err(format!(
+                    "turingmachine.io only runs deterministic machines, but state `{}` has several transitions on {}",
+                    state, trans.tape_value_before
+                )
+                .into());
+            }
+            let target = exported_target(&trans.state_after);
+            let (direction, target) = match trans.tape_head_move_direction {
+                HeadMoveDirection::Stay => {
+                    return_targets.insert(target.clone());
+                    ("R", stay_return_state(&target))
+                }
+                HeadMoveDirection::Left => ("L", target),
+                HeadMoveDirection::Right => ("R", target),
+            };
+            if is_halting(&target) {
+                halting_targets.insert(target.clone());
+            }
+            lines.push(format!(
+                "    {}: {{write: {}, {}: {}}}",
+                quoted_symbol(&trans.tape_value_before)?,
+                quoted_symbol(&trans.tape_value_after)?,
+                direction,
+                quoted(target.value())
+            ));
+        }
+    }
+
+    for target in return_targets.iter() {
+        lines.push(format!("  {}:", quoted(stay_return_state(target).value())));
+        lines.push(format!(
+            "    {}: {{L: {}}}",
+            alphabet_key,
+            quoted(target.value())
+        ));
+        if is_halting(target) {
+            halting_targets.insert(target.clone());
+        }
+    }
+    for state in halting_targets.iter() {
+        if !states_with_transitions.contains(state) {
+            lines.push(format!("  {}:", quoted(state.value())));
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
@@ -0,0 +1,37 @@
+use crate::machine::component::transition::Transition;
+use crate::types::*;
+use std::collections::BTreeSet;
+
+/* The letters passes over one-tape machines need to cover. They all take an `input_alphabet`
+of letters the input may hold besides those the transitions mention: a letter no
+transition reads still ends up on the tape when it's given in the input. */
+
+/* The letters a run can get past on the input: the declared ones, and the digits `read` by
+the machine on its input tape - the input is given in digits, and a letter no transition
+reads ends every run reaching it. */
+pub fn input_letters<'a>(
+    read: impl IntoIterator<Item = &'a TapeEntry>,
+    input_alphabet: &[TapeEntry],
+) -> BTreeSet<TapeEntry> {
+    let mut letters: BTreeSet<TapeEntry> = input_alphabet.iter().cloned().collect();
+    letters.extend(
+        read.into_iter()
+            .filter(|letter| (1..10).contains(&letter.0))
+            .cloned(),
+    );
+    letters
+}
+
+// the letters that can turn up on the tape: blank, the input and whatever gets read or written
+pub fn tape_letters(
+    transitions: &[Transition],
+    input_alphabet: &[TapeEntry],
+) -> BTreeSet<TapeEntry> {
+    let mut letters: BTreeSet<TapeEntry> = input_alphabet.iter().cloned().collect();
+    letters.insert(TapeEntry::BLANK);
+    for trans in transitions {
+        letters.insert(trans.tape_value_before.clone());
+        letters.insert(trans.tape_value_after.clone());
+    }
+    letters
+}
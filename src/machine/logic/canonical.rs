@@ -0,0 +1,240 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::alphabet;
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+/* A machine with its states and letters renamed in the order a walk from `start` meets them,
+so that machines differing only in how they name things, or in the order of their
+transitions, come out the same. The walk goes breadth first; the transitions of a state are
+taken in the order of the letters they read, those already met first, then by where they
+move. Where that doesn't decide, states and letters are told apart by their colours: classes
+refined from what their transitions look like until the classes stay the same. States or
+letters sharing a colour look alike however far the machine is followed, so which of them
+goes first only matters for machines built to fool the refinement.
+
+`start`, `accept` and `reject` keep their names, the other states become `q1`, `q2`, ...
+Blank and the input letters - those declared, or the digits read when none are - keep their
+numbers too, as renaming those would change the words accepted; the other letters get the
+numbers after the largest input letter. Parts no run from `start` can reach are left out. */
+pub struct CanonicalForm {
+    transitions: Vec<Transition>,
+    state_names: BTreeMap<State, State>,
+    letters: BTreeMap<TapeEntry, TapeEntry>,
+}
+
+fn is_kept(state: &State) -> bool {
+    [std_states::START, std_states::ACCEPT, std_states::REJECT].contains(&state.value())
+}
+
+// numbers the signatures in their order, so that the numbers only depend on what they say
+fn numbered<K: Ord, S: Ord + Clone>(signatures: BTreeMap<K, S>) -> (BTreeMap<K, usize>, usize) {
+    let distinct: BTreeSet<S> = signatures.values().cloned().collect();
+    let number: BTreeMap<S, usize> = distinct.into_iter().zip(0..).collect();
+    let numbers = signatures
+        .into_iter()
+        .map(|(key, signature)| (key, number[&signature]))
+        .collect();
+    (numbers, number.len())
+}
+
+type Colours<'a> = (BTreeMap<&'a State, usize>, BTreeMap<&'a TapeEntry, usize>);
+
+/* Colours the states and letters of the transitions. At first only the states and letters
+keeping their names stand out, then each one gets told apart by the colours around it in the
+transitions it's part of, until no class splits any more. */
+fn colours<'a>(transitions: &'a [Transition], input_letters: &BTreeSet<TapeEntry>) -> Colours<'a> {
+    let mut state_names: BTreeMap<&State, Option<&str>> = BTreeMap::new();
+    let mut letter_names: BTreeMap<&TapeEntry, Option<Number>> = BTreeMap::new();
+    for trans in transitions {
+        for state in [&trans.state_before, &trans.state_after].iter() {
+            state_names.insert(state, Some(state.value()).filter(|_| is_kept(state)));
+        }
+        for letter in [&trans.tape_value_before, &trans.tape_value_after].iter() {
+            let kept = **letter == TapeEntry::BLANK || input_letters.contains(letter);
+            letter_names.insert(letter, Some(letter.0).filter(|_| kept));
+        }
+    }
+    let (mut states, mut state_count) = numbered(state_names);
+    let (mut letters, mut letter_count) = numbered(letter_names);
+
+    loop {
+        let mut state_signatures: BTreeMap<&State, (usize, Vec<_>)> =
+            states.iter().map(|(&k, &c)| (k, (c, vec![]))).collect();
+        let mut letter_signatures: BTreeMap<&TapeEntry, (usize, Vec<_>)> =
+            letters.iter().map(|(&k, &c)| (k, (c, vec![]))).collect();
+        for trans in transitions {
+            let before = states[&trans.state_before];
+            let after = states[&trans.state_after];
+            let read = letters[&trans.tape_value_before];
+            let written = letters[&trans.tape_value_after];
+            let dir = &trans.tape_head_move_direction;
+            let mut add_state = |state, signature| {
+                state_signatures.get_mut(state).unwrap().1.push(signature);
+            };
+            add_state(&trans.state_before, (true, read, written, dir, after));
+            add_state(&trans.state_after, (false, read, written, dir, before));
+            let mut add_letter = |letter, signature| {
+                letter_signatures.get_mut(letter).unwrap().1.push(signature);
+            };
+            add_letter(
+                &trans.tape_value_before,
+                (true, before, after, dir, written),
+            );
+            add_letter(&trans.tape_value_after, (false, before, after, dir, read));
+        }
+        for (_, signature) in state_signatures
+            .values_mut()
+            .chain(letter_signatures.values_mut())
+        {
+            signature.sort();
+        }
+
+        let (refined_states, refined_state_count) = numbered(state_signatures);
+        let (refined_letters, refined_letter_count) = numbered(letter_signatures);
+        states = refined_states;
+        letters = refined_letters;
+        // classes only ever split, so the same counts mean the same classes
+        if (refined_state_count, refined_letter_count) == (state_count, letter_count) {
+            return (states, letters);
+        }
+        state_count = refined_state_count;
+        letter_count = refined_letter_count;
+    }
+}
+
+impl CanonicalForm {
+    pub fn new(transitions: &[Transition], input_alphabet: &[TapeEntry]) -> Self {
+        // a declared alphabet says which digits are working letters, which can be renamed
+        let input_letters = if input_alphabet.is_empty() {
+            let read = transitions.iter().map(|trans| &trans.tape_value_before);
+            alphabet::input_letters(read, &[])
+        } else {
+            input_alphabet.iter().cloned().collect()
+        };
+        let mut outgoing: HashMap<&State, Vec<&Transition>> = HashMap::new();
+        for trans in transitions {
+            outgoing.entry(&trans.state_before).or_default().push(trans);
+        }
+
+        let (state_colours, letter_colours) = colours(transitions, &input_letters);
+        let start = State(std_states::START.to_string());
+        let mut order: HashMap<State, usize> = HashMap::new();
+        let mut state_names: BTreeMap<State, State> = BTreeMap::new();
+        let mut letters: BTreeMap<TapeEntry, TapeEntry> = input_letters
+            .iter()
+            .chain(Some(&TapeEntry::BLANK))
+            .map(|letter| (letter.clone(), letter.clone()))
+            .collect();
+        let mut next_letter = letters.keys().max().unwrap().0 + 1;
+        let mut renamed_count = 0;
+        let mut canonical: Vec<Transition> = vec![];
+
+        let mut queue: VecDeque<State> = VecDeque::new();
+        order.insert(start.clone(), 0);
+        state_names.insert(start.clone(), start.clone());
+        queue.push_back(start);
+        while let Some(state) = queue.pop_front() {
+            let mut choices = outgoing.get(&state).cloned().unwrap_or_default();
+            // letters and states met already come first, in the order they were met in
+            let letter_key = |letter: &TapeEntry| {
+                let met = letters.get(letter).map_or(Number::MAX, |l| l.0);
+                (met, letter_colours[letter])
+            };
+            let state_key = |state: &State| {
+                let met = order.get(state).cloned().unwrap_or(usize::MAX);
+                (met, state_colours[state])
+            };
+            choices.sort_by_cached_key(|trans| {
+                (
+                    letter_key(&trans.tape_value_before),
+                    trans.tape_head_move_direction.clone(),
+                    letter_key(&trans.tape_value_after),
+                    state_key(&trans.state_after),
+                )
+            });
+
+            for trans in choices {
+                for letter in [&trans.tape_value_before, &trans.tape_value_after].iter() {
+                    if !letters.contains_key(letter) {
+                        letters.insert((*letter).clone(), TapeEntry(next_letter));
+                        next_letter += 1;
+                    }
+                }
+                let after = &trans.state_after;
+                if !order.contains_key(after) {
+                    let name = if is_kept(after) {
+                        after.clone()
+                    } else {
+                        renamed_count += 1;
+                        State(format!("q{}", renamed_count))
+                    };
+                    order.insert(after.clone(), order.len());
+                    state_names.insert(after.clone(), name);
+                    queue.push_back(after.clone());
+                }
+                canonical.push(Transition {
+                    state_before: state_names[&state].clone(),
+                    tape_value_before: letters[&trans.tape_value_before].clone(),
+                    state_after: state_names[after].clone(),
+                    tape_value_after: letters[&trans.tape_value_after].clone(),
+                    tape_head_move_direction: trans.tape_head_move_direction.clone(),
+                });
+            }
+        }
+
+        // a numbering of the renamed states, to sort by
+        let position: HashMap<&State, usize> = state_names
+            .iter()
+            .map(|(original, renamed)| (renamed, order[original]))
+            .collect();
+        canonical.sort_by_key(|trans| {
+            (
+                position[&trans.state_before],
+                trans.tape_value_before.clone(),
+                position[&trans.state_after],
+                trans.tape_value_after.clone(),
+                trans.tape_head_move_direction.clone(),
+            )
+        });
+        canonical.dedup();
+
+        Self {
+            transitions: canonical,
+            state_names,
+            letters,
+        }
+    }
+
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    // the name each state reachable from `start` got
+    pub fn state_names(&self) -> &BTreeMap<State, State> {
+        &self.state_names
+    }
+
+    // the number each letter got: the input letters and those the walk met
+    pub fn letters(&self) -> &BTreeMap<TapeEntry, TapeEntry> {
+        &self.letters
+    }
+
+    /* FNV-1a of the text form. Unlike the hashers of the standard library it's the same on
+    every platform and version, so hashes can be kept around and compared later. */
+    pub fn hash(&self) -> u64 {
+        self.to_string()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+}
+impl fmt::Display for CanonicalForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for trans in self.transitions.iter() {
+            writeln!(f, "{}", trans)?;
+        }
+        Ok(())
+    }
+}
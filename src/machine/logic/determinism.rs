@@ -0,0 +1,83 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// a transition is picked by what the machine is in and what its heads currently see
+pub trait ReadCondition: Clone + PartialEq + fmt::Display {
+    type Key: Ord;
+
+    fn read_key(&self) -> Self::Key;
+    fn describe_read(&self) -> String;
+}
+impl ReadCondition for Transition {
+    type Key = (State, TapeEntry);
+
+    fn read_key(&self) -> Self::Key {
+        (self.state_before.clone(), self.tape_value_before.clone())
+    }
+    fn describe_read(&self) -> String {
+        format!(
+            "state `{}` reading {}",
+            self.state_before, self.tape_value_before
+        )
+    }
+}
+impl ReadCondition for MultiTransition {
+    type Key = (State, Vec<TapeEntry>);
+
+    fn read_key(&self) -> Self::Key {
+        (self.state_before.clone(), self.tape_values_before.clone())
+    }
+    fn describe_read(&self) -> String {
+        format!(
+            "state `{}` reading {}",
+            self.state_before,
+            self.tape_values_before
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConflictGroup<T> {
+    pub transitions: Vec<T>,
+}
+impl<T: ReadCondition> fmt::Display for ConflictGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} has {} transitions:",
+            self.transitions[0].describe_read(),
+            self.transitions.len()
+        )?;
+        for trans in self.transitions.iter() {
+            write!(f, "\n    {}", trans)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn conflicting_transitions<T: ReadCondition>(transitions: &[T]) -> Vec<ConflictGroup<T>> {
+    let mut groups: BTreeMap<T::Key, Vec<T>> = BTreeMap::new();
+    for trans in transitions {
+        let group = groups.entry(trans.read_key()).or_default();
+        // exact duplicates lead to the same configuration, so they are not a real choice
+        if !group.contains(trans) {
+            group.push(trans.clone());
+        }
+    }
+    groups
+        .into_values()
+        .map(|transitions| ConflictGroup { transitions })
+        .filter(|group| group.transitions.len() > 1)
+        .collect()
+}
+
+pub fn is_deterministic<T: ReadCondition>(transitions: &[T]) -> bool {
+    conflicting_transitions(transitions).is_empty()
+}
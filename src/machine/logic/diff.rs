@@ -0,0 +1,161 @@
+use crate::machine::classic::{ClassicMachine, Config};
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::equivalence::{BoundedEquivalence, Comparison, Verdict};
+use crate::machine::multi_tape::Outcome;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+    Added(Transition),
+    Removed(Transition),
+    // the only transition for a state and letter, replaced by another
+    Changed {
+        before: Transition,
+        after: Transition,
+    },
+}
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added(trans) => write!(f, "added `{}`", trans),
+            Change::Removed(trans) => write!(f, "removed `{}`", trans),
+            Change::Changed { before, after } => {
+                write!(f, "changed `{}` to `{}`", before, after)
+            }
+        }
+    }
+}
+
+type ChoicesByKey<'a> = BTreeMap<(&'a State, &'a TapeEntry), Vec<&'a Transition>>;
+
+fn by_state_and_letter(transitions: &[Transition]) -> ChoicesByKey<'_> {
+    let mut choices: ChoicesByKey = BTreeMap::new();
+    for trans in transitions {
+        let same = choices
+            .entry((&trans.state_before, &trans.tape_value_before))
+            .or_default();
+        if !same.contains(&trans) {
+            same.push(trans);
+        }
+    }
+    choices
+}
+
+/* The transitions of `second` that aren't in `first` and the other way round, taking states
+with the same name for the same state. Where both have a single transition for a state and
+letter, the two make up a change. */
+pub fn transition_changes(first: &[Transition], second: &[Transition]) -> Vec<Change> {
+    let first_choices = by_state_and_letter(first);
+    let second_choices = by_state_and_letter(second);
+    let keys: BTreeSet<_> = first_choices.keys().chain(second_choices.keys()).collect();
+    let missing_from = |choices: &[&Transition], others: &[&Transition]| -> Vec<Transition> {
+        let mut missing: Vec<Transition> = choices
+            .iter()
+            .filter(|trans| !others.contains(trans))
+            .map(|&trans| trans.clone())
+            .collect();
+        missing.sort_by_cached_key(Transition::to_string);
+        missing
+    };
+
+    let mut changes = vec![];
+    for key in keys {
+        let before = first_choices.get(key).map_or(&[][..], |choices| choices);
+        let after = second_choices.get(key).map_or(&[][..], |choices| choices);
+        let mut removed = missing_from(before, after);
+        let mut added = missing_from(after, before);
+        if before.len() == 1 && after.len() == 1 && removed.len() == 1 {
+            changes.push(Change::Changed {
+                before: removed.remove(0),
+                after: added.remove(0),
+            });
+            continue;
+        }
+        changes.extend(removed.into_iter().map(Change::Removed));
+        changes.extend(added.into_iter().map(Change::Added));
+    }
+    changes
+}
+
+// a word one of two machines accepts and the other rejects, with the run that accepts it
+pub struct Disagreement {
+    pub word: String,
+    pub accepted_by_first: bool,
+    pub witness: Vec<Config>,
+}
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (first, second) = if self.accepted_by_first {
+            ("accepts", "rejects")
+        } else {
+            ("rejects", "accepts")
+        };
+        writeln!(
+            f,
+            "`{}`: the first machine {} but the second one {}",
+            self.word, first, second
+        )?;
+        for (step, cfg) in self.witness.iter().enumerate() {
+            writeln!(f, "{}: {} {}", step, cfg.state, cfg.tape)?;
+        }
+        Ok(())
+    }
+}
+
+/* How two machines did on the words: the first word, shortest first, that one of them accepts
+and the other rejects, and the words before it where a machine ran out of steps, which can't
+tell either way. */
+pub struct WordCheck {
+    pub disagreement: Option<Disagreement>,
+    pub undecided: Vec<Comparison>,
+}
+
+// runs both machines on the words up to the first disagreement, `step_limit` steps per word
+pub fn first_disagreement(
+    first: &[Transition],
+    second: &[Transition],
+    words: &[String],
+    step_limit: Number,
+) -> AppResult<WordCheck> {
+    let multi_tape = |transitions: &[Transition]| -> Vec<MultiTransition> {
+        transitions
+            .iter()
+            .cloned()
+            .map(MultiTransition::from)
+            .collect()
+    };
+    let check = BoundedEquivalence::new(multi_tape(first), multi_tape(second), step_limit)?;
+    let mut undecided = vec![];
+    for word in words {
+        let comparison = check.compare(word);
+        match comparison.verdict() {
+            Verdict::Agree => continue,
+            Verdict::Undecided => {
+                undecided.push(comparison);
+                continue;
+            }
+            Verdict::Disagree => (),
+        }
+        // the witness is only worth keeping every step's configs for once it's needed
+        let accepted_by_first = matches!(comparison.first, Outcome::Accepted(_));
+        let accepting = if accepted_by_first { first } else { second };
+        let witness = ClassicMachine::new(accepting.to_vec(), step_limit, word.clone())?
+            .accepting_run()
+            .unwrap();
+        return Ok(WordCheck {
+            disagreement: Some(Disagreement {
+                word: word.clone(),
+                accepted_by_first,
+                witness,
+            }),
+            undecided,
+        });
+    }
+    Ok(WordCheck {
+        disagreement: None,
+        undecided,
+    })
+}
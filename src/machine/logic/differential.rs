@@ -0,0 +1,157 @@
+use crate::machine::classic::ClassicMachine;
+use crate::machine::logic::alphabet;
+use crate::machine::multi_tape::MultiTapeMachine;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::machine::translation::translator::{MachineTranslator, Strategy};
+use crate::types::*;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Finding {
+    Disagreement {
+        word: String,
+        accepted_natively: bool,
+    },
+    Blowup {
+        word: String,
+        source_steps: Number,
+        translated_steps: Number,
+        bound: Number,
+    },
+}
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Finding::Disagreement {
+                word,
+                accepted_natively,
+            } => {
+                let (native, translated) = if *accepted_natively {
+                    ("accepts", "rejects")
+                } else {
+                    ("rejects", "accepts")
+                };
+                write!(
+                    f,
+                    "`{}`: the multi-tape machine {} but the translated one {}",
+                    word, native, translated
+                )
+            }
+            Finding::Blowup {
+                word,
+                source_steps,
+                translated_steps,
+                bound,
+            } => write!(
+                f,
+                "`{}`: {} steps took {} translated steps, more than the bound of {}",
+                word, source_steps, translated_steps, bound
+            ),
+        }
+    }
+}
+
+/* Runs words through a multi-tape machine and through its translation side by side.
+Every translated run needs at least one step per simulated step, so a translated run
+accepting in `t` steps is only compared against a native run of up to `t` steps. */
+pub struct DifferentialTest {
+    source: MultiTapeMachine,
+    translated: ClassicMachine,
+    strategy: Strategy,
+    tapes: usize,
+    step_limit: Number,
+}
+impl DifferentialTest {
+    pub fn new(
+        source: Vec<MultiTransition>,
+        input_alphabet: &[TapeEntry],
+        strategy: Strategy,
+        step_limit: Number,
+    ) -> AppResult<Self> {
+        let translated = MachineTranslator::new(source.clone(), strategy)?
+            .with_input_alphabet(input_alphabet)?
+            .translation()
+            .transitions()
+            .collect();
+        let tapes = source.first().map_or(2, |trans| trans.tape_count());
+        Ok(Self {
+            source: MultiTapeMachine::new(source, step_limit, String::new())?,
+            translated: ClassicMachine::new(translated, step_limit, String::new())?,
+            strategy,
+            tapes,
+            step_limit,
+        })
+    }
+
+    fn source_run_length(&self, word: &str, step_limit: Number) -> Option<Number> {
+        self.source
+            .with_input(step_limit, word.to_string())
+            .accepting_run_length()
+    }
+
+    fn translated_run_length(&self, word: &str, step_limit: Number) -> Option<Number> {
+        self.translated
+            .with_input(step_limit, word.to_string())
+            .accepting_run_length()
+    }
+
+    pub fn check(&self, word: &str) -> Option<Finding> {
+        let translated_limit = self.strategy.run_length_bound(self.tapes, self.step_limit);
+        let translated_steps = self.translated_run_length(word, translated_limit);
+        let source_limit =
+            translated_steps.map_or(self.step_limit, |steps| steps.max(self.step_limit));
+        let source_steps = self.source_run_length(word, source_limit);
+
+        match (source_steps, translated_steps) {
+            (Some(source_steps), Some(translated_steps)) => {
+                let bound = self.strategy.run_length_bound(self.tapes, source_steps);
+                if translated_steps > bound {
+                    Some(Finding::Blowup {
+                        word: word.to_string(),
+                        source_steps,
+                        translated_steps,
+                        bound,
+                    })
+                } else {
+                    None
+                }
+            }
+            (None, None) => None,
+            (source_steps, _) => Some(Finding::Disagreement {
+                word: word.to_string(),
+                accepted_natively: source_steps.is_some(),
+            }),
+        }
+    }
+}
+
+pub fn alphabet_letters(alphabet: &[TapeEntry]) -> Vec<char> {
+    alphabet
+        .iter()
+        .filter_map(|letter| std::char::from_digit(letter.0 as u32, 10))
+        .collect()
+}
+
+// the letters a run can get past on the input, which is on the first tape
+pub fn input_alphabet(transitions: &[MultiTransition]) -> Vec<char> {
+    let read = transitions.iter().map(|trans| &trans.tape_values_before[0]);
+    let letters: Vec<TapeEntry> = alphabet::input_letters(read, &[]).into_iter().collect();
+    alphabet_letters(&letters)
+}
+
+pub fn words_up_to(alphabet: &[char], length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..length {
+        last = last
+            .iter()
+            .flat_map(|word| {
+                alphabet
+                    .iter()
+                    .map(move |letter| format!("{}{}", word, letter))
+            })
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
@@ -0,0 +1,76 @@
+use crate::machine::multi_tape::{MultiTapeMachine, Outcome};
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Verdict {
+    Agree,
+    // one machine accepts and the other rejects
+    Disagree,
+    // a machine ran out of steps, so it's not known whether they agree
+    Undecided,
+}
+
+// how two machines did on a word
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub word: String,
+    pub first: Outcome,
+    pub second: Outcome,
+}
+impl Comparison {
+    pub fn verdict(&self) -> Verdict {
+        match (self.first, self.second) {
+            (Outcome::OutOfSteps, _) | (_, Outcome::OutOfSteps) => Verdict::Undecided,
+            (Outcome::Accepted(_), Outcome::Accepted(_))
+            | (Outcome::Rejected(_), Outcome::Rejected(_)) => Verdict::Agree,
+            _ => Verdict::Disagree,
+        }
+    }
+}
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}`: the first machine {}, the second one {}",
+            self.word, self.first, self.second
+        )
+    }
+}
+
+/* Checks two machines against each other on words given one by one, each machine having at
+most `step_limit` steps per word. The machines can have different numbers of tapes, the
+input going on the first one. */
+pub struct BoundedEquivalence {
+    first: MultiTapeMachine,
+    second: MultiTapeMachine,
+    step_limit: Number,
+}
+impl BoundedEquivalence {
+    pub fn new(
+        first: Vec<MultiTransition>,
+        second: Vec<MultiTransition>,
+        step_limit: Number,
+    ) -> AppResult<Self> {
+        Ok(Self {
+            first: MultiTapeMachine::new(first, step_limit, String::new())?,
+            second: MultiTapeMachine::new(second, step_limit, String::new())?,
+            step_limit,
+        })
+    }
+
+    pub fn compare(&self, word: &str) -> Comparison {
+        Comparison {
+            word: word.to_string(),
+            first: self
+                .first
+                .with_input(self.step_limit, word.to_string())
+                .outcome(),
+            second: self
+                .second
+                .with_input(self.step_limit, word.to_string())
+                .outcome(),
+        }
+    }
+}
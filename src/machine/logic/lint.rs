@@ -0,0 +1,158 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::determinism::{self, ConflictGroup};
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LintWarning {
+    UnreachableState(State),
+    DeadState(State),
+    DuplicateTransition(Transition),
+    TransitionFromAccept(Transition),
+    WrittenButNeverRead(TapeEntry),
+    Nondeterminism(ConflictGroup<Transition>),
+}
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintWarning::UnreachableState(state) => {
+                write!(
+                    f,
+                    "state `{}` is unreachable from `{}`",
+                    state,
+                    std_states::START
+                )
+            }
+            LintWarning::DeadState(state) => write!(
+                f,
+                "state `{}` can never reach `{}`",
+                state,
+                std_states::ACCEPT
+            ),
+            LintWarning::DuplicateTransition(trans) => {
+                write!(f, "duplicate transition `{}`", trans)
+            }
+            LintWarning::TransitionFromAccept(trans) => write!(
+                f,
+                "transition `{}` leaves the `{}` state",
+                trans,
+                std_states::ACCEPT
+            ),
+            LintWarning::WrittenButNeverRead(entry) => {
+                write!(f, "symbol {} is written but never read", entry)
+            }
+            LintWarning::Nondeterminism(group) => write!(f, "{}", group),
+        }
+    }
+}
+
+fn all_states(transitions: &[Transition]) -> BTreeSet<State> {
+    transitions
+        .iter()
+        .flat_map(|trans| vec![trans.state_before.clone(), trans.state_after.clone()])
+        .collect()
+}
+
+// BFS over the state graph, following edges forwards or backwards
+fn reachable_states(transitions: &[Transition], from: &State, backwards: bool) -> HashSet<State> {
+    let mut edges: BTreeMap<&State, Vec<&State>> = BTreeMap::new();
+    for trans in transitions {
+        let (source, target) = if backwards {
+            (&trans.state_after, &trans.state_before)
+        } else {
+            (&trans.state_before, &trans.state_after)
+        };
+        edges.entry(source).or_default().push(target);
+    }
+
+    let mut visited: HashSet<State> = HashSet::new();
+    let mut queue: VecDeque<&State> = VecDeque::new();
+    visited.insert(from.clone());
+    queue.push_back(from);
+    while let Some(state) = queue.pop_front() {
+        for next in edges.get(state).into_iter().flatten() {
+            if visited.insert((*next).clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited
+}
+
+fn unreachable_states(transitions: &[Transition]) -> Vec<LintWarning> {
+    let reachable = reachable_states(transitions, &State(std_states::START.to_string()), false);
+    all_states(transitions)
+        .into_iter()
+        .filter(|state| !reachable.contains(state))
+        .map(LintWarning::UnreachableState)
+        .collect()
+}
+
+fn dead_states(transitions: &[Transition]) -> Vec<LintWarning> {
+    let accepting = reachable_states(transitions, &State(std_states::ACCEPT.to_string()), true);
+    all_states(transitions)
+        .into_iter()
+        // `reject` is expected to be a dead end
+        .filter(|state| state.value() != std_states::REJECT && !accepting.contains(state))
+        .map(LintWarning::DeadState)
+        .collect()
+}
+
+fn duplicate_transitions(transitions: &[Transition]) -> Vec<LintWarning> {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    transitions
+        .iter()
+        .filter(|trans| !seen.insert(*trans) && reported.insert(*trans))
+        .cloned()
+        .map(LintWarning::DuplicateTransition)
+        .collect()
+}
+
+fn transitions_from_accept(transitions: &[Transition]) -> Vec<LintWarning> {
+    transitions
+        .iter()
+        .filter(|trans| trans.leaves_accept())
+        .cloned()
+        .map(LintWarning::TransitionFromAccept)
+        .collect()
+}
+
+fn written_but_never_read(transitions: &[Transition]) -> Vec<LintWarning> {
+    let read: HashSet<&TapeEntry> = transitions
+        .iter()
+        .map(|trans| &trans.tape_value_before)
+        .collect();
+    let written: BTreeSet<&TapeEntry> = transitions
+        .iter()
+        .map(|trans| &trans.tape_value_after)
+        .collect();
+    written
+        .into_iter()
+        .filter(|entry| !read.contains(entry))
+        .cloned()
+        .map(LintWarning::WrittenButNeverRead)
+        .collect()
+}
+
+fn nondeterministic_choices(transitions: &[Transition]) -> Vec<LintWarning> {
+    determinism::conflicting_transitions(transitions)
+        .into_iter()
+        .map(LintWarning::Nondeterminism)
+        .collect()
+}
+
+pub fn lint(transitions: &[Transition]) -> Vec<LintWarning> {
+    vec![
+        unreachable_states(transitions),
+        dead_states(transitions),
+        duplicate_transitions(transitions),
+        transitions_from_accept(transitions),
+        written_but_never_read(transitions),
+        nondeterministic_choices(transitions),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
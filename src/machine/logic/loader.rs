@@ -1,17 +1,86 @@
 use crate::machine::component::transition::Transition;
-use crate::machine::translation::double_transition::DoubleTransition;
+use crate::machine::format::structured::MachineDocument;
+use crate::machine::format::{bbchallenge, jflap, morphett, turingmachine_io, MachineFormat};
+use crate::machine::translation::multi_transition::MultiTransition;
 use crate::types::*;
 
-fn sanitize_machine_description(description: String) -> Vec<String> {
-    description
+const INPUT_ALPHABET_HEADER: &str = "input:";
+
+fn is_header(line: &str) -> bool {
+    line.trim_start().starts_with(INPUT_ALPHABET_HEADER)
+}
+
+// the transition lines, an `input:` header being allowed only as the first line
+fn sanitize_machine_description(description: String) -> AppResult<Vec<String>> {
+    let mut lines = description
         .split("\n")
-        .map(|x| x.to_string())
-        .filter(|x| !x.is_empty())
+        .filter(|x| !x.trim().is_empty())
+        .peekable();
+    if lines.peek().is_some_and(|line| is_header(line)) {
+        lines.next();
+    }
+    lines
+        .map(|x| {
+            if is_header(x) {
+                return Err(format!("`{}` has to be the first line", x.trim()).into());
+            }
+            Ok(x.to_string())
+        })
         .collect()
 }
 
+// the letters input words are written with, as in `123` or `1 2 3` - blanks aren't letters
+pub fn parse_input_alphabet(letters: &str) -> AppResult<Vec<TapeEntry>> {
+    let mut alphabet = letters
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(|ch| match ch.to_digit(10) {
+            Some(digit) if digit != 0 => Ok(TapeEntry(digit.into())),
+            _ => Err(format!("`{}` can't be an input letter, only 1-9 can", ch)),
+        })
+        .collect::<Result<Vec<TapeEntry>, _>>()?;
+    alphabet.sort();
+    alphabet.dedup();
+    Ok(alphabet)
+}
+
+/* A text description may declare the input alphabet of its machine in a first line
+`input: <letters>`, for input letters the transitions don't mention. */
+pub fn input_alphabet_from_description(description: &str) -> AppResult<Option<Vec<TapeEntry>>> {
+    match description.lines().find(|line| !line.trim().is_empty()) {
+        Some(line) if is_header(line) => Ok(Some(parse_input_alphabet(
+            &line.trim_start()[INPUT_ALPHABET_HEADER.len()..],
+        )?)),
+        _ => Ok(None),
+    }
+}
+
+/* The text format declares the input alphabet in its header, structured documents in their
+`alphabet`, where the input letters are the nonzero digits among the symbols. The other
+formats have no place for it. */
+pub fn input_alphabet_from_file(path: &str) -> AppResult<Option<Vec<TapeEntry>>> {
+    let content = std::fs::read_to_string(path)?;
+    match MachineFormat::from_path(path) {
+        MachineFormat::Text => input_alphabet_from_description(&content),
+        MachineFormat::Yaml if turingmachine_io::looks_like(&content) => Ok(None),
+        format @ MachineFormat::Json
+        | format @ MachineFormat::Yaml
+        | format @ MachineFormat::Toml => {
+            let document = MachineDocument::parse(&content, format)?;
+            let letters: Vec<TapeEntry> = document
+                .alphabet
+                .into_iter()
+                .filter(|letter| (1..10).contains(letter))
+                .map(TapeEntry)
+                .collect();
+            Ok(Some(letters).filter(|letters| !letters.is_empty()))
+        }
+        _ => Ok(None),
+    }
+}
+
 pub fn transitions_from_description(machine_description: String) -> AppResult<Vec<Transition>> {
-    let transition_descriptions = sanitize_machine_description(machine_description);
+    let transition_descriptions = sanitize_machine_description(machine_description)?;
 
     transition_descriptions
         .iter()
@@ -19,13 +88,104 @@ pub fn transitions_from_description(machine_description: String) -> AppResult<Ve
         .collect()
 }
 
-pub fn two_tape_transitions_from_description(
+pub fn description_from_transitions(transitions: &[Transition]) -> String {
+    transitions
+        .iter()
+        .map(|trans| trans.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn multi_tape_transitions_from_description(
     machine_description: String,
-) -> AppResult<Vec<DoubleTransition>> {
-    let transition_descriptions = sanitize_machine_description(machine_description);
+) -> AppResult<Vec<MultiTransition>> {
+    let transition_descriptions = sanitize_machine_description(machine_description)?;
 
     transition_descriptions
         .iter()
-        .map(|x| DoubleTransition::from_description(x))
+        .map(|x| MultiTransition::from_description(x))
         .collect()
 }
+
+pub fn transitions_from_file(path: &str) -> AppResult<Vec<Transition>> {
+    let content = std::fs::read_to_string(path)?;
+    match MachineFormat::from_path(path) {
+        MachineFormat::Text => transitions_from_description(content),
+        MachineFormat::Yaml if turingmachine_io::looks_like(&content) => {
+            turingmachine_io::parse(&content)
+        }
+        MachineFormat::TuringMachineIo => turingmachine_io::parse(&content),
+        MachineFormat::Morphett => morphett::parse(&content),
+        MachineFormat::BbChallenge => bbchallenge::parse(&content),
+        MachineFormat::Jflap => jflap::parse(&content),
+        format => MachineDocument::parse(&content, format)?.to_transitions(),
+    }
+}
+
+// the text format's first line declaring `input_alphabet`, if there's any
+fn input_alphabet_header(input_alphabet: &[TapeEntry]) -> String {
+    if input_alphabet.is_empty() {
+        return String::new();
+    }
+    let letters: Vec<String> = input_alphabet.iter().map(TapeEntry::to_string).collect();
+    format!("{} {}\n", INPUT_ALPHABET_HEADER, letters.join(" "))
+}
+
+/* `input_alphabet` is declared as in `input_alphabet_from_file`, in the formats with a
+place for it. */
+pub fn transitions_to_format(
+    transitions: &[Transition],
+    input_alphabet: &[TapeEntry],
+    format: MachineFormat,
+) -> AppResult<String> {
+    match format {
+        MachineFormat::Text => {
+            Ok(input_alphabet_header(input_alphabet) + &description_from_transitions(transitions))
+        }
+        MachineFormat::TuringMachineIo => turingmachine_io::render(transitions),
+        MachineFormat::Morphett => morphett::render(transitions),
+        MachineFormat::BbChallenge => bbchallenge::render(transitions),
+        MachineFormat::Jflap => jflap::render(transitions),
+        format => MachineDocument::from_transitions(transitions)
+            .declaring_input(input_alphabet)
+            .render(format),
+    }
+}
+
+// the tape count comes from the file, and the formats of simulators only have one tape
+pub fn multi_tape_transitions_from_file(path: &str) -> AppResult<Vec<MultiTransition>> {
+    let content = std::fs::read_to_string(path)?;
+    let one_tape = |transitions: Vec<Transition>| -> Vec<MultiTransition> {
+        transitions.into_iter().map(MultiTransition::from).collect()
+    };
+    match MachineFormat::from_path(path) {
+        MachineFormat::Text => multi_tape_transitions_from_description(content),
+        MachineFormat::Yaml if turingmachine_io::looks_like(&content) => {
+            Ok(one_tape(turingmachine_io::parse(&content)?))
+        }
+        MachineFormat::TuringMachineIo => Ok(one_tape(turingmachine_io::parse(&content)?)),
+        MachineFormat::Morphett => Ok(one_tape(morphett::parse(&content)?)),
+        MachineFormat::BbChallenge => Ok(one_tape(bbchallenge::parse(&content)?)),
+        MachineFormat::Jflap => jflap::parse_multi_tape(&content),
+        format => MachineDocument::parse(&content, format)?.to_multi_tape_transitions(),
+    }
+}
+
+pub fn multi_tape_transitions_to_format(
+    transitions: &[MultiTransition],
+    input_alphabet: &[TapeEntry],
+    format: MachineFormat,
+) -> AppResult<String> {
+    match format {
+        MachineFormat::Text => Ok(input_alphabet_header(input_alphabet)
+            + &transitions
+                .iter()
+                .map(|trans| trans.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")),
+        MachineFormat::Jflap => jflap::render_multi_tape(transitions),
+        format => MachineDocument::from_multi_tape_transitions(transitions)
+            .declaring_input(input_alphabet)
+            .render(format),
+    }
+}
@@ -1 +1,8 @@
+pub mod alphabet;
+pub mod canonical;
+pub mod determinism;
+pub mod diff;
+pub mod differential;
+pub mod equivalence;
+pub mod lint;
 pub mod loader;
@@ -1,5 +1,7 @@
-mod component;
-mod logic;
+pub mod component;
+pub mod format;
+pub mod logic;
 
 pub mod classic;
+pub mod multi_tape;
 pub mod translation;
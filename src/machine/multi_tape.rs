@@ -0,0 +1,179 @@
+use crate::machine::component::tape::Tape;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+type TransitionIndex = HashMap<(State, Vec<TapeEntry>), Vec<MultiTransition>>;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct MultiTapeConfig {
+    pub tapes: Vec<Tape>,
+    pub state: State,
+}
+impl MultiTapeConfig {
+    fn read_from_heads(&self) -> Vec<TapeEntry> {
+        self.tapes
+            .iter()
+            .map(|tape| tape.read_from_head().clone())
+            .collect()
+    }
+
+    fn apply(&self, trans: &MultiTransition) -> MultiTapeConfig {
+        let mut cfg = self.clone();
+        cfg.state = trans.state_after.clone();
+        for (tape, (value, direction)) in cfg.tapes.iter_mut().zip(
+            trans
+                .tape_values_after
+                .iter()
+                .zip(trans.tape_head_move_directions.iter()),
+        ) {
+            tape.write_to_head(value);
+            tape.move_head(direction);
+        }
+        cfg
+    }
+}
+
+// how a run on a word ended
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    // the length of the shortest accepting run
+    Accepted(Number),
+    // every run halted or went round in a loop, all of them by the given step
+    Rejected(Number),
+    // some runs were still going when the limit was reached
+    OutOfSteps,
+}
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Accepted(steps) => write!(f, "accepts in {} steps", steps),
+            Outcome::Rejected(steps) => write!(f, "rejects after {} steps", steps),
+            Outcome::OutOfSteps => write!(f, "runs out of steps"),
+        }
+    }
+}
+
+// runs a k-tape machine natively, the same way `ClassicMachine` runs a one-tape one
+#[derive(Debug)]
+pub struct MultiTapeMachine {
+    tape_count: usize,
+    transitions: Rc<TransitionIndex>,
+    execution_limit: Number,
+    visited_configs: HashSet<MultiTapeConfig>,
+    current_configs: HashSet<MultiTapeConfig>,
+    current_step_no: Number,
+}
+
+impl MultiTapeMachine {
+    pub fn new(
+        transitions: Vec<MultiTransition>,
+        execution_limit: Number,
+        input_word: String,
+    ) -> AppResult<MultiTapeMachine> {
+        let tape_count = transitions.first().map_or(1, |trans| trans.tape_count());
+        let mut transition_index = TransitionIndex::new();
+        for trans in transitions {
+            transition_index
+                .entry((trans.state_before.clone(), trans.tape_values_before.clone()))
+                .or_default()
+                .push(trans);
+        }
+
+        Ok(Self::starting(
+            tape_count,
+            Rc::new(transition_index),
+            execution_limit,
+            input_word,
+        ))
+    }
+
+    fn starting(
+        tape_count: usize,
+        transitions: Rc<TransitionIndex>,
+        execution_limit: Number,
+        input_word: String,
+    ) -> MultiTapeMachine {
+        let mut tapes = vec![Tape::new(input_word)];
+        tapes.extend((1..tape_count).map(|_| Tape::new(String::new())));
+
+        let mut step_configs = HashSet::new();
+        step_configs.insert(MultiTapeConfig {
+            tapes,
+            state: State(std_states::START.to_string()),
+        });
+
+        MultiTapeMachine {
+            tape_count,
+            transitions,
+            execution_limit,
+            visited_configs: step_configs.clone(),
+            current_configs: step_configs,
+            current_step_no: 0,
+        }
+    }
+
+    // a fresh machine on another word, sharing the transitions with this one
+    pub fn with_input(&self, execution_limit: Number, input_word: String) -> MultiTapeMachine {
+        Self::starting(
+            self.tape_count,
+            Rc::clone(&self.transitions),
+            execution_limit,
+            input_word,
+        )
+    }
+
+    fn is_accepting_run_reached(&self) -> bool {
+        self.current_configs
+            .iter()
+            .any(|cfg| cfg.state.value() == std_states::ACCEPT)
+    }
+
+    fn step(&mut self) {
+        let mut new_configs = HashSet::new();
+        for cfg in self.current_configs.iter() {
+            let key = (cfg.state.clone(), cfg.read_from_heads());
+            for trans in self.transitions.get(&key).into_iter().flatten() {
+                let new_config = cfg.apply(trans);
+                if !self.visited_configs.contains(&new_config) {
+                    new_configs.insert(new_config);
+                }
+            }
+        }
+
+        self.visited_configs.extend(new_configs.iter().cloned());
+        self.current_configs = new_configs;
+        self.current_step_no += 1;
+    }
+
+    pub fn accepts(self) -> bool {
+        self.accepting_run_length().is_some()
+    }
+
+    // number of steps of the shortest accepting run, if there is one within the limit
+    pub fn accepting_run_length(self) -> Option<Number> {
+        match self.outcome() {
+            Outcome::Accepted(steps) => Some(steps),
+            _ => None,
+        }
+    }
+
+    pub fn outcome(mut self) -> Outcome {
+        while self.current_step_no < self.execution_limit
+            && !self.is_accepting_run_reached()
+            && !self.current_configs.is_empty()
+        {
+            self.step();
+        }
+        if self.is_accepting_run_reached() {
+            Outcome::Accepted(self.current_step_no)
+        } else if self.current_configs.is_empty() {
+            // the step that found nothing new isn't one any run took
+            Outcome::Rejected(self.current_step_no - 1)
+        } else {
+            Outcome::OutOfSteps
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::machine::component::transition::Transition;
+use crate::types::*;
+use std::collections::BTreeSet;
+
+/* Rewrites a one-tape machine into one using only the letters 0 and 1. Every letter becomes
+a block of `width` cells holding it in binary, most significant bit first, so blanks stay
+blank and the input has to be given encoded the same way (see `encode_word`).
+
+A step of the original machine starts with the head on the first cell of a block. The block
+is read moving right, remembering the bits read so far in the state; the last bit picks the
+original transition. Then the new letter is written moving back left and the head makes
+`width` moves to the block the original head would be on. Moving left off the first block
+stays on it, just as the original head stays on the first cell. So a step takes at most
+`3 * width - 2` steps - `2 * width - 1` when the head stays. */
+pub struct BinaryReduction {
+    transitions: Vec<Transition>,
+    width: Number,
+    original_state_count: usize,
+}
+impl BinaryReduction {
+    pub fn new(transitions: Vec<Transition>, input_alphabet: &[TapeEntry]) -> Self {
+        let mut states: BTreeSet<&State> = BTreeSet::new();
+        let mut largest = input_alphabet
+            .iter()
+            .max()
+            .cloned()
+            .unwrap_or(TapeEntry::BLANK);
+        for trans in transitions.iter() {
+            states.insert(&trans.state_before);
+            states.insert(&trans.state_after);
+            largest = largest
+                .max(trans.tape_value_before.clone())
+                .max(trans.tape_value_after.clone());
+        }
+        let width = (Number::BITS - largest.0.leading_zeros()).max(1) as Number;
+
+        let mut reduction = Self {
+            transitions: vec![],
+            width,
+            original_state_count: states.len(),
+        };
+        reduction.transitions = reduction.reduced(&transitions);
+        reduction
+    }
+
+    // how many cells a letter of the original machine takes
+    pub fn block_width(&self) -> Number {
+        self.width
+    }
+
+    // the most steps one step of the original machine turns into
+    pub fn step_overhead(&self) -> Number {
+        3 * self.width - 2
+    }
+
+    pub fn original_state_count(&self) -> usize {
+        self.original_state_count
+    }
+
+    pub fn state_count(&self) -> usize {
+        let states: BTreeSet<&State> = self
+            .transitions
+            .iter()
+            .flat_map(|trans| vec![&trans.state_before, &trans.state_after])
+            .collect();
+        states.len()
+    }
+
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    pub fn into_transitions(self) -> Vec<Transition> {
+        self.transitions
+    }
+
+    // the block of cells standing for `letter`
+    pub fn encode(&self, letter: &TapeEntry) -> Vec<bool> {
+        (0..self.width)
+            .rev()
+            .map(|bit| letter.0 >> bit & 1 == 1)
+            .collect()
+    }
+
+    // an input word of the original machine as one for the reduced machine
+    pub fn encode_word(&self, word: &str) -> AppResult<String> {
+        let mut encoded = String::new();
+        for letter in word.chars() {
+            let value = letter
+                .to_digit(10)
+                .ok_or_else(|| format!("`{}` isn't a digit", letter))?;
+            if Number::from(value) >= 1 << self.width {
+                return Err(format!("letter {} doesn't fit in {} bits", value, self.width).into());
+            }
+            for bit in self.encode(&TapeEntry(Number::from(value))) {
+                encoded.push(if bit { '1' } else { '0' });
+            }
+        }
+        Ok(encoded)
+    }
+
+    fn bit_entry(bit: bool) -> TapeEntry {
+        TapeEntry(bit as Number)
+    }
+
+    fn bits_to_string(bits: &[bool]) -> String {
+        bits.iter()
+            .map(|&bit| if bit { '1' } else { '0' })
+            .collect()
+    }
+
+    // the states where a step starts keep the original names
+    fn reading(state: &State, bits: &[bool]) -> State {
+        if bits.is_empty() {
+            return state.clone();
+        }
+        State(format!("#$READ_{}#{}$#", state, Self::bits_to_string(bits)))
+    }
+
+    // writing bit `position` of the block, then the ones left of it
+    fn writing(state: &State, block: &[bool], dir: &HeadMoveDirection, position: usize) -> State {
+        State(format!(
+            "#$WRITE_{}#{}#{}#{}$#",
+            state,
+            Self::bits_to_string(block),
+            direction_to_string(dir),
+            position
+        ))
+    }
+
+    // `moves_left` more moves before the step is over
+    fn moving(state: &State, dir: &HeadMoveDirection, moves_left: Number) -> State {
+        if moves_left == 0 {
+            return state.clone();
+        }
+        State(format!(
+            "#$MOVE_{}#{}#{}$#",
+            state,
+            direction_to_string(dir),
+            moves_left
+        ))
+    }
+
+    fn reduced(&self, transitions: &[Transition]) -> Vec<Transition> {
+        use HeadMoveDirection::*;
+        let width = self.width as usize;
+        // the states needed to finish a step, each one once
+        let mut writes: BTreeSet<(State, Vec<bool>, HeadMoveDirection, usize)> = BTreeSet::new();
+        let mut moves: BTreeSet<(State, HeadMoveDirection)> = BTreeSet::new();
+        // a prefix shared by several letters is read once
+        let mut reads: BTreeSet<(State, Vec<bool>)> = BTreeSet::new();
+        let mut result = vec![];
+
+        let mut push = |before: State, read: bool, after: State, written: bool, dir| {
+            result.push(Transition {
+                state_before: before,
+                tape_value_before: Self::bit_entry(read),
+                state_after: after,
+                tape_value_after: Self::bit_entry(written),
+                tape_head_move_direction: dir,
+            })
+        };
+
+        for trans in transitions {
+            let read = self.encode(&trans.tape_value_before);
+            for len in 0..width - 1 {
+                reads.insert((trans.state_before.clone(), read[..=len].to_vec()));
+            }
+
+            let written = self.encode(&trans.tape_value_after);
+            let state = &trans.state_before;
+            let after = &trans.state_after;
+            let dir = &trans.tape_head_move_direction;
+            let last = *written.last().unwrap();
+            if width == 1 {
+                push(
+                    Self::reading(state, &[]),
+                    read[0],
+                    after.clone(),
+                    last,
+                    dir.clone(),
+                );
+                continue;
+            }
+            push(
+                Self::reading(state, &read[..width - 1]),
+                read[width - 1],
+                Self::writing(after, &written, dir, width - 2),
+                last,
+                Left,
+            );
+            writes.insert((after.clone(), written, dir.clone(), width - 2));
+        }
+
+        for (state, bits) in reads.iter() {
+            let (last, prefix) = bits.split_last().unwrap();
+            push(
+                Self::reading(state, prefix),
+                *last,
+                Self::reading(state, bits),
+                *last,
+                Right,
+            );
+        }
+
+        let mut pending: Vec<_> = writes.iter().cloned().collect();
+        while let Some((state, block, dir, position)) = pending.pop() {
+            let (after, move_dir) = match (position, &dir) {
+                (0, Stay) => (state.clone(), Stay),
+                (0, _) => {
+                    moves.insert((state.clone(), dir.clone()));
+                    (Self::moving(&state, &dir, self.width - 1), dir.clone())
+                }
+                _ => {
+                    let next = (state.clone(), block.clone(), dir.clone(), position - 1);
+                    if writes.insert(next.clone()) {
+                        pending.push(next);
+                    }
+                    (Self::writing(&state, &block, &dir, position - 1), Left)
+                }
+            };
+            for &bit in [false, true].iter() {
+                push(
+                    Self::writing(&state, &block, &dir, position),
+                    bit,
+                    after.clone(),
+                    block[position],
+                    move_dir.clone(),
+                );
+            }
+        }
+
+        for (state, dir) in moves {
+            for left in 1..self.width {
+                for &bit in [false, true].iter() {
+                    push(
+                        Self::moving(&state, &dir, left),
+                        bit,
+                        Self::moving(&state, &dir, left - 1),
+                        bit,
+                        dir.clone(),
+                    );
+                }
+            }
+        }
+
+        result.sort_by_cached_key(Transition::to_string);
+        result.dedup();
+        result
+    }
+}
@@ -0,0 +1,390 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::alphabet;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::BTreeSet;
+use std::fmt;
+
+// `quadratic * n^2 + linear * n + constant` for inputs of length `n`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBound {
+    pub quadratic: Number,
+    pub linear: Number,
+    pub constant: Number,
+}
+impl TimeBound {
+    // a sum of terms like `3n^2`, `n` or `5`, spaces allowed
+    pub fn from_description(description: &str) -> AppResult<Self> {
+        let mut bound = Self {
+            quadratic: 0,
+            linear: 0,
+            constant: 0,
+        };
+        let description: String = description
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect();
+        if description.is_empty() {
+            return Err("the time bound is empty".into());
+        }
+        for term in description.split('+') {
+            let (coefficient, power) = match term.find('n') {
+                Some(idx) => {
+                    let power = match &term[idx + 1..] {
+                        "" => 1,
+                        "^1" => 1,
+                        "^2" => 2,
+                        _ => return Err(format!("can't read the term `{}`", term).into()),
+                    };
+                    (&term[..idx], power)
+                }
+                None => (term, 0),
+            };
+            let coefficient = match coefficient {
+                "" if power > 0 => 1,
+                _ => coefficient
+                    .parse::<Number>()
+                    .map_err(|_| format!("can't read the term `{}`", term))?,
+            };
+            match power {
+                2 => bound.quadratic += coefficient,
+                1 => bound.linear += coefficient,
+                _ => bound.constant += coefficient,
+            }
+        }
+        Ok(bound)
+    }
+
+    pub fn evaluate(&self, length: Number) -> Number {
+        self.quadratic * length * length + self.linear * length + self.constant
+    }
+}
+// written the way it's read, leaving out zero terms
+impl fmt::Display for TimeBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms = [
+            (self.quadratic, "n^2"),
+            (self.linear, "n"),
+            (self.constant, ""),
+        ];
+        let written: Vec<String> = terms
+            .iter()
+            .filter(|(coefficient, _)| *coefficient > 0)
+            .map(|(coefficient, power)| match (coefficient, power) {
+                (1, power) if !power.is_empty() => power.to_string(),
+                (coefficient, power) => format!("{}{}", coefficient, power),
+            })
+            .collect();
+        if written.is_empty() {
+            return write!(f, "0");
+        }
+        write!(f, "{}", written.join("+"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    // looking for the next letter to count `n` steps for
+    OuterSeek,
+    RewindForInner,
+    // `k` marks written for the letter under the head so far
+    InnerSweep(Number),
+    RewindForOuter,
+    RewindForLinear,
+    LinearSweep(Number),
+    Constant(Number),
+    Restore,
+    RewindClock,
+}
+impl Phase {
+    fn state(self) -> State {
+        State(match self {
+            Phase::OuterSeek => "#$OUTER_SEEK$#".to_string(),
+            Phase::RewindForInner => "#$REWIND_FOR_INNER$#".to_string(),
+            Phase::InnerSweep(marks) => format!("#$INNER_SWEEP_{}$#", marks),
+            Phase::RewindForOuter => "#$REWIND_FOR_OUTER$#".to_string(),
+            Phase::RewindForLinear => "#$REWIND_FOR_LINEAR$#".to_string(),
+            Phase::LinearSweep(marks) => format!("#$LINEAR_SWEEP_{}$#", marks),
+            Phase::Constant(marks) => format!("#$CONSTANT_{}$#", marks),
+            Phase::Restore => "#$RESTORE$#".to_string(),
+            Phase::RewindClock => "#$REWIND_CLOCK$#".to_string(),
+        })
+    }
+}
+
+/* Wraps a one-tape machine into a two-tape one that rejects once the original has made
+`bound` steps, so that it always halts. The second tape is the clock: before the original
+starts, it gets a mark for every step allowed, and each step of the original moves its head
+right over one. Reading a blank there instead means the time is up. With the clock done by
+`MachineTranslator` the two tapes end up as two tracks of one.
+
+Writing `a * n^2` marks takes a loop over the input inside another, so the setup marks the
+letters in place: every letter of the input gets visited in turn, each time sweeping over
+the whole input to write `a` marks per letter. The first cell is marked as well, to find the
+way back, and it's all undone before the original starts. That's about `(a + 3) * n^2`
+steps, and `(b + 2) * n + c` more for the rest of the bound. */
+pub struct Clock {
+    transitions: Vec<Transition>,
+    bound: TimeBound,
+    tape_letters: BTreeSet<TapeEntry>,
+    input_letters: BTreeSet<TapeEntry>,
+}
+impl Clock {
+    const SENTINEL: TapeEntry = TapeEntry(2);
+    const MARK: TapeEntry = TapeEntry(1);
+
+    pub fn new(
+        transitions: Vec<Transition>,
+        bound: TimeBound,
+        input_alphabet: &[TapeEntry],
+    ) -> Self {
+        let read = transitions.iter().map(|trans| &trans.tape_value_before);
+        Self {
+            input_letters: alphabet::input_letters(read, input_alphabet),
+            tape_letters: alphabet::tape_letters(&transitions, input_alphabet),
+            transitions,
+            bound,
+        }
+    }
+
+    pub fn bound(&self) -> TimeBound {
+        self.bound
+    }
+
+    // the states of the original machine keep their names, but `start` is the setup's
+    fn running(state: &State) -> State {
+        if state.value() == std_states::START {
+            return State("#$RUN_start$#".to_string());
+        }
+        state.clone()
+    }
+
+    // an input cell during the setup, with the visited and first cell marks
+    fn marked(&self, letter: &TapeEntry, visited: bool, first: bool) -> TapeEntry {
+        let k = self.tape_letters.iter().max().unwrap().0 + 1;
+        TapeEntry(letter.0 + visited as Number * k + first as Number * 2 * k)
+    }
+
+    // the input letters and blank with every combination of marks
+    fn marked_cells(&self) -> Vec<(TapeEntry, bool, bool)> {
+        let mut cells = vec![];
+        for letter in std::iter::once(&TapeEntry::BLANK).chain(self.input_letters.iter()) {
+            for &visited in [false, true].iter() {
+                for &first in [false, true].iter() {
+                    cells.push((letter.clone(), visited, first));
+                }
+            }
+        }
+        cells
+    }
+
+    fn transition(
+        state_before: State,
+        read: [TapeEntry; 2],
+        state_after: State,
+        written: [TapeEntry; 2],
+        moves: [HeadMoveDirection; 2],
+    ) -> MultiTransition {
+        MultiTransition {
+            state_before,
+            tape_values_before: read.to_vec(),
+            state_after,
+            tape_values_after: written.to_vec(),
+            tape_head_move_directions: moves.to_vec(),
+        }
+    }
+
+    pub fn multi_tape_transitions(&self) -> Vec<MultiTransition> {
+        let mut result = self.setup();
+        result.extend(self.running_transitions());
+        result
+    }
+
+    fn setup(&self) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let mut result = vec![];
+        let blank = TapeEntry::BLANK;
+        let after_start = if self.bound.quadratic > 0 {
+            Phase::OuterSeek
+        } else {
+            Phase::LinearSweep(0)
+        };
+        for letter in std::iter::once(&blank).chain(self.input_letters.iter()) {
+            result.push(Self::transition(
+                State(std_states::START.to_string()),
+                [letter.clone(), blank.clone()],
+                after_start.state(),
+                [self.marked(letter, false, true), Self::SENTINEL],
+                [Stay, Right],
+            ));
+        }
+
+        /* From a cell of the input with the clock's head past the marks written so far, so
+        it moves right only to leave another one. */
+        let mut step = |phase: Phase, cell: &TapeEntry, after: Phase, written: TapeEntry, moves| {
+            let clock_written = if moves == [Stay, Right] {
+                Self::MARK
+            } else {
+                blank.clone()
+            };
+            result.push(Self::transition(
+                phase.state(),
+                [cell.clone(), blank.clone()],
+                after.state(),
+                [written, clock_written],
+                moves,
+            ));
+        };
+        let quadratic = self.bound.quadratic > 0;
+        let mut sweeps = vec![(
+            Phase::LinearSweep as fn(Number) -> Phase,
+            self.bound.linear,
+            Phase::Constant(0),
+        )];
+        if quadratic {
+            sweeps.push((
+                Phase::InnerSweep,
+                self.bound.quadratic,
+                Phase::RewindForOuter,
+            ));
+        }
+        for (letter, visited, first) in self.marked_cells() {
+            let cell = self.marked(&letter, visited, first);
+            let at_end = letter == TapeEntry::BLANK;
+
+            // going back to the first cell
+            let mut rewinds = vec![(Phase::RewindForLinear, Phase::LinearSweep(0))];
+            if quadratic {
+                rewinds.push((Phase::RewindForInner, Phase::InnerSweep(0)));
+                rewinds.push((Phase::RewindForOuter, Phase::OuterSeek));
+            }
+            for &(rewind, after) in rewinds.iter() {
+                if first {
+                    step(rewind, &cell, after, cell.clone(), [Stay, Stay]);
+                } else {
+                    step(rewind, &cell, rewind, cell.clone(), [Left, Stay]);
+                }
+            }
+
+            if quadratic {
+                let (after, written, moves) = if at_end {
+                    (Phase::RewindForLinear, cell.clone(), [Stay, Stay])
+                } else if visited {
+                    (Phase::OuterSeek, cell.clone(), [Right, Stay])
+                } else {
+                    (
+                        Phase::RewindForInner,
+                        self.marked(&letter, true, first),
+                        [Stay, Stay],
+                    )
+                };
+                step(Phase::OuterSeek, &cell, after, written, moves);
+            }
+
+            for &(sweep, marks_per_letter, after) in sweeps.iter() {
+                for marks in 0..=marks_per_letter {
+                    if at_end {
+                        step(sweep(marks), &cell, after, cell.clone(), [Stay, Stay]);
+                    } else if marks < marks_per_letter {
+                        step(
+                            sweep(marks),
+                            &cell,
+                            sweep(marks + 1),
+                            cell.clone(),
+                            [Stay, Right],
+                        );
+                    } else {
+                        step(sweep(marks), &cell, sweep(0), cell.clone(), [Right, Stay]);
+                    }
+                }
+            }
+
+            // past the input: the constant part, then the marks come off going back
+            if at_end {
+                for marks in 0..self.bound.constant {
+                    step(
+                        Phase::Constant(marks),
+                        &cell,
+                        Phase::Constant(marks + 1),
+                        cell.clone(),
+                        [Stay, Right],
+                    );
+                }
+                step(
+                    Phase::Constant(self.bound.constant),
+                    &cell,
+                    Phase::Restore,
+                    cell.clone(),
+                    [Stay, Stay],
+                );
+            }
+            if first {
+                step(
+                    Phase::Restore,
+                    &cell,
+                    Phase::RewindClock,
+                    letter.clone(),
+                    [Stay, Stay],
+                );
+            } else {
+                step(
+                    Phase::Restore,
+                    &cell,
+                    Phase::Restore,
+                    letter.clone(),
+                    [Left, Stay],
+                );
+            }
+        }
+
+        for letter in std::iter::once(&blank).chain(self.input_letters.iter()) {
+            for clock in [blank.clone(), Self::MARK].iter() {
+                result.push(Self::transition(
+                    Phase::RewindClock.state(),
+                    [letter.clone(), clock.clone()],
+                    Phase::RewindClock.state(),
+                    [letter.clone(), clock.clone()],
+                    [Stay, Left],
+                ));
+            }
+            result.push(Self::transition(
+                Phase::RewindClock.state(),
+                [letter.clone(), Self::SENTINEL],
+                Self::running(&State(std_states::START.to_string())),
+                [letter.clone(), Self::SENTINEL],
+                [Stay, Right],
+            ));
+        }
+        result
+    }
+
+    // every step of the original takes a mark, and finding none rejects
+    fn running_transitions(&self) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let mut result = vec![];
+        let mut timing_out: BTreeSet<(State, TapeEntry)> = BTreeSet::new();
+        for trans in self.transitions.iter() {
+            if trans.leaves_accept() {
+                continue;
+            }
+            result.push(Self::transition(
+                Self::running(&trans.state_before),
+                [trans.tape_value_before.clone(), Self::MARK],
+                Self::running(&trans.state_after),
+                [trans.tape_value_after.clone(), Self::MARK],
+                [trans.tape_head_move_direction.clone(), Right],
+            ));
+            timing_out.insert((trans.state_before.clone(), trans.tape_value_before.clone()));
+        }
+        for (state, letter) in timing_out {
+            result.push(Self::transition(
+                Self::running(&state),
+                [letter.clone(), TapeEntry::BLANK],
+                State(std_states::REJECT.to_string()),
+                [letter, TapeEntry::BLANK],
+                [Stay, Stay],
+            ));
+        }
+        result
+    }
+}
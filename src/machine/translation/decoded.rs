@@ -0,0 +1,96 @@
+use crate::machine::component::tape::Tape;
+use crate::machine::translation::legend::SymbolLegend;
+use crate::machine::translation::state_map::StateMap;
+use crate::machine::translation::translator::MachineTranslator;
+use crate::types::*;
+use std::fmt;
+
+// one tape of the original machine, with the cells its head is on marked
+#[derive(Debug, PartialEq, Eq)]
+pub struct SimulatedTape {
+    pub letters: Vec<TapeEntry>,
+    pub heads: Vec<bool>,
+}
+// same as a real tape: the head's cell is put in brackets
+impl fmt::Display for SimulatedTape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<String> = self
+            .letters
+            .iter()
+            .zip(self.heads.iter())
+            .map(|(letter, &head)| {
+                if head {
+                    format!("[{}]", letter)
+                } else {
+                    letter.to_string()
+                }
+            })
+            .collect();
+        write!(f, "{}", cells.join(" "))
+    }
+}
+
+/* A config of a translated machine seen as a config of the original one - every cell
+decoded through the legend into the k cells it stands for. A well-behaved translation has
+exactly one head per simulated tape once set up, so anything else points at a bug. */
+#[derive(Debug, PartialEq, Eq)]
+pub struct DecodedConfig {
+    pub state: Option<State>,
+    pub phase: Option<String>,
+    pub tapes: Vec<SimulatedTape>,
+}
+impl DecodedConfig {
+    // with short names the long ones are looked up in the `state_map`
+    pub fn decode(
+        state: &State,
+        tape: &Tape,
+        legend: &SymbolLegend,
+        state_map: Option<&StateMap>,
+    ) -> AppResult<Self> {
+        let long_name = state_map
+            .and_then(|map| map.meaning(state))
+            .map_or(state, |meaning| &meaning.phase);
+        let (state, phase) = match MachineTranslator::original_state(long_name) {
+            Some((state, phase)) => (Some(state), phase),
+            None => (None, None),
+        };
+
+        let mut tapes: Vec<SimulatedTape> = (0..legend.tape_count())
+            .map(|_| SimulatedTape {
+                letters: vec![],
+                heads: vec![],
+            })
+            .collect();
+        for cell in tape.cells() {
+            let entry = legend
+                .decode(cell)
+                .ok_or_else(|| format!("symbol {} isn't in the legend", cell))?;
+            for (tape, simulated) in tapes.iter_mut().enumerate() {
+                simulated.letters.push(entry.letters[tape].clone());
+                simulated.heads.push(entry.heads[tape]);
+            }
+        }
+
+        Ok(Self {
+            state,
+            phase,
+            tapes,
+        })
+    }
+}
+// `<original state> (<phase>)` followed by one line per simulated tape
+impl fmt::Display for DecodedConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.state {
+            Some(state) => write!(f, "{}", state)?,
+            None => write!(f, "?")?,
+        }
+        if let Some(phase) = &self.phase {
+            write!(f, " ({})", phase)?;
+        }
+        for (idx, tape) in self.tapes.iter().enumerate() {
+            write!(f, "\n    tape {}: {}", idx + 1, tape)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::alphabet;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/* What the deterministic machine is doing. All but `LengthenChoices` carry whether some run
+of the current length was still going when its choices ran out - if none was, longer
+choice sequences can't accept either and the machine rejects. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    CopyInput,
+    RewindAfterCopy,
+    RewindAfterSimulation,
+    SeekLastChoice,
+    NextChoice,
+    RewindAfterNextChoice,
+}
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Phase::CopyInput => "copy_input",
+            Phase::RewindAfterCopy => "rewind_after_copy",
+            Phase::RewindAfterSimulation => "rewind_after_simulation",
+            Phase::SeekLastChoice => "seek_last_choice",
+            Phase::NextChoice => "next_choice",
+            Phase::RewindAfterNextChoice => "rewind_after_next_choice",
+        }
+    }
+}
+
+/* Turns a nondeterministic one-tape machine into a deterministic two-tape one, which
+`MachineTranslator` can then flatten to one tape. The textbook construction has three tapes:
+one keeping the input, one where runs of the original machine are replayed and one holding
+a sequence of choices - which of the transitions possible to take at each step. The input
+isn't needed while the choices move, so here those two share the first tape as two tracks,
+which keeps the flattened machine a lot smaller.
+
+Choice sequences are tried in breadth-first order: all of length 0, then 1, and so on, each
+length in lexicographic order. A sequence is written with the digits `1..b` (`b` being the
+most transitions the original has for one state and letter) after a start marker, so moving
+on to the next one is an increment in bijective base `b`. Every try copies the input anew,
+replays the choices and accepts if the original machine accepts on them. */
+pub struct Determinizer {
+    // the possible transitions for a state and letter, in a fixed order
+    choices: BTreeMap<(State, TapeEntry), Vec<Transition>>,
+    states: BTreeSet<State>,
+    tape_letters: BTreeSet<TapeEntry>,
+    input_letters: BTreeSet<TapeEntry>,
+    branching: Number,
+}
+impl Determinizer {
+    pub fn new(transitions: Vec<Transition>, input_alphabet: &[TapeEntry]) -> Self {
+        let mut choices: BTreeMap<(State, TapeEntry), Vec<Transition>> = BTreeMap::new();
+        let mut states: BTreeSet<State> =
+            std::iter::once(State(std_states::START.to_string())).collect();
+        let read = transitions.iter().map(|trans| &trans.tape_value_before);
+        let input_letters = alphabet::input_letters(read, input_alphabet);
+        let tape_letters = alphabet::tape_letters(&transitions, input_alphabet);
+
+        for trans in transitions {
+            states.insert(trans.state_before.clone());
+            states.insert(trans.state_after.clone());
+            if !trans.leaves_accept() {
+                choices
+                    .entry((trans.state_before.clone(), trans.tape_value_before.clone()))
+                    .or_default()
+                    .push(trans);
+            }
+        }
+        for options in choices.values_mut() {
+            options.sort_by(|a, b| {
+                let key = |trans: &Transition| {
+                    (
+                        trans.state_after.clone(),
+                        trans.tape_value_after.clone(),
+                        trans.tape_head_move_direction.clone(),
+                    )
+                };
+                key(a).cmp(&key(b))
+            });
+            options.dedup();
+        }
+
+        let branching = choices.values().map(Vec::len).max().unwrap_or(0).max(1) as Number;
+        Self {
+            choices,
+            states,
+            tape_letters,
+            input_letters,
+            branching,
+        }
+    }
+
+    // the most transitions the original machine has to pick from in one step
+    pub fn branching(&self) -> Number {
+        self.branching
+    }
+
+    fn start_marker(&self) -> TapeEntry {
+        TapeEntry(self.branching + 1)
+    }
+
+    // blank, the choices and the start marker
+    fn choice_letters(&self) -> Vec<TapeEntry> {
+        (0..=self.branching + 1).map(TapeEntry).collect()
+    }
+
+    fn input_tape_letters(&self) -> Vec<TapeEntry> {
+        std::iter::once(TapeEntry::BLANK)
+            .chain(self.input_letters.iter().cloned())
+            .collect()
+    }
+
+    fn phase_state(phase: Phase, still_running: bool) -> State {
+        State(format!("{}_{}", phase.name(), still_running as u8))
+    }
+
+    fn simulating(state: &State, still_running: bool) -> State {
+        if state.value() == std_states::ACCEPT {
+            return state.clone();
+        }
+        State(format!(
+            "simulate_{}_{}",
+            still_running as u8,
+            state.value()
+        ))
+    }
+
+    fn lengthen_choices() -> State {
+        State("lengthen_choices".to_string())
+    }
+
+    // a transition for every combination of letters the input, work tape and choice may hold
+    fn for_all_letters(
+        &self,
+        mut transition: impl FnMut(&TapeEntry, &TapeEntry, &TapeEntry) -> Option<MultiTransition>,
+    ) -> Vec<MultiTransition> {
+        let mut transitions = vec![];
+        for input in self.input_tape_letters().iter() {
+            for work in self.tape_letters.iter() {
+                for choice in self.choice_letters().iter() {
+                    transitions.extend(transition(input, work, choice));
+                }
+            }
+        }
+        transitions
+    }
+
+    // an input letter and a choice together, the plain input letter when there's no choice
+    fn control_letter(&self, input: &TapeEntry, choice: &TapeEntry) -> TapeEntry {
+        TapeEntry(input.0 + choice.0 * self.input_letter_limit())
+    }
+
+    fn input_letter_limit(&self) -> Number {
+        self.input_letters
+            .iter()
+            .map(|letter| letter.0 + 1)
+            .max()
+            .unwrap_or(1)
+    }
+
+    // letters as `[input, work, choice]`, moves as `[control, work]`
+    fn transition(
+        &self,
+        state_before: State,
+        read: [&TapeEntry; 3],
+        state_after: State,
+        written: [&TapeEntry; 3],
+        moves: [HeadMoveDirection; 2],
+    ) -> MultiTransition {
+        MultiTransition {
+            state_before,
+            tape_values_before: vec![self.control_letter(read[0], read[2]), read[1].clone()],
+            state_after,
+            tape_values_after: vec![
+                self.control_letter(written[0], written[2]),
+                written[1].clone(),
+            ],
+            tape_head_move_directions: moves.to_vec(),
+        }
+    }
+
+    /* The start marker goes on the choice track, then the input is copied onto the work tape
+    up to the last cell of the input or the choices, whichever is further. A run making `n`
+    choices can't get past cell `n`, so that also wipes what the previous try left there. */
+    fn copying(&self, still_running: bool) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let copy = Self::phase_state(Phase::CopyInput, still_running);
+        let rewind = Self::phase_state(Phase::RewindAfterCopy, still_running);
+        let marker = self.start_marker();
+
+        self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            if input == &TapeEntry::BLANK && choice == &TapeEntry::BLANK {
+                Some(self.transition(
+                    copy.clone(),
+                    read,
+                    rewind.clone(),
+                    [input, input, choice],
+                    [Left, Left],
+                ))
+            } else {
+                Some(self.transition(
+                    copy.clone(),
+                    read,
+                    copy.clone(),
+                    [input, input, choice],
+                    [Right, Right],
+                ))
+            }
+        })
+        .into_iter()
+        .chain(self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            Some(if choice == &marker {
+                // the replay starts with the head on the first choice
+                self.transition(
+                    rewind.clone(),
+                    read,
+                    Self::simulating(&State(std_states::START.to_string()), still_running),
+                    read,
+                    [Right, Stay],
+                )
+            } else {
+                self.transition(rewind.clone(), read, rewind.clone(), read, [Left, Left])
+            })
+        }))
+        .collect()
+    }
+
+    /* Replays the original machine on the work tape, taking the transition the current
+    choice points at. A run is over once it makes a choice that isn't there or runs out of
+    choices - in the latter case it may still be going, which the state remembers. */
+    fn simulating_state(&self, state: &State, still_running: bool) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let current = Self::simulating(state, still_running);
+        let marker = self.start_marker();
+
+        self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            if choice == &marker {
+                return None;
+            }
+            let options = self
+                .choices
+                .get(&(state.clone(), work.clone()))
+                .map_or(&[][..], |options| options.as_slice());
+
+            if choice == &TapeEntry::BLANK {
+                let still_running = still_running || !options.is_empty();
+                return Some(self.transition(
+                    current.clone(),
+                    read,
+                    Self::phase_state(Phase::RewindAfterSimulation, still_running),
+                    read,
+                    [Stay, Stay],
+                ));
+            }
+            Some(match options.get(choice.0 as usize - 1) {
+                Some(trans) => self.transition(
+                    current.clone(),
+                    read,
+                    Self::simulating(&trans.state_after, still_running),
+                    [input, &trans.tape_value_after, choice],
+                    [Right, trans.tape_head_move_direction.clone()],
+                ),
+                None => self.transition(
+                    current.clone(),
+                    read,
+                    Self::phase_state(Phase::RewindAfterSimulation, still_running),
+                    read,
+                    [Stay, Stay],
+                ),
+            })
+        })
+    }
+
+    /* Back to the start marker, then on to the last choice and incrementing from there. The
+    work tape's head is never further right than the control tape's, so it gets back to its
+    first cell on the way. Once every choice of the current length was tried, the machine
+    either rejects or tries the sequences one choice longer. */
+    fn next_choices(&self, still_running: bool) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let rewind = Self::phase_state(Phase::RewindAfterSimulation, still_running);
+        let seek = Self::phase_state(Phase::SeekLastChoice, still_running);
+        let next = Self::phase_state(Phase::NextChoice, still_running);
+        let rewind_next = Self::phase_state(Phase::RewindAfterNextChoice, still_running);
+        let marker = self.start_marker();
+        let last_choice = TapeEntry(self.branching);
+        let first_choice = TapeEntry(1);
+
+        let mut transitions = self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            Some(if choice == &marker {
+                self.transition(rewind.clone(), read, seek.clone(), read, [Right, Stay])
+            } else {
+                self.transition(rewind.clone(), read, rewind.clone(), read, [Left, Left])
+            })
+        });
+        transitions.extend(self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            Some(if choice == &TapeEntry::BLANK {
+                self.transition(seek.clone(), read, next.clone(), read, [Left, Stay])
+            } else {
+                self.transition(seek.clone(), read, seek.clone(), read, [Right, Stay])
+            })
+        }));
+        transitions.extend(self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            if choice == &marker {
+                return Some(if still_running {
+                    self.transition(
+                        next.clone(),
+                        read,
+                        Self::lengthen_choices(),
+                        read,
+                        [Right, Stay],
+                    )
+                } else {
+                    self.transition(
+                        next.clone(),
+                        read,
+                        State(std_states::REJECT.to_string()),
+                        read,
+                        [Stay, Stay],
+                    )
+                });
+            }
+            if choice == &TapeEntry::BLANK {
+                return None;
+            }
+            Some(if choice == &last_choice {
+                self.transition(
+                    next.clone(),
+                    read,
+                    next.clone(),
+                    [input, work, &first_choice],
+                    [Left, Stay],
+                )
+            } else {
+                self.transition(
+                    next.clone(),
+                    read,
+                    rewind_next.clone(),
+                    [input, work, &TapeEntry(choice.0 + 1)],
+                    [Stay, Stay],
+                )
+            })
+        }));
+        transitions.extend(self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            Some(if choice == &marker {
+                self.transition(
+                    rewind_next.clone(),
+                    read,
+                    Self::phase_state(Phase::CopyInput, still_running),
+                    read,
+                    [Stay, Stay],
+                )
+            } else {
+                self.transition(
+                    rewind_next.clone(),
+                    read,
+                    rewind_next.clone(),
+                    read,
+                    [Left, Stay],
+                )
+            })
+        }));
+        transitions
+    }
+
+    // every choice is back to the first one, so one more of those makes the next sequence
+    fn lengthening(&self) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let lengthen = Self::lengthen_choices();
+        let rewind_next = Self::phase_state(Phase::RewindAfterNextChoice, false);
+        let first_choice = TapeEntry(1);
+
+        self.for_all_letters(|input, work, choice| {
+            let read = [input, work, choice];
+            Some(if choice == &TapeEntry::BLANK {
+                self.transition(
+                    lengthen.clone(),
+                    read,
+                    rewind_next.clone(),
+                    [input, work, &first_choice],
+                    [Stay, Stay],
+                )
+            } else {
+                self.transition(
+                    lengthen.clone(),
+                    read,
+                    lengthen.clone(),
+                    read,
+                    [Right, Stay],
+                )
+            })
+        })
+    }
+
+    pub fn multi_tape_transitions(&self) -> Vec<MultiTransition> {
+        use HeadMoveDirection::*;
+        let marker = self.start_marker();
+
+        // the choice track starts with the marker and the empty sequence
+        let mut transitions: Vec<MultiTransition> = self
+            .input_tape_letters()
+            .iter()
+            .map(|input| {
+                self.transition(
+                    State(std_states::START.to_string()),
+                    [input, &TapeEntry::BLANK, &TapeEntry::BLANK],
+                    Self::phase_state(Phase::CopyInput, false),
+                    [input, &TapeEntry::BLANK, &marker],
+                    [Stay, Stay],
+                )
+            })
+            .collect();
+
+        for &still_running in [false, true].iter() {
+            transitions.extend(self.copying(still_running));
+            for state in self.states.iter() {
+                if state.value() != std_states::ACCEPT {
+                    transitions.extend(self.simulating_state(state, still_running));
+                }
+            }
+            transitions.extend(self.next_choices(still_running));
+        }
+        transitions.extend(self.lengthening());
+        transitions
+    }
+}
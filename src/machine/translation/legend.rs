@@ -0,0 +1,124 @@
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+/* Maps the dense symbol numbers of a translated machine back to the super-symbols they
+stand for. One line per symbol: `<number> <letter_1> .. <letter_k> <head_1> .. <head_k> <start>` */
+pub struct SymbolLegend {
+    tapes: usize,
+    symbols: BTreeMap<TapeEntry, SuperTapeEntry>,
+}
+impl SymbolLegend {
+    /* Numbers every super-symbol in `used` densely, returning the new number of each
+    encoding. The `fixed` symbols - those that can be found on the input tape - keep their
+    numbers, the rest fill the lowest free ones in the order of their encoding. */
+    pub fn renumber(
+        used: impl IntoIterator<Item = TapeEntry>,
+        tapes: usize,
+        fixed: &[TapeEntry],
+    ) -> (HashMap<TapeEntry, TapeEntry>, Self) {
+        let used: BTreeSet<TapeEntry> = used.into_iter().chain(fixed.iter().cloned()).collect();
+
+        let mut numbers: HashMap<TapeEntry, TapeEntry> = fixed
+            .iter()
+            .map(|entry| (entry.clone(), entry.clone()))
+            .collect();
+        let taken: BTreeSet<TapeEntry> = fixed.iter().cloned().collect();
+        let mut free_numbers = (0..)
+            .map(TapeEntry)
+            .filter(|number| !taken.contains(number));
+        for entry in used.iter().filter(|entry| !taken.contains(entry)) {
+            numbers.insert(entry.clone(), free_numbers.next().unwrap());
+        }
+
+        let symbols = numbers
+            .iter()
+            .map(|(encoded, number)| {
+                (
+                    number.clone(),
+                    SuperTapeEntry::decode(encoded.clone(), tapes),
+                )
+            })
+            .collect();
+
+        (numbers, Self { tapes, symbols })
+    }
+
+    pub fn from_description(legend_description: String) -> AppResult<Self> {
+        let mut tapes = None;
+        let mut symbols = BTreeMap::new();
+
+        for line in legend_description.lines().filter(|l| !l.trim().is_empty()) {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() < 4 || !tokens.len().is_multiple_of(2) {
+                return Err(format!("malformed legend line `{}`", line).into());
+            }
+            let line_tapes = (tokens.len() - 2) / 2;
+            let expected = *tapes.get_or_insert(line_tapes);
+            if expected != line_tapes {
+                return Err(format!(
+                    "expected {} tapes but legend line `{}` has {}",
+                    expected, line, line_tapes
+                )
+                .into());
+            }
+
+            let number: TapeEntry = tokens[0].parse()?;
+            let letters = tokens[1..=line_tapes]
+                .iter()
+                .map(|token| token.parse())
+                .collect::<Result<Vec<TapeEntry>, _>>()?;
+            let heads = tokens[line_tapes + 1..=2 * line_tapes]
+                .iter()
+                .map(|&token| match token {
+                    "^" => Ok(true),
+                    "." => Ok(false),
+                    _ => Err(format!("unknown head flag `{}`", token)),
+                })
+                .collect::<Result<Vec<bool>, _>>()?;
+            let is_start = match tokens[tokens.len() - 1] {
+                "S" => true,
+                "-" => false,
+                token => return Err(format!("unknown start marker `{}`", token).into()),
+            };
+
+            symbols.insert(
+                number,
+                SuperTapeEntry {
+                    letters,
+                    heads,
+                    is_start,
+                },
+            );
+        }
+
+        Ok(Self {
+            tapes: tapes.ok_or("empty legend")?,
+            symbols,
+        })
+    }
+
+    pub fn tape_count(&self) -> usize {
+        self.tapes
+    }
+
+    pub fn decode(&self, entry: &TapeEntry) -> Option<&SuperTapeEntry> {
+        self.symbols.get(entry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+impl fmt::Display for SymbolLegend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (number, entry) in self.symbols.iter() {
+            writeln!(f, "{} {}", number, entry)?;
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,11 @@
-pub mod double_transition;
+pub mod binary;
+pub mod clock;
+pub mod decoded;
+pub mod determinizer;
+pub mod legend;
+pub mod multi_transition;
+pub mod restricted;
+pub mod simplifier;
+pub mod speedup;
+pub mod state_map;
 pub mod translator;
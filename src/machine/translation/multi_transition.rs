@@ -0,0 +1,88 @@
+use crate::machine::component::transition as one_tape_transition;
+use crate::types::*;
+use std::fmt;
+
+/* k-tape transition, written as
+`<state> <let1> .. <letk> <target_state> <out_let1> .. <out_letk> <dir1> .. <dirk>` */
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct MultiTransition {
+    pub state_before: State,
+    pub state_after: State,
+    pub tape_values_before: Vec<TapeEntry>,
+    pub tape_values_after: Vec<TapeEntry>,
+    pub tape_head_move_directions: Vec<HeadMoveDirection>,
+}
+
+impl MultiTransition {
+    pub fn from_description(description: &str) -> AppResult<Self> {
+        let tokens: Vec<&str> = description.split_whitespace().collect();
+        if tokens.len() < 5 || !(tokens.len() - 2).is_multiple_of(3) {
+            return Err(format!("bad multi-tape transition description `{}`", description).into());
+        }
+        let tapes = (tokens.len() - 2) / 3;
+        let parse_entries = |tokens: &[&str]| -> AppResult<Vec<TapeEntry>> {
+            tokens
+                .iter()
+                .map(|token| Ok(TapeEntry(token.parse::<Number>()?)))
+                .collect()
+        };
+
+        Ok(Self {
+            state_before: State(tokens[0].to_string()),
+            tape_values_before: parse_entries(&tokens[1..1 + tapes])?,
+            state_after: State(tokens[1 + tapes].to_string()),
+            tape_values_after: parse_entries(&tokens[2 + tapes..2 + 2 * tapes])?,
+            tape_head_move_directions: tokens[2 + 2 * tapes..]
+                .iter()
+                .map(
+                    |token| match token.chars().collect::<Vec<char>>().as_slice() {
+                        [symbol] => one_tape_transition::tape_head_move_from_char(*symbol),
+                        _ => Err(format!("bad tape head move description symbol {}", token).into()),
+                    },
+                )
+                .collect::<AppResult<Vec<HeadMoveDirection>>>()?,
+        })
+    }
+
+    pub fn tape_count(&self) -> usize {
+        self.tape_values_before.len()
+    }
+}
+
+// a one-tape transition as one for a single tape, to run along with multi-tape machines
+impl From<one_tape_transition::Transition> for MultiTransition {
+    fn from(trans: one_tape_transition::Transition) -> Self {
+        Self {
+            state_before: trans.state_before,
+            state_after: trans.state_after,
+            tape_values_before: vec![trans.tape_value_before],
+            tape_values_after: vec![trans.tape_value_after],
+            tape_head_move_directions: vec![trans.tape_head_move_direction],
+        }
+    }
+}
+
+impl fmt::Display for MultiTransition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = |entries: &[TapeEntry]| -> String {
+            entries
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.state_before,
+            join(&self.tape_values_before),
+            self.state_after,
+            join(&self.tape_values_after),
+            self.tape_head_move_directions
+                .iter()
+                .map(direction_to_string)
+                .collect::<Vec<String>>()
+                .join(" "),
+        )
+    }
+}
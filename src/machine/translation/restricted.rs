@@ -0,0 +1,130 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::alphabet::tape_letters;
+use crate::types::*;
+use std::collections::BTreeSet;
+
+/* Passes rewriting a one-tape machine into an equivalent one of a more restricted model.
+Both give fresh states `#$...$#` names, and both keep a deterministic machine deterministic:
+the transitions they add for a state all read different letters. */
+
+/* Replaces every `S` move with a move right into a fresh state, which moves back left
+whatever it reads. There's one fresh state per state stayed in, shared by all the
+transitions staying there. The cell right of the head may hold any letter, the input ones
+included. */
+pub fn eliminate_stays(
+    transitions: &[Transition],
+    input_alphabet: &[TapeEntry],
+) -> Vec<Transition> {
+    let mut stepping_back: BTreeSet<State> = BTreeSet::new();
+    let mut result: Vec<Transition> = vec![];
+    for trans in transitions {
+        if trans.tape_head_move_direction != HeadMoveDirection::Stay {
+            result.push(trans.clone());
+            continue;
+        }
+        let back = State(format!("#$STAY_{}$#", trans.state_after));
+        stepping_back.insert(trans.state_after.clone());
+        result.push(Transition {
+            state_after: back,
+            tape_head_move_direction: HeadMoveDirection::Right,
+            ..trans.clone()
+        });
+    }
+
+    let letters = tape_letters(transitions, input_alphabet);
+    for state in stepping_back {
+        for letter in letters.iter() {
+            result.push(Transition {
+                state_before: State(format!("#$STAY_{}$#", state)),
+                tape_value_before: letter.clone(),
+                state_after: state.clone(),
+                tape_value_after: letter.clone(),
+                tape_head_move_direction: HeadMoveDirection::Left,
+            });
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Track {
+    Upper,
+    Lower,
+}
+
+/* Runs a machine meant for a tape infinite in both directions on our tape, infinite only to
+the right. The two-way tape is folded in half at the start of the input: cell `i` of our tape
+holds cell `i` of the two-way one on its upper track and cell `-i - 1` on its lower track,
+and the first cell also carries a mark. The state remembers the track the head is on; on
+the lower track moves go the other way round, and moving off the first cell's left switches
+tracks.
+
+With `K` more than the largest letter, the cell holding `upper`, `lower` and the mark is the
+letter `upper + lower * K + mark * K * K`. A letter of the input on its own is thus a valid
+cell already, so marking the first cell is the only setup needed. */
+pub fn fold_two_way_tape(
+    transitions: &[Transition],
+    input_alphabet: &[TapeEntry],
+) -> Vec<Transition> {
+    use HeadMoveDirection::*;
+    let letters = tape_letters(transitions, input_alphabet);
+    let k = letters.iter().max().unwrap().0 + 1;
+    let cell = |upper: &TapeEntry, lower: &TapeEntry, marked: bool| {
+        TapeEntry(upper.0 + lower.0 * k + marked as Number * k * k)
+    };
+    let simulating = |state: &State, track: Track| {
+        if state.value() == std_states::ACCEPT {
+            return state.clone();
+        }
+        match track {
+            Track::Upper => State(format!("#$UPPER_{}$#", state)),
+            Track::Lower => State(format!("#$LOWER_{}$#", state)),
+        }
+    };
+
+    let start = State(std_states::START.to_string());
+    let mut result: Vec<Transition> = letters
+        .iter()
+        .map(|letter| Transition {
+            state_before: start.clone(),
+            tape_value_before: letter.clone(),
+            state_after: simulating(&start, Track::Upper),
+            tape_value_after: cell(letter, &TapeEntry::BLANK, true),
+            tape_head_move_direction: Stay,
+        })
+        .collect();
+
+    for trans in transitions {
+        if trans.leaves_accept() {
+            continue;
+        }
+        let read = &trans.tape_value_before;
+        let written = &trans.tape_value_after;
+        for other in letters.iter() {
+            for &marked in [false, true].iter() {
+                for &track in [Track::Upper, Track::Lower].iter() {
+                    let (before, after) = match track {
+                        Track::Upper => (cell(read, other, marked), cell(written, other, marked)),
+                        Track::Lower => (cell(other, read, marked), cell(other, written, marked)),
+                    };
+                    // where the head goes on our tape and the track it ends up on
+                    let (dir, track_after) = match (track, &trans.tape_head_move_direction) {
+                        (_, Stay) => (Stay, track),
+                        (Track::Upper, Right) | (Track::Lower, Left) => (Right, track),
+                        (Track::Upper, Left) if marked => (Stay, Track::Lower),
+                        (Track::Lower, Right) if marked => (Stay, Track::Upper),
+                        _ => (Left, track),
+                    };
+                    result.push(Transition {
+                        state_before: simulating(&trans.state_before, track),
+                        tape_value_before: before,
+                        state_after: simulating(&trans.state_after, track_after),
+                        tape_value_after: after,
+                        tape_head_move_direction: dir,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,209 @@
+use crate::machine::component::transition::Transition;
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+/* Passes making a one-tape machine smaller without changing the words it accepts. They only
+care about acceptance: a run that can no longer accept may end anywhere, and runs get
+shorter when a stay doing nothing is skipped. `simplify` repeats them until none of them
+finds anything more to do. */
+
+fn start() -> State {
+    State(std_states::START.to_string())
+}
+
+fn accept() -> State {
+    State(std_states::ACCEPT.to_string())
+}
+
+fn sorted(mut transitions: Vec<Transition>) -> Vec<Transition> {
+    transitions.sort_by_cached_key(Transition::to_string);
+    transitions.dedup();
+    transitions
+}
+
+/* Keeps the transitions that can fire on some input and still lead to `accept`. A transition
+can fire if its state is reachable from `start` and the letter it reads can be on the tape:
+blank, a digit of the input, or a letter written by a transition that can fire. Both are
+found together, so phases reading letters that never get written go away as well. Nothing
+leaves `accept`, as runs stop there. */
+pub fn remove_dead_transitions(transitions: &[Transition]) -> Vec<Transition> {
+    let mut letters: HashSet<TapeEntry> = (0..=9).map(TapeEntry).collect();
+    let mut reachable: HashSet<State> = vec![start()].into_iter().collect();
+    let mut firing: Vec<bool> = vec![false; transitions.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (trans, fires) in transitions.iter().zip(firing.iter_mut()) {
+            if *fires
+                || trans.leaves_accept()
+                || !reachable.contains(&trans.state_before)
+                || !letters.contains(&trans.tape_value_before)
+            {
+                continue;
+            }
+            *fires = true;
+            changed = true;
+            reachable.insert(trans.state_after.clone());
+            letters.insert(trans.tape_value_after.clone());
+        }
+    }
+
+    // states that lead to `accept`, over the transitions that can fire
+    let mut accepting: HashSet<&State> = HashSet::new();
+    let mut predecessors: BTreeMap<&State, Vec<&State>> = BTreeMap::new();
+    for (trans, _) in transitions.iter().zip(firing.iter()).filter(|(_, &f)| f) {
+        predecessors
+            .entry(&trans.state_after)
+            .or_default()
+            .push(&trans.state_before);
+    }
+    let accept = accept();
+    let mut queue: VecDeque<&State> = VecDeque::new();
+    accepting.insert(&accept);
+    queue.push_back(&accept);
+    while let Some(state) = queue.pop_front() {
+        for before in predecessors.get(state).into_iter().flatten() {
+            if accepting.insert(before) {
+                queue.push_back(before);
+            }
+        }
+    }
+
+    sorted(
+        transitions
+            .iter()
+            .zip(firing.iter())
+            .filter(|(trans, &fires)| fires && accepting.contains(&trans.state_after))
+            .map(|(trans, _)| trans.clone())
+            .collect(),
+    )
+}
+
+fn is_no_op(trans: &Transition) -> bool {
+    trans.tape_head_move_direction == HeadMoveDirection::Stay
+        && trans.tape_value_before == trans.tape_value_after
+}
+
+/* Skips the stays that neither write nor move. Such a step from `q` to `p` reading `a` leaves
+the tape as it was, so `q` may as well do right away whatever `p` (or the state a chain of
+such steps ends in) does reading `a`. A chain going round in a circle never accepts, and one
+ending in `accept` becomes a single step there. */
+pub fn collapse_stays(transitions: &[Transition]) -> Vec<Transition> {
+    let mut index: BTreeMap<(&State, &TapeEntry), Vec<&Transition>> = BTreeMap::new();
+    for trans in transitions {
+        index
+            .entry((&trans.state_before, &trans.tape_value_before))
+            .or_default()
+            .push(trans);
+    }
+
+    let mut result: Vec<Transition> = vec![];
+    for (&(state, letter), choices) in index.iter() {
+        if !choices.iter().any(|trans| is_no_op(trans)) {
+            result.extend(choices.iter().map(|&trans| trans.clone()));
+            continue;
+        }
+        // the states the chains starting here go through, `state` included
+        let mut chained: BTreeSet<&State> = BTreeSet::new();
+        let mut pending = vec![state];
+        chained.insert(state);
+        while let Some(current) = pending.pop() {
+            for trans in index.get(&(current, letter)).into_iter().flatten() {
+                if is_no_op(trans) {
+                    if chained.insert(&trans.state_after) {
+                        pending.push(&trans.state_after);
+                    }
+                    continue;
+                }
+                result.push(Transition {
+                    state_before: state.clone(),
+                    ..(*trans).clone()
+                });
+            }
+        }
+        if chained.contains(&accept()) {
+            result.push(Transition {
+                state_before: state.clone(),
+                tape_value_before: letter.clone(),
+                state_after: accept(),
+                tape_value_after: letter.clone(),
+                tape_head_move_direction: HeadMoveDirection::Stay,
+            });
+        }
+    }
+    sorted(result)
+}
+
+/* Merges the states no run can tell apart: those that, for every letter, have the same
+choices of what to write, where to move and which group of states to go to next. The
+groups start out as `accept` and everything else and get split until they stay the same.
+A group keeps the name of `start` if it holds it, and of its first state otherwise. */
+pub fn merge_equivalent_states(transitions: &[Transition]) -> Vec<Transition> {
+    let mut states: BTreeSet<State> = transitions
+        .iter()
+        .flat_map(|trans| vec![trans.state_before.clone(), trans.state_after.clone()])
+        .collect();
+    states.insert(start());
+    states.insert(accept());
+
+    let mut group: BTreeMap<&State, usize> = states
+        .iter()
+        .map(|state| (state, (state.value() != std_states::ACCEPT) as usize))
+        .collect();
+    let mut group_count = 0;
+    loop {
+        let mut choices: BTreeMap<&State, BTreeSet<_>> = BTreeMap::new();
+        for trans in transitions {
+            choices.entry(&trans.state_before).or_default().insert((
+                &trans.tape_value_before,
+                &trans.tape_value_after,
+                &trans.tape_head_move_direction,
+                group[&trans.state_after],
+            ));
+        }
+        let mut signatures: BTreeMap<_, usize> = BTreeMap::new();
+        let mut refined: BTreeMap<&State, usize> = BTreeMap::new();
+        for state in states.iter() {
+            let signature = (group[state], choices.remove(state).unwrap_or_default());
+            let next_id = signatures.len();
+            refined.insert(state, *signatures.entry(signature).or_insert(next_id));
+        }
+        group = refined;
+        if signatures.len() == group_count {
+            break;
+        }
+        group_count = signatures.len();
+    }
+
+    // states come in order, so the first one seen in a group is its smallest
+    let mut names: BTreeMap<usize, &State> = BTreeMap::new();
+    for state in states.iter() {
+        names.entry(group[state]).or_insert(state);
+    }
+    let start = start();
+    names.insert(group[&start], &start);
+    let renamed = |state: &State| names[&group[state]].clone();
+
+    sorted(
+        transitions
+            .iter()
+            .map(|trans| Transition {
+                state_before: renamed(&trans.state_before),
+                state_after: renamed(&trans.state_after),
+                ..trans.clone()
+            })
+            .collect(),
+    )
+}
+
+pub fn simplify(transitions: &[Transition]) -> Vec<Transition> {
+    let mut current = sorted(transitions.to_vec());
+    loop {
+        let simplified =
+            merge_equivalent_states(&collapse_stays(&remove_dead_transitions(&current)));
+        if simplified == current {
+            return current;
+        }
+        current = simplified;
+    }
+}
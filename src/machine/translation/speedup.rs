@@ -0,0 +1,687 @@
+use crate::machine::component::transition::Transition;
+use crate::machine::logic::alphabet;
+use crate::types::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+// how a run of the original machine leaves a window of blocks
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Outcome {
+    Accepted,
+    // no transition applied in `state`
+    Halted {
+        state: State,
+        window: Vec<TapeEntry>,
+    },
+    // the head went past the right end of the window if `to_right`, past the left one if not
+    Exited {
+        state: State,
+        window: Vec<TapeEntry>,
+        to_right: bool,
+    },
+}
+
+// the block a step of the main loop came from, as it left it
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Behind {
+    block: Vec<TapeEntry>,
+    is_start: bool,
+    on_left: bool,
+}
+
+/* The linear speedup theorem for our one-tape machines: every cell of the new machine holds
+a block of `factor` cells of the original one, packed like a `SuperTapeEntry` with a letter
+per cell. The first block carries the start marker, and an all-blank block is just a blank.
+
+A step of the new machine's main loop reads the block the head is in, knowing the one it
+came from, and runs the original machine on those two in the finite control until the head
+leaves them. It comes in at the edge next to the block behind, so that takes `factor` steps
+at least. Writing the blocks back and getting to the next one takes three steps at most,
+so `t` steps of the original machine take at most `3 * (t / factor) + 1` here.
+
+The input comes one letter per cell though, so it's packed first: letters are gathered
+`factor` at a time, leaving holes - blanks with the first head flag set - behind, and each
+block is carried left next to the last one packed. That takes about `n * n / factor` steps
+on its own, as there's no faster way to move things around on a single tape. The control
+knows every pair of blocks, so the number of transitions grows with the alphabet to the
+power of `2 * factor`; factors needing more than `MAX_TRANSITIONS` are refused. */
+pub struct LinearSpeedup {
+    factor: usize,
+    choices: BTreeMap<(State, TapeEntry), Vec<Transition>>,
+    tape_letters: Vec<TapeEntry>,
+    input_letters: Vec<TapeEntry>,
+}
+impl LinearSpeedup {
+    pub const MAX_TRANSITIONS: u128 = 500_000;
+
+    pub fn new(
+        transitions: Vec<Transition>,
+        factor: usize,
+        input_alphabet: &[TapeEntry],
+    ) -> AppResult<Self> {
+        if factor == 0 {
+            return Err("blocks need at least one cell".into());
+        }
+        let read = transitions.iter().map(|trans| &trans.tape_value_before);
+        let input_letters = alphabet::input_letters(read, input_alphabet);
+        let tape_letters = alphabet::tape_letters(&transitions, input_alphabet);
+        let mut choices: BTreeMap<(State, TapeEntry), Vec<Transition>> = BTreeMap::new();
+        for trans in transitions {
+            if !trans.leaves_accept() {
+                choices
+                    .entry((trans.state_before.clone(), trans.tape_value_before.clone()))
+                    .or_default()
+                    .push(trans);
+            }
+        }
+
+        let largest = tape_letters.iter().max().unwrap();
+        if factor > 60 || largest.0 >> SuperTapeEntry::letter_bit_count(factor) != 0 {
+            return Err(format!(
+                "letter {} doesn't fit in a block of {} cells",
+                largest, factor
+            )
+            .into());
+        }
+
+        /* A step of the main loop can start in any state with any block behind, both
+        maybe the first, and read any block: eight transitions per state and pair of
+        blocks at most. */
+        let states: BTreeSet<&State> = choices.keys().map(|(state, _)| state).collect();
+        let blocks = (tape_letters.len() as u128).saturating_pow(factor as u32);
+        let most = (8 * states.len().max(1) as u128)
+            .saturating_mul(blocks)
+            .saturating_mul(blocks);
+        if most > Self::MAX_TRANSITIONS {
+            return Err(format!(
+                "blocks of {} cells would take up to {} transitions, more than {}",
+                factor,
+                most,
+                Self::MAX_TRANSITIONS
+            )
+            .into());
+        }
+
+        Ok(Self {
+            factor,
+            choices,
+            tape_letters: tape_letters.into_iter().collect(),
+            input_letters: input_letters.into_iter().collect(),
+        })
+    }
+
+    pub fn factor(&self) -> usize {
+        self.factor
+    }
+
+    // the most steps the main loop takes for `factor` steps of the original machine
+    pub fn steps_per_block_step() -> Number {
+        3
+    }
+
+    // whether a run in `state` has packed its input and is at the start of a block step
+    pub fn is_block_step_start(state: &State) -> bool {
+        state.value().starts_with("#$AT_")
+    }
+
+    fn packed(&self, block: &[TapeEntry], is_start: bool, is_hole: bool) -> TapeEntry {
+        let mut heads = vec![false; self.factor];
+        heads[0] = is_hole;
+        SuperTapeEntry {
+            letters: block.to_vec(),
+            heads,
+            is_start,
+        }
+        .encoded()
+    }
+
+    fn hole(&self, is_start: bool) -> TapeEntry {
+        self.packed(&vec![TapeEntry::BLANK; self.factor], is_start, true)
+    }
+
+    // every block of `letters`, in a fixed order
+    fn blocks(&self, letters: &[TapeEntry]) -> Vec<Vec<TapeEntry>> {
+        let mut blocks: Vec<Vec<TapeEntry>> = vec![vec![]];
+        for _ in 0..self.factor {
+            blocks = blocks
+                .into_iter()
+                .flat_map(|block| {
+                    letters.iter().map(move |letter| {
+                        let mut longer = block.clone();
+                        longer.push(letter.clone());
+                        longer
+                    })
+                })
+                .collect();
+        }
+        blocks
+    }
+
+    fn block_name(block: &[TapeEntry]) -> String {
+        let letters: Vec<String> = block.iter().map(TapeEntry::to_string).collect();
+        letters.join(".")
+    }
+
+    fn named(phase: &str, fields: &[String]) -> State {
+        State(format!("#${}_{}$#", phase, fields.join("#")))
+    }
+
+    /* Where a step of the main loop starts, in `state` with the block behind it. The first
+    one has nothing behind it, the first block of the tape ahead. */
+    fn at(state: &State, behind: Option<&Behind>) -> State {
+        match behind {
+            None => Self::named("AT", &[state.to_string()]),
+            Some(behind) => Self::named(
+                "AT",
+                &[
+                    state.to_string(),
+                    Self::block_name(&behind.block),
+                    behind.is_start.to_string(),
+                    if behind.on_left { "L" } else { "R" }.to_string(),
+                ],
+            ),
+        }
+    }
+
+    // a halted run of the original machine halts in the same state, unless that's the setup's
+    fn halted(state: &State) -> State {
+        if state.value() == std_states::START {
+            return Self::named("HALTED", &[state.to_string()]);
+        }
+        state.clone()
+    }
+
+    /* All the ways the original machine can leave `window` from `head`, running until the
+    head gets out of it. If the window starts at the first cell, moving left there stays. */
+    fn outcomes(
+        &self,
+        state: &State,
+        window: &[TapeEntry],
+        head: usize,
+        at_tape_start: bool,
+    ) -> BTreeSet<Outcome> {
+        let mut outcomes = BTreeSet::new();
+        let first = (state.clone(), window.to_vec(), head);
+        let mut seen: BTreeSet<(State, Vec<TapeEntry>, usize)> = BTreeSet::new();
+        let mut pending = vec![first.clone()];
+        seen.insert(first);
+        while let Some((state, window, head)) = pending.pop() {
+            if state.value() == std_states::ACCEPT {
+                outcomes.insert(Outcome::Accepted);
+                continue;
+            }
+            let options = match self.choices.get(&(state.clone(), window[head].clone())) {
+                Some(options) => options,
+                None => {
+                    outcomes.insert(Outcome::Halted { state, window });
+                    continue;
+                }
+            };
+            for trans in options {
+                let mut window = window.clone();
+                window[head] = trans.tape_value_after.clone();
+                let state = trans.state_after.clone();
+                let head = match trans.tape_head_move_direction {
+                    HeadMoveDirection::Left if head == 0 && at_tape_start => 0,
+                    HeadMoveDirection::Left if head == 0 => {
+                        outcomes.insert(Outcome::Exited {
+                            state,
+                            window,
+                            to_right: false,
+                        });
+                        continue;
+                    }
+                    HeadMoveDirection::Right if head + 1 == window.len() => {
+                        outcomes.insert(Outcome::Exited {
+                            state,
+                            window,
+                            to_right: true,
+                        });
+                        continue;
+                    }
+                    HeadMoveDirection::Left => head - 1,
+                    HeadMoveDirection::Right => head + 1,
+                    HeadMoveDirection::Stay => head,
+                };
+                let next = (state, window, head);
+                if seen.insert(next.clone()) {
+                    pending.push(next);
+                }
+            }
+        }
+        outcomes
+    }
+
+    pub fn transitions(&self) -> Vec<Transition> {
+        let mut result = self.packing();
+        result.extend(self.main_loop());
+        result.sort_by_cached_key(Transition::to_string);
+        result.dedup();
+        result
+    }
+
+    fn push(
+        result: &mut Vec<Transition>,
+        before: State,
+        read: TapeEntry,
+        after: State,
+        written: TapeEntry,
+        dir: HeadMoveDirection,
+    ) {
+        result.push(Transition {
+            state_before: before,
+            tape_value_before: read,
+            state_after: after,
+            tape_value_after: written,
+            tape_head_move_direction: dir,
+        });
+    }
+
+    /* Packs the input and leaves the head on the first block. `start` and `#$SEEK$#` begin
+    a block, `#$GATHER_..$#` picks up the rest of its letters, `#$DEPOSIT_..$#` walks left
+    over the holes and `#$PLACE_..$#` puts it down on the first of them. Once the input is
+    over the head goes back to the start; the main loop reads the holes left as blanks. */
+    fn packing(&self) -> Vec<Transition> {
+        use HeadMoveDirection::*;
+        let mut result = vec![];
+        let blank_block = vec![TapeEntry::BLANK; self.factor];
+        let start = State(std_states::START.to_string());
+        let seek = Self::named("SEEK", &[]);
+        let rewind = Self::named("REWIND", &[]);
+        let gather = |letters: &[TapeEntry]| Self::named("GATHER", &[Self::block_name(letters)]);
+        let deposit = |block: &[TapeEntry], last: bool| {
+            Self::named("DEPOSIT", &[Self::block_name(block), last.to_string()])
+        };
+        let place = |block: &[TapeEntry], last: bool| {
+            Self::named("PLACE", &[Self::block_name(block), last.to_string()])
+        };
+        let first_block = Self::at(&start, None);
+
+        // gathered letters so far, the state gathering after them and the hole left behind
+        let mut gathering: Vec<(Vec<TapeEntry>, State, bool)> =
+            vec![(vec![], start.clone(), true), (vec![], seek.clone(), false)];
+        while let Some((letters, state, is_start)) = gathering.pop() {
+            for letter in self.input_letters.iter() {
+                let mut letters = letters.clone();
+                letters.push(letter.clone());
+                let (after, dir) = if letters.len() == self.factor {
+                    (deposit(&letters, false), Left)
+                } else {
+                    gathering.push((letters.clone(), gather(&letters), false));
+                    (gather(&letters), Right)
+                };
+                Self::push(
+                    &mut result,
+                    state.clone(),
+                    letter.clone(),
+                    after,
+                    self.hole(is_start),
+                    dir,
+                );
+            }
+            if letters.is_empty() {
+                continue;
+            }
+            // the input is over, so the rest of the block is blank
+            let mut block = letters.clone();
+            block.resize(self.factor, TapeEntry::BLANK);
+            Self::push(
+                &mut result,
+                state,
+                TapeEntry::BLANK,
+                deposit(&block, true),
+                TapeEntry::BLANK,
+                Left,
+            );
+        }
+        // an empty input is a single blank block
+        Self::push(
+            &mut result,
+            start,
+            TapeEntry::BLANK,
+            first_block.clone(),
+            self.packed(&blank_block, true, false),
+            Stay,
+        );
+
+        // only the input gets packed, with blanks after its end
+        let mut letters: BTreeSet<TapeEntry> = self.input_letters.iter().cloned().collect();
+        letters.insert(TapeEntry::BLANK);
+        let packed_blocks = self.blocks(&letters.into_iter().collect::<Vec<_>>());
+        for block in packed_blocks.iter() {
+            for &last in [false, true].iter() {
+                let (done, dir) = if last {
+                    (rewind.clone(), Left)
+                } else {
+                    (seek.clone(), Right)
+                };
+                let (first_done, first_dir) = if last {
+                    (first_block.clone(), Stay)
+                } else {
+                    (seek.clone(), Right)
+                };
+                Self::push(
+                    &mut result,
+                    deposit(block, last),
+                    self.hole(false),
+                    deposit(block, last),
+                    self.hole(false),
+                    Left,
+                );
+                Self::push(
+                    &mut result,
+                    deposit(block, last),
+                    self.hole(true),
+                    first_done,
+                    self.packed(block, true, false),
+                    first_dir,
+                );
+                Self::push(
+                    &mut result,
+                    place(block, last),
+                    self.hole(false),
+                    done,
+                    self.packed(block, false, false),
+                    dir,
+                );
+                // the last block packed, the first one perhaps
+                for other in packed_blocks.iter() {
+                    for &is_start in [false, true].iter() {
+                        let other = self.packed(other, is_start, false);
+                        Self::push(
+                            &mut result,
+                            deposit(block, last),
+                            other.clone(),
+                            place(block, last),
+                            other,
+                            Right,
+                        );
+                    }
+                }
+            }
+        }
+
+        Self::push(
+            &mut result,
+            seek.clone(),
+            self.hole(false),
+            seek.clone(),
+            self.hole(false),
+            Right,
+        );
+        Self::push(
+            &mut result,
+            seek,
+            TapeEntry::BLANK,
+            rewind.clone(),
+            TapeEntry::BLANK,
+            Left,
+        );
+        Self::push(
+            &mut result,
+            rewind.clone(),
+            self.hole(false),
+            rewind.clone(),
+            self.hole(false),
+            Left,
+        );
+        for block in packed_blocks.iter() {
+            let packed = self.packed(block, false, false);
+            Self::push(
+                &mut result,
+                rewind.clone(),
+                packed.clone(),
+                rewind.clone(),
+                packed,
+                Left,
+            );
+            let first = self.packed(block, true, false);
+            Self::push(
+                &mut result,
+                rewind.clone(),
+                first.clone(),
+                first_block.clone(),
+                first,
+                Stay,
+            );
+        }
+        result
+    }
+
+    /* `#$AT_..$#` reads the block the head is in and runs the original machine on it and the
+    one behind. If that one changed, or the head left past it, `#$WRITE_BACK_..$#` goes back
+    to write it; `#$PASS_..$#` moves on over the block read when the head left past that. */
+    fn main_loop(&self) -> Vec<Transition> {
+        let mut main_loop = MainLoop {
+            speedup: self,
+            blocks: self.blocks(&self.tape_letters),
+            result: vec![],
+            pending: vec![],
+            seen: BTreeSet::new(),
+        };
+        main_loop.visit(&State(std_states::START.to_string()), None);
+        while let Some((state, behind)) = main_loop.pending.pop() {
+            main_loop.block_step(&state, behind.as_ref());
+        }
+        main_loop.result
+    }
+}
+
+// the transitions of the main loop, for the steps a run can get to
+struct MainLoop<'a> {
+    speedup: &'a LinearSpeedup,
+    blocks: Vec<Vec<TapeEntry>>,
+    result: Vec<Transition>,
+    pending: Vec<(State, Option<Behind>)>,
+    seen: BTreeSet<(State, Option<Behind>)>,
+}
+impl MainLoop<'_> {
+    fn visit(&mut self, state: &State, behind: Option<Behind>) -> State {
+        let at = LinearSpeedup::at(state, behind.as_ref());
+        if self.seen.insert((state.clone(), behind.clone())) {
+            self.pending.push((state.clone(), behind));
+        }
+        at
+    }
+
+    fn packed(&self, block: &[TapeEntry], is_start: bool) -> TapeEntry {
+        self.speedup.packed(block, is_start, false)
+    }
+
+    fn push(
+        &mut self,
+        before: State,
+        read: TapeEntry,
+        after: State,
+        written: TapeEntry,
+        dir: HeadMoveDirection,
+    ) {
+        LinearSpeedup::push(&mut self.result, before, read, after, written, dir);
+    }
+
+    // rewrites the block behind from `old` to `new` on the way to `after`
+    fn write_back(
+        &mut self,
+        old: &[TapeEntry],
+        new: &[TapeEntry],
+        is_start: bool,
+        after: State,
+        dir: HeadMoveDirection,
+    ) -> State {
+        let write_back = LinearSpeedup::named(
+            "WRITE_BACK",
+            &[
+                LinearSpeedup::block_name(old),
+                LinearSpeedup::block_name(new),
+                is_start.to_string(),
+                direction_to_string(&dir),
+                after.to_string(),
+            ],
+        );
+        let (read, written) = (self.packed(old, is_start), self.packed(new, is_start));
+        self.push(write_back.clone(), read, after, written, dir);
+        write_back
+    }
+
+    // goes over the block just written, `block`, on the way to `after`
+    fn pass(
+        &mut self,
+        block: &[TapeEntry],
+        is_start: bool,
+        after: State,
+        dir: HeadMoveDirection,
+    ) -> State {
+        let pass = LinearSpeedup::named(
+            "PASS",
+            &[
+                LinearSpeedup::block_name(block),
+                is_start.to_string(),
+                direction_to_string(&dir),
+                after.to_string(),
+            ],
+        );
+        let read = self.packed(block, is_start);
+        self.push(pass.clone(), read.clone(), after, read, dir);
+        pass
+    }
+
+    fn block_step(&mut self, state: &State, behind: Option<&Behind>) {
+        let factor = self.speedup.factor;
+        let at = LinearSpeedup::at(state, behind);
+        // only the first block has nothing behind it, or the first block left of it
+        let starts: &[bool] = match behind {
+            None => &[true],
+            Some(behind) if behind.on_left => &[false],
+            Some(_) => &[false, true],
+        };
+        for block in self.blocks.clone() {
+            // the window and where the head comes in
+            let (window, head) = match behind {
+                None => (block.clone(), 0),
+                Some(behind) if behind.on_left => {
+                    let window = behind.block.iter().chain(block.iter()).cloned().collect();
+                    (window, factor)
+                }
+                Some(behind) => {
+                    let window = block.iter().chain(behind.block.iter()).cloned().collect();
+                    (window, factor - 1)
+                }
+            };
+            for &is_start in starts {
+                let at_tape_start = is_start || behind.is_some_and(|behind| behind.is_start);
+                let mut reads = vec![self.packed(&block, is_start)];
+                // the holes packing left past the last block are blank too
+                if !is_start && block.iter().all(|letter| *letter == TapeEntry::BLANK) {
+                    reads.push(self.speedup.hole(false));
+                }
+                let outcomes = self.speedup.outcomes(state, &window, head, at_tape_start);
+                for outcome in outcomes {
+                    let (after, written, dir) = self.finish(behind, &block, is_start, outcome);
+                    for read in reads.iter() {
+                        let (after, written) = (after.clone(), written.clone());
+                        self.push(at.clone(), read.clone(), after, written, dir.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /* What a step of the main loop does after reading `block`, given how the original
+    machine left the window: the state to go to, the block to write and where to move. */
+    fn finish(
+        &mut self,
+        behind: Option<&Behind>,
+        block: &[TapeEntry],
+        is_start: bool,
+        outcome: Outcome,
+    ) -> (State, TapeEntry, HeadMoveDirection) {
+        use HeadMoveDirection::*;
+        let factor = self.speedup.factor;
+        let (after, window, to_right) = match outcome {
+            Outcome::Accepted => {
+                let accept = State(std_states::ACCEPT.to_string());
+                return (accept, self.packed(block, is_start), Stay);
+            }
+            Outcome::Halted { state, window } => (LinearSpeedup::halted(&state), window, None),
+            Outcome::Exited {
+                state,
+                window,
+                to_right,
+            } => (state, window, Some(to_right)),
+        };
+
+        let behind = match behind {
+            Some(behind) => behind,
+            // on the first block the head can only leave to the right
+            None => {
+                let written = self.packed(&window, is_start);
+                let after = match to_right {
+                    None => after,
+                    Some(_) => {
+                        let behind = Behind {
+                            block: window,
+                            is_start,
+                            on_left: true,
+                        };
+                        self.visit(&after, Some(behind))
+                    }
+                };
+                let dir = if to_right.is_some() { Right } else { Stay };
+                return (after, written, dir);
+            }
+        };
+        let (left_behind, ahead) = if behind.on_left {
+            (window[..factor].to_vec(), window[factor..].to_vec())
+        } else {
+            (window[factor..].to_vec(), window[..factor].to_vec())
+        };
+        let written = self.packed(&ahead, is_start);
+        let (back, forward) = if behind.on_left {
+            (Left, Right)
+        } else {
+            (Right, Left)
+        };
+        let changed = left_behind != behind.block;
+
+        let to_right = match to_right {
+            None if !changed => return (after, written, Stay),
+            None => {
+                let write_back =
+                    self.write_back(&behind.block, &left_behind, behind.is_start, after, Stay);
+                return (write_back, written, back);
+            }
+            Some(to_right) => to_right,
+        };
+        if to_right == behind.on_left {
+            // on past the block read, which is behind the next step
+            let next = Behind {
+                block: ahead.clone(),
+                is_start,
+                on_left: behind.on_left,
+            };
+            let next = self.visit(&after, Some(next));
+            if !changed {
+                return (next, written, forward);
+            }
+            let pass = self.pass(&ahead, is_start, next, forward.clone());
+            let write_back =
+                self.write_back(&behind.block, &left_behind, behind.is_start, pass, forward);
+            (write_back, written, back)
+        } else {
+            // on past the block behind, which is behind the next step then
+            let next = Behind {
+                block: left_behind.clone(),
+                is_start: behind.is_start,
+                on_left: !behind.on_left,
+            };
+            let next = self.visit(&after, Some(next));
+            let write_back = self.write_back(
+                &behind.block,
+                &left_behind,
+                behind.is_start,
+                next,
+                back.clone(),
+            );
+            (write_back, written, back)
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::types::*;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// what a state of a translated machine is doing, and for which transitions of the original
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StateMeaning {
+    pub phase: State,
+    pub transitions: Vec<MultiTransition>,
+}
+impl fmt::Display for StateMeaning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.phase)?;
+        for trans in self.transitions.iter() {
+            write!(f, " | {}", trans)?;
+        }
+        Ok(())
+    }
+}
+
+/* Maps the states of a translated machine to their meaning. One line per state:
+`<state> <phase> | <original_transition> | ..`, where `<phase>` is the long descriptive
+name of the state and the transitions are the ones it may be simulating. */
+#[derive(Debug, Default)]
+pub struct StateMap {
+    meanings: BTreeMap<State, StateMeaning>,
+}
+impl StateMap {
+    pub fn insert(&mut self, state: State, meaning: StateMeaning) {
+        self.meanings.insert(state, meaning);
+    }
+
+    pub fn meaning(&self, state: &State) -> Option<&StateMeaning> {
+        self.meanings.get(state)
+    }
+
+    // renames the states, keeping their meanings
+    pub fn renamed(self, new_name: impl Fn(&State) -> State) -> Self {
+        Self {
+            meanings: self
+                .meanings
+                .into_iter()
+                .map(|(state, meaning)| (new_name(&state), meaning))
+                .collect(),
+        }
+    }
+
+    pub fn from_description(map_description: String) -> AppResult<Self> {
+        let mut map = Self::default();
+        for line in map_description.lines().filter(|l| !l.trim().is_empty()) {
+            let mut parts = line.split(" | ");
+            let names: Vec<&str> = parts
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect();
+            if names.len() != 2 {
+                return Err(format!("malformed state map line `{}`", line).into());
+            }
+            let transitions = parts
+                .map(MultiTransition::from_description)
+                .collect::<AppResult<Vec<MultiTransition>>>()?;
+
+            map.insert(
+                State(names[0].to_string()),
+                StateMeaning {
+                    phase: State(names[1].to_string()),
+                    transitions,
+                },
+            );
+        }
+        Ok(map)
+    }
+}
+impl fmt::Display for StateMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (state, meaning) in self.meanings.iter() {
+            writeln!(f, "{} {}", state, meaning)?;
+        }
+        Ok(())
+    }
+}
@@ -1,83 +1,336 @@
 use crate::machine::component::transition::Transition;
-use crate::machine::logic::loader;
-use crate::machine::translation::double_transition::DoubleTransition;
+use crate::machine::logic::determinism::{self, ConflictGroup};
+use crate::machine::translation::legend::SymbolLegend;
+use crate::machine::translation::multi_transition::MultiTransition;
+use crate::machine::translation::state_map::{StateMap, StateMeaning};
 use crate::types::*;
-use itertools::iproduct;
-use std::collections::HashSet;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::io;
 
 struct TranslatorInput {
-    transitions: Vec<DoubleTransition>,
-    states: Vec<State>,
+    transitions: Vec<MultiTransition>,
+    tapes: usize,
     tape_entries: Vec<TapeEntry>,
+    halting_states: Vec<State>,
 }
 impl TranslatorInput {
-    pub fn new(machine_description: String) -> AppResult<Self> {
-        let transitions = loader::two_tape_transitions_from_description(machine_description)?;
-        let program_states: HashSet<State> = transitions
-            .iter()
-            .cloned()
-            .map(|trans| vec![trans.state_before, trans.state_after])
-            .flatten()
-            .collect();
+    pub fn new(transitions: Vec<MultiTransition>) -> AppResult<Self> {
+        let tapes = transitions.first().map_or(2, |trans| trans.tape_count());
+        if tapes < 2 {
+            return Err("only machines with at least two tapes need translating".into());
+        }
+        if let Some(trans) = transitions.iter().find(|trans| trans.tape_count() != tapes) {
+            return Err(format!("expected {} tapes in transition `{}`", tapes, trans).into());
+        }
 
         let mut program_tape_entries: HashSet<TapeEntry> = transitions
             .iter()
             .cloned()
-            .map(|trans| {
-                vec![
-                    trans.first_tape_value_before,
-                    trans.first_tape_value_after,
-                    trans.second_tape_value_before,
-                    trans.second_tape_value_after,
-                ]
+            .flat_map(|trans| {
+                let mut entries = trans.tape_values_before;
+                entries.extend(trans.tape_values_after);
+                entries
             })
-            .flatten()
             .collect();
 
-        // let digits: Vec<TapeEntry> = (0..9).map(|d| TapeEntry(d)).collect();
-        // program_tape_entries.extend(digits);
-        let digits: Vec<TapeEntry> = (0..1).map(|d| TapeEntry(d)).collect();
-        program_tape_entries.extend(digits);
+        // other input letters come from a declared alphabet, see `declare_input_alphabet`
+        program_tape_entries.insert(TapeEntry::BLANK);
+        Self::check_fit(program_tape_entries.iter(), tapes)?;
+
+        // the order of the description doesn't matter, so that the output doesn't depend on it
+        let mut transitions = transitions;
+        transitions.sort_by(|a, b| {
+            let key = |trans: &MultiTransition| {
+                (
+                    trans.state_before.clone(),
+                    trans.tape_values_before.clone(),
+                    trans.state_after.clone(),
+                    trans.tape_values_after.clone(),
+                    trans.tape_head_move_directions.clone(),
+                )
+            };
+            key(a).cmp(&key(b))
+        });
 
+        let halting_states = Self::halting_states(&transitions);
         Ok(Self {
-            transitions: transitions,
-            states: program_states.iter().cloned().collect(),
-            tape_entries: program_tape_entries.iter().cloned().collect(),
+            transitions,
+            tapes,
+            tape_entries: program_tape_entries.into_iter().sorted().collect(),
+            halting_states,
         })
     }
+
+    fn check_fit<'a>(
+        mut entries: impl Iterator<Item = &'a TapeEntry>,
+        tapes: usize,
+    ) -> AppResult<()> {
+        let letter_limit: Number = 1 << SuperTapeEntry::letter_bit_count(tapes);
+        if let Some(entry) = entries.find(|entry| entry.0 >= letter_limit) {
+            return Err(format!(
+                "symbol {} doesn't fit in a {}-tape super-symbol",
+                entry, tapes
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    // input words may hold letters no transition mentions, the setup has to get past them too
+    fn declare_input_alphabet(&mut self, alphabet: &[TapeEntry]) -> AppResult<()> {
+        Self::check_fit(alphabet.iter(), self.tapes)?;
+        self.tape_entries.extend(alphabet.iter().cloned());
+        self.tape_entries.sort();
+        self.tape_entries.dedup();
+        Ok(())
+    }
+
+    /* `accept`, and every state a run can end up in and not leave - `reject` or any custom
+    final state of a transducer. The translated machine halts in the same ones. */
+    fn halting_states(transitions: &[MultiTransition]) -> Vec<State> {
+        let left: HashSet<&State> = transitions
+            .iter()
+            .map(|trans| &trans.state_before)
+            .collect();
+        let mut halting: Vec<State> = transitions
+            .iter()
+            .map(|trans| trans.state_after.clone())
+            .filter(|state| !left.contains(state))
+            .chain(std::iter::once(State(std_states::ACCEPT.to_string())))
+            .collect();
+        halting.sort();
+        halting.dedup();
+        halting
+    }
+}
+
+type ReadPrefix = (State, Vec<TapeEntry>);
+
+/* what is left to do once the transition to simulate is known - as tapes are written from
+the last one down, only the letters and moves for the first `tape_count` tapes are kept */
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct WriteData {
+    tape_values_after: Vec<TapeEntry>,
+    tape_head_move_directions: Vec<HeadMoveDirection>,
+    state_after: State,
+}
+impl From<&MultiTransition> for WriteData {
+    fn from(trans: &MultiTransition) -> Self {
+        Self {
+            tape_values_after: trans.tape_values_after.clone(),
+            tape_head_move_directions: trans.tape_head_move_directions.clone(),
+            state_after: trans.state_after.clone(),
+        }
+    }
+}
+impl WriteData {
+    // written tapes are dropped so that transitions differing only on them share the phases
+    fn up_to(&self, tape_count: usize) -> Self {
+        Self {
+            tape_values_after: self.tape_values_after[..tape_count].to_vec(),
+            tape_head_move_directions: self.tape_head_move_directions[..tape_count].to_vec(),
+            state_after: self.state_after.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum ReadStep {
+    GoToStart,
+    ReachTape,
+    ReadValue,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum WriteStep {
+    HandleHeadMoved,
+    GoToStart,
+    ReachTape,
+    WriteValue,
+}
+
+/* How a single step of the k-tape machine gets simulated:
+- `HeadToHead` waits on the first tape's head, knowing on which side of it every other
+  head is, and walks straight to each of them and back - the letters read on the way
+  come back along with the state, the first tape's letter is read again once back,
+- `StartMarker` waits on the first tape's head and, for every other tape, walks back to
+  the start marker and then right until its head is found - once to read it and once to
+  write it,
+- `OneSweep` waits on the start marker, reads all the heads in one sweep right up to the
+  rightmost one and then writes them all while sweeping back. */
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Strategy {
+    HeadToHead,
+    StartMarker,
+    OneSweep,
+}
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [
+        Strategy::HeadToHead,
+        Strategy::StartMarker,
+        Strategy::OneSweep,
+    ];
+
+    pub fn from_name(name: &str) -> AppResult<Self> {
+        Self::ALL
+            .iter()
+            .find(|strategy| strategy.to_string() == name)
+            .cloned()
+            .ok_or_else(|| format!("unknown translation strategy `{}`", name).into())
+    }
+
+    // upper bound on the steps needed to simulate one step, with the rightmost head on cell `n`
+    pub fn step_overhead(&self, tapes: usize, n: usize) -> Number {
+        let (tapes, n) = (tapes as Number, n as Number);
+        match self {
+            Strategy::HeadToHead => (2 * tapes - 3) * (2 * n + 3) + 1,
+            Strategy::StartMarker => (tapes - 1) * (4 * n + 9) + 2,
+            Strategy::OneSweep => 2 * n + 3 * tapes + 3,
+        }
+    }
+
+    // upper bound on the length of a translated run simulating `source_steps` steps
+    pub fn run_length_bound(&self, tapes: usize, source_steps: Number) -> Number {
+        // the heads can't get further than one cell per step
+        let setup_and_teardown = 2;
+        (0..source_steps as usize)
+            .map(|step| self.step_overhead(tapes, step))
+            .sum::<Number>()
+            + setup_and_teardown
+    }
+
+    pub fn step_overhead_formula(&self) -> &'static str {
+        match self {
+            Strategy::HeadToHead => "(2k-3)*(2n+3) + 1",
+            Strategy::StartMarker => "(k-1)*(4n+9) + 2",
+            Strategy::OneSweep => "2n + 3k + 3",
+        }
+    }
+}
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Strategy::HeadToHead => write!(f, "head-to-head"),
+            Strategy::StartMarker => write!(f, "start-marker"),
+            Strategy::OneSweep => write!(f, "one-sweep"),
+        }
+    }
+}
+
+/* What a `Strategy::HeadToHead` step carries while walking between the heads: the letters
+read so far (the first tape's only on the way to the last head, it's read again once back),
+which tapes are written already and on which side of the first tape's head every other head
+is - `Stay` when on the same cell. A nondeterministic choice of transition is kept once it's
+made, so that the heads written later agree with it. */
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct Trip {
+    state: State,
+    letters: Vec<Option<TapeEntry>>,
+    written: Vec<bool>,
+    sides: Vec<HeadMoveDirection>,
+    choice: Option<WriteData>,
+}
+
+// a state of the translated machine
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+enum Phase {
+    Setup,
+    Simulating(State),
+    // on the first tape's head between steps, see `Strategy::HeadToHead`
+    Waiting {
+        state: State,
+        sides: Vec<HeadMoveDirection>,
+    },
+    // the first tape's head just moved here, the other heads' sides are yet to be checked
+    Landing {
+        state: State,
+        sides: Vec<HeadMoveDirection>,
+    },
+    // on the way from the first tape's head to the head of `tape`
+    Fetching {
+        tape: usize,
+        trip: Trip,
+    },
+    // the head of `tape` just moved here, its side already updated
+    Moving {
+        tape: usize,
+        trip: Trip,
+    },
+    // on the way back to the first tape's head from the head of `tape`
+    Returning {
+        tape: usize,
+        trip: Trip,
+    },
+    Reading {
+        step: ReadStep,
+        tape: usize,
+        state: State,
+        letters: Vec<TapeEntry>,
+    },
+    Writing {
+        step: WriteStep,
+        tape: usize,
+        data: WriteData,
+    },
+    // letters under the heads found so far while sweeping right, see `Strategy::OneSweep`
+    Collecting {
+        state: State,
+        letters: Vec<Option<TapeEntry>>,
+    },
+    // sweeping left, writing every tape whose head is found and isn't `done` yet
+    Applying {
+        data: WriteData,
+        done: Vec<bool>,
+    },
+    // just moved off a written cell, the head of `tape` goes here before moving back
+    Placing {
+        data: WriteData,
+        done: Vec<bool>,
+        tape: usize,
+        moved: HeadMoveDirection,
+    },
+    // the original machine halted - this phase is named after its state and has no transitions
+    Halted(State),
+}
+
+fn opposite(direction: &HeadMoveDirection) -> HeadMoveDirection {
+    match direction {
+        HeadMoveDirection::Left => HeadMoveDirection::Right,
+        HeadMoveDirection::Right => HeadMoveDirection::Left,
+        HeadMoveDirection::Stay => HeadMoveDirection::Stay,
+    }
+}
+
+// the transition taken by the translated machine in some phase on some super-symbol
+struct Step {
+    next: Phase,
+    written: SuperTapeEntry,
+    direction: HeadMoveDirection,
 }
 
 pub struct MachineTranslator {
     input: TranslatorInput,
-    possible_superentries: Vec<SuperTapeEntry>,
+    strategy: Strategy,
+    read_prefixes: HashSet<ReadPrefix>,
 }
 impl MachineTranslator {
-    pub fn new(machine_description: String) -> AppResult<Self> {
-        let input = TranslatorInput::new(machine_description)?;
-        let possible_superentries = iproduct!(
-            &input.tape_entries.clone(),
-            &input.tape_entries.clone(),
-            vec![true, false],
-            vec![true, false],
-            vec![true, false]
-        )
-        .map(
-            |(val1, val2, has_first_head, has_second_head, is_start)| SuperTapeEntry {
-                first_letter: val1.clone(),
-                second_letter: val2.clone(),
-                has_first_tape_head: has_first_head,
-                has_second_tape_head: has_second_head,
-                is_start: is_start,
-            },
-        )
-        .collect();
-
+    pub fn new(transitions: Vec<MultiTransition>, strategy: Strategy) -> AppResult<Self> {
+        let input = TranslatorInput::new(transitions)?;
+        let read_prefixes = Self::read_prefixes(&input);
         Ok(Self {
-            input: input,
-            possible_superentries: possible_superentries,
+            input,
+            strategy,
+            read_prefixes,
         })
     }
 
+    pub fn with_input_alphabet(mut self, alphabet: &[TapeEntry]) -> AppResult<Self> {
+        self.input.declare_input_alphabet(alphabet)?;
+        Ok(self)
+    }
+
     fn wrap_original_state(state: &State) -> State {
         State(format!("#$ORIGINAL_{}$#", state.value()).to_string())
     }
@@ -86,311 +339,1313 @@ impl MachineTranslator {
         State(format!("#$CUSTOMIZED_{}#{}$#", data, values.join("#")).to_string())
     }
 
-    fn initial_start_setup_transitions(&self) -> Vec<Transition> {
-        self.input
-            .tape_entries
-            .iter()
-            .map(|e| SuperTapeEntry {
-                first_letter: e.clone(),
-                second_letter: TapeEntry::BLANK,
-                has_first_tape_head: false,
-                has_second_tape_head: false,
-                is_start: false,
-            })
-            .map(|se| Transition {
-                state_before: State(std_states::START.to_string()),
-                state_after: Self::wrap_original_state(&State(std_states::START.to_string())),
-                tape_value_before: se.encoded(),
-                tape_value_after: SuperTapeEntry {
-                    has_first_tape_head: true,
-                    has_second_tape_head: true,
-                    is_start: true,
-                    ..se.clone()
-                }
-                .encoded(),
-                tape_head_move_direction: HeadMoveDirection::Stay,
-            })
-            .collect()
+    /* The inverse of `state_of`, as far as the original machine goes: the original state a
+    translated state is simulating, along with the name of the customized phase it's in.
+    Phases still reading name the state they read in, the writing ones the state they
+    write their way into - for `Strategy::HeadToHead` that's only once the first tape's head
+    has moved. `start` and the halting states aren't wrapped at all, and waiting for the
+    next step isn't a phase of any. */
+    pub fn original_state(state: &State) -> Option<(State, Option<String>)> {
+        let name = state.value();
+        if !name.starts_with("#$") {
+            return Some((state.clone(), None));
+        }
+        if let Some(original) = name
+            .strip_prefix("#$ORIGINAL_")
+            .and_then(|rest| rest.strip_suffix("$#"))
+        {
+            return Some((State(original.to_string()), None));
+        }
+
+        let mut parts = name
+            .strip_prefix("#$CUSTOMIZED_")?
+            .strip_suffix("$#")?
+            .split('#');
+        let phase = parts.next()?;
+        let values: Vec<&str> = parts.collect();
+        let original = match phase {
+            "go_to_start_to_read_from_tape"
+            | "reach_tape_for_read"
+            | "read_value_from_tape"
+            | "fetch_head"
+            | "place_fetched_head"
+            | "return_to_first_head" => values.get(1),
+            "collect_letters" => values.first(),
+            _ => values.last(),
+        }?;
+        if phase == "wait_on_first_head" {
+            return Some((State(original.to_string()), None));
+        }
+        Some((State(original.to_string()), Some(phase.to_string())))
     }
 
-    fn get_data_to_write(trans: &DoubleTransition) -> Vec<String> {
-        vec![
-            trans.first_tape_value_after.to_string(),
-            trans.second_tape_value_after.to_string(),
-            direction_to_string(&trans.first_tape_head_move_direction),
-            direction_to_string(&trans.second_tape_head_move_direction),
-            trans.state_after.to_string(),
-        ]
+    fn last_tape(&self) -> usize {
+        self.input.tapes - 1
     }
 
-    fn get_data_to_read_and_write(trans: &DoubleTransition) -> Vec<String> {
-        let mut write_data: Vec<String> = Self::get_data_to_write(trans);
-        write_data.push(trans.second_tape_value_before.to_string());
-        write_data
+    // letters read so far are part of the state - otherwise we'd lose determinism
+    fn get_data_to_read(tape: usize, state: &State, letters: &[TapeEntry]) -> Vec<String> {
+        let mut data = vec![(tape + 1).to_string(), state.to_string()];
+        data.extend(letters.iter().map(|letter| letter.to_string()));
+        data
     }
 
-    fn initialize_two_tape_read(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+    fn get_data_to_write(tape: usize, data: &WriteData) -> Vec<String> {
+        Self::get_data_to_apply((tape + 1).to_string(), data)
+    }
+
+    fn get_data_to_apply(progress: String, data: &WriteData) -> Vec<String> {
+        let mut values = vec![progress];
+        values.extend(data.tape_values_after.iter().map(|e| e.to_string()));
+        values.extend(
+            data.tape_head_move_directions
+                .iter()
+                .map(direction_to_string),
+        );
+        values.push(data.state_after.to_string());
+        values
+    }
+
+    fn get_data_of_trip(tape: usize, trip: &Trip) -> Vec<String> {
+        let mut values = vec![(tape + 1).to_string(), trip.state.to_string()];
+        values.extend(trip.letters.iter().map(|letter| match letter {
+            Some(letter) => letter.to_string(),
+            None => "_".to_string(),
+        }));
+        values.push(Self::mask(&trip.written));
+        values.push(Self::sides(&trip.sides));
+        if let Some(choice) = &trip.choice {
+            values.extend(Self::get_data_to_apply("chosen".to_string(), choice));
+        }
+        values
+    }
+
+    fn sides(sides: &[HeadMoveDirection]) -> String {
+        sides.iter().map(direction_to_string).collect()
+    }
+
+    fn state_of(phase: &Phase) -> State {
+        match phase {
+            Phase::Setup => State(std_states::START.to_string()),
+            Phase::Simulating(state) => Self::wrap_original_state(state),
+            Phase::Waiting { state, sides } => Self::customized_state(
+                "wait_on_first_head",
+                vec![Self::sides(sides), state.to_string()],
+            ),
+            Phase::Landing { state, sides } => Self::customized_state(
+                "land_first_head",
+                vec![Self::sides(sides), state.to_string()],
+            ),
+            Phase::Fetching { tape, trip } => {
+                Self::customized_state("fetch_head", Self::get_data_of_trip(*tape, trip))
+            }
+            Phase::Moving { tape, trip } => {
+                Self::customized_state("place_fetched_head", Self::get_data_of_trip(*tape, trip))
+            }
+            Phase::Returning { tape, trip } => {
+                Self::customized_state("return_to_first_head", Self::get_data_of_trip(*tape, trip))
+            }
+            Phase::Reading {
+                step,
+                tape,
+                state,
+                letters,
+            } => Self::customized_state(
+                match step {
+                    ReadStep::GoToStart => "go_to_start_to_read_from_tape",
+                    ReadStep::ReachTape => "reach_tape_for_read",
+                    ReadStep::ReadValue => "read_value_from_tape",
+                },
+                Self::get_data_to_read(*tape, state, letters),
+            ),
+            Phase::Writing { step, tape, data } => Self::customized_state(
+                match step {
+                    WriteStep::HandleHeadMoved => "handle_head_moved",
+                    WriteStep::GoToStart => "go_to_start_to_write_to_tape",
+                    WriteStep::ReachTape => "reach_tape_for_write",
+                    WriteStep::WriteValue => "write_value_to_tape",
+                },
+                Self::get_data_to_write(*tape, data),
+            ),
+            Phase::Collecting { state, letters } => {
+                let mut values = vec![state.to_string()];
+                values.extend(letters.iter().map(|letter| match letter {
+                    Some(letter) => letter.to_string(),
+                    None => "_".to_string(),
+                }));
+                Self::customized_state("collect_letters", values)
+            }
+            Phase::Applying { data, done } => Self::customized_state(
+                "apply_writes",
+                Self::get_data_to_apply(Self::mask(done), data),
+            ),
+            Phase::Placing {
+                data,
+                done,
+                tape,
+                moved,
+            } => {
+                let mut values = vec![(tape + 1).to_string(), direction_to_string(moved)];
+                values.extend(Self::get_data_to_apply(Self::mask(done), data));
+                Self::customized_state("place_head", values)
+            }
+            Phase::Halted(state) => state.clone(),
+        }
+    }
+
+    fn mask(flags: &[bool]) -> String {
+        flags
             .iter()
-            .filter(|se| se.first_letter == trans.first_tape_value_before)
-            .map(|se| Transition {
-                state_before: Self::wrap_original_state(&trans.state_before),
-                state_after: Self::customized_state(
-                    "go_to_start_to_read_from_q",
-                    Self::get_data_to_read_and_write(trans),
-                ),
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
-                tape_head_move_direction: HeadMoveDirection::Stay,
-            })
+            .map(|&flag| if flag { '1' } else { '0' })
             .collect()
     }
 
-    fn go_to_start_to_read_from_q(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+    // every (state, letters on the first tapes) that some transition starts with
+    fn read_prefixes(input: &TranslatorInput) -> HashSet<ReadPrefix> {
+        input
+            .transitions
             .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "go_to_start_to_read_from_q",
-                    Self::get_data_to_read_and_write(trans),
-                ),
-                state_after: if se.is_start {
-                    Self::customized_state(
-                        "reach_q_for_read",
-                        Self::get_data_to_read_and_write(trans),
-                    )
-                } else {
-                    Self::customized_state(
-                        "go_to_start_to_read_from_q",
-                        Self::get_data_to_read_and_write(trans),
+            .flat_map(|trans| {
+                (1..input.tapes).map(move |length| {
+                    (
+                        trans.state_before.clone(),
+                        trans.tape_values_before[..length].to_vec(),
                     )
-                },
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
-                tape_head_move_direction: if se.is_start {
-                    HeadMoveDirection::Stay
-                } else {
-                    HeadMoveDirection::Left
-                },
+                })
             })
             .collect()
     }
 
-    fn reach_q_for_read(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+    // cells of the input word before anything else touches them
+    fn plain_superentry(&self, letter: &TapeEntry) -> SuperTapeEntry {
+        let mut letters = vec![TapeEntry::BLANK; self.input.tapes];
+        letters[0] = letter.clone();
+        SuperTapeEntry {
+            letters,
+            heads: vec![false; self.input.tapes],
+            is_start: false,
+        }
+    }
+
+    fn stay(next: Phase, se: &SuperTapeEntry) -> Step {
+        Step {
+            next,
+            written: se.clone(),
+            direction: HeadMoveDirection::Stay,
+        }
+    }
+
+    // stays put once `found` holds, otherwise keeps moving in `direction`
+    fn sweep(
+        current: &Phase,
+        se: &SuperTapeEntry,
+        found: bool,
+        next: Phase,
+        direction: HeadMoveDirection,
+    ) -> Vec<Step> {
+        if found {
+            vec![Self::stay(next, se)]
+        } else {
+            vec![Step {
+                next: current.clone(),
+                written: se.clone(),
+                direction,
+            }]
+        }
+    }
+
+    fn written(se: &SuperTapeEntry, tape: usize, data: &WriteData) -> SuperTapeEntry {
+        let mut written = se.clone();
+        written.letters[tape] = data.tape_values_after[tape].clone();
+        written.heads[tape] = false;
+        written
+    }
+
+    fn steps(&self, phase: &Phase, se: &SuperTapeEntry) -> Vec<Step> {
+        match phase {
+            Phase::Setup => {
+                if se != &self.plain_superentry(&se.letters[0]) {
+                    return vec![];
+                }
+                // heads are found by walking to them from the first one, there's no need for a marker
+                let head_to_head = self.strategy == Strategy::HeadToHead;
+                let marked = SuperTapeEntry {
+                    heads: vec![true; self.input.tapes],
+                    is_start: !head_to_head,
+                    ..se.clone()
+                };
+                let start = State(std_states::START.to_string());
+                vec![Step {
+                    next: if head_to_head {
+                        Phase::Waiting {
+                            state: start,
+                            sides: vec![HeadMoveDirection::Stay; self.input.tapes],
+                        }
+                    } else {
+                        Phase::Simulating(start)
+                    },
+                    written: marked,
+                    direction: HeadMoveDirection::Stay,
+                }]
+            }
+            Phase::Simulating(state) => self.simulate(state, se),
+            Phase::Waiting { state, sides } => self.wait(state, sides, se),
+            Phase::Landing { state, sides } => self.land(state, sides, se),
+            Phase::Fetching { tape, trip } => self.fetch(*tape, trip, se),
+            Phase::Moving { tape, trip } => {
+                if se.heads[*tape] {
+                    return vec![];
+                }
+                let mut placed = se.clone();
+                placed.heads[*tape] = true;
+                if se.heads[0] {
+                    let mut trip = trip.clone();
+                    trip.sides[*tape] = HeadMoveDirection::Stay;
+                    return self.at_first_head(trip, &placed);
+                }
+                vec![Step {
+                    next: Phase::Returning {
+                        tape: *tape,
+                        trip: trip.clone(),
+                    },
+                    written: placed,
+                    direction: opposite(&trip.sides[*tape]),
+                }]
+            }
+            Phase::Returning { tape, trip } => {
+                if se.heads[*tape] {
+                    return vec![];
+                }
+                if se.heads[0] {
+                    return self.at_first_head(trip.clone(), se);
+                }
+                vec![Step {
+                    next: phase.clone(),
+                    written: se.clone(),
+                    direction: opposite(&trip.sides[*tape]),
+                }]
+            }
+            Phase::Reading {
+                step,
+                tape,
+                state,
+                letters,
+            } => self.read(*step, *tape, state, letters, se),
+            Phase::Writing { step, tape, data } => self.write(*step, *tape, data, se),
+            Phase::Collecting { state, letters } => self.collect(state, letters, se),
+            Phase::Applying { data, done } => self.apply(data, done, se),
+            Phase::Placing {
+                data,
+                done,
+                tape,
+                moved,
+            } => {
+                let mut placed = se.clone();
+                placed.heads[*tape] = true;
+                let applying = Phase::Applying {
+                    data: data.clone(),
+                    done: done.clone(),
+                };
+                // back to the written cell, other heads there may still be waiting
+                vec![Step {
+                    next: applying,
+                    written: placed,
+                    direction: match moved {
+                        HeadMoveDirection::Left => HeadMoveDirection::Right,
+                        _ => HeadMoveDirection::Left,
+                    },
+                }]
+            }
+            Phase::Halted(_) => vec![],
+        }
+    }
+
+    /* between simulated steps the combined head waits on the first tape's head for
+    `Strategy::StartMarker` and on the start marker for `Strategy::OneSweep` - the setup
+    leaves it on both */
+    fn simulate(&self, state: &State, se: &SuperTapeEntry) -> Vec<Step> {
+        let mut steps = vec![];
+        let first_letter = vec![se.letters[0].clone()];
+        let has_transitions = self
+            .input
+            .transitions
             .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "reach_q_for_read",
-                    Self::get_data_to_read_and_write(trans),
-                ),
-                state_after: if se.has_second_tape_head {
-                    Self::customized_state(
-                        "read_value_from_q",
-                        Self::get_data_to_read_and_write(trans),
-                    )
-                } else {
-                    Self::customized_state(
-                        "reach_q_for_read",
-                        Self::get_data_to_read_and_write(trans),
-                    )
+            .any(|trans| &trans.state_before == state);
+        if self.strategy == Strategy::OneSweep && has_transitions {
+            steps.push(Self::stay(
+                Phase::Collecting {
+                    state: state.clone(),
+                    letters: vec![None; self.input.tapes],
+                },
+                se,
+            ));
+        }
+        if self.strategy == Strategy::StartMarker
+            && self
+                .read_prefixes
+                .contains(&(state.clone(), first_letter.clone()))
+        {
+            steps.push(Self::stay(
+                Phase::Reading {
+                    step: ReadStep::GoToStart,
+                    tape: 1,
+                    state: state.clone(),
+                    letters: first_letter,
                 },
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
+                se,
+            ));
+        }
+        if self.input.halting_states.contains(state) {
+            steps.push(Self::stay(Phase::Halted(state.clone()), se));
+        }
+        steps
+    }
 
-                tape_head_move_direction: if se.has_second_tape_head {
-                    HeadMoveDirection::Stay
-                } else {
-                    HeadMoveDirection::Right
+    fn read(
+        &self,
+        step: ReadStep,
+        tape: usize,
+        state: &State,
+        letters: &[TapeEntry],
+        se: &SuperTapeEntry,
+    ) -> Vec<Step> {
+        let phase = |step| Phase::Reading {
+            step,
+            tape,
+            state: state.clone(),
+            letters: letters.to_vec(),
+        };
+        match step {
+            ReadStep::GoToStart => Self::sweep(
+                &phase(step),
+                se,
+                se.is_start,
+                phase(ReadStep::ReachTape),
+                HeadMoveDirection::Left,
+            ),
+            ReadStep::ReachTape => Self::sweep(
+                &phase(step),
+                se,
+                se.heads[tape],
+                phase(ReadStep::ReadValue),
+                HeadMoveDirection::Right,
+            ),
+            ReadStep::ReadValue => self.read_value_from_tape(tape, state, letters, se),
+        }
+    }
+
+    // once the last tape is read, the transition is known and the last tape gets written right away
+    fn read_value_from_tape(
+        &self,
+        tape: usize,
+        state: &State,
+        letters: &[TapeEntry],
+        se: &SuperTapeEntry,
+    ) -> Vec<Step> {
+        let mut read_letters = letters.to_vec();
+        read_letters.push(se.letters[tape].clone());
+
+        if tape < self.last_tape() {
+            if !self
+                .read_prefixes
+                .contains(&(state.clone(), read_letters.clone()))
+            {
+                return vec![];
+            }
+            return vec![Self::stay(
+                Phase::Reading {
+                    step: ReadStep::GoToStart,
+                    tape: tape + 1,
+                    state: state.clone(),
+                    letters: read_letters,
+                },
+                se,
+            )];
+        }
+
+        self.input
+            .transitions
+            .iter()
+            .filter(|trans| {
+                &trans.state_before == state && trans.tape_values_before == read_letters
+            })
+            .map(WriteData::from)
+            .unique()
+            .map(|data| Step {
+                written: Self::written(se, tape, &data),
+                direction: data.tape_head_move_directions[tape].clone(),
+                next: Phase::Writing {
+                    step: WriteStep::HandleHeadMoved,
+                    tape,
+                    data: data.up_to(tape),
                 },
             })
             .collect()
     }
-    fn read_value_from_q(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+
+    fn sides_match(sides: &[HeadMoveDirection], se: &SuperTapeEntry) -> bool {
+        se.heads[0]
+            && sides
+                .iter()
+                .zip(se.heads.iter())
+                .skip(1)
+                .all(|(side, &head)| head == (side == &HeadMoveDirection::Stay))
+    }
+
+    fn settled(&self, state: State, sides: Vec<HeadMoveDirection>) -> Phase {
+        if self.input.halting_states.contains(&state) {
+            Phase::Halted(state)
+        } else {
+            Phase::Waiting { state, sides }
+        }
+    }
+
+    fn wait(&self, state: &State, sides: &[HeadMoveDirection], se: &SuperTapeEntry) -> Vec<Step> {
+        if !Self::sides_match(sides, se) {
+            return vec![];
+        }
+        // only the start state gets here while halting, the others halt right away
+        if self.input.halting_states.contains(state) {
+            return vec![Self::stay(Phase::Halted(state.clone()), se)];
+        }
+        let trip = Trip {
+            state: state.clone(),
+            letters: vec![None; self.input.tapes],
+            written: vec![false; self.input.tapes],
+            sides: sides.to_vec(),
+            choice: None,
+        };
+        self.at_first_head(trip, se)
+    }
+
+    // the first tape's head just moved, onto the cell of any head found here
+    fn land(&self, state: &State, sides: &[HeadMoveDirection], se: &SuperTapeEntry) -> Vec<Step> {
+        if se.heads[0] {
+            return vec![];
+        }
+        let sides = sides
             .iter()
-            .filter(|se| se.second_letter == trans.second_tape_value_before)
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "read_value_from_q",
-                    Self::get_data_to_read_and_write(trans),
-                ),
-                state_after: Self::customized_state(
-                    "handle_q_head_moved",
-                    Self::get_data_to_write(trans),
-                ),
-                tape_value_before: se.encoded(),
-                tape_value_after: SuperTapeEntry {
-                    second_letter: trans.second_tape_value_after.clone(),
-                    has_second_tape_head: false,
-                    ..se.clone()
+            .zip(se.heads.iter())
+            .map(|(side, &head)| {
+                if head {
+                    HeadMoveDirection::Stay
+                } else {
+                    side.clone()
                 }
-                .encoded(),
-                tape_head_move_direction: trans.second_tape_head_move_direction.clone(),
             })
+            .collect();
+        let mut landed = se.clone();
+        landed.heads[0] = true;
+        vec![Self::stay(self.settled(state.clone(), sides), &landed)]
+    }
+
+    fn fetch(&self, tape: usize, trip: &Trip, se: &SuperTapeEntry) -> Vec<Step> {
+        if se.heads[0] {
+            return vec![];
+        }
+        if !se.heads[tape] {
+            return vec![Step {
+                next: Phase::Fetching {
+                    tape,
+                    trip: trip.clone(),
+                },
+                written: se.clone(),
+                direction: trip.sides[tape].clone(),
+            }];
+        }
+
+        let mut trip = trip.clone();
+        trip.letters[tape] = Some(se.letters[tape].clone());
+        if trip.letters.iter().any(|letter| letter.is_none()) {
+            if self.candidates(&trip).is_empty() {
+                return vec![];
+            }
+            return vec![Step {
+                direction: opposite(&trip.sides[tape]),
+                next: Phase::Returning { tape, trip },
+                written: se.clone(),
+            }];
+        }
+        self.choices(&trip)
+            .into_iter()
+            .map(|(data, trip)| Self::write_far_head(tape, trip, &data, se))
+            .collect()
+    }
+
+    // the first tape's letter is still on its cell, so it's dropped until needed again
+    fn write_far_head(tape: usize, mut trip: Trip, data: &WriteData, se: &SuperTapeEntry) -> Step {
+        trip.letters[0] = None;
+        trip.written[tape] = true;
+        let mut written = se.clone();
+        written.letters[tape] = data.tape_values_after[tape].clone();
+
+        let direction = data.tape_head_move_directions[tape].clone();
+        if direction == HeadMoveDirection::Stay {
+            return Step {
+                direction: opposite(&trip.sides[tape]),
+                next: Phase::Returning { tape, trip },
+                written,
+            };
+        }
+        written.heads[tape] = false;
+        Step {
+            next: Phase::Moving { tape, trip },
+            written,
+            direction,
+        }
+    }
+
+    /* Back on the first tape's head: the heads on the same cell are read right away, the
+    others get fetched one by one. Once the transition is known, the heads here are
+    written, then the others, and the first tape's last. */
+    fn at_first_head(&self, mut trip: Trip, se: &SuperTapeEntry) -> Vec<Step> {
+        if !Self::sides_match(&trip.sides, se) {
+            return vec![];
+        }
+        for tape in 1..self.input.tapes {
+            if trip.sides[tape] == HeadMoveDirection::Stay && trip.letters[tape].is_none() {
+                trip.letters[tape] = Some(se.letters[tape].clone());
+            }
+        }
+        trip.letters[0] = Some(se.letters[0].clone());
+        if self.candidates(&trip).is_empty() {
+            return vec![];
+        }
+
+        let unknown: Vec<usize> = (1..self.input.tapes)
+            .filter(|&tape| trip.letters[tape].is_none())
+            .collect();
+        if let Some(&tape) = unknown.first() {
+            // the first tape's letter is only needed once the last head is read
+            if unknown.len() > 1 {
+                trip.letters[0] = None;
+            }
+            return vec![Step {
+                direction: trip.sides[tape].clone(),
+                next: Phase::Fetching { tape, trip },
+                written: se.clone(),
+            }];
+        }
+        self.choices(&trip)
+            .into_iter()
+            .map(|(data, trip)| self.write_at_first_head(trip, &data, se))
             .collect()
     }
 
-    fn handle_q_head_moved(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+    fn write_at_first_head(&self, mut trip: Trip, data: &WriteData, se: &SuperTapeEntry) -> Step {
+        let tapes = trip.sides.len();
+        let mut written = se.clone();
+        let mut moving = None;
+        for tape in 1..tapes {
+            if trip.sides[tape] != HeadMoveDirection::Stay || trip.written[tape] {
+                continue;
+            }
+            let direction = data.tape_head_move_directions[tape].clone();
+            if direction != HeadMoveDirection::Stay {
+                moving = moving.or(Some(tape));
+                continue;
+            }
+            written.letters[tape] = data.tape_values_after[tape].clone();
+            trip.written[tape] = true;
+        }
+
+        // one head moves off at a time, it comes right back
+        if let Some(tape) = moving {
+            let direction = data.tape_head_move_directions[tape].clone();
+            written.letters[tape] = data.tape_values_after[tape].clone();
+            written.heads[tape] = false;
+            trip.written[tape] = true;
+            trip.sides[tape] = direction.clone();
+            trip.letters[0] = None;
+            return Step {
+                next: Phase::Moving { tape, trip },
+                written,
+                direction,
+            };
+        }
+        if let Some(tape) = (1..tapes).find(|&tape| !trip.written[tape]) {
+            return Step {
+                direction: trip.sides[tape].clone(),
+                next: Phase::Fetching { tape, trip },
+                written,
+            };
+        }
+
+        written.letters[0] = data.tape_values_after[0].clone();
+        let direction = data.tape_head_move_directions[0].clone();
+        if direction == HeadMoveDirection::Stay {
+            return Self::stay(self.settled(data.state_after.clone(), trip.sides), &written);
+        }
+        // heads left behind end up on the other side, `Phase::Landing` sees if any are reached
+        written.heads[0] = false;
+        let sides = trip
+            .sides
             .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "handle_q_head_moved",
-                    Self::get_data_to_write(trans),
-                ),
-                state_after: Self::customized_state(
-                    "go_to_start_to_write_to_p",
-                    Self::get_data_to_write(trans),
-                ),
-                tape_value_before: se.encoded(),
-                tape_value_after: SuperTapeEntry {
-                    has_second_tape_head: true,
-                    ..se.clone()
+            .enumerate()
+            .map(|(tape, side)| match side {
+                HeadMoveDirection::Stay if tape > 0 => opposite(&direction),
+                _ => side.clone(),
+            })
+            .collect();
+        Step {
+            next: Phase::Landing {
+                state: data.state_after.clone(),
+                sides,
+            },
+            written,
+            direction,
+        }
+    }
+
+    // the original transitions still possible with what a trip knows
+    fn trip_transitions<'a>(&'a self, trip: &'a Trip) -> impl Iterator<Item = &'a MultiTransition> {
+        self.transitions_from(&trip.state).filter(move |trans| {
+            trip.letters
+                .iter()
+                .zip(trans.tape_values_before.iter())
+                .all(|(letter, before)| letter.as_ref().is_none_or(|l| l == before))
+                && trip
+                    .choice
+                    .as_ref()
+                    .is_none_or(|choice| &WriteData::from(*trans) == choice)
+        })
+    }
+
+    fn candidates(&self, trip: &Trip) -> Vec<WriteData> {
+        self.trip_transitions(trip)
+            .map(WriteData::from)
+            .unique()
+            .collect()
+    }
+
+    // a choice between transitions is only remembered when there is one
+    fn choices(&self, trip: &Trip) -> Vec<(WriteData, Trip)> {
+        let candidates = self.candidates(trip);
+        let ambiguous = candidates.len() > 1;
+        candidates
+            .into_iter()
+            .map(|data| {
+                let mut trip = trip.clone();
+                if ambiguous {
+                    trip.choice = Some(data.clone());
                 }
-                .encoded(),
-                tape_head_move_direction: HeadMoveDirection::Stay,
+                (data, trip)
             })
             .collect()
     }
-    fn go_to_start_to_write_to_p(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+
+    // the last head found is the rightmost one, so the sweep back passes all of them
+    fn collect(
+        &self,
+        state: &State,
+        letters: &[Option<TapeEntry>],
+        se: &SuperTapeEntry,
+    ) -> Vec<Step> {
+        let letters: Vec<Option<TapeEntry>> = letters
             .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "go_to_start_to_write_to_p",
-                    Self::get_data_to_write(trans),
-                ),
-                state_after: if se.is_start {
-                    Self::customized_state("reach_p_for_write", Self::get_data_to_write(trans))
-                } else {
-                    Self::customized_state(
-                        "go_to_start_to_write_to_p",
-                        Self::get_data_to_write(trans),
-                    )
-                },
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
-                tape_head_move_direction: if se.is_start {
-                    HeadMoveDirection::Stay
-                } else {
-                    HeadMoveDirection::Left
+            .zip(se.heads.iter().zip(se.letters.iter()))
+            .map(|(collected, (&head, letter))| match collected {
+                None if head => Some(letter.clone()),
+                _ => collected.clone(),
+            })
+            .collect();
+        let compatible: Vec<&MultiTransition> =
+            self.input
+                .transitions
+                .iter()
+                .filter(|trans| {
+                    &trans.state_before == state
+                        && letters.iter().zip(trans.tape_values_before.iter()).all(
+                            |(collected, before)| collected.as_ref().is_none_or(|l| l == before),
+                        )
+                })
+                .collect();
+
+        if letters.iter().any(|letter| letter.is_none()) {
+            if compatible.is_empty() {
+                return vec![];
+            }
+            return vec![Step {
+                next: Phase::Collecting {
+                    state: state.clone(),
+                    letters,
                 },
+                written: se.clone(),
+                direction: HeadMoveDirection::Right,
+            }];
+        }
+
+        compatible
+            .into_iter()
+            .map(WriteData::from)
+            .unique()
+            .map(|data| {
+                Self::stay(
+                    Phase::Applying {
+                        data,
+                        done: vec![false; self.input.tapes],
+                    },
+                    se,
+                )
             })
             .collect()
     }
-    fn reach_p_for_write(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
-            .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "reach_p_for_write",
-                    Self::get_data_to_write(trans),
-                ),
-                state_after: if se.has_first_tape_head {
-                    Self::customized_state("write_value_to_p", Self::get_data_to_write(trans))
-                } else {
-                    Self::customized_state("reach_p_for_write", Self::get_data_to_write(trans))
+
+    // moving left from the start marker keeps the head where it is, like on any tape
+    fn apply(&self, data: &WriteData, done: &[bool], se: &SuperTapeEntry) -> Vec<Step> {
+        let pending = (0..self.input.tapes).find(|&tape| se.heads[tape] && !done[tape]);
+        let tape = match pending {
+            Some(tape) => tape,
+            None if se.is_start => {
+                return vec![Self::stay(Phase::Simulating(data.state_after.clone()), se)];
+            }
+            None => {
+                return vec![Step {
+                    next: Phase::Applying {
+                        data: data.clone(),
+                        done: done.to_vec(),
+                    },
+                    written: se.clone(),
+                    direction: HeadMoveDirection::Left,
+                }];
+            }
+        };
+
+        let mut done = done.to_vec();
+        done[tape] = true;
+        let mut written = se.clone();
+        written.letters[tape] = data.tape_values_after[tape].clone();
+
+        let direction = data.tape_head_move_directions[tape].clone();
+        if direction == HeadMoveDirection::Stay
+            || (direction == HeadMoveDirection::Left && se.is_start)
+        {
+            return vec![Self::stay(
+                Phase::Applying {
+                    data: data.clone(),
+                    done,
                 },
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
+                &written,
+            )];
+        }
+        written.heads[tape] = false;
+        vec![Step {
+            next: Phase::Placing {
+                data: data.clone(),
+                done,
+                tape,
+                moved: direction.clone(),
+            },
+            written,
+            direction,
+        }]
+    }
 
-                tape_head_move_direction: if se.has_first_tape_head {
-                    HeadMoveDirection::Stay
+    // tapes are written from the last one down, so we end up on the first tape's head
+    fn write(
+        &self,
+        step: WriteStep,
+        tape: usize,
+        data: &WriteData,
+        se: &SuperTapeEntry,
+    ) -> Vec<Step> {
+        let phase = |step, tape| Phase::Writing {
+            step,
+            tape,
+            data: data.clone(),
+        };
+        match step {
+            WriteStep::HandleHeadMoved => {
+                let mut moved = se.clone();
+                moved.heads[tape] = true;
+                let next = if tape == 0 {
+                    Phase::Simulating(data.state_after.clone())
                 } else {
-                    HeadMoveDirection::Right
+                    phase(WriteStep::GoToStart, tape - 1)
+                };
+                vec![Self::stay(next, &moved)]
+            }
+            WriteStep::GoToStart => Self::sweep(
+                &phase(step, tape),
+                se,
+                se.is_start,
+                phase(WriteStep::ReachTape, tape),
+                HeadMoveDirection::Left,
+            ),
+            WriteStep::ReachTape => Self::sweep(
+                &phase(step, tape),
+                se,
+                se.heads[tape],
+                phase(WriteStep::WriteValue, tape),
+                HeadMoveDirection::Right,
+            ),
+            WriteStep::WriteValue => vec![Step {
+                next: Phase::Writing {
+                    step: WriteStep::HandleHeadMoved,
+                    tape,
+                    data: data.up_to(tape),
                 },
-            })
-            .collect()
+                written: Self::written(se, tape, data),
+                direction: data.tape_head_move_directions[tape].clone(),
+            }],
+        }
     }
-    fn write_value_to_p(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
-            .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "write_value_to_p",
-                    Self::get_data_to_write(trans),
-                ),
-                state_after: Self::customized_state(
-                    "handle_p_head_moved",
-                    Self::get_data_to_write(trans),
-                ),
-                tape_value_before: se.encoded(),
-                tape_value_after: SuperTapeEntry {
-                    first_letter: trans.first_tape_value_after.clone(),
-                    has_first_tape_head: false,
-                    ..se.clone()
-                }
-                .encoded(),
-                tape_head_move_direction: trans.first_tape_head_move_direction.clone(),
-            })
-            .collect()
+
+    pub fn input_transitions(&self) -> &[MultiTransition] {
+        &self.input.transitions
     }
 
-    fn handle_p_head_moved(&self, trans: &DoubleTransition) -> Vec<Transition> {
-        self.possible_superentries
+    /* Explores the translated machine from the setup transition, so only the phases and
+    super-symbols that can actually show up get transitions. Only which phase sees which
+    symbol is kept, the transitions themselves are worked out again when written. */
+    fn explore(&self) -> Exploration {
+        let mut exploration = Exploration::default();
+        let setup = exploration.phase_id(&Phase::Setup);
+        let input_word: Vec<usize> = self
+            .input
+            .tape_entries
             .iter()
-            .map(|se| Transition {
-                state_before: Self::customized_state(
-                    "handle_p_head_moved",
-                    Self::get_data_to_write(trans),
-                ),
-                state_after: Self::wrap_original_state(&trans.state_after),
-                tape_value_before: se.encoded(),
-                tape_value_after: SuperTapeEntry {
-                    has_first_tape_head: true,
-                    ..se.clone()
+            .map(|letter| exploration.symbol_id(&self.plain_superentry(letter)))
+            .collect();
+        exploration.extend_context(setup, &input_word);
+        for &symbol in input_word.iter() {
+            exploration.visit_exactly(setup, symbol);
+        }
+
+        while let Some(phase) = exploration.queue.pop_front() {
+            exploration.queued[phase] = false;
+            let pending = std::mem::take(&mut exploration.pending[phase]);
+            for symbol in pending.iter() {
+                let steps = self.steps(&exploration.phases[phase], &exploration.symbols[symbol]);
+                exploration.transition_count += steps.len();
+                for step in steps {
+                    exploration.follow(phase, step);
                 }
-                .encoded(),
-                tape_head_move_direction: HeadMoveDirection::Stay,
-            })
-            .collect()
+            }
+        }
+        exploration
     }
 
-    fn teardown_accepting_transitions(&self) -> Vec<Transition> {
-        self.possible_superentries
+    fn transitions_from<'a>(
+        &'a self,
+        state: &'a State,
+    ) -> impl Iterator<Item = &'a MultiTransition> {
+        self.input
+            .transitions
             .iter()
-            .map(|se| Transition {
-                state_before: Self::wrap_original_state(&State(std_states::ACCEPT.to_string())),
-                state_after: State(std_states::ACCEPT.to_string()),
-                tape_value_before: se.encoded(),
-                tape_value_after: se.encoded(),
-                tape_head_move_direction: HeadMoveDirection::Stay,
+            .filter(move |trans| &trans.state_before == state)
+    }
+
+    // the original transitions a phase may be in the middle of simulating
+    fn meaning(&self, phase: &Phase) -> Option<StateMeaning> {
+        let writing = |data: &WriteData| {
+            self.input
+                .transitions
+                .iter()
+                .filter(move |trans| {
+                    &WriteData::from(*trans).up_to(data.tape_values_after.len()) == data
+                })
+                .cloned()
+                .collect()
+        };
+
+        let transitions = match phase {
+            Phase::Setup | Phase::Halted(_) => return None,
+            Phase::Simulating(state) | Phase::Waiting { state, .. } => {
+                self.transitions_from(state).cloned().collect()
+            }
+            Phase::Landing { state, .. } => self
+                .input
+                .transitions
+                .iter()
+                .filter(|trans| {
+                    &trans.state_after == state
+                        && trans.tape_head_move_directions[0] != HeadMoveDirection::Stay
+                })
+                .cloned()
+                .collect(),
+            Phase::Fetching { trip, .. }
+            | Phase::Moving { trip, .. }
+            | Phase::Returning { trip, .. } => self.trip_transitions(trip).cloned().collect(),
+            Phase::Reading { state, letters, .. } => self
+                .transitions_from(state)
+                .filter(|trans| trans.tape_values_before.starts_with(letters))
+                .cloned()
+                .collect(),
+            Phase::Collecting { state, letters } => {
+                self.transitions_from(state)
+                    .filter(|trans| {
+                        letters.iter().zip(trans.tape_values_before.iter()).all(
+                            |(collected, before)| collected.as_ref().is_none_or(|l| l == before),
+                        )
+                    })
+                    .cloned()
+                    .collect()
+            }
+            Phase::Writing { data, .. }
+            | Phase::Applying { data, .. }
+            | Phase::Placing { data, .. } => writing(data),
+        };
+        Some(StateMeaning {
+            phase: Self::state_of(phase),
+            transitions,
+        })
+    }
+
+    // the order of the translated machine's states in the output, see `Translation`
+    fn group(&self, meaning: Option<&StateMeaning>) -> (u8, usize) {
+        meaning
+            .and_then(|meaning| {
+                meaning
+                    .transitions
+                    .iter()
+                    .filter_map(|trans| self.input.transitions.iter().position(|t| t == trans))
+                    .min()
             })
-            .collect()
+            .map_or((2, 0), |idx| (1, idx))
     }
 
-    pub fn translate(&self) -> String {
-        let new_transitions: Vec<Transition> = self
-            .input
-            .transitions
+    // the input word is written with plain letters, so those must keep their numbers
+    pub fn translation(self) -> Translation {
+        let exploration = self.explore();
+        let (numbers, legend) = SymbolLegend::renumber(
+            exploration.symbols.iter().map(SuperTapeEntry::encoded),
+            self.input.tapes,
+            &self.input.tape_entries,
+        );
+        let numbers: Vec<TapeEntry> = exploration
+            .symbols
             .iter()
-            .map(|trans| {
-                vec![
-                    self.initialize_two_tape_read(trans),
-                    self.go_to_start_to_read_from_q(trans),
-                    self.reach_q_for_read(trans),
-                    self.read_value_from_q(trans),
-                    self.handle_q_head_moved(trans),
-                    self.go_to_start_to_write_to_p(trans),
-                    self.reach_p_for_write(trans),
-                    self.write_value_to_p(trans),
-                    self.handle_p_head_moved(trans),
-                ]
+            .map(|se| numbers[&se.encoded()].clone())
+            .collect();
+        let mut by_number: Vec<usize> = (0..numbers.len()).collect();
+        by_number.sort_by_key(|&symbol| numbers[symbol].clone());
+
+        let mut state_map = StateMap::default();
+        let names: Vec<State> = exploration.phases.iter().map(Self::state_of).collect();
+        let mut groups = vec![];
+        for (phase, name) in exploration.phases.iter().zip(names.iter()) {
+            let meaning = self.meaning(phase);
+            groups.push(if phase == &Phase::Setup {
+                (0, 0)
+            } else {
+                self.group(meaning.as_ref())
+            });
+            if let Some(meaning) = meaning {
+                state_map.insert(name.clone(), meaning);
+            }
+        }
+        let mut order: Vec<usize> = (0..names.len()).collect();
+        order.sort_by(|&a, &b| (groups[a], &names[a]).cmp(&(groups[b], &names[b])));
+
+        Translation {
+            halting_states: self.input.halting_states.clone(),
+            translator: self,
+            phase_ids: exploration.phase_ids,
+            phases: exploration.phases,
+            names,
+            order,
+            seen: exploration.visited,
+            symbol_ids: exploration.symbol_ids,
+            symbols: exploration.symbols,
+            numbers,
+            by_number,
+            len: exploration.transition_count,
+            legend,
+            state_map,
+        }
+    }
+}
+
+// a transition of the translated machine, with the states as indices into `Translation::names`
+struct CompactTransition {
+    state_before: usize,
+    tape_value_before: TapeEntry,
+    state_after: usize,
+    tape_value_after: TapeEntry,
+    tape_head_move_direction: HeadMoveDirection,
+}
+
+/* A translated machine. Translations easily get to millions of transitions sharing a few
+long state names, so only which of its states sees which symbol is kept - the transitions
+are worked out one at a time when asked for. They come grouped by the first original
+transition their state may be simulating, then by state and by the symbol read, with the
+setup first and the states only leading to a halting one last. */
+pub struct Translation {
+    translator: MachineTranslator,
+    phases: Vec<Phase>,
+    phase_ids: HashMap<Phase, usize>,
+    names: Vec<State>,
+    order: Vec<usize>,
+    // the symbols each phase sees, by their index in `symbols`
+    seen: Vec<SymbolSet>,
+    symbols: Vec<SuperTapeEntry>,
+    symbol_ids: HashMap<TapeEntry, usize>,
+    numbers: Vec<TapeEntry>,
+    by_number: Vec<usize>,
+    len: usize,
+    pub legend: SymbolLegend,
+    pub state_map: StateMap,
+    // named as in the original machine
+    pub halting_states: Vec<State>,
+}
+impl Translation {
+    // the transitions of one phase on one symbol, sorted like all the others
+    fn compact_on(&self, phase: usize, symbol: usize) -> Vec<CompactTransition> {
+        let mut transitions: Vec<CompactTransition> = self
+            .translator
+            .steps(&self.phases[phase], &self.symbols[symbol])
+            .into_iter()
+            .map(|step| CompactTransition {
+                state_before: phase,
+                tape_value_before: self.numbers[symbol].clone(),
+                state_after: self.phase_ids[&step.next],
+                tape_value_after: self.numbers[self.symbol_ids[&step.written.encoded()]].clone(),
+                tape_head_move_direction: step.direction,
             })
-            .flatten()
-            .flatten()
             .collect();
+        transitions.sort_by(|a, b| {
+            let key = |trans: &CompactTransition| {
+                (
+                    trans.tape_value_after.clone(),
+                    trans.tape_head_move_direction.clone(),
+                    &self.names[trans.state_after],
+                )
+            };
+            key(a).cmp(&key(b))
+        });
+        transitions
+    }
+
+    // the transitions in order, those read on the same symbol in the same state together
+    fn groups(&self) -> impl Iterator<Item = Vec<CompactTransition>> + '_ {
+        self.order.iter().flat_map(move |&phase| {
+            self.by_number
+                .iter()
+                .filter(move |&&symbol| self.seen[phase].contains(symbol))
+                .map(move |&symbol| self.compact_on(phase, symbol))
+        })
+    }
+
+    fn expand(&self, trans: &CompactTransition) -> Transition {
+        Transition {
+            state_before: self.names[trans.state_before].clone(),
+            state_after: self.names[trans.state_after].clone(),
+            tape_value_before: trans.tape_value_before.clone(),
+            tape_value_after: trans.tape_value_after.clone(),
+            tape_head_move_direction: trans.tape_head_move_direction.clone(),
+        }
+    }
 
-        let mut all_transitions: Vec<Transition> = vec![];
-        all_transitions.extend(new_transitions);
-        all_transitions.extend(self.initial_start_setup_transitions());
-        all_transitions.extend(self.teardown_accepting_transitions());
-        let all_transition_descriptions: Vec<String> = all_transitions
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn transitions(&self) -> impl Iterator<Item = Transition> + '_ {
+        self.groups()
+            .flatten()
+            .map(move |trans| self.expand(&trans))
+    }
+
+    // one transition per line, in the machine description format
+    pub fn write_to(&self, out: &mut impl io::Write) -> io::Result<()> {
+        for trans in self.transitions() {
+            writeln!(out, "{}", trans)?;
+        }
+        Ok(())
+    }
+
+    pub fn conflicting_transitions(&self) -> Vec<ConflictGroup<Transition>> {
+        self.groups()
+            .filter(|group| group.len() > 1)
+            .flat_map(|group| {
+                let transitions: Vec<Transition> =
+                    group.iter().map(|trans| self.expand(trans)).collect();
+                determinism::conflicting_transitions(&transitions)
+            })
+            .collect()
+    }
+
+    /* `t0`, `t1`, .. in the order the states first show up, skipping the names of halting
+    states. Those and the standard states keep their names. */
+    pub fn with_short_names(self) -> Self {
+        let keeps_name = |state: &State| {
+            [std_states::START, std_states::ACCEPT, std_states::REJECT].contains(&state.value())
+                || self.halting_states.contains(state)
+        };
+        let mut free_names = (0..)
+            .map(|idx| State(format!("t{}", idx)))
+            .filter(|name| !keeps_name(name));
+        let mut short_names: Vec<Option<State>> = vec![None; self.names.len()];
+        for trans in self.groups().flatten() {
+            for &id in [trans.state_before, trans.state_after].iter() {
+                if !keeps_name(&self.names[id]) && short_names[id].is_none() {
+                    short_names[id] = free_names.next();
+                }
+            }
+        }
+        let renamed: HashMap<State, State> = self
+            .names
             .iter()
-            .map(|trans| trans.to_string())
+            .cloned()
+            .zip(short_names.iter().cloned())
+            .filter_map(|(state, short_name)| Some((state, short_name?)))
             .collect();
 
-        all_transition_descriptions.join("\n")
+        Self {
+            names: self
+                .names
+                .iter()
+                .zip(short_names)
+                .map(|(state, short_name)| short_name.unwrap_or_else(|| state.clone()))
+                .collect(),
+            state_map: self
+                .state_map
+                .renamed(|state| renamed.get(state).unwrap_or(state).clone()),
+            ..self
+        }
+    }
+}
+
+// symbols by their index in `Exploration::symbols`
+#[derive(Default)]
+struct SymbolSet(Vec<u64>);
+impl SymbolSet {
+    fn insert(&mut self, symbol: usize) -> bool {
+        let (word, bit) = (symbol / 64, 1 << (symbol % 64));
+        if self.0.len() <= word {
+            self.0.resize(word + 1, 0);
+        }
+        let inserted = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        inserted
+    }
+
+    fn contains(&self, symbol: usize) -> bool {
+        self.0
+            .get(symbol / 64)
+            .is_some_and(|word| word & (1 << (symbol % 64)) != 0)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.0.len() * 64).filter(move |&symbol| self.contains(symbol))
+    }
+}
+
+/* Every phase gets a context - the super-symbols that may be anywhere on the tape while
+the translated machine is in it - grown from the contexts of the phases leading to it.
+A phase entered by moving the head may then see anything from its context (except the
+start cell when moving right), while one entered by staying put sees exactly what was
+just written. Phases and symbols are numbered as they're found. */
+#[derive(Default)]
+struct Exploration {
+    phases: Vec<Phase>,
+    phase_ids: HashMap<Phase, usize>,
+    symbols: Vec<SuperTapeEntry>,
+    symbol_ids: HashMap<TapeEntry, usize>,
+    contexts: Vec<SymbolSet>,
+    entered_left: Vec<bool>,
+    entered_right: Vec<bool>,
+    successors: Vec<Vec<usize>>,
+    visited: Vec<SymbolSet>,
+    // visited but not yet followed, for the phases in `queue`
+    pending: Vec<SymbolSet>,
+    queued: Vec<bool>,
+    queue: VecDeque<usize>,
+    transition_count: usize,
+}
+impl Exploration {
+    fn phase_id(&mut self, phase: &Phase) -> usize {
+        if let Some(&id) = self.phase_ids.get(phase) {
+            return id;
+        }
+        self.phase_ids.insert(phase.clone(), self.phases.len());
+        self.phases.push(phase.clone());
+        self.contexts.push(SymbolSet::default());
+        self.entered_left.push(false);
+        self.entered_right.push(false);
+        self.successors.push(vec![]);
+        self.visited.push(SymbolSet::default());
+        self.pending.push(SymbolSet::default());
+        self.queued.push(false);
+        self.phases.len() - 1
+    }
+
+    fn symbol_id(&mut self, se: &SuperTapeEntry) -> usize {
+        let symbols = &mut self.symbols;
+        *self.symbol_ids.entry(se.encoded()).or_insert_with(|| {
+            symbols.push(se.clone());
+            symbols.len() - 1
+        })
+    }
+
+    fn visit(&mut self, phase: usize, symbol: usize) {
+        let may_see = self.entered_left[phase]
+            || (self.entered_right[phase] && !self.symbols[symbol].is_start);
+        if may_see {
+            self.visit_exactly(phase, symbol);
+        }
+    }
+
+    fn visit_exactly(&mut self, phase: usize, symbol: usize) {
+        if self.visited[phase].insert(symbol) {
+            self.pending[phase].insert(symbol);
+            if !self.queued[phase] {
+                self.queued[phase] = true;
+                self.queue.push_back(phase);
+            }
+        }
+    }
+
+    fn extend_context(&mut self, phase: usize, symbols: &[usize]) {
+        let mut pending = vec![(phase, symbols.to_vec())];
+        while let Some((phase, symbols)) = pending.pop() {
+            let added: Vec<usize> = symbols
+                .into_iter()
+                .filter(|&symbol| self.contexts[phase].insert(symbol))
+                .collect();
+            if added.is_empty() {
+                continue;
+            }
+
+            for &symbol in added.iter() {
+                self.visit(phase, symbol);
+            }
+            for &next in self.successors[phase].iter() {
+                pending.push((next, added.clone()));
+            }
+        }
+    }
+
+    fn follow(&mut self, phase: usize, step: Step) {
+        let next = self.phase_id(&step.next);
+        let written = self.symbol_id(&step.written);
+        if !self.successors[phase].contains(&next) {
+            self.successors[phase].push(next);
+            let context: Vec<usize> = self.contexts[phase].iter().collect();
+            self.extend_context(next, &context);
+        }
+        self.extend_context(next, &[written]);
+
+        // moving left from the first cell keeps the head on what was just written, which is
+        // in the context by now
+        let newly_entered = match step.direction {
+            HeadMoveDirection::Stay => false,
+            HeadMoveDirection::Left => !std::mem::replace(&mut self.entered_left[next], true),
+            HeadMoveDirection::Right => !std::mem::replace(&mut self.entered_right[next], true),
+        };
+        if newly_entered {
+            let context: Vec<usize> = self.contexts[next].iter().collect();
+            for symbol in context {
+                self.visit(next, symbol);
+            }
+        }
+        if step.direction == HeadMoveDirection::Stay {
+            self.visit_exactly(next, written);
+        }
     }
 }
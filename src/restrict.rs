@@ -0,0 +1,81 @@
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, loader};
+use turinglike_oversophisticated_calculator::machine::translation::restricted;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    two_way: bool,
+    no_stay: bool,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [--two-way] [--no-stay] [-o output_file] [--input-alphabet letters] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./restrict".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let two_way = cli::take_flag(&mut cmd_args, "--two-way");
+    let no_stay = cli::take_flag(&mut cmd_args, "--no-stay");
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        output_path,
+        input_alphabet,
+        two_way,
+        no_stay,
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let mut transitions = loader::transitions_from_file(&args.machine_path)?;
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => alphabet,
+        None => loader::input_alphabet_from_file(&args.machine_path)?.unwrap_or_default(),
+    };
+    let deterministic = determinism::is_deterministic(&transitions);
+
+    // folding adds stays of its own, so it goes first
+    if args.two_way {
+        transitions = restricted::fold_two_way_tape(&transitions, &input_alphabet);
+    }
+    if args.no_stay {
+        transitions = restricted::eliminate_stays(&transitions, &input_alphabet);
+    }
+    if deterministic && !determinism::is_deterministic(&transitions) {
+        return Err("the deterministic machine came out nondeterministic".into());
+    }
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    for trans in transitions.iter() {
+        writeln!(out, "{}", trans)?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
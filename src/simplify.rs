@@ -0,0 +1,71 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::simplifier;
+use turinglike_oversophisticated_calculator::types::*;
+
+struct Arguments {
+    machine_path: String,
+    output_path: Option<String>,
+}
+
+fn print_usage_message() {
+    println!(
+        "Usage: {} [-o output_file] [machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./simplify".to_string())
+    )
+}
+
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
+        print_usage_message();
+        std::process::exit(1);
+    }
+
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        output_path,
+    })
+}
+
+fn state_count(transitions: &[Transition]) -> usize {
+    let states: BTreeSet<&State> = transitions
+        .iter()
+        .flat_map(|trans| vec![&trans.state_before, &trans.state_after])
+        .collect();
+    states.len()
+}
+
+fn main() -> Result<(), AppError> {
+    let args = parse_cmd_arguments()?;
+    let transitions = loader::transitions_from_file(&args.machine_path)?;
+
+    let simplified = simplifier::simplify(&transitions);
+    eprintln!(
+        "{} transitions became {}, {} states became {}",
+        transitions.len(),
+        simplified.len(),
+        state_count(&transitions),
+        state_count(&simplified)
+    );
+
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    for trans in simplified.iter() {
+        writeln!(out, "{}", trans)?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
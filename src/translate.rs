@@ -1,37 +1,115 @@
-#[macro_use]
-extern crate scan_fmt;
-extern crate derive_more;
+use std::io::{self, BufWriter, Write};
+use turinglike_oversophisticated_calculator::cli;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, loader};
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+use turinglike_oversophisticated_calculator::types::*;
 
-mod machine;
-mod types;
-
-use intbits::Bits;
-use types::*;
+struct Arguments {
+    machine_path: String,
+    legend_path: Option<String>,
+    state_map_path: Option<String>,
+    output_path: Option<String>,
+    input_alphabet: Option<Vec<TapeEntry>>,
+    short_names: bool,
+    show_overhead: bool,
+    strategy: Strategy,
+}
 
 fn print_usage_message() {
     println!(
-        "Usage: {} [two_tape_machine_description_file]",
-        std::env::args().nth(0).unwrap_or("./translate".to_string())
+        "Usage: {} [--legend legend_file] [--short-names] [--show-overhead] [--state-map state_map_file] [--strategy head-to-head|start-marker|one-sweep] [-o output_file] [--input-alphabet letters] [multi_tape_machine_description_file]",
+        std::env::args()
+            .next()
+            .unwrap_or_else(|| "./translate".to_string())
     )
 }
-fn parse_cmd_arguments() -> AppResult<String> {
-    let cmd_args: Vec<String> = std::env::args().collect();
 
-    if cmd_args.len() != 2 {
-        eprintln!(
-            "error: expected 1 arguments but found {}",
-            cmd_args.len() - 1
-        );
+fn parse_cmd_arguments() -> AppResult<Arguments> {
+    let mut cmd_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let legend_path = cli::take_option(&mut cmd_args, "--legend", print_usage_message);
+    let state_map_path = cli::take_option(&mut cmd_args, "--state-map", print_usage_message);
+    let output_path = cli::take_option(&mut cmd_args, "-o", print_usage_message);
+    let input_alphabet =
+        match cli::take_option(&mut cmd_args, "--input-alphabet", print_usage_message) {
+            Some(letters) => Some(loader::parse_input_alphabet(&letters)?),
+            None => None,
+        };
+    let short_names = cli::take_flag(&mut cmd_args, "--short-names");
+    let show_overhead = cli::take_flag(&mut cmd_args, "--show-overhead");
+    let strategy = match cli::take_option(&mut cmd_args, "--strategy", print_usage_message) {
+        Some(name) => Strategy::from_name(&name)?,
+        None => Strategy::HeadToHead,
+    };
+
+    if cmd_args.len() != 1 {
+        eprintln!("error: expected 1 arguments but found {}", cmd_args.len());
         print_usage_message();
         std::process::exit(1);
     }
 
-    Ok(std::fs::read_to_string(&cmd_args[1])?)
+    Ok(Arguments {
+        machine_path: cmd_args.remove(0),
+        legend_path,
+        state_map_path,
+        output_path,
+        input_alphabet,
+        short_names,
+        show_overhead,
+        strategy,
+    })
 }
 fn main() -> Result<(), AppError> {
-    let machine_description = parse_cmd_arguments()?;
-    let translator = machine::translation::translator::MachineTranslator::new(machine_description)?;
-    print!("{}\n", translator.translate());
+    let args = parse_cmd_arguments()?;
+    let multi_tape_transitions = loader::multi_tape_transitions_from_file(&args.machine_path)?;
+    let input_alphabet = match args.input_alphabet {
+        Some(alphabet) => Some(alphabet),
+        None => loader::input_alphabet_from_file(&args.machine_path)?,
+    };
+    let translator = MachineTranslator::new(multi_tape_transitions, args.strategy)?
+        .with_input_alphabet(&input_alphabet.unwrap_or_default())?;
+    let deterministic = determinism::is_deterministic(translator.input_transitions());
+    let mut translation = translator.translation();
+    if args.short_names {
+        translation = translation.with_short_names();
+    }
+
+    if deterministic {
+        let conflicts = translation.conflicting_transitions();
+        if !conflicts.is_empty() {
+            for group in conflicts.iter() {
+                eprintln!("{}", group);
+            }
+            return Err(
+                "deterministic input machine was translated into a nondeterministic one".into(),
+            );
+        }
+    }
+
+    if let Some(legend_path) = args.legend_path {
+        std::fs::write(legend_path, translation.legend.to_string())?;
+    }
+    if let Some(state_map_path) = args.state_map_path {
+        std::fs::write(state_map_path, translation.state_map.to_string())?;
+    }
+
+    if args.show_overhead {
+        eprintln!(
+            "{} strategy: at most {} steps per simulated step, n being the rightmost head's cell",
+            args.strategy,
+            args.strategy.step_overhead_formula()
+        );
+    }
+
+    // written as it goes, a whole translation might not fit in memory as text
+    let mut out: Box<dyn Write> = match args.output_path {
+        Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    translation.write_to(&mut out)?;
+    out.flush()?;
 
     Ok(())
 }
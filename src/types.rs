@@ -1,17 +1,21 @@
-use core::ops::{Bound, Range, RangeBounds};
+use core::ops::Range;
 use derive_more::{Add, FromStr};
-use intbits::{Bits, BitsIndex};
+use intbits::Bits;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 pub type Number = u64;
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct State(pub String);
 impl State {
-    pub fn value<'a>(&'a self) -> &'a str {
+    pub fn value(&self) -> &str {
         &self.0
     }
-    pub fn to_string(&self) -> String {
-        self.value().clone().to_string()
+}
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
     }
 }
 
@@ -19,21 +23,16 @@ pub mod std_states {
     pub const START: &str = "start";
     pub const ACCEPT: &str = "accept";
 
-    /* REJECT unused because if we got a valid machine, this state wouldn't be used as
-    a prerequisite transition state anyway - so here I'm actually allowing
-    transitions >from< `reject` state */
-    // pub const REJECT: &str = "reject";
+    /* REJECT isn't special-cased by the interpreter because if we got a valid machine,
+    this state wouldn't be used as a prerequisite transition state anyway - so there
+    I'm actually allowing transitions >from< `reject` state; only the linter cares */
+    pub const REJECT: &str = "reject";
 }
 
-#[derive(Debug, Hash, PartialOrd, FromStr, PartialEq, Eq, Add, Clone)]
+#[derive(Debug, Hash, PartialOrd, Ord, FromStr, PartialEq, Eq, Add, Clone)]
 pub struct TapeEntry(pub Number);
 impl TapeEntry {
     pub const BLANK: TapeEntry = TapeEntry(0);
-    pub const MAX_BIT_COUNT: Number = 30;
-
-    pub fn to_string(&self) -> String {
-        self.0.to_string()
-    }
 
     // forwarding Bits trait manually
     // disregard the following - I'm only forwarding methods from TapeEntry to Number
@@ -44,24 +43,26 @@ impl TapeEntry {
     fn bits(&self, range: std::ops::Range<Number>) -> Number {
         self.0.bits(range)
     }
-    fn set_bit(&mut self, i: Number, bit: bool) {
-        self.0.set_bit(i, bit)
-    }
-    fn set_bits(&mut self, range: Range<Number>, bits: Number) {
-        self.0.set_bits(range, bits)
-    }
-    fn with_bit(&mut self, i: Number, bit: bool) -> TapeEntry {
+    fn with_bit(&self, i: Number, bit: bool) -> TapeEntry {
         TapeEntry(self.0.with_bit(i, bit))
     }
-    fn with_bits(&mut self, range: Range<Number>, bits: Number) -> TapeEntry {
+    fn with_bits(&self, range: Range<Number>, bits: Number) -> TapeEntry {
         TapeEntry(self.0.with_bits(range, bits))
     }
 }
+impl fmt::Display for TapeEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
 pub enum HeadMoveDirection {
+    #[serde(rename = "L")]
     Left,
+    #[serde(rename = "R")]
     Right,
+    #[serde(rename = "S")]
     Stay,
 }
 
@@ -76,45 +77,74 @@ pub fn direction_to_string(direction: &HeadMoveDirection) -> String {
 pub type AppError = Box<dyn std::error::Error>;
 pub type AppResult<T> = Result<T, AppError>;
 
+/* One cell of a k-tape machine simulated on a single tape: a letter and a head flag
+for every tape, plus a marker for the leftmost cell. Packed into a single number as
+`letter_1 | .. | letter_k | head_1 | .. | head_k | is_start`, so the two-tape layout
+gives each letter 30 bits. */
 #[derive(Debug, Hash, PartialOrd, PartialEq, Eq, Clone)]
 pub struct SuperTapeEntry {
-    pub first_letter: TapeEntry,
-    pub second_letter: TapeEntry,
-    pub has_first_tape_head: bool,
-    pub has_second_tape_head: bool,
+    pub letters: Vec<TapeEntry>,
+    pub heads: Vec<bool>,
     pub is_start: bool,
 }
 impl SuperTapeEntry {
-    const TAPE_ENTRY_DATA_RANGE: Range<Number> = 0..TapeEntry::MAX_BIT_COUNT;
-    const FIRST_LETTER_RANGE: Range<Number> = 0..TapeEntry::MAX_BIT_COUNT;
-    const SECOND_LETTER_RANGE: Range<Number> =
-        TapeEntry::MAX_BIT_COUNT..2 * TapeEntry::MAX_BIT_COUNT;
-    const HAS_FIRST_TAPE_HEAD_ID: Number = 2 * TapeEntry::MAX_BIT_COUNT;
-    const HAS_SECOND_TAPE_HEAD_ID: Number = 2 * TapeEntry::MAX_BIT_COUNT + 1;
-    const IS_START_ID: Number = 2 * TapeEntry::MAX_BIT_COUNT + 2;
+    const ENCODED_BIT_COUNT: Number = 63;
+
+    pub fn letter_bit_count(tapes: usize) -> Number {
+        let tapes = tapes as Number;
+        (Self::ENCODED_BIT_COUNT - tapes - 1) / tapes
+    }
+    fn letter_range(tapes: usize, tape: usize) -> Range<Number> {
+        let width = Self::letter_bit_count(tapes);
+        (tape as Number) * width..(tape as Number + 1) * width
+    }
+    fn head_id(tapes: usize, tape: usize) -> Number {
+        (tapes as Number) * Self::letter_bit_count(tapes) + tape as Number
+    }
+    fn is_start_id(tapes: usize) -> Number {
+        Self::head_id(tapes, tapes)
+    }
+
+    pub fn tape_count(&self) -> usize {
+        self.letters.len()
+    }
 
     pub fn encoded(&self) -> TapeEntry {
+        let tapes = self.tape_count();
         let mut result = TapeEntry(0);
-        result
-            .with_bits(
-                Self::FIRST_LETTER_RANGE,
-                self.first_letter.bits(Self::TAPE_ENTRY_DATA_RANGE),
-            )
-            .with_bits(
-                Self::SECOND_LETTER_RANGE,
-                self.second_letter.bits(Self::TAPE_ENTRY_DATA_RANGE),
-            )
-            .with_bit(Self::HAS_FIRST_TAPE_HEAD_ID, self.has_first_tape_head)
-            .with_bit(Self::HAS_SECOND_TAPE_HEAD_ID, self.has_second_tape_head)
-            .with_bit(Self::IS_START_ID, self.is_start)
-    }
-    pub fn decode(entry: TapeEntry) -> Self {
+        for (tape, letter) in self.letters.iter().enumerate() {
+            result = result.with_bits(
+                Self::letter_range(tapes, tape),
+                letter.bits(0..Self::letter_bit_count(tapes)),
+            );
+        }
+        for (tape, &head) in self.heads.iter().enumerate() {
+            result = result.with_bit(Self::head_id(tapes, tape), head);
+        }
+        result.with_bit(Self::is_start_id(tapes), self.is_start)
+    }
+    pub fn decode(entry: TapeEntry, tapes: usize) -> Self {
         Self {
-            first_letter: TapeEntry(entry.bits(Self::FIRST_LETTER_RANGE)),
-            second_letter: TapeEntry(entry.bits(Self::SECOND_LETTER_RANGE)),
-            has_first_tape_head: entry.bit(Self::HAS_FIRST_TAPE_HEAD_ID),
-            has_second_tape_head: entry.bit(Self::HAS_SECOND_TAPE_HEAD_ID),
-            is_start: entry.bit(Self::IS_START_ID),
+            letters: (0..tapes)
+                .map(|tape| TapeEntry(entry.bits(Self::letter_range(tapes, tape))))
+                .collect(),
+            heads: (0..tapes)
+                .map(|tape| entry.bit(Self::head_id(tapes, tape)))
+                .collect(),
+            is_start: entry.bit(Self::is_start_id(tapes)),
         }
     }
 }
+// `letter_1 .. letter_k head_1 .. head_k start`, heads shown as `^`/`.` and the start marker as `S`/`-`
+impl fmt::Display for SuperTapeEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letters = self.letters.iter().map(|letter| letter.to_string());
+        let heads = self
+            .heads
+            .iter()
+            .map(|&head| if head { "^" } else { "." }.to_string());
+        let start = if self.is_start { "S" } else { "-" }.to_string();
+        let fields: Vec<String> = letters.chain(heads).chain(std::iter::once(start)).collect();
+        write!(f, "{}", fields.join(" "))
+    }
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::{example, load, CONTAINS_ONE_ONE_GOING_LEFT};
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::differential;
+use turinglike_oversophisticated_calculator::machine::translation::binary::BinaryReduction;
+use turinglike_oversophisticated_calculator::types::*;
+
+fn run_length(transitions: &[Transition], limit: Number, word: String) -> Option<Number> {
+    ClassicMachine::new(transitions.to_vec(), limit, word)
+        .unwrap()
+        .accepting_run_length()
+}
+
+// the reduced machine accepts the encoded words, within the promised number of steps
+fn check_reduction(transitions: Vec<Transition>, limit: Number, words: Vec<String>) {
+    let reduction = BinaryReduction::new(transitions.clone(), &[]);
+    assert!(reduction
+        .transitions()
+        .iter()
+        .all(|trans| { trans.tape_value_before.0 <= 1 && trans.tape_value_after.0 <= 1 }));
+
+    for word in words {
+        let original = run_length(&transitions, limit, word.clone());
+        let reduced = run_length(
+            reduction.transitions(),
+            limit * reduction.step_overhead(),
+            reduction.encode_word(&word).unwrap(),
+        );
+        assert_eq!(
+            original.is_some(),
+            reduced.is_some(),
+            "disagreement on `{}`",
+            word
+        );
+        if let (Some(original), Some(reduced)) = (original, reduced) {
+            assert!(reduced <= original * reduction.step_overhead());
+        }
+    }
+}
+
+#[test]
+fn reduced_palindrome_accepts_the_same_words() {
+    check_reduction(
+        example("palindrome.tm"),
+        1_000,
+        differential::words_up_to(&['1', '2'], 5),
+    );
+}
+
+#[test]
+fn nondeterministic_machines_stay_nondeterministic() {
+    check_reduction(
+        load(CONTAINS_ONE_ONE_GOING_LEFT),
+        100,
+        differential::words_up_to(&['1', '2'], 5),
+    );
+}
+
+#[test]
+fn reduced_translations_accept_the_same_words() {
+    check_reduction(
+        common::translated_palindrome(),
+        100_000,
+        differential::words_up_to(&['1', '2'], 3),
+    );
+}
+
+#[test]
+fn blocks_are_as_wide_as_the_largest_letter() {
+    let transitions = load("start 5 accept 1 S");
+    let reduction = BinaryReduction::new(transitions.clone(), &[]);
+    assert_eq!(reduction.block_width(), 3);
+    assert_eq!(reduction.encode_word("51").unwrap(), "101001");
+    assert!(reduction.encode_word("8").is_err());
+
+    let reduction = BinaryReduction::new(transitions, &[TapeEntry(9)]);
+    assert_eq!(reduction.block_width(), 4);
+    assert_eq!(reduction.encode_word("9").unwrap(), "1001");
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use turinglike_oversophisticated_calculator::machine::format::MachineFormat;
+use turinglike_oversophisticated_calculator::machine::logic::equivalence::{
+    BoundedEquivalence, Verdict,
+};
+use turinglike_oversophisticated_calculator::machine::logic::{differential, loader};
+use turinglike_oversophisticated_calculator::machine::multi_tape::Outcome;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+
+// accepts every word right away
+const ANYTHING: &str = "
+start 0 accept 0 S
+start 1 accept 1 S
+start 2 accept 2 S
+";
+
+// walks right forever
+const RUNAWAY: &str = "
+start 0 start 0 R
+start 1 start 1 R
+start 2 start 2 R
+";
+
+fn palindrome() -> Vec<MultiTransition> {
+    common::example("palindrome.tm")
+        .into_iter()
+        .map(MultiTransition::from)
+        .collect()
+}
+
+fn load(description: &str) -> Vec<MultiTransition> {
+    common::load(description)
+        .into_iter()
+        .map(MultiTransition::from)
+        .collect()
+}
+
+#[test]
+fn machines_with_different_tape_counts_agree() {
+    let two_tape = common::palindrome_two_tape();
+    assert_eq!(palindrome()[0].tape_count(), 1);
+    assert_eq!(two_tape[0].tape_count(), 2);
+
+    let check = BoundedEquivalence::new(palindrome(), two_tape, 1000).unwrap();
+    for word in differential::words_up_to(&['1', '2'], 5) {
+        let comparison = check.compare(&word);
+        assert_eq!(comparison.verdict(), Verdict::Agree, "{}", comparison);
+    }
+}
+
+#[test]
+fn disagreements_are_told_from_running_out_of_steps() {
+    let check = BoundedEquivalence::new(load(ANYTHING), palindrome(), 1000).unwrap();
+    let comparison = check.compare("12");
+    assert_eq!(comparison.verdict(), Verdict::Disagree);
+    assert_eq!(comparison.first, Outcome::Accepted(1));
+    assert!(matches!(comparison.second, Outcome::Rejected(_)));
+    assert_eq!(check.compare("121").verdict(), Verdict::Agree);
+
+    let check = BoundedEquivalence::new(load(RUNAWAY), palindrome(), 50).unwrap();
+    let comparison = check.compare("12");
+    assert_eq!(comparison.verdict(), Verdict::Undecided);
+    assert_eq!(comparison.first, Outcome::OutOfSteps);
+    assert_eq!(
+        comparison.to_string(),
+        format!(
+            "`12`: the first machine runs out of steps, the second one {}",
+            comparison.second
+        )
+    );
+}
+
+#[test]
+fn simulator_formats_load_as_one_tape_machines() {
+    let path = std::env::temp_dir().join("bounded_equivalence_palindrome.morphett");
+    let morphett = loader::transitions_to_format(
+        &common::example("palindrome.tm"),
+        &[],
+        MachineFormat::Morphett,
+    )
+    .unwrap();
+    std::fs::write(&path, morphett).unwrap();
+    let loaded = loader::multi_tape_transitions_from_file(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert!(loaded.iter().all(|trans| trans.tape_count() == 1));
+    let check = BoundedEquivalence::new(palindrome(), loaded, 1000).unwrap();
+    for word in differential::words_up_to(&['1', '2'], 5) {
+        assert_eq!(check.compare(&word).verdict(), Verdict::Agree);
+    }
+}
+
+#[test]
+fn errors_come_from_the_loader_of_the_format() {
+    let path = std::env::temp_dir().join("bounded_equivalence_broken.morphett");
+    std::fs::write(&path, "0 1 1\n").unwrap();
+    let loaded = loader::multi_tape_transitions_from_file(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    assert!(loaded.unwrap_err().to_string().contains("`0 1 1`"));
+}
@@ -0,0 +1,183 @@
+mod common;
+
+use common::{accepts, example, load};
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::canonical::CanonicalForm;
+use turinglike_oversophisticated_calculator::machine::logic::differential;
+use turinglike_oversophisticated_calculator::types::*;
+
+fn input_alphabet() -> Vec<TapeEntry> {
+    vec![TapeEntry(1), TapeEntry(2)]
+}
+
+// the same machine as a student might have written it: other names, letters and order
+fn disguised(transitions: &[Transition]) -> Vec<Transition> {
+    let state = |state: &State| match state.value() {
+        std_states::START | std_states::ACCEPT | std_states::REJECT => state.clone(),
+        name => State(format!("my_{}", name.len() * 31 % 17) + name),
+    };
+    // any digit can be in the input, so only the letters past them get renumbered
+    let letter = |letter: &TapeEntry| match letter.0 {
+        0..=9 => letter.clone(),
+        other => TapeEntry(100 - other),
+    };
+    transitions
+        .iter()
+        .rev()
+        .map(|trans| Transition {
+            state_before: state(&trans.state_before),
+            tape_value_before: letter(&trans.tape_value_before),
+            state_after: state(&trans.state_after),
+            tape_value_after: letter(&trans.tape_value_after),
+            tape_head_move_direction: trans.tape_head_move_direction.clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn renamed_machines_compare_equal() {
+    let machines = vec![
+        example("palindrome.tm"),
+        example("square.tm"),
+        load(MARKING),
+    ];
+    for transitions in machines {
+        let canonical = CanonicalForm::new(&transitions, &input_alphabet());
+        let of_disguised = CanonicalForm::new(&disguised(&transitions), &input_alphabet());
+        assert_eq!(canonical.to_string(), of_disguised.to_string());
+        assert_eq!(canonical.hash(), of_disguised.hash());
+    }
+    assert_ne!(
+        CanonicalForm::new(&example("palindrome.tm"), &input_alphabet()).hash(),
+        CanonicalForm::new(&example("square.tm"), &input_alphabet()).hash()
+    );
+}
+
+// marks the ones of the input with `12`, then turns the marks into `13` on the way back
+const MARKING: &str = "
+start 1 start 12 R
+start 2 start 2 R
+start 0 back 0 L
+back 12 back 13 L
+back 2 back 2 L
+back 0 accept 0 S
+";
+
+// two choices only told apart by what their states do later
+const TWIN_CHOICES: &str = "
+start 1 a 1 R
+start 1 b 1 R
+a 1 accept 1 S
+b 2 accept 2 S
+";
+
+// the transitions in an order picked by `seed`
+fn shuffled(transitions: &[Transition], seed: usize) -> Vec<Transition> {
+    let mut shuffled = transitions.to_vec();
+    let mut state = seed;
+    for idx in (1..shuffled.len()).rev() {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        shuffled.swap(idx, (state >> 33) % (idx + 1));
+    }
+    shuffled
+}
+
+#[test]
+fn line_order_doesnt_matter() {
+    let machines = vec![
+        example("palindrome.tm"),
+        example("square.tm"),
+        load(MARKING),
+        load(TWIN_CHOICES),
+    ];
+    for transitions in machines {
+        let canonical = CanonicalForm::new(&transitions, &input_alphabet());
+        for seed in 0..20 {
+            let reordered = shuffled(&disguised(&transitions), seed);
+            assert_eq!(
+                CanonicalForm::new(&reordered, &input_alphabet()).to_string(),
+                canonical.to_string()
+            );
+        }
+    }
+    let mut swapped = load(TWIN_CHOICES);
+    swapped.swap(0, 1);
+    assert_eq!(
+        CanonicalForm::new(&swapped, &input_alphabet()).hash(),
+        CanonicalForm::new(&load(TWIN_CHOICES), &input_alphabet()).hash()
+    );
+}
+
+#[test]
+fn names_follow_the_walk_from_start() {
+    let transitions = load(
+        "
+later 17 accept 17 S
+start 1 first 19 R
+spare 1 accept 1 S
+first 0 later 17 L
+first 1 reject 1 S
+",
+    );
+    let canonical = CanonicalForm::new(&transitions, &input_alphabet());
+    assert_eq!(
+        canonical.to_string(),
+        "start 1 q1 3 R\nq1 0 q2 4 L\nq1 1 reject 1 S\nq2 4 accept 4 S\n"
+    );
+    assert!(!canonical
+        .state_names()
+        .contains_key(&State("spare".to_string())));
+    assert_eq!(canonical.letters()[&TapeEntry(19)], TapeEntry(3));
+}
+
+#[test]
+fn canonical_forms_accept_the_same_words() {
+    for name in ["palindrome.tm", "square.tm"].iter() {
+        let transitions = example(name);
+        let canonical = CanonicalForm::new(&transitions, &input_alphabet());
+        for word in differential::words_up_to(&['1', '2'], 5) {
+            assert_eq!(
+                accepts(canonical.transitions(), &word),
+                accepts(&transitions, &word)
+            );
+        }
+    }
+}
+
+#[test]
+fn digits_read_keep_their_numbers() {
+    let transitions = load(
+        "
+start 1 x 5 R
+start 1 y 7 R
+x 1 accept 5 S
+y 2 accept 7 S
+",
+    );
+    let canonical = CanonicalForm::new(&transitions, &[]);
+    for word in differential::words_up_to(&['1', '2', '5'], 3) {
+        assert_eq!(
+            accepts(canonical.transitions(), &word),
+            accepts(&transitions, &word)
+        );
+    }
+    assert!(accepts(canonical.transitions(), "12"));
+}
+
+#[test]
+fn declared_alphabets_leave_the_other_digits_to_be_renamed() {
+    let with_three = load("start 1 s 3 R\ns 3 accept 3 S");
+    let with_four = load("start 1 s 4 R\ns 4 accept 4 S");
+    let declared = [TapeEntry(1)];
+    assert_eq!(
+        CanonicalForm::new(&with_three, &declared).hash(),
+        CanonicalForm::new(&with_four, &declared).hash()
+    );
+    // with nothing declared, every digit read may be in the input
+    assert_ne!(
+        CanonicalForm::new(&with_three, &[]).hash(),
+        CanonicalForm::new(&with_four, &[]).hash()
+    );
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::example;
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::{differential, loader};
+use turinglike_oversophisticated_calculator::machine::multi_tape::MultiTapeMachine;
+use turinglike_oversophisticated_calculator::machine::translation::clock::{Clock, TimeBound};
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+// walks right forever
+const RUNAWAY: &str = "
+start 0 start 0 R
+start 1 start 1 R
+start 2 start 2 R
+";
+
+fn bound(description: &str) -> TimeBound {
+    TimeBound::from_description(description).unwrap()
+}
+
+#[test]
+fn time_bounds_are_read_as_polynomials() {
+    let read = bound("3n^2 + 2n + 5");
+    assert_eq!((read.quadratic, read.linear, read.constant), (3, 2, 5));
+    assert_eq!(bound("n^2+n^2+n").to_string(), "2n^2+n");
+    assert_eq!(bound("7").evaluate(10), 7);
+    assert_eq!(bound("2n+1").evaluate(10), 21);
+    assert_eq!(bound("0").to_string(), "0");
+    for wrong in ["", "n^3", "x", "2m", "+"].iter() {
+        assert!(TimeBound::from_description(wrong).is_err(), "{}", wrong);
+    }
+}
+
+#[test]
+fn clocked_machines_accept_what_is_accepted_in_time() {
+    let machine = ClassicMachine::new(example("palindrome.tm"), 1_000, String::new()).unwrap();
+    for description in ["n^2+n+1", "n", "2n+3", "5", "0"].iter() {
+        let bound = bound(description);
+        let clocked = MultiTapeMachine::new(
+            Clock::new(example("palindrome.tm"), bound, &[]).multi_tape_transitions(),
+            1,
+            String::new(),
+        )
+        .unwrap();
+        for word in differential::words_up_to(&['1', '2'], 4) {
+            let in_time = machine
+                .with_input(1_000, word.clone())
+                .accepting_run_length()
+                .is_some_and(|steps| steps <= bound.evaluate(word.len() as Number));
+            assert_eq!(
+                clocked.with_input(100_000, word.clone()).accepts(),
+                in_time,
+                "disagreement on `{}` within {}",
+                word,
+                bound
+            );
+        }
+    }
+}
+
+#[test]
+fn translated_clocked_machines_always_halt() {
+    let transitions = loader::transitions_from_description(RUNAWAY.to_string()).unwrap();
+    let clocked = Clock::new(transitions, bound("n^2+2n+3"), &[]).multi_tape_transitions();
+    let translated: Vec<Transition> = MachineTranslator::new(clocked, Strategy::StartMarker)
+        .unwrap()
+        .translation()
+        .transitions()
+        .collect();
+    let machine = ClassicMachine::new(translated, 1, String::new()).unwrap();
+    for word in differential::words_up_to(&['1', '2'], 3) {
+        let mut halted = false;
+        let accepted = machine
+            .with_input(1_000_000, word.clone())
+            .traced_accepting_run_length(|_, configs| halted |= configs.is_empty());
+        assert!(accepted.is_none());
+        assert!(halted, "`{}` ran out of steps", word);
+    }
+}
@@ -0,0 +1,75 @@
+// fixtures shared by the integration tests, not every test using all of them
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+
+// guesses where `11` starts, staying put on the way
+pub const CONTAINS_ONE_ONE: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 1 one 1 S
+one 1 next 3 R
+next 1 accept 1 S
+";
+
+// guesses where `11` starts, moving left off the first cell on the way
+pub const CONTAINS_ONE_ONE_GOING_LEFT: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 1 one 1 R
+start 0 back 0 L
+back 2 back 2 L
+one 1 accept 1 S
+";
+
+pub fn path(relative: &str) -> String {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(relative)
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+// a one-tape machine from `example/`
+pub fn example(name: &str) -> Vec<Transition> {
+    loader::transitions_from_file(&path(&format!("example/{}", name))).unwrap()
+}
+
+// the two-tape palindrome machine from TASK.md - about 4 steps per input letter
+pub fn palindrome_two_tape() -> Vec<MultiTransition> {
+    loader::multi_tape_transitions_from_file(&path("example/palindrome_two_tape.tm")).unwrap()
+}
+
+pub fn load(description: &str) -> Vec<Transition> {
+    loader::transitions_from_description(description.to_string()).unwrap()
+}
+
+pub fn accepts(transitions: &[Transition], word: &str) -> bool {
+    ClassicMachine::new(transitions.to_vec(), 100_000, word.to_string())
+        .unwrap()
+        .accepts()
+}
+
+// the small one-tape machines every pass gets checked on, all deterministic but the last
+pub fn corpus() -> Vec<Vec<Transition>> {
+    vec![
+        example("palindrome.tm"),
+        example("square.tm"),
+        load(CONTAINS_ONE_ONE),
+    ]
+}
+
+pub fn translated_palindrome() -> Vec<Transition> {
+    MachineTranslator::new(palindrome_two_tape(), Strategy::StartMarker)
+        .unwrap()
+        .translation()
+        .transitions()
+        .collect()
+}
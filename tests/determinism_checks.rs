@@ -0,0 +1,113 @@
+mod common;
+
+use common::{example, load, CONTAINS_ONE_ONE};
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::determinism;
+use turinglike_oversophisticated_calculator::machine::logic::differential::{
+    self, DifferentialTest,
+};
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+
+/* Copies the input to the second tape and checks it against the copy once more, so `check`
+reads the same first letter with different second ones. The reading phases of a translation
+are shared by all the transitions reading the same first letter in the same state. */
+const BRANCHING_ON_SECOND_TAPE: &str = "
+start 1 0 start 1 1 R R
+start 2 0 start 2 2 R R
+start 0 0 back 0 0 L L
+back 1 1 back 1 1 L L
+back 2 2 back 2 2 L L
+back 1 0 check 1 0 S R
+back 2 0 check 2 0 S R
+check 1 1 check 1 1 R R
+check 1 2 reject 1 2 S S
+check 2 2 check 2 2 R R
+check 2 1 reject 2 1 S S
+check 0 0 accept 0 0 S S
+";
+
+#[test]
+fn palindromes_are_checked_deterministically() {
+    assert!(determinism::is_deterministic(&example("palindrome.tm")));
+    let two_tape = common::palindrome_two_tape();
+    assert!(determinism::is_deterministic(&two_tape));
+}
+
+#[test]
+fn square_guesses_where_words_start() {
+    let conflicts = determinism::conflicting_transitions(&example("square.tm"));
+    let first_choices: Vec<String> = conflicts
+        .iter()
+        .map(|group| group.transitions[0].to_string())
+        .collect();
+    assert_eq!(
+        first_choices,
+        vec![
+            "findSomeOtherWordBeginningWith1 1 goBackToNextLetterOfFirstWord 8 L",
+            "findSomeOtherWordBeginningWith2 2 goBackToNextLetterOfFirstWord 8 L",
+        ]
+    );
+    assert!(conflicts.iter().all(|group| group.transitions.len() == 2));
+}
+
+#[test]
+fn conflicts_are_grouped_by_what_is_read() {
+    let conflicts = determinism::conflicting_transitions(&load(CONTAINS_ONE_ONE));
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        conflicts[0].to_string(),
+        "state `start` reading 1 has 2 transitions:\n    start 1 start 1 R\n    start 1 one 1 S"
+    );
+
+    // the same transition twice leads to the same config, so it's no choice
+    let mut duplicated = load(CONTAINS_ONE_ONE);
+    duplicated.retain(|trans| trans.state_after.value() != "start");
+    duplicated.push(duplicated[0].clone());
+    assert!(determinism::is_deterministic(&duplicated));
+}
+
+#[test]
+fn two_tape_conflicts_need_both_letters_equal() {
+    let parse = |description: &str| -> Vec<MultiTransition> {
+        loader::multi_tape_transitions_from_description(description.to_string()).unwrap()
+    };
+    assert!(determinism::is_deterministic(&parse(
+        BRANCHING_ON_SECOND_TAPE
+    )));
+
+    let conflicting = parse("start 1 0 a 1 0 R S\nstart 1 0 b 1 1 S R\nstart 1 1 a 1 1 R S");
+    let conflicts = determinism::conflicting_transitions(&conflicting);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].transitions.len(), 2);
+    assert!(conflicts[0]
+        .to_string()
+        .starts_with("state `start` reading 1 0 has 2 transitions:"));
+}
+
+#[test]
+fn shared_read_phases_keep_translations_right() {
+    let source =
+        loader::multi_tape_transitions_from_description(BRANCHING_ON_SECOND_TAPE.to_string())
+            .unwrap();
+    for strategy in Strategy::ALL.iter() {
+        let translated: Vec<Transition> = MachineTranslator::new(source.clone(), *strategy)
+            .unwrap()
+            .translation()
+            .transitions()
+            .collect();
+        assert!(
+            determinism::is_deterministic(&translated),
+            "{:?}",
+            determinism::conflicting_transitions(&translated)
+        );
+
+        let test = DifferentialTest::new(source.clone(), &[], *strategy, 1000).unwrap();
+        for word in differential::words_up_to(&['1', '2'], 4) {
+            assert_eq!(test.check(&word), None, "{:?}", strategy);
+        }
+    }
+}
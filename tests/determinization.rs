@@ -0,0 +1,99 @@
+mod common;
+
+use common::load;
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, differential};
+use turinglike_oversophisticated_calculator::machine::multi_tape::MultiTapeMachine;
+use turinglike_oversophisticated_calculator::machine::translation::determinizer::Determinizer;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+
+// guesses where `11` starts
+const CONTAINS_ONE_ONE: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 1 one 1 R
+one 1 accept 1 S
+";
+
+// guesses the last letter, and keeps running forever on a wrong guess of 1
+const ENDS_WITH_TWO: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 2 last 2 R
+start 1 loop 1 S
+loop 1 loop 1 S
+last 0 accept 0 S
+";
+
+// writes a guessed letter and checks it against the next one, three choices in a step
+const REPEATED_LETTER: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 1 saw1 1 R
+start 2 saw2 2 R
+saw1 1 accept 3 S
+saw2 2 accept 3 S
+";
+
+const MACHINES: [&str; 3] = [CONTAINS_ONE_ONE, ENDS_WITH_TWO, REPEATED_LETTER];
+
+fn accepts_natively(description: &str, word: &str) -> bool {
+    ClassicMachine::new(load(description), 100, word.to_string())
+        .unwrap()
+        .accepts()
+}
+
+fn words() -> Vec<String> {
+    differential::words_up_to(&['1', '2'], 4)
+}
+
+#[test]
+fn two_tape_machines_are_deterministic() {
+    for description in MACHINES.iter() {
+        let transitions = Determinizer::new(load(description), &[]).multi_tape_transitions();
+        assert!(determinism::is_deterministic(&transitions));
+    }
+}
+
+#[test]
+fn two_tape_machines_accept_the_same_words() {
+    for description in MACHINES.iter() {
+        let transitions = Determinizer::new(load(description), &[]).multi_tape_transitions();
+        // accepting runs take less than 2000 steps here, some rejecting ones never end
+        let machine = MultiTapeMachine::new(transitions, 5_000, String::new()).unwrap();
+        for word in words() {
+            assert_eq!(
+                machine
+                    .with_input(5_000, word.clone())
+                    .accepting_run_length()
+                    .is_some(),
+                accepts_natively(description, &word),
+                "disagreement on `{}` for {}",
+                word,
+                description
+            );
+        }
+    }
+}
+
+#[test]
+fn one_tape_machines_accept_the_same_words() {
+    let transitions = Determinizer::new(load(CONTAINS_ONE_ONE), &[]).multi_tape_transitions();
+    let translation = MachineTranslator::new(transitions, Strategy::StartMarker)
+        .unwrap()
+        .translation();
+    assert!(translation.conflicting_transitions().is_empty());
+
+    let machine =
+        ClassicMachine::new(translation.transitions().collect(), 1, String::new()).unwrap();
+    for word in differential::words_up_to(&['1', '2'], 3) {
+        assert_eq!(
+            machine.with_input(1_000_000, word.clone()).accepts(),
+            accepts_natively(CONTAINS_ONE_ONE, &word),
+            "disagreement on `{}`",
+            word
+        );
+    }
+}
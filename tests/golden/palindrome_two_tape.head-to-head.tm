@@ -0,0 +1,1254 @@
+start 0 t0 45 S
+start 1 t0 46 S
+start 2 t0 47 S
+start 3 t0 48 S
+start 4 t0 49 S
+t1 0 t1 0 L
+t1 1 t1 1 L
+t1 2 t1 2 L
+t1 3 t1 3 L
+t1 4 t1 4 L
+t1 5 t1 5 L
+t1 6 t1 6 L
+t1 7 t1 7 L
+t1 8 t1 8 L
+t1 9 t1 9 L
+t1 10 t1 10 L
+t1 11 t1 11 L
+t1 12 t1 12 L
+t1 13 t1 13 L
+t1 14 t1 14 L
+t1 35 t2 5 L
+t1 36 t2 6 L
+t1 37 t2 7 L
+t1 38 t2 8 L
+t1 39 t2 9 L
+t3 0 t3 0 R
+t3 1 t3 1 R
+t3 2 t3 2 R
+t3 3 t3 3 R
+t3 4 t3 4 R
+t3 5 t3 5 R
+t3 6 t3 6 R
+t3 7 t3 7 R
+t3 8 t3 8 R
+t3 9 t3 9 R
+t3 10 t3 10 R
+t3 11 t3 11 R
+t3 12 t3 12 R
+t3 13 t3 13 R
+t3 14 t3 14 R
+t3 35 t4 5 L
+t3 36 t4 6 L
+t3 37 t4 7 L
+t3 38 t4 8 L
+t3 39 t4 9 L
+t5 0 t6 15 S
+t5 1 t6 16 S
+t5 2 t6 17 S
+t5 3 t6 18 S
+t5 4 t6 19 S
+t5 5 t6 20 S
+t5 6 t6 21 S
+t5 7 t6 22 S
+t5 8 t6 23 S
+t5 9 t6 24 S
+t5 10 t6 25 S
+t5 11 t6 26 S
+t5 12 t6 27 S
+t5 13 t6 28 S
+t5 14 t6 29 S
+t5 30 t7 45 S
+t5 31 t7 46 S
+t5 32 t7 47 S
+t5 33 t7 48 S
+t5 34 t7 49 S
+t5 35 t7 50 S
+t5 36 t7 51 S
+t5 37 t7 52 S
+t5 38 t7 53 S
+t5 39 t7 54 S
+t5 40 t7 55 S
+t5 41 t7 56 S
+t5 42 t7 57 S
+t5 43 t7 58 S
+t5 44 t7 59 S
+t8 0 t9 15 S
+t8 1 t9 16 S
+t8 2 t9 17 S
+t8 3 t9 18 S
+t8 4 t9 19 S
+t8 5 t9 20 S
+t8 6 t9 21 S
+t8 7 t9 22 S
+t8 8 t9 23 S
+t8 9 t9 24 S
+t8 10 t9 25 S
+t8 11 t9 26 S
+t8 12 t9 27 S
+t8 13 t9 28 S
+t8 14 t9 29 S
+t8 30 t7 45 S
+t8 31 t7 46 S
+t8 32 t7 47 S
+t8 33 t7 48 S
+t8 34 t7 49 S
+t8 35 t7 50 S
+t8 36 t7 51 S
+t8 37 t7 52 S
+t8 38 t7 53 S
+t8 39 t7 54 S
+t8 40 t7 55 S
+t8 41 t7 56 S
+t8 42 t7 57 S
+t8 43 t7 58 S
+t8 44 t7 59 S
+t2 0 t10 30 R
+t2 1 t10 31 R
+t2 2 t10 32 R
+t2 3 t10 33 R
+t2 4 t10 34 R
+t2 5 t10 35 R
+t2 6 t10 36 R
+t2 7 t10 37 R
+t2 8 t10 38 R
+t2 9 t10 39 R
+t2 10 t10 40 R
+t2 11 t10 41 R
+t2 12 t10 42 R
+t2 13 t10 43 R
+t2 14 t10 44 R
+t2 16 t8 31 L
+t2 18 accept 48 S
+t2 21 t8 36 L
+t2 23 accept 53 S
+t2 26 t8 41 L
+t2 28 accept 58 S
+t4 0 t11 30 L
+t4 1 t11 31 L
+t4 2 t11 32 L
+t4 3 t11 33 L
+t4 4 t11 34 L
+t4 5 t11 35 L
+t4 6 t11 36 L
+t4 7 t11 37 L
+t4 8 t11 38 L
+t4 9 t11 39 L
+t4 10 t11 40 L
+t4 11 t11 41 L
+t4 12 t11 42 L
+t4 13 t11 43 L
+t4 14 t11 44 L
+t4 16 t8 31 L
+t4 18 accept 48 S
+t4 21 t8 36 L
+t4 23 accept 53 S
+t4 26 t8 41 L
+t4 28 accept 58 S
+t10 0 t10 0 R
+t10 1 t10 1 R
+t10 2 t10 2 R
+t10 3 t10 3 R
+t10 4 t10 4 R
+t10 5 t10 5 R
+t10 6 t10 6 R
+t10 7 t10 7 R
+t10 8 t10 8 R
+t10 9 t10 9 R
+t10 10 t10 10 R
+t10 11 t10 11 R
+t10 12 t10 12 R
+t10 13 t10 13 R
+t10 14 t10 14 R
+t10 16 t5 1 L
+t10 18 accept 18 S
+t10 21 t5 6 L
+t10 23 accept 23 S
+t10 26 t5 11 L
+t10 28 accept 28 S
+t11 0 t11 0 L
+t11 1 t11 1 L
+t11 2 t11 2 L
+t11 3 t11 3 L
+t11 4 t11 4 L
+t11 5 t11 5 L
+t11 6 t11 6 L
+t11 7 t11 7 L
+t11 8 t11 8 L
+t11 9 t11 9 L
+t11 10 t11 10 L
+t11 11 t11 11 L
+t11 12 t11 12 L
+t11 13 t11 13 L
+t11 14 t11 14 L
+t11 16 t8 1 L
+t11 18 accept 18 S
+t11 21 t8 6 L
+t11 23 accept 23 S
+t11 26 t8 11 L
+t11 28 accept 28 S
+t6 16 t1 16 L
+t6 17 t12 17 L
+t6 18 t13 18 L
+t6 19 t14 19 L
+t6 21 t1 21 L
+t6 22 t12 22 L
+t6 23 t13 23 L
+t6 24 t14 24 L
+t6 26 t1 26 L
+t6 27 t12 27 L
+t6 28 t13 28 L
+t6 29 t14 29 L
+t9 16 t3 16 R
+t9 17 t15 17 R
+t9 18 t16 18 R
+t9 19 t17 19 R
+t9 21 t3 21 R
+t9 22 t15 22 R
+t9 23 t16 23 R
+t9 24 t17 24 R
+t9 26 t3 26 R
+t9 27 t15 27 R
+t9 28 t16 28 R
+t9 29 t17 29 R
+t7 51 t2 21 L
+t7 53 accept 53 S
+t7 57 t18 27 L
+t7 59 accept 59 S
+t12 0 t12 0 L
+t12 1 t12 1 L
+t12 2 t12 2 L
+t12 3 t12 3 L
+t12 4 t12 4 L
+t12 5 t12 5 L
+t12 6 t12 6 L
+t12 7 t12 7 L
+t12 8 t12 8 L
+t12 9 t12 9 L
+t12 10 t12 10 L
+t12 11 t12 11 L
+t12 12 t12 12 L
+t12 13 t12 13 L
+t12 14 t12 14 L
+t12 40 t18 10 L
+t12 41 t18 11 L
+t12 42 t18 12 L
+t12 43 t18 13 L
+t12 44 t18 14 L
+t15 0 t15 0 R
+t15 1 t15 1 R
+t15 2 t15 2 R
+t15 3 t15 3 R
+t15 4 t15 4 R
+t15 5 t15 5 R
+t15 6 t15 6 R
+t15 7 t15 7 R
+t15 8 t15 8 R
+t15 9 t15 9 R
+t15 10 t15 10 R
+t15 11 t15 11 R
+t15 12 t15 12 R
+t15 13 t15 13 R
+t15 14 t15 14 R
+t15 40 t19 10 L
+t15 41 t19 11 L
+t15 42 t19 12 L
+t15 43 t19 13 L
+t15 44 t19 14 L
+t18 0 t20 30 R
+t18 1 t20 31 R
+t18 2 t20 32 R
+t18 3 t20 33 R
+t18 4 t20 34 R
+t18 5 t20 35 R
+t18 6 t20 36 R
+t18 7 t20 37 R
+t18 8 t20 38 R
+t18 9 t20 39 R
+t18 10 t20 40 R
+t18 11 t20 41 R
+t18 12 t20 42 R
+t18 13 t20 43 R
+t18 14 t20 44 R
+t18 17 t8 32 L
+t18 19 accept 49 S
+t18 22 t8 37 L
+t18 24 accept 54 S
+t18 27 t8 42 L
+t18 29 accept 59 S
+t19 0 t21 30 L
+t19 1 t21 31 L
+t19 2 t21 32 L
+t19 3 t21 33 L
+t19 4 t21 34 L
+t19 5 t21 35 L
+t19 6 t21 36 L
+t19 7 t21 37 L
+t19 8 t21 38 L
+t19 9 t21 39 L
+t19 10 t21 40 L
+t19 11 t21 41 L
+t19 12 t21 42 L
+t19 13 t21 43 L
+t19 14 t21 44 L
+t19 17 t8 32 L
+t19 19 accept 49 S
+t19 22 t8 37 L
+t19 24 accept 54 S
+t19 27 t8 42 L
+t19 29 accept 59 S
+t20 0 t20 0 R
+t20 1 t20 1 R
+t20 2 t20 2 R
+t20 3 t20 3 R
+t20 4 t20 4 R
+t20 5 t20 5 R
+t20 6 t20 6 R
+t20 7 t20 7 R
+t20 8 t20 8 R
+t20 9 t20 9 R
+t20 10 t20 10 R
+t20 11 t20 11 R
+t20 12 t20 12 R
+t20 13 t20 13 R
+t20 14 t20 14 R
+t20 17 t5 2 L
+t20 19 accept 19 S
+t20 22 t5 7 L
+t20 24 accept 24 S
+t20 27 t5 12 L
+t20 29 accept 29 S
+t21 0 t21 0 L
+t21 1 t21 1 L
+t21 2 t21 2 L
+t21 3 t21 3 L
+t21 4 t21 4 L
+t21 5 t21 5 L
+t21 6 t21 6 L
+t21 7 t21 7 L
+t21 8 t21 8 L
+t21 9 t21 9 L
+t21 10 t21 10 L
+t21 11 t21 11 L
+t21 12 t21 12 L
+t21 13 t21 13 L
+t21 14 t21 14 L
+t21 17 t8 2 L
+t21 19 accept 19 S
+t21 22 t8 7 L
+t21 24 accept 24 S
+t21 27 t8 12 L
+t21 29 accept 29 S
+t13 0 t13 0 L
+t13 1 t13 1 L
+t13 2 t13 2 L
+t13 3 t13 3 L
+t13 4 t13 4 L
+t13 5 t13 5 L
+t13 6 t13 6 L
+t13 7 t13 7 L
+t13 8 t13 8 L
+t13 9 t13 9 L
+t13 10 t13 10 L
+t13 11 t13 11 L
+t13 12 t13 12 L
+t13 13 t13 13 L
+t13 14 t13 14 L
+t13 35 t10 35 R
+t13 36 t10 36 R
+t13 37 t10 37 R
+t13 38 t10 38 R
+t13 39 t10 39 R
+t16 0 t16 0 R
+t16 1 t16 1 R
+t16 2 t16 2 R
+t16 3 t16 3 R
+t16 4 t16 4 R
+t16 5 t16 5 R
+t16 6 t16 6 R
+t16 7 t16 7 R
+t16 8 t16 8 R
+t16 9 t16 9 R
+t16 10 t16 10 R
+t16 11 t16 11 R
+t16 12 t16 12 R
+t16 13 t16 13 R
+t16 14 t16 14 R
+t16 35 t11 35 L
+t16 36 t11 36 L
+t16 37 t11 37 L
+t16 38 t11 38 L
+t16 39 t11 39 L
+t14 0 t14 0 L
+t14 1 t14 1 L
+t14 2 t14 2 L
+t14 3 t14 3 L
+t14 4 t14 4 L
+t14 5 t14 5 L
+t14 6 t14 6 L
+t14 7 t14 7 L
+t14 8 t14 8 L
+t14 9 t14 9 L
+t14 10 t14 10 L
+t14 11 t14 11 L
+t14 12 t14 12 L
+t14 13 t14 13 L
+t14 14 t14 14 L
+t14 40 t20 40 R
+t14 41 t20 41 R
+t14 42 t20 42 R
+t14 43 t20 43 R
+t14 44 t20 44 R
+t17 0 t17 0 R
+t17 1 t17 1 R
+t17 2 t17 2 R
+t17 3 t17 3 R
+t17 4 t17 4 R
+t17 5 t17 5 R
+t17 6 t17 6 R
+t17 7 t17 7 R
+t17 8 t17 8 R
+t17 9 t17 9 R
+t17 10 t17 10 R
+t17 11 t17 11 R
+t17 12 t17 12 R
+t17 13 t17 13 R
+t17 14 t17 14 R
+t17 40 t21 40 L
+t17 41 t21 41 L
+t17 42 t21 42 L
+t17 43 t21 43 L
+t17 44 t21 44 L
+t22 0 t22 0 L
+t22 1 t22 1 L
+t22 2 t22 2 L
+t22 3 t22 3 L
+t22 4 t22 4 L
+t22 5 t22 5 L
+t22 6 t22 6 L
+t22 7 t22 7 L
+t22 8 t22 8 L
+t22 9 t22 9 L
+t22 10 t22 10 L
+t22 11 t22 11 L
+t22 12 t22 12 L
+t22 13 t22 13 L
+t22 14 t22 14 L
+t22 30 t23 5 R
+t22 31 t23 6 R
+t22 32 t23 7 R
+t22 33 t23 8 R
+t22 34 t23 9 R
+t24 0 t24 0 R
+t24 1 t24 1 R
+t24 2 t24 2 R
+t24 3 t24 3 R
+t24 4 t24 4 R
+t24 5 t24 5 R
+t24 6 t24 6 R
+t24 7 t24 7 R
+t24 8 t24 8 R
+t24 9 t24 9 R
+t24 10 t24 10 R
+t24 11 t24 11 R
+t24 12 t24 12 R
+t24 13 t24 13 R
+t24 14 t24 14 R
+t24 30 t25 5 R
+t24 31 t25 6 R
+t24 32 t25 7 R
+t24 33 t25 8 R
+t24 34 t25 9 R
+t26 0 t27 15 S
+t26 1 t27 16 S
+t26 2 t27 17 S
+t26 3 t27 18 S
+t26 4 t27 19 S
+t26 5 t27 20 S
+t26 6 t27 21 S
+t26 7 t27 22 S
+t26 8 t27 23 S
+t26 9 t27 24 S
+t26 10 t27 25 S
+t26 11 t27 26 S
+t26 12 t27 27 S
+t26 13 t27 28 S
+t26 14 t27 29 S
+t26 30 t28 45 S
+t26 31 t28 46 S
+t26 32 t28 47 S
+t26 33 t28 48 S
+t26 34 t28 49 S
+t26 35 t28 50 S
+t26 36 t28 51 S
+t26 37 t28 52 S
+t26 38 t28 53 S
+t26 39 t28 54 S
+t26 40 t28 55 S
+t26 41 t28 56 S
+t26 42 t28 57 S
+t26 43 t28 58 S
+t26 44 t28 59 S
+t29 0 t30 15 S
+t29 1 t30 16 S
+t29 2 t30 17 S
+t29 3 t30 18 S
+t29 4 t30 19 S
+t29 5 t30 20 S
+t29 6 t30 21 S
+t29 7 t30 22 S
+t29 8 t30 23 S
+t29 9 t30 24 S
+t29 10 t30 25 S
+t29 11 t30 26 S
+t29 12 t30 27 S
+t29 13 t30 28 S
+t29 14 t30 29 S
+t29 30 t28 45 S
+t29 31 t28 46 S
+t29 32 t28 47 S
+t29 33 t28 48 S
+t29 34 t28 49 S
+t29 35 t28 50 S
+t29 36 t28 51 S
+t29 37 t28 52 S
+t29 38 t28 53 S
+t29 39 t28 54 S
+t29 40 t28 55 S
+t29 41 t28 56 S
+t29 42 t28 57 S
+t29 43 t28 58 S
+t29 44 t28 59 S
+t23 0 t31 30 R
+t23 1 t31 31 R
+t23 2 t31 32 R
+t23 3 t31 33 R
+t23 4 t31 34 R
+t23 5 t31 35 R
+t23 6 t31 36 R
+t23 7 t31 37 R
+t23 8 t31 38 R
+t23 9 t31 39 R
+t23 10 t31 40 R
+t23 11 t31 41 R
+t23 12 t31 42 R
+t23 13 t31 43 R
+t23 14 t31 44 R
+t23 16 t29 31 L
+t23 17 t29 32 L
+t23 18 t32 33 L
+t23 19 t32 34 L
+t23 21 t29 36 L
+t23 22 t29 37 L
+t23 23 t32 38 L
+t23 24 t32 39 L
+t23 26 t29 41 L
+t23 27 t29 42 L
+t23 28 t32 43 L
+t23 29 t32 44 L
+t25 0 t33 30 L
+t25 1 t33 31 L
+t25 2 t33 32 L
+t25 3 t33 33 L
+t25 4 t33 34 L
+t25 5 t33 35 L
+t25 6 t33 36 L
+t25 7 t33 37 L
+t25 8 t33 38 L
+t25 9 t33 39 L
+t25 10 t33 40 L
+t25 11 t33 41 L
+t25 12 t33 42 L
+t25 13 t33 43 L
+t25 14 t33 44 L
+t25 16 t29 31 L
+t25 17 t29 32 L
+t25 18 t32 33 L
+t25 19 t32 34 L
+t25 21 t29 36 L
+t25 22 t29 37 L
+t25 23 t32 38 L
+t25 24 t32 39 L
+t25 26 t29 41 L
+t25 27 t29 42 L
+t25 28 t32 43 L
+t25 29 t32 44 L
+t31 0 t31 0 R
+t31 1 t31 1 R
+t31 2 t31 2 R
+t31 3 t31 3 R
+t31 4 t31 4 R
+t31 5 t31 5 R
+t31 6 t31 6 R
+t31 7 t31 7 R
+t31 8 t31 8 R
+t31 9 t31 9 R
+t31 10 t31 10 R
+t31 11 t31 11 R
+t31 12 t31 12 R
+t31 13 t31 13 R
+t31 14 t31 14 R
+t31 16 t26 1 L
+t31 17 t26 2 L
+t31 18 t34 3 L
+t31 19 t34 4 L
+t31 21 t26 6 L
+t31 22 t26 7 L
+t31 23 t34 8 L
+t31 24 t34 9 L
+t31 26 t26 11 L
+t31 27 t26 12 L
+t31 28 t34 13 L
+t31 29 t34 14 L
+t33 0 t33 0 L
+t33 1 t33 1 L
+t33 2 t33 2 L
+t33 3 t33 3 L
+t33 4 t33 4 L
+t33 5 t33 5 L
+t33 6 t33 6 L
+t33 7 t33 7 L
+t33 8 t33 8 L
+t33 9 t33 9 L
+t33 10 t33 10 L
+t33 11 t33 11 L
+t33 12 t33 12 L
+t33 13 t33 13 L
+t33 14 t33 14 L
+t33 16 t29 1 L
+t33 17 t29 2 L
+t33 18 t32 3 L
+t33 19 t32 4 L
+t33 21 t29 6 L
+t33 22 t29 7 L
+t33 23 t32 8 L
+t33 24 t32 9 L
+t33 26 t29 11 L
+t33 27 t29 12 L
+t33 28 t32 13 L
+t33 29 t32 14 L
+t27 16 t22 16 L
+t27 17 t35 17 L
+t27 18 t36 18 L
+t27 19 t37 19 L
+t27 21 t22 21 L
+t27 22 t35 22 L
+t27 23 t36 23 L
+t27 24 t37 24 L
+t27 26 t22 26 L
+t27 27 t35 27 L
+t27 28 t36 28 L
+t27 29 t37 29 L
+t30 16 t24 16 R
+t30 17 t38 17 R
+t30 18 t39 18 R
+t30 19 t40 19 R
+t30 21 t24 21 R
+t30 22 t38 22 R
+t30 23 t39 23 R
+t30 24 t40 24 R
+t30 26 t24 26 R
+t30 27 t38 27 R
+t30 28 t39 28 R
+t30 29 t40 29 R
+t28 46 t25 21 R
+t28 47 t25 27 R
+t28 48 t25 23 R
+t28 49 t25 29 R
+t35 0 t35 0 L
+t35 1 t35 1 L
+t35 2 t35 2 L
+t35 3 t35 3 L
+t35 4 t35 4 L
+t35 5 t35 5 L
+t35 6 t35 6 L
+t35 7 t35 7 L
+t35 8 t35 8 L
+t35 9 t35 9 L
+t35 10 t35 10 L
+t35 11 t35 11 L
+t35 12 t35 12 L
+t35 13 t35 13 L
+t35 14 t35 14 L
+t35 30 t23 10 R
+t35 31 t23 11 R
+t35 32 t23 12 R
+t35 33 t23 13 R
+t35 34 t23 14 R
+t38 0 t38 0 R
+t38 1 t38 1 R
+t38 2 t38 2 R
+t38 3 t38 3 R
+t38 4 t38 4 R
+t38 5 t38 5 R
+t38 6 t38 6 R
+t38 7 t38 7 R
+t38 8 t38 8 R
+t38 9 t38 9 R
+t38 10 t38 10 R
+t38 11 t38 11 R
+t38 12 t38 12 R
+t38 13 t38 13 R
+t38 14 t38 14 R
+t38 30 t25 10 R
+t38 31 t25 11 R
+t38 32 t25 12 R
+t38 33 t25 13 R
+t38 34 t25 14 R
+t36 0 t36 0 L
+t36 1 t36 1 L
+t36 2 t36 2 L
+t36 3 t36 3 L
+t36 4 t36 4 L
+t36 5 t36 5 L
+t36 6 t36 6 L
+t36 7 t36 7 L
+t36 8 t36 8 L
+t36 9 t36 9 L
+t36 10 t36 10 L
+t36 11 t36 11 L
+t36 12 t36 12 L
+t36 13 t36 13 L
+t36 14 t36 14 L
+t36 30 t23 5 R
+t36 31 t23 6 R
+t36 32 t23 7 R
+t36 33 t23 8 R
+t36 34 t23 9 R
+t39 0 t39 0 R
+t39 1 t39 1 R
+t39 2 t39 2 R
+t39 3 t39 3 R
+t39 4 t39 4 R
+t39 5 t39 5 R
+t39 6 t39 6 R
+t39 7 t39 7 R
+t39 8 t39 8 R
+t39 9 t39 9 R
+t39 10 t39 10 R
+t39 11 t39 11 R
+t39 12 t39 12 R
+t39 13 t39 13 R
+t39 14 t39 14 R
+t39 30 t25 5 R
+t39 31 t25 6 R
+t39 32 t25 7 R
+t39 33 t25 8 R
+t39 34 t25 9 R
+t34 0 t41 15 S
+t34 1 t41 16 S
+t34 2 t41 17 S
+t34 3 t41 18 S
+t34 4 t41 19 S
+t34 5 t41 20 S
+t34 6 t41 21 S
+t34 7 t41 22 S
+t34 8 t41 23 S
+t34 9 t41 24 S
+t34 10 t41 25 S
+t34 11 t41 26 S
+t34 12 t41 27 S
+t34 13 t41 28 S
+t34 14 t41 29 S
+t34 30 t42 45 S
+t34 31 t42 46 S
+t34 32 t42 47 S
+t34 33 t42 48 S
+t34 34 t42 49 S
+t34 35 t42 50 S
+t34 36 t42 51 S
+t34 37 t42 52 S
+t34 38 t42 53 S
+t34 39 t42 54 S
+t34 40 t42 55 S
+t34 41 t42 56 S
+t34 42 t42 57 S
+t34 43 t42 58 S
+t34 44 t42 59 S
+t32 0 t43 15 S
+t32 1 t43 16 S
+t32 2 t43 17 S
+t32 3 t43 18 S
+t32 4 t43 19 S
+t32 5 t43 20 S
+t32 6 t43 21 S
+t32 7 t43 22 S
+t32 8 t43 23 S
+t32 9 t43 24 S
+t32 10 t43 25 S
+t32 11 t43 26 S
+t32 12 t43 27 S
+t32 13 t43 28 S
+t32 14 t43 29 S
+t32 30 t42 45 S
+t32 31 t42 46 S
+t32 32 t42 47 S
+t32 33 t42 48 S
+t32 34 t42 49 S
+t32 35 t42 50 S
+t32 36 t42 51 S
+t32 37 t42 52 S
+t32 38 t42 53 S
+t32 39 t42 54 S
+t32 40 t42 55 S
+t32 41 t42 56 S
+t32 42 t42 57 S
+t32 43 t42 58 S
+t32 44 t42 59 S
+t37 0 t37 0 L
+t37 1 t37 1 L
+t37 2 t37 2 L
+t37 3 t37 3 L
+t37 4 t37 4 L
+t37 5 t37 5 L
+t37 6 t37 6 L
+t37 7 t37 7 L
+t37 8 t37 8 L
+t37 9 t37 9 L
+t37 10 t37 10 L
+t37 11 t37 11 L
+t37 12 t37 12 L
+t37 13 t37 13 L
+t37 14 t37 14 L
+t37 30 t23 10 R
+t37 31 t23 11 R
+t37 32 t23 12 R
+t37 33 t23 13 R
+t37 34 t23 14 R
+t40 0 t40 0 R
+t40 1 t40 1 R
+t40 2 t40 2 R
+t40 3 t40 3 R
+t40 4 t40 4 R
+t40 5 t40 5 R
+t40 6 t40 6 R
+t40 7 t40 7 R
+t40 8 t40 8 R
+t40 9 t40 9 R
+t40 10 t40 10 R
+t40 11 t40 11 R
+t40 12 t40 12 R
+t40 13 t40 13 R
+t40 14 t40 14 R
+t40 30 t25 10 R
+t40 31 t25 11 R
+t40 32 t25 12 R
+t40 33 t25 13 R
+t40 34 t25 14 R
+t44 0 t44 0 L
+t44 1 t44 1 L
+t44 2 t44 2 L
+t44 3 t44 3 L
+t44 4 t44 4 L
+t44 33 t45 33 R
+t44 34 t45 34 R
+t45 0 t45 0 R
+t45 1 t45 1 R
+t45 2 t45 2 R
+t45 3 t45 3 R
+t45 4 t45 4 R
+t45 15 t26 0 L
+t45 16 t46 1 R
+t45 17 t46 2 R
+t45 18 t46 3 R
+t45 19 t46 4 R
+t47 15 t44 15 L
+t47 16 t48 16 L
+t47 17 t49 17 L
+t47 18 t50 18 L
+t47 19 t51 19 L
+t52 48 t46 33 R
+t52 49 t46 34 R
+t48 0 t48 0 L
+t48 1 t48 1 L
+t48 2 t48 2 L
+t48 3 t48 3 L
+t48 4 t48 4 L
+t48 33 t45 33 R
+t48 34 t45 34 R
+t46 0 t47 15 S
+t46 1 t47 16 S
+t46 2 t47 17 S
+t46 3 t47 18 S
+t46 4 t47 19 S
+t46 33 t52 48 S
+t46 34 t52 49 S
+t49 0 t49 0 L
+t49 1 t49 1 L
+t49 2 t49 2 L
+t49 3 t49 3 L
+t49 4 t49 4 L
+t49 33 t45 33 R
+t49 34 t45 34 R
+t50 0 t50 0 L
+t50 1 t50 1 L
+t50 2 t50 2 L
+t50 3 t50 3 L
+t50 4 t50 4 L
+t50 33 t45 33 R
+t50 34 t45 34 R
+t51 0 t51 0 L
+t51 1 t51 1 L
+t51 2 t51 2 L
+t51 3 t51 3 L
+t51 4 t51 4 L
+t51 33 t45 33 R
+t51 34 t45 34 R
+t53 0 t53 0 L
+t53 1 t53 1 L
+t53 2 t53 2 L
+t53 3 t53 3 L
+t53 4 t53 4 L
+t53 5 t53 5 L
+t53 6 t53 6 L
+t53 7 t53 7 L
+t53 8 t53 8 L
+t53 9 t53 9 L
+t53 10 t53 10 L
+t53 11 t53 11 L
+t53 12 t53 12 L
+t53 13 t53 13 L
+t53 14 t53 14 L
+t53 30 t54 0 L
+t53 31 t54 1 L
+t53 32 t54 2 L
+t53 33 t54 3 L
+t53 34 t54 4 L
+t55 0 t55 0 R
+t55 1 t55 1 R
+t55 2 t55 2 R
+t55 3 t55 3 R
+t55 4 t55 4 R
+t55 5 t55 5 R
+t55 6 t55 6 R
+t55 7 t55 7 R
+t55 8 t55 8 R
+t55 9 t55 9 R
+t55 10 t55 10 R
+t55 11 t55 11 R
+t55 12 t55 12 R
+t55 13 t55 13 R
+t55 14 t55 14 R
+t55 30 t56 0 L
+t55 31 t56 1 L
+t55 32 t56 2 L
+t55 33 t56 3 L
+t55 34 t56 4 L
+t54 0 t57 30 R
+t54 1 t57 31 R
+t54 2 t57 32 R
+t54 3 t57 33 R
+t54 4 t57 34 R
+t54 5 t57 35 R
+t54 6 t57 36 R
+t54 7 t57 37 R
+t54 8 t57 38 R
+t54 9 t57 39 R
+t54 10 t57 40 R
+t54 11 t57 41 R
+t54 12 t57 42 R
+t54 13 t57 43 R
+t54 14 t57 44 R
+t54 15 t8 30 L
+t54 16 t34 31 R
+t54 17 t34 32 R
+t54 18 t34 33 R
+t54 19 t34 34 R
+t54 20 t8 35 L
+t54 21 t34 36 R
+t54 22 t34 37 R
+t54 23 t34 38 R
+t54 24 t34 39 R
+t54 25 t8 40 L
+t54 26 t34 41 R
+t54 27 t34 42 R
+t54 28 t34 43 R
+t54 29 t34 44 R
+t56 0 t58 30 L
+t56 1 t58 31 L
+t56 2 t58 32 L
+t56 3 t58 33 L
+t56 4 t58 34 L
+t56 5 t58 35 L
+t56 6 t58 36 L
+t56 7 t58 37 L
+t56 8 t58 38 L
+t56 9 t58 39 L
+t56 10 t58 40 L
+t56 11 t58 41 L
+t56 12 t58 42 L
+t56 13 t58 43 L
+t56 14 t58 44 L
+t56 15 t8 30 L
+t56 16 t34 31 R
+t56 17 t34 32 R
+t56 18 t34 33 R
+t56 19 t34 34 R
+t56 20 t8 35 L
+t56 21 t34 36 R
+t56 22 t34 37 R
+t56 23 t34 38 R
+t56 24 t34 39 R
+t56 25 t8 40 L
+t56 26 t34 41 R
+t56 27 t34 42 R
+t56 28 t34 43 R
+t56 29 t34 44 R
+t57 0 t57 0 R
+t57 1 t57 1 R
+t57 2 t57 2 R
+t57 3 t57 3 R
+t57 4 t57 4 R
+t57 5 t57 5 R
+t57 6 t57 6 R
+t57 7 t57 7 R
+t57 8 t57 8 R
+t57 9 t57 9 R
+t57 10 t57 10 R
+t57 11 t57 11 R
+t57 12 t57 12 R
+t57 13 t57 13 R
+t57 14 t57 14 R
+t57 15 t5 0 L
+t57 16 t34 1 R
+t57 17 t34 2 R
+t57 18 t34 3 R
+t57 19 t34 4 R
+t57 20 t5 5 L
+t57 21 t34 6 R
+t57 22 t34 7 R
+t57 23 t34 8 R
+t57 24 t34 9 R
+t57 25 t5 10 L
+t57 26 t34 11 R
+t57 27 t34 12 R
+t57 28 t34 13 R
+t57 29 t34 14 R
+t58 0 t58 0 L
+t58 1 t58 1 L
+t58 2 t58 2 L
+t58 3 t58 3 L
+t58 4 t58 4 L
+t58 5 t58 5 L
+t58 6 t58 6 L
+t58 7 t58 7 L
+t58 8 t58 8 L
+t58 9 t58 9 L
+t58 10 t58 10 L
+t58 11 t58 11 L
+t58 12 t58 12 L
+t58 13 t58 13 L
+t58 14 t58 14 L
+t58 15 t8 0 L
+t58 16 t32 1 R
+t58 17 t32 2 R
+t58 18 t32 3 R
+t58 19 t32 4 R
+t58 20 t8 5 L
+t58 21 t32 6 R
+t58 22 t32 7 R
+t58 23 t32 8 R
+t58 24 t32 9 R
+t58 25 t8 10 L
+t58 26 t32 11 R
+t58 27 t32 12 R
+t58 28 t32 13 R
+t58 29 t32 14 R
+t41 15 t53 15 L
+t41 16 t59 16 L
+t41 17 t60 17 L
+t41 18 t61 18 L
+t41 19 t62 19 L
+t41 20 t53 20 L
+t41 21 t59 21 L
+t41 22 t60 22 L
+t41 23 t61 23 L
+t41 24 t62 24 L
+t41 25 t53 25 L
+t41 26 t59 26 L
+t41 27 t60 27 L
+t41 28 t61 28 L
+t41 29 t62 29 L
+t43 15 t55 15 R
+t43 16 t63 16 R
+t43 17 t64 17 R
+t43 18 t65 18 R
+t43 19 t66 19 R
+t43 20 t55 20 R
+t43 21 t63 21 R
+t43 22 t64 22 R
+t43 23 t65 23 R
+t43 24 t66 24 R
+t43 25 t55 25 R
+t43 26 t63 26 R
+t43 27 t64 27 R
+t43 28 t65 28 R
+t43 29 t66 29 R
+t42 45 t54 15 L
+t42 46 t34 31 R
+t42 47 t34 32 R
+t42 48 t34 33 R
+t42 49 t34 34 R
+t59 0 t59 0 L
+t59 1 t59 1 L
+t59 2 t59 2 L
+t59 3 t59 3 L
+t59 4 t59 4 L
+t59 5 t59 5 L
+t59 6 t59 6 L
+t59 7 t59 7 L
+t59 8 t59 8 L
+t59 9 t59 9 L
+t59 10 t59 10 L
+t59 11 t59 11 L
+t59 12 t59 12 L
+t59 13 t59 13 L
+t59 14 t59 14 L
+t59 30 t57 30 R
+t59 31 t57 31 R
+t59 32 t57 32 R
+t59 33 t57 33 R
+t59 34 t57 34 R
+t63 0 t63 0 R
+t63 1 t63 1 R
+t63 2 t63 2 R
+t63 3 t63 3 R
+t63 4 t63 4 R
+t63 5 t63 5 R
+t63 6 t63 6 R
+t63 7 t63 7 R
+t63 8 t63 8 R
+t63 9 t63 9 R
+t63 10 t63 10 R
+t63 11 t63 11 R
+t63 12 t63 12 R
+t63 13 t63 13 R
+t63 14 t63 14 R
+t63 30 t58 30 L
+t63 31 t58 31 L
+t63 32 t58 32 L
+t63 33 t58 33 L
+t63 34 t58 34 L
+t60 0 t60 0 L
+t60 1 t60 1 L
+t60 2 t60 2 L
+t60 3 t60 3 L
+t60 4 t60 4 L
+t60 5 t60 5 L
+t60 6 t60 6 L
+t60 7 t60 7 L
+t60 8 t60 8 L
+t60 9 t60 9 L
+t60 10 t60 10 L
+t60 11 t60 11 L
+t60 12 t60 12 L
+t60 13 t60 13 L
+t60 14 t60 14 L
+t60 30 t57 30 R
+t60 31 t57 31 R
+t60 32 t57 32 R
+t60 33 t57 33 R
+t60 34 t57 34 R
+t64 0 t64 0 R
+t64 1 t64 1 R
+t64 2 t64 2 R
+t64 3 t64 3 R
+t64 4 t64 4 R
+t64 5 t64 5 R
+t64 6 t64 6 R
+t64 7 t64 7 R
+t64 8 t64 8 R
+t64 9 t64 9 R
+t64 10 t64 10 R
+t64 11 t64 11 R
+t64 12 t64 12 R
+t64 13 t64 13 R
+t64 14 t64 14 R
+t64 30 t58 30 L
+t64 31 t58 31 L
+t64 32 t58 32 L
+t64 33 t58 33 L
+t64 34 t58 34 L
+t61 0 t61 0 L
+t61 1 t61 1 L
+t61 2 t61 2 L
+t61 3 t61 3 L
+t61 4 t61 4 L
+t61 5 t61 5 L
+t61 6 t61 6 L
+t61 7 t61 7 L
+t61 8 t61 8 L
+t61 9 t61 9 L
+t61 10 t61 10 L
+t61 11 t61 11 L
+t61 12 t61 12 L
+t61 13 t61 13 L
+t61 14 t61 14 L
+t61 30 t57 30 R
+t61 31 t57 31 R
+t61 32 t57 32 R
+t61 33 t57 33 R
+t61 34 t57 34 R
+t65 0 t65 0 R
+t65 1 t65 1 R
+t65 2 t65 2 R
+t65 3 t65 3 R
+t65 4 t65 4 R
+t65 5 t65 5 R
+t65 6 t65 6 R
+t65 7 t65 7 R
+t65 8 t65 8 R
+t65 9 t65 9 R
+t65 10 t65 10 R
+t65 11 t65 11 R
+t65 12 t65 12 R
+t65 13 t65 13 R
+t65 14 t65 14 R
+t65 30 t58 30 L
+t65 31 t58 31 L
+t65 32 t58 32 L
+t65 33 t58 33 L
+t65 34 t58 34 L
+t62 0 t62 0 L
+t62 1 t62 1 L
+t62 2 t62 2 L
+t62 3 t62 3 L
+t62 4 t62 4 L
+t62 5 t62 5 L
+t62 6 t62 6 L
+t62 7 t62 7 L
+t62 8 t62 8 L
+t62 9 t62 9 L
+t62 10 t62 10 L
+t62 11 t62 11 L
+t62 12 t62 12 L
+t62 13 t62 13 L
+t62 14 t62 14 L
+t62 30 t57 30 R
+t62 31 t57 31 R
+t62 32 t57 32 R
+t62 33 t57 33 R
+t62 34 t57 34 R
+t66 0 t66 0 R
+t66 1 t66 1 R
+t66 2 t66 2 R
+t66 3 t66 3 R
+t66 4 t66 4 R
+t66 5 t66 5 R
+t66 6 t66 6 R
+t66 7 t66 7 R
+t66 8 t66 8 R
+t66 9 t66 9 R
+t66 10 t66 10 R
+t66 11 t66 11 R
+t66 12 t66 12 R
+t66 13 t66 13 R
+t66 14 t66 14 R
+t66 30 t58 30 L
+t66 31 t58 31 L
+t66 32 t58 32 L
+t66 33 t58 33 L
+t66 34 t58 34 L
+t0 45 accept 45 S
+t0 46 t52 48 S
+t0 47 t52 49 S
@@ -0,0 +1,8032 @@
+start 0 t0 90 S
+start 1 t0 91 S
+start 2 t0 92 S
+start 3 t0 93 S
+start 4 t0 94 S
+t1 16 t2 1 L
+t1 21 t2 6 L
+t1 26 t2 11 L
+t1 35 t3 5 L
+t1 36 t3 6 L
+t1 37 t3 7 L
+t1 38 t3 8 L
+t1 39 t3 9 L
+t1 46 t2 31 L
+t1 50 t2 36 L
+t1 51 t2 36 L
+t1 52 t2 36 L
+t1 53 t2 36 L
+t1 54 t2 36 L
+t1 56 t2 41 L
+t1 96 t4 96 S
+t5 0 t5 0 L
+t5 1 t5 1 L
+t5 2 t5 2 L
+t5 3 t5 3 L
+t5 4 t5 4 L
+t5 5 t5 5 L
+t5 6 t5 6 L
+t5 7 t5 7 L
+t5 8 t5 8 L
+t5 9 t5 9 L
+t5 10 t5 10 L
+t5 11 t5 11 L
+t5 12 t5 12 L
+t5 13 t5 13 L
+t5 14 t5 14 L
+t5 15 t6 1 L
+t5 16 t6 1 L
+t5 17 t6 1 L
+t5 18 t6 1 L
+t5 19 t6 1 L
+t5 20 t6 6 L
+t5 21 t6 6 L
+t5 22 t6 6 L
+t5 23 t6 6 L
+t5 24 t6 6 L
+t5 25 t6 11 L
+t5 26 t6 11 L
+t5 27 t6 11 L
+t5 28 t6 11 L
+t5 29 t6 11 L
+t5 30 t5 30 L
+t5 31 t5 31 L
+t5 32 t5 32 L
+t5 33 t5 33 L
+t5 34 t5 34 L
+t5 35 t5 35 L
+t5 36 t5 36 L
+t5 37 t5 37 L
+t5 38 t5 38 L
+t5 39 t5 39 L
+t5 40 t5 40 L
+t5 41 t5 41 L
+t5 42 t5 42 L
+t5 43 t5 43 L
+t5 44 t5 44 L
+t5 45 t6 31 L
+t5 46 t6 31 L
+t5 47 t6 31 L
+t5 48 t6 31 L
+t5 49 t6 31 L
+t5 50 t6 36 L
+t5 51 t6 36 L
+t5 52 t6 36 L
+t5 53 t6 36 L
+t5 54 t6 36 L
+t5 55 t6 41 L
+t5 56 t6 41 L
+t5 57 t6 41 L
+t5 58 t6 41 L
+t5 59 t6 41 L
+t5 60 t7 60 S
+t5 61 t7 61 S
+t5 62 t7 62 S
+t5 63 t7 63 S
+t5 64 t7 64 S
+t5 65 t7 65 S
+t5 66 t7 66 S
+t5 67 t7 67 S
+t5 68 t8 69 S
+t5 69 t8 69 S
+t5 70 t8 69 S
+t5 71 t8 69 S
+t5 72 t8 69 S
+t5 73 t8 74 S
+t5 74 t8 74 S
+t5 75 t8 74 S
+t5 76 t8 74 S
+t5 77 t8 74 S
+t5 78 t7 78 S
+t5 79 t7 79 S
+t5 80 t7 80 S
+t5 81 t7 81 S
+t5 82 t7 82 S
+t5 83 t7 83 S
+t5 84 t7 84 S
+t5 85 t7 85 S
+t5 86 t7 86 S
+t5 87 t7 87 S
+t5 88 t7 88 S
+t5 89 t7 89 S
+t5 90 t8 91 S
+t5 91 t8 91 S
+t5 92 t8 91 S
+t5 93 t8 91 S
+t5 94 t8 91 S
+t5 95 t8 96 S
+t5 96 t8 96 S
+t5 97 t8 96 S
+t5 98 t8 96 S
+t5 99 t8 96 S
+t5 100 t8 101 S
+t5 101 t8 101 S
+t5 102 t8 101 S
+t5 103 t8 101 S
+t5 104 t8 101 S
+t4 0 t4 0 L
+t4 1 t4 1 L
+t4 2 t4 2 L
+t4 3 t4 3 L
+t4 4 t4 4 L
+t4 5 t4 5 L
+t4 6 t4 6 L
+t4 7 t4 7 L
+t4 8 t4 8 L
+t4 9 t4 9 L
+t4 10 t4 10 L
+t4 11 t4 11 L
+t4 12 t4 12 L
+t4 13 t4 13 L
+t4 14 t4 14 L
+t4 15 t4 15 L
+t4 16 t4 16 L
+t4 17 t4 17 L
+t4 18 t4 18 L
+t4 19 t4 19 L
+t4 20 t4 20 L
+t4 21 t4 21 L
+t4 22 t4 22 L
+t4 23 t4 23 L
+t4 24 t4 24 L
+t4 25 t4 25 L
+t4 26 t4 26 L
+t4 27 t4 27 L
+t4 28 t4 28 L
+t4 29 t4 29 L
+t4 30 t9 5 L
+t4 31 t9 6 L
+t4 32 t9 7 L
+t4 33 t9 8 L
+t4 34 t9 9 L
+t4 35 t9 5 L
+t4 36 t9 6 L
+t4 37 t9 7 L
+t4 38 t9 8 L
+t4 39 t9 9 L
+t4 40 t9 5 L
+t4 41 t9 6 L
+t4 42 t9 7 L
+t4 43 t9 8 L
+t4 44 t9 9 L
+t4 45 t9 20 L
+t4 46 t9 21 L
+t4 47 t9 22 L
+t4 48 t9 23 L
+t4 49 t9 24 L
+t4 50 t9 20 L
+t4 51 t9 21 L
+t4 52 t9 22 L
+t4 53 t9 23 L
+t4 54 t9 24 L
+t4 55 t9 20 L
+t4 56 t9 21 L
+t4 57 t9 22 L
+t4 58 t9 23 L
+t4 59 t9 24 L
+t4 60 t7 60 S
+t4 61 t7 61 S
+t4 62 t7 62 S
+t4 63 t7 63 S
+t4 64 t7 64 S
+t4 65 t7 65 S
+t4 66 t7 66 S
+t4 67 t7 67 S
+t4 68 t7 68 S
+t4 69 t7 69 S
+t4 70 t7 70 S
+t4 71 t7 71 S
+t4 72 t7 72 S
+t4 73 t7 73 S
+t4 74 t7 74 S
+t4 75 t7 75 S
+t4 76 t7 76 S
+t4 77 t7 77 S
+t4 78 t8 82 S
+t4 79 t8 83 S
+t4 80 t8 84 S
+t4 81 t8 85 S
+t4 82 t8 82 S
+t4 83 t8 83 S
+t4 84 t8 84 S
+t4 85 t8 85 S
+t4 86 t8 82 S
+t4 87 t8 83 S
+t4 88 t8 84 S
+t4 89 t8 85 S
+t4 90 t8 95 S
+t4 91 t8 96 S
+t4 92 t8 97 S
+t4 93 t8 98 S
+t4 94 t8 99 S
+t4 95 t8 95 S
+t4 96 t8 96 S
+t4 97 t8 97 S
+t4 98 t8 98 S
+t4 99 t8 99 S
+t4 100 t8 95 S
+t4 101 t8 96 S
+t4 102 t8 97 S
+t4 103 t8 98 S
+t4 104 t8 99 S
+t8 0 t8 0 L
+t8 1 t8 1 L
+t8 2 t8 2 L
+t8 3 t8 3 L
+t8 4 t8 4 L
+t8 5 t8 5 L
+t8 6 t8 6 L
+t8 7 t8 7 L
+t8 8 t8 8 L
+t8 9 t8 9 L
+t8 10 t8 10 L
+t8 11 t8 11 L
+t8 12 t8 12 L
+t8 13 t8 13 L
+t8 14 t8 14 L
+t8 15 t8 15 L
+t8 16 t8 16 L
+t8 17 t8 17 L
+t8 18 t8 18 L
+t8 19 t8 19 L
+t8 20 t8 20 L
+t8 21 t8 21 L
+t8 22 t8 22 L
+t8 23 t8 23 L
+t8 24 t8 24 L
+t8 25 t8 25 L
+t8 26 t8 26 L
+t8 27 t8 27 L
+t8 28 t8 28 L
+t8 29 t8 29 L
+t8 30 t8 30 L
+t8 31 t8 31 L
+t8 32 t8 32 L
+t8 33 t8 33 L
+t8 34 t8 34 L
+t8 35 t8 35 L
+t8 36 t8 36 L
+t8 37 t8 37 L
+t8 38 t8 38 L
+t8 39 t8 39 L
+t8 40 t8 40 L
+t8 41 t8 41 L
+t8 42 t8 42 L
+t8 43 t8 43 L
+t8 44 t8 44 L
+t8 45 t8 45 L
+t8 46 t8 46 L
+t8 47 t8 47 L
+t8 48 t8 48 L
+t8 49 t8 49 L
+t8 50 t8 50 L
+t8 51 t8 51 L
+t8 52 t8 52 L
+t8 53 t8 53 L
+t8 54 t8 54 L
+t8 55 t8 55 L
+t8 56 t8 56 L
+t8 57 t8 57 L
+t8 58 t8 58 L
+t8 59 t8 59 L
+t8 60 t7 60 S
+t8 61 t7 61 S
+t8 62 t7 62 S
+t8 63 t7 63 S
+t8 64 t7 64 S
+t8 65 t7 65 S
+t8 66 t7 66 S
+t8 67 t7 67 S
+t8 68 t7 68 S
+t8 69 t7 69 S
+t8 70 t7 70 S
+t8 71 t7 71 S
+t8 72 t7 72 S
+t8 73 t7 73 S
+t8 74 t7 74 S
+t8 75 t7 75 S
+t8 76 t7 76 S
+t8 77 t7 77 S
+t8 78 t7 78 S
+t8 79 t7 79 S
+t8 80 t7 80 S
+t8 81 t7 81 S
+t8 82 t7 82 S
+t8 83 t7 83 S
+t8 84 t7 84 S
+t8 85 t7 85 S
+t8 86 t7 86 S
+t8 87 t7 87 S
+t8 88 t7 88 S
+t8 89 t7 89 S
+t8 90 t7 90 S
+t8 91 t7 91 S
+t8 92 t7 92 S
+t8 93 t7 93 S
+t8 94 t7 94 S
+t8 95 t7 95 S
+t8 96 t7 96 S
+t8 97 t7 97 S
+t8 98 t7 98 S
+t8 99 t7 99 S
+t8 100 t7 100 S
+t8 101 t7 101 S
+t8 102 t7 102 S
+t8 103 t7 103 S
+t8 104 t7 104 S
+t10 0 t10 0 R
+t10 1 t10 1 R
+t10 2 t10 2 R
+t10 3 t10 3 R
+t10 4 t10 4 R
+t10 5 t10 5 R
+t10 6 t10 6 R
+t10 7 t10 7 R
+t10 8 t10 8 R
+t10 9 t10 9 R
+t10 10 t10 10 R
+t10 11 t10 11 R
+t10 12 t10 12 R
+t10 13 t10 13 R
+t10 14 t10 14 R
+t10 15 t10 15 R
+t10 16 t10 16 R
+t10 17 t10 17 R
+t10 18 t10 18 R
+t10 19 t10 19 R
+t10 20 t10 20 R
+t10 21 t10 21 R
+t10 22 t10 22 R
+t10 23 t10 23 R
+t10 24 t10 24 R
+t10 25 t10 25 R
+t10 26 t10 26 R
+t10 27 t10 27 R
+t10 28 t10 28 R
+t10 29 t10 29 R
+t10 35 t1 35 S
+t10 36 t1 36 S
+t10 37 t1 37 S
+t10 38 t1 38 S
+t10 39 t1 39 S
+t10 50 t1 50 S
+t10 51 t1 51 S
+t10 52 t1 52 S
+t10 53 t1 53 S
+t10 54 t1 54 S
+t11 0 t11 0 R
+t11 1 t11 1 R
+t11 2 t11 2 R
+t11 3 t11 3 R
+t11 4 t11 4 R
+t11 5 t11 5 R
+t11 6 t11 6 R
+t11 7 t11 7 R
+t11 8 t11 8 R
+t11 9 t11 9 R
+t11 10 t11 10 R
+t11 11 t11 11 R
+t11 12 t11 12 R
+t11 13 t11 13 R
+t11 14 t11 14 R
+t11 16 t1 16 S
+t11 18 t12 18 S
+t11 21 t1 21 S
+t11 23 t12 23 S
+t11 26 t1 26 S
+t11 28 t12 28 S
+t11 30 t11 30 R
+t11 31 t11 31 R
+t11 32 t11 32 R
+t11 33 t11 33 R
+t11 34 t11 34 R
+t11 35 t11 35 R
+t11 36 t11 36 R
+t11 37 t11 37 R
+t11 38 t11 38 R
+t11 39 t11 39 R
+t11 40 t11 40 R
+t11 41 t11 41 R
+t11 42 t11 42 R
+t11 43 t11 43 R
+t11 44 t11 44 R
+t11 46 t1 46 S
+t11 48 t12 48 S
+t11 51 t1 51 S
+t11 53 t12 53 S
+t11 56 t1 56 S
+t11 58 t12 58 S
+t13 0 t13 0 R
+t13 1 t13 1 R
+t13 2 t13 2 R
+t13 3 t13 3 R
+t13 4 t13 4 R
+t13 5 t13 5 R
+t13 6 t13 6 R
+t13 7 t13 7 R
+t13 8 t13 8 R
+t13 9 t13 9 R
+t13 10 t13 10 R
+t13 11 t13 11 R
+t13 12 t13 12 R
+t13 13 t13 13 R
+t13 14 t13 14 R
+t13 16 t10 16 R
+t13 17 t14 17 R
+t13 18 t15 18 R
+t13 19 t16 19 R
+t13 21 t10 21 R
+t13 22 t14 22 R
+t13 23 t15 23 R
+t13 24 t16 24 R
+t13 26 t10 26 R
+t13 27 t14 27 R
+t13 28 t15 28 R
+t13 29 t16 29 R
+t13 35 t11 35 R
+t13 36 t11 36 R
+t13 37 t11 37 R
+t13 38 t11 38 R
+t13 39 t11 39 R
+t13 40 t17 40 R
+t13 41 t17 41 R
+t13 42 t17 42 R
+t13 43 t17 43 R
+t13 44 t17 44 R
+t13 51 t1 51 S
+t13 53 t12 53 S
+t13 57 t18 57 S
+t13 59 t19 59 S
+t13 60 t13 60 R
+t13 61 t13 61 R
+t13 62 t13 62 R
+t13 63 t13 63 R
+t13 64 t13 64 R
+t13 65 t13 65 R
+t13 66 t13 66 R
+t13 67 t13 67 R
+t13 69 t10 69 R
+t13 70 t14 70 R
+t13 71 t15 71 R
+t13 72 t16 72 R
+t13 74 t10 74 R
+t13 75 t14 75 R
+t13 76 t15 76 R
+t13 77 t16 77 R
+t13 82 t11 82 R
+t13 83 t11 83 R
+t13 84 t11 84 R
+t13 85 t11 85 R
+t13 86 t17 86 R
+t13 87 t17 87 R
+t13 88 t17 88 R
+t13 89 t17 89 R
+t13 96 t1 96 S
+t13 98 t12 98 S
+t13 102 t18 102 S
+t13 104 t19 104 S
+t2 0 t4 15 R
+t2 1 t4 16 R
+t2 2 t4 17 R
+t2 3 t4 18 R
+t2 4 t4 19 R
+t2 5 t4 20 R
+t2 6 t4 21 R
+t2 7 t4 22 R
+t2 8 t4 23 R
+t2 9 t4 24 R
+t2 10 t4 25 R
+t2 11 t4 26 R
+t2 12 t4 27 R
+t2 13 t4 28 R
+t2 14 t4 29 R
+t2 15 t4 15 R
+t2 16 t4 16 R
+t2 17 t4 17 R
+t2 18 t4 18 R
+t2 19 t4 19 R
+t2 20 t4 20 R
+t2 21 t4 21 R
+t2 22 t4 22 R
+t2 23 t4 23 R
+t2 24 t4 24 R
+t2 25 t4 25 R
+t2 26 t4 26 R
+t2 27 t4 27 R
+t2 28 t4 28 R
+t2 29 t4 29 R
+t2 30 t4 45 R
+t2 31 t4 46 R
+t2 32 t4 47 R
+t2 33 t4 48 R
+t2 34 t4 49 R
+t2 35 t4 50 R
+t2 36 t4 51 R
+t2 37 t4 52 R
+t2 38 t4 53 R
+t2 39 t4 54 R
+t2 40 t4 55 R
+t2 41 t4 56 R
+t2 42 t4 57 R
+t2 43 t4 58 R
+t2 44 t4 59 R
+t2 45 t4 45 R
+t2 46 t4 46 R
+t2 47 t4 47 R
+t2 48 t4 48 R
+t2 49 t4 49 R
+t2 50 t4 50 R
+t2 51 t4 51 R
+t2 52 t4 52 R
+t2 53 t4 53 R
+t2 54 t4 54 R
+t2 55 t4 55 R
+t2 56 t4 56 R
+t2 57 t4 57 R
+t2 58 t4 58 R
+t2 59 t4 59 R
+t2 60 t4 69 R
+t2 61 t4 70 R
+t2 62 t4 71 R
+t2 63 t4 72 R
+t2 64 t4 74 R
+t2 65 t4 75 R
+t2 66 t4 76 R
+t2 67 t4 77 R
+t2 68 t4 68 R
+t2 69 t4 69 R
+t2 70 t4 70 R
+t2 71 t4 71 R
+t2 72 t4 72 R
+t2 73 t4 73 R
+t2 74 t4 74 R
+t2 75 t4 75 R
+t2 76 t4 76 R
+t2 77 t4 77 R
+t2 78 t4 91 R
+t2 79 t4 92 R
+t2 80 t4 93 R
+t2 81 t4 94 R
+t2 82 t4 96 R
+t2 83 t4 97 R
+t2 84 t4 98 R
+t2 85 t4 99 R
+t2 86 t4 101 R
+t2 87 t4 102 R
+t2 88 t4 103 R
+t2 89 t4 104 R
+t2 90 t4 90 R
+t2 91 t4 91 R
+t2 92 t4 92 R
+t2 93 t4 93 R
+t2 94 t4 94 R
+t2 95 t4 95 R
+t2 96 t4 96 R
+t2 97 t4 97 R
+t2 98 t4 98 R
+t2 99 t4 99 R
+t2 100 t4 100 R
+t2 101 t4 101 R
+t2 102 t4 102 R
+t2 103 t4 103 R
+t2 104 t4 104 R
+t6 0 t8 15 R
+t6 1 t8 16 R
+t6 2 t8 17 R
+t6 3 t8 18 R
+t6 4 t8 19 R
+t6 5 t8 20 R
+t6 6 t8 21 R
+t6 7 t8 22 R
+t6 8 t8 23 R
+t6 9 t8 24 R
+t6 10 t8 25 R
+t6 11 t8 26 R
+t6 12 t8 27 R
+t6 13 t8 28 R
+t6 14 t8 29 R
+t6 15 t8 15 R
+t6 16 t8 16 R
+t6 17 t8 17 R
+t6 18 t8 18 R
+t6 19 t8 19 R
+t6 20 t8 20 R
+t6 21 t8 21 R
+t6 22 t8 22 R
+t6 23 t8 23 R
+t6 24 t8 24 R
+t6 25 t8 25 R
+t6 26 t8 26 R
+t6 27 t8 27 R
+t6 28 t8 28 R
+t6 29 t8 29 R
+t6 30 t8 45 R
+t6 31 t8 46 R
+t6 32 t8 47 R
+t6 33 t8 48 R
+t6 34 t8 49 R
+t6 35 t8 50 R
+t6 36 t8 51 R
+t6 37 t8 52 R
+t6 38 t8 53 R
+t6 39 t8 54 R
+t6 40 t8 55 R
+t6 41 t8 56 R
+t6 42 t8 57 R
+t6 43 t8 58 R
+t6 44 t8 59 R
+t6 45 t8 45 R
+t6 46 t8 46 R
+t6 47 t8 47 R
+t6 48 t8 48 R
+t6 49 t8 49 R
+t6 50 t8 50 R
+t6 51 t8 51 R
+t6 52 t8 52 R
+t6 53 t8 53 R
+t6 54 t8 54 R
+t6 55 t8 55 R
+t6 56 t8 56 R
+t6 57 t8 57 R
+t6 58 t8 58 R
+t6 59 t8 59 R
+t6 60 t8 69 R
+t6 61 t8 70 R
+t6 62 t8 71 R
+t6 63 t8 72 R
+t6 64 t8 74 R
+t6 65 t8 75 R
+t6 66 t8 76 R
+t6 67 t8 77 R
+t6 68 t8 68 R
+t6 69 t8 69 R
+t6 70 t8 70 R
+t6 71 t8 71 R
+t6 72 t8 72 R
+t6 73 t8 73 R
+t6 74 t8 74 R
+t6 75 t8 75 R
+t6 76 t8 76 R
+t6 77 t8 77 R
+t6 78 t8 91 R
+t6 79 t8 92 R
+t6 80 t8 93 R
+t6 81 t8 94 R
+t6 82 t8 96 R
+t6 83 t8 97 R
+t6 84 t8 98 R
+t6 85 t8 99 R
+t6 86 t8 101 R
+t6 87 t8 102 R
+t6 88 t8 103 R
+t6 89 t8 104 R
+t6 90 t8 90 R
+t6 91 t8 91 R
+t6 92 t8 92 R
+t6 93 t8 93 R
+t6 94 t8 94 R
+t6 95 t8 95 R
+t6 96 t8 96 R
+t6 97 t8 97 R
+t6 98 t8 98 R
+t6 99 t8 99 R
+t6 100 t8 100 R
+t6 101 t8 101 R
+t6 102 t8 102 R
+t6 103 t8 103 R
+t6 104 t8 104 R
+t3 0 t5 30 R
+t3 1 t5 31 R
+t3 2 t5 32 R
+t3 3 t5 33 R
+t3 4 t5 34 R
+t3 5 t5 35 R
+t3 6 t5 36 R
+t3 7 t5 37 R
+t3 8 t5 38 R
+t3 9 t5 39 R
+t3 10 t5 40 R
+t3 11 t5 41 R
+t3 12 t5 42 R
+t3 13 t5 43 R
+t3 14 t5 44 R
+t3 15 t5 45 R
+t3 16 t5 46 R
+t3 17 t5 47 R
+t3 18 t5 48 R
+t3 19 t5 49 R
+t3 20 t5 50 R
+t3 21 t5 51 R
+t3 22 t5 52 R
+t3 23 t5 53 R
+t3 24 t5 54 R
+t3 25 t5 55 R
+t3 26 t5 56 R
+t3 27 t5 57 R
+t3 28 t5 58 R
+t3 29 t5 59 R
+t3 30 t5 30 R
+t3 31 t5 31 R
+t3 32 t5 32 R
+t3 33 t5 33 R
+t3 34 t5 34 R
+t3 35 t5 35 R
+t3 36 t5 36 R
+t3 37 t5 37 R
+t3 38 t5 38 R
+t3 39 t5 39 R
+t3 40 t5 40 R
+t3 41 t5 41 R
+t3 42 t5 42 R
+t3 43 t5 43 R
+t3 44 t5 44 R
+t3 45 t5 45 R
+t3 46 t5 46 R
+t3 47 t5 47 R
+t3 48 t5 48 R
+t3 49 t5 49 R
+t3 50 t5 50 R
+t3 51 t5 51 R
+t3 52 t5 52 R
+t3 53 t5 53 R
+t3 54 t5 54 R
+t3 55 t5 55 R
+t3 56 t5 56 R
+t3 57 t5 57 R
+t3 58 t5 58 R
+t3 59 t5 59 R
+t3 60 t5 82 R
+t3 61 t5 83 R
+t3 62 t5 84 R
+t3 63 t5 85 R
+t3 64 t5 86 R
+t3 65 t5 87 R
+t3 66 t5 88 R
+t3 67 t5 89 R
+t3 68 t5 95 R
+t3 69 t5 96 R
+t3 70 t5 97 R
+t3 71 t5 98 R
+t3 72 t5 99 R
+t3 73 t5 100 R
+t3 74 t5 101 R
+t3 75 t5 102 R
+t3 76 t5 103 R
+t3 77 t5 104 R
+t3 78 t5 78 R
+t3 79 t5 79 R
+t3 80 t5 80 R
+t3 81 t5 81 R
+t3 82 t5 82 R
+t3 83 t5 83 R
+t3 84 t5 84 R
+t3 85 t5 85 R
+t3 86 t5 86 R
+t3 87 t5 87 R
+t3 88 t5 88 R
+t3 89 t5 89 R
+t3 90 t5 90 R
+t3 91 t5 91 R
+t3 92 t5 92 R
+t3 93 t5 93 R
+t3 94 t5 94 R
+t3 95 t5 95 R
+t3 96 t5 96 R
+t3 97 t5 97 R
+t3 98 t5 98 R
+t3 99 t5 99 R
+t3 100 t5 100 R
+t3 101 t5 101 R
+t3 102 t5 102 R
+t3 103 t5 103 R
+t3 104 t5 104 R
+t9 0 t8 30 R
+t9 1 t8 31 R
+t9 2 t8 32 R
+t9 3 t8 33 R
+t9 4 t8 34 R
+t9 5 t8 35 R
+t9 6 t8 36 R
+t9 7 t8 37 R
+t9 8 t8 38 R
+t9 9 t8 39 R
+t9 10 t8 40 R
+t9 11 t8 41 R
+t9 12 t8 42 R
+t9 13 t8 43 R
+t9 14 t8 44 R
+t9 15 t8 45 R
+t9 16 t8 46 R
+t9 17 t8 47 R
+t9 18 t8 48 R
+t9 19 t8 49 R
+t9 20 t8 50 R
+t9 21 t8 51 R
+t9 22 t8 52 R
+t9 23 t8 53 R
+t9 24 t8 54 R
+t9 25 t8 55 R
+t9 26 t8 56 R
+t9 27 t8 57 R
+t9 28 t8 58 R
+t9 29 t8 59 R
+t9 30 t8 30 R
+t9 31 t8 31 R
+t9 32 t8 32 R
+t9 33 t8 33 R
+t9 34 t8 34 R
+t9 35 t8 35 R
+t9 36 t8 36 R
+t9 37 t8 37 R
+t9 38 t8 38 R
+t9 39 t8 39 R
+t9 40 t8 40 R
+t9 41 t8 41 R
+t9 42 t8 42 R
+t9 43 t8 43 R
+t9 44 t8 44 R
+t9 45 t8 45 R
+t9 46 t8 46 R
+t9 47 t8 47 R
+t9 48 t8 48 R
+t9 49 t8 49 R
+t9 50 t8 50 R
+t9 51 t8 51 R
+t9 52 t8 52 R
+t9 53 t8 53 R
+t9 54 t8 54 R
+t9 55 t8 55 R
+t9 56 t8 56 R
+t9 57 t8 57 R
+t9 58 t8 58 R
+t9 59 t8 59 R
+t9 60 t8 82 R
+t9 61 t8 83 R
+t9 62 t8 84 R
+t9 63 t8 85 R
+t9 64 t8 86 R
+t9 65 t8 87 R
+t9 66 t8 88 R
+t9 67 t8 89 R
+t9 68 t8 95 R
+t9 69 t8 96 R
+t9 70 t8 97 R
+t9 71 t8 98 R
+t9 72 t8 99 R
+t9 73 t8 100 R
+t9 74 t8 101 R
+t9 75 t8 102 R
+t9 76 t8 103 R
+t9 77 t8 104 R
+t9 78 t8 78 R
+t9 79 t8 79 R
+t9 80 t8 80 R
+t9 81 t8 81 R
+t9 82 t8 82 R
+t9 83 t8 83 R
+t9 84 t8 84 R
+t9 85 t8 85 R
+t9 86 t8 86 R
+t9 87 t8 87 R
+t9 88 t8 88 R
+t9 89 t8 89 R
+t9 90 t8 90 R
+t9 91 t8 91 R
+t9 92 t8 92 R
+t9 93 t8 93 R
+t9 94 t8 94 R
+t9 95 t8 95 R
+t9 96 t8 96 R
+t9 97 t8 97 R
+t9 98 t8 98 R
+t9 99 t8 99 R
+t9 100 t8 100 R
+t9 101 t8 101 R
+t9 102 t8 102 R
+t9 103 t8 103 R
+t9 104 t8 104 R
+t7 60 t13 60 S
+t7 61 t13 61 S
+t7 62 t13 62 S
+t7 63 t13 63 S
+t7 64 t13 64 S
+t7 65 t13 65 S
+t7 66 t13 66 S
+t7 67 t13 67 S
+t7 68 t13 68 S
+t7 69 t13 69 S
+t7 70 t13 70 S
+t7 71 t13 71 S
+t7 72 t13 72 S
+t7 73 t13 73 S
+t7 74 t13 74 S
+t7 75 t13 75 S
+t7 76 t13 76 S
+t7 77 t13 77 S
+t7 78 t13 78 S
+t7 79 t13 79 S
+t7 80 t13 80 S
+t7 81 t13 81 S
+t7 82 t13 82 S
+t7 83 t13 83 S
+t7 84 t13 84 S
+t7 85 t13 85 S
+t7 86 t13 86 S
+t7 87 t13 87 S
+t7 88 t13 88 S
+t7 89 t13 89 S
+t7 90 t13 90 S
+t7 91 t13 91 S
+t7 92 t13 92 S
+t7 93 t13 93 S
+t7 94 t13 94 S
+t7 95 t13 95 S
+t7 96 t13 96 S
+t7 97 t13 97 S
+t7 98 t13 98 S
+t7 99 t13 99 S
+t7 100 t13 100 S
+t7 101 t13 101 S
+t7 102 t13 102 S
+t7 103 t13 103 S
+t7 104 t13 104 S
+t18 17 t20 2 L
+t18 22 t20 7 L
+t18 27 t20 12 L
+t18 40 t21 10 L
+t18 41 t21 11 L
+t18 42 t21 12 L
+t18 43 t21 13 L
+t18 44 t21 14 L
+t18 47 t20 32 L
+t18 52 t20 37 L
+t18 55 t20 42 L
+t18 56 t20 42 L
+t18 57 t20 42 L
+t18 58 t20 42 L
+t18 59 t20 42 L
+t18 102 t22 102 S
+t23 0 t23 0 L
+t23 1 t23 1 L
+t23 2 t23 2 L
+t23 3 t23 3 L
+t23 4 t23 4 L
+t23 5 t23 5 L
+t23 6 t23 6 L
+t23 7 t23 7 L
+t23 8 t23 8 L
+t23 9 t23 9 L
+t23 10 t23 10 L
+t23 11 t23 11 L
+t23 12 t23 12 L
+t23 13 t23 13 L
+t23 14 t23 14 L
+t23 15 t24 2 L
+t23 16 t24 2 L
+t23 17 t24 2 L
+t23 18 t24 2 L
+t23 19 t24 2 L
+t23 20 t24 7 L
+t23 21 t24 7 L
+t23 22 t24 7 L
+t23 23 t24 7 L
+t23 24 t24 7 L
+t23 25 t24 12 L
+t23 26 t24 12 L
+t23 27 t24 12 L
+t23 28 t24 12 L
+t23 29 t24 12 L
+t23 30 t23 30 L
+t23 31 t23 31 L
+t23 32 t23 32 L
+t23 33 t23 33 L
+t23 34 t23 34 L
+t23 35 t23 35 L
+t23 36 t23 36 L
+t23 37 t23 37 L
+t23 38 t23 38 L
+t23 39 t23 39 L
+t23 40 t23 40 L
+t23 41 t23 41 L
+t23 42 t23 42 L
+t23 43 t23 43 L
+t23 44 t23 44 L
+t23 45 t24 32 L
+t23 46 t24 32 L
+t23 47 t24 32 L
+t23 48 t24 32 L
+t23 49 t24 32 L
+t23 50 t24 37 L
+t23 51 t24 37 L
+t23 52 t24 37 L
+t23 53 t24 37 L
+t23 54 t24 37 L
+t23 55 t24 42 L
+t23 56 t24 42 L
+t23 57 t24 42 L
+t23 58 t24 42 L
+t23 59 t24 42 L
+t23 60 t7 60 S
+t23 61 t7 61 S
+t23 62 t7 62 S
+t23 63 t7 63 S
+t23 64 t7 64 S
+t23 65 t7 65 S
+t23 66 t7 66 S
+t23 67 t7 67 S
+t23 68 t25 70 S
+t23 69 t25 70 S
+t23 70 t25 70 S
+t23 71 t25 70 S
+t23 72 t25 70 S
+t23 73 t25 75 S
+t23 74 t25 75 S
+t23 75 t25 75 S
+t23 76 t25 75 S
+t23 77 t25 75 S
+t23 78 t7 78 S
+t23 79 t7 79 S
+t23 80 t7 80 S
+t23 81 t7 81 S
+t23 82 t7 82 S
+t23 83 t7 83 S
+t23 84 t7 84 S
+t23 85 t7 85 S
+t23 86 t7 86 S
+t23 87 t7 87 S
+t23 88 t7 88 S
+t23 89 t7 89 S
+t23 90 t25 92 S
+t23 91 t25 92 S
+t23 92 t25 92 S
+t23 93 t25 92 S
+t23 94 t25 92 S
+t23 95 t25 97 S
+t23 96 t25 97 S
+t23 97 t25 97 S
+t23 98 t25 97 S
+t23 99 t25 97 S
+t23 100 t25 102 S
+t23 101 t25 102 S
+t23 102 t25 102 S
+t23 103 t25 102 S
+t23 104 t25 102 S
+t22 0 t22 0 L
+t22 1 t22 1 L
+t22 2 t22 2 L
+t22 3 t22 3 L
+t22 4 t22 4 L
+t22 5 t22 5 L
+t22 6 t22 6 L
+t22 7 t22 7 L
+t22 8 t22 8 L
+t22 9 t22 9 L
+t22 10 t22 10 L
+t22 11 t22 11 L
+t22 12 t22 12 L
+t22 13 t22 13 L
+t22 14 t22 14 L
+t22 15 t22 15 L
+t22 16 t22 16 L
+t22 17 t22 17 L
+t22 18 t22 18 L
+t22 19 t22 19 L
+t22 20 t22 20 L
+t22 21 t22 21 L
+t22 22 t22 22 L
+t22 23 t22 23 L
+t22 24 t22 24 L
+t22 25 t22 25 L
+t22 26 t22 26 L
+t22 27 t22 27 L
+t22 28 t22 28 L
+t22 29 t22 29 L
+t22 30 t26 10 L
+t22 31 t26 11 L
+t22 32 t26 12 L
+t22 33 t26 13 L
+t22 34 t26 14 L
+t22 35 t26 10 L
+t22 36 t26 11 L
+t22 37 t26 12 L
+t22 38 t26 13 L
+t22 39 t26 14 L
+t22 40 t26 10 L
+t22 41 t26 11 L
+t22 42 t26 12 L
+t22 43 t26 13 L
+t22 44 t26 14 L
+t22 45 t26 25 L
+t22 46 t26 26 L
+t22 47 t26 27 L
+t22 48 t26 28 L
+t22 49 t26 29 L
+t22 50 t26 25 L
+t22 51 t26 26 L
+t22 52 t26 27 L
+t22 53 t26 28 L
+t22 54 t26 29 L
+t22 55 t26 25 L
+t22 56 t26 26 L
+t22 57 t26 27 L
+t22 58 t26 28 L
+t22 59 t26 29 L
+t22 60 t7 60 S
+t22 61 t7 61 S
+t22 62 t7 62 S
+t22 63 t7 63 S
+t22 64 t7 64 S
+t22 65 t7 65 S
+t22 66 t7 66 S
+t22 67 t7 67 S
+t22 68 t7 68 S
+t22 69 t7 69 S
+t22 70 t7 70 S
+t22 71 t7 71 S
+t22 72 t7 72 S
+t22 73 t7 73 S
+t22 74 t7 74 S
+t22 75 t7 75 S
+t22 76 t7 76 S
+t22 77 t7 77 S
+t22 78 t25 86 S
+t22 79 t25 87 S
+t22 80 t25 88 S
+t22 81 t25 89 S
+t22 82 t25 86 S
+t22 83 t25 87 S
+t22 84 t25 88 S
+t22 85 t25 89 S
+t22 86 t25 86 S
+t22 87 t25 87 S
+t22 88 t25 88 S
+t22 89 t25 89 S
+t22 90 t25 100 S
+t22 91 t25 101 S
+t22 92 t25 102 S
+t22 93 t25 103 S
+t22 94 t25 104 S
+t22 95 t25 100 S
+t22 96 t25 101 S
+t22 97 t25 102 S
+t22 98 t25 103 S
+t22 99 t25 104 S
+t22 100 t25 100 S
+t22 101 t25 101 S
+t22 102 t25 102 S
+t22 103 t25 103 S
+t22 104 t25 104 S
+t25 0 t25 0 L
+t25 1 t25 1 L
+t25 2 t25 2 L
+t25 3 t25 3 L
+t25 4 t25 4 L
+t25 5 t25 5 L
+t25 6 t25 6 L
+t25 7 t25 7 L
+t25 8 t25 8 L
+t25 9 t25 9 L
+t25 10 t25 10 L
+t25 11 t25 11 L
+t25 12 t25 12 L
+t25 13 t25 13 L
+t25 14 t25 14 L
+t25 15 t25 15 L
+t25 16 t25 16 L
+t25 17 t25 17 L
+t25 18 t25 18 L
+t25 19 t25 19 L
+t25 20 t25 20 L
+t25 21 t25 21 L
+t25 22 t25 22 L
+t25 23 t25 23 L
+t25 24 t25 24 L
+t25 25 t25 25 L
+t25 26 t25 26 L
+t25 27 t25 27 L
+t25 28 t25 28 L
+t25 29 t25 29 L
+t25 30 t25 30 L
+t25 31 t25 31 L
+t25 32 t25 32 L
+t25 33 t25 33 L
+t25 34 t25 34 L
+t25 35 t25 35 L
+t25 36 t25 36 L
+t25 37 t25 37 L
+t25 38 t25 38 L
+t25 39 t25 39 L
+t25 40 t25 40 L
+t25 41 t25 41 L
+t25 42 t25 42 L
+t25 43 t25 43 L
+t25 44 t25 44 L
+t25 45 t25 45 L
+t25 46 t25 46 L
+t25 47 t25 47 L
+t25 48 t25 48 L
+t25 49 t25 49 L
+t25 50 t25 50 L
+t25 51 t25 51 L
+t25 52 t25 52 L
+t25 53 t25 53 L
+t25 54 t25 54 L
+t25 55 t25 55 L
+t25 56 t25 56 L
+t25 57 t25 57 L
+t25 58 t25 58 L
+t25 59 t25 59 L
+t25 60 t7 60 S
+t25 61 t7 61 S
+t25 62 t7 62 S
+t25 63 t7 63 S
+t25 64 t7 64 S
+t25 65 t7 65 S
+t25 66 t7 66 S
+t25 67 t7 67 S
+t25 68 t7 68 S
+t25 69 t7 69 S
+t25 70 t7 70 S
+t25 71 t7 71 S
+t25 72 t7 72 S
+t25 73 t7 73 S
+t25 74 t7 74 S
+t25 75 t7 75 S
+t25 76 t7 76 S
+t25 77 t7 77 S
+t25 78 t7 78 S
+t25 79 t7 79 S
+t25 80 t7 80 S
+t25 81 t7 81 S
+t25 82 t7 82 S
+t25 83 t7 83 S
+t25 84 t7 84 S
+t25 85 t7 85 S
+t25 86 t7 86 S
+t25 87 t7 87 S
+t25 88 t7 88 S
+t25 89 t7 89 S
+t25 90 t7 90 S
+t25 91 t7 91 S
+t25 92 t7 92 S
+t25 93 t7 93 S
+t25 94 t7 94 S
+t25 95 t7 95 S
+t25 96 t7 96 S
+t25 97 t7 97 S
+t25 98 t7 98 S
+t25 99 t7 99 S
+t25 100 t7 100 S
+t25 101 t7 101 S
+t25 102 t7 102 S
+t25 103 t7 103 S
+t25 104 t7 104 S
+t14 0 t14 0 R
+t14 1 t14 1 R
+t14 2 t14 2 R
+t14 3 t14 3 R
+t14 4 t14 4 R
+t14 5 t14 5 R
+t14 6 t14 6 R
+t14 7 t14 7 R
+t14 8 t14 8 R
+t14 9 t14 9 R
+t14 10 t14 10 R
+t14 11 t14 11 R
+t14 12 t14 12 R
+t14 13 t14 13 R
+t14 14 t14 14 R
+t14 15 t14 15 R
+t14 16 t14 16 R
+t14 17 t14 17 R
+t14 18 t14 18 R
+t14 19 t14 19 R
+t14 20 t14 20 R
+t14 21 t14 21 R
+t14 22 t14 22 R
+t14 23 t14 23 R
+t14 24 t14 24 R
+t14 25 t14 25 R
+t14 26 t14 26 R
+t14 27 t14 27 R
+t14 28 t14 28 R
+t14 29 t14 29 R
+t14 40 t18 40 S
+t14 41 t18 41 S
+t14 42 t18 42 S
+t14 43 t18 43 S
+t14 44 t18 44 S
+t14 55 t18 55 S
+t14 56 t18 56 S
+t14 57 t18 57 S
+t14 58 t18 58 S
+t14 59 t18 59 S
+t17 0 t17 0 R
+t17 1 t17 1 R
+t17 2 t17 2 R
+t17 3 t17 3 R
+t17 4 t17 4 R
+t17 5 t17 5 R
+t17 6 t17 6 R
+t17 7 t17 7 R
+t17 8 t17 8 R
+t17 9 t17 9 R
+t17 10 t17 10 R
+t17 11 t17 11 R
+t17 12 t17 12 R
+t17 13 t17 13 R
+t17 14 t17 14 R
+t17 17 t18 17 S
+t17 19 t19 19 S
+t17 22 t18 22 S
+t17 24 t19 24 S
+t17 27 t18 27 S
+t17 29 t19 29 S
+t17 30 t17 30 R
+t17 31 t17 31 R
+t17 32 t17 32 R
+t17 33 t17 33 R
+t17 34 t17 34 R
+t17 35 t17 35 R
+t17 36 t17 36 R
+t17 37 t17 37 R
+t17 38 t17 38 R
+t17 39 t17 39 R
+t17 40 t17 40 R
+t17 41 t17 41 R
+t17 42 t17 42 R
+t17 43 t17 43 R
+t17 44 t17 44 R
+t17 47 t18 47 S
+t17 49 t19 49 S
+t17 52 t18 52 S
+t17 54 t19 54 S
+t17 57 t18 57 S
+t17 59 t19 59 S
+t20 0 t22 15 R
+t20 1 t22 16 R
+t20 2 t22 17 R
+t20 3 t22 18 R
+t20 4 t22 19 R
+t20 5 t22 20 R
+t20 6 t22 21 R
+t20 7 t22 22 R
+t20 8 t22 23 R
+t20 9 t22 24 R
+t20 10 t22 25 R
+t20 11 t22 26 R
+t20 12 t22 27 R
+t20 13 t22 28 R
+t20 14 t22 29 R
+t20 15 t22 15 R
+t20 16 t22 16 R
+t20 17 t22 17 R
+t20 18 t22 18 R
+t20 19 t22 19 R
+t20 20 t22 20 R
+t20 21 t22 21 R
+t20 22 t22 22 R
+t20 23 t22 23 R
+t20 24 t22 24 R
+t20 25 t22 25 R
+t20 26 t22 26 R
+t20 27 t22 27 R
+t20 28 t22 28 R
+t20 29 t22 29 R
+t20 30 t22 45 R
+t20 31 t22 46 R
+t20 32 t22 47 R
+t20 33 t22 48 R
+t20 34 t22 49 R
+t20 35 t22 50 R
+t20 36 t22 51 R
+t20 37 t22 52 R
+t20 38 t22 53 R
+t20 39 t22 54 R
+t20 40 t22 55 R
+t20 41 t22 56 R
+t20 42 t22 57 R
+t20 43 t22 58 R
+t20 44 t22 59 R
+t20 45 t22 45 R
+t20 46 t22 46 R
+t20 47 t22 47 R
+t20 48 t22 48 R
+t20 49 t22 49 R
+t20 50 t22 50 R
+t20 51 t22 51 R
+t20 52 t22 52 R
+t20 53 t22 53 R
+t20 54 t22 54 R
+t20 55 t22 55 R
+t20 56 t22 56 R
+t20 57 t22 57 R
+t20 58 t22 58 R
+t20 59 t22 59 R
+t20 60 t22 69 R
+t20 61 t22 70 R
+t20 62 t22 71 R
+t20 63 t22 72 R
+t20 64 t22 74 R
+t20 65 t22 75 R
+t20 66 t22 76 R
+t20 67 t22 77 R
+t20 68 t22 68 R
+t20 69 t22 69 R
+t20 70 t22 70 R
+t20 71 t22 71 R
+t20 72 t22 72 R
+t20 73 t22 73 R
+t20 74 t22 74 R
+t20 75 t22 75 R
+t20 76 t22 76 R
+t20 77 t22 77 R
+t20 78 t22 91 R
+t20 79 t22 92 R
+t20 80 t22 93 R
+t20 81 t22 94 R
+t20 82 t22 96 R
+t20 83 t22 97 R
+t20 84 t22 98 R
+t20 85 t22 99 R
+t20 86 t22 101 R
+t20 87 t22 102 R
+t20 88 t22 103 R
+t20 89 t22 104 R
+t20 90 t22 90 R
+t20 91 t22 91 R
+t20 92 t22 92 R
+t20 93 t22 93 R
+t20 94 t22 94 R
+t20 95 t22 95 R
+t20 96 t22 96 R
+t20 97 t22 97 R
+t20 98 t22 98 R
+t20 99 t22 99 R
+t20 100 t22 100 R
+t20 101 t22 101 R
+t20 102 t22 102 R
+t20 103 t22 103 R
+t20 104 t22 104 R
+t24 0 t25 15 R
+t24 1 t25 16 R
+t24 2 t25 17 R
+t24 3 t25 18 R
+t24 4 t25 19 R
+t24 5 t25 20 R
+t24 6 t25 21 R
+t24 7 t25 22 R
+t24 8 t25 23 R
+t24 9 t25 24 R
+t24 10 t25 25 R
+t24 11 t25 26 R
+t24 12 t25 27 R
+t24 13 t25 28 R
+t24 14 t25 29 R
+t24 15 t25 15 R
+t24 16 t25 16 R
+t24 17 t25 17 R
+t24 18 t25 18 R
+t24 19 t25 19 R
+t24 20 t25 20 R
+t24 21 t25 21 R
+t24 22 t25 22 R
+t24 23 t25 23 R
+t24 24 t25 24 R
+t24 25 t25 25 R
+t24 26 t25 26 R
+t24 27 t25 27 R
+t24 28 t25 28 R
+t24 29 t25 29 R
+t24 30 t25 45 R
+t24 31 t25 46 R
+t24 32 t25 47 R
+t24 33 t25 48 R
+t24 34 t25 49 R
+t24 35 t25 50 R
+t24 36 t25 51 R
+t24 37 t25 52 R
+t24 38 t25 53 R
+t24 39 t25 54 R
+t24 40 t25 55 R
+t24 41 t25 56 R
+t24 42 t25 57 R
+t24 43 t25 58 R
+t24 44 t25 59 R
+t24 45 t25 45 R
+t24 46 t25 46 R
+t24 47 t25 47 R
+t24 48 t25 48 R
+t24 49 t25 49 R
+t24 50 t25 50 R
+t24 51 t25 51 R
+t24 52 t25 52 R
+t24 53 t25 53 R
+t24 54 t25 54 R
+t24 55 t25 55 R
+t24 56 t25 56 R
+t24 57 t25 57 R
+t24 58 t25 58 R
+t24 59 t25 59 R
+t24 60 t25 69 R
+t24 61 t25 70 R
+t24 62 t25 71 R
+t24 63 t25 72 R
+t24 64 t25 74 R
+t24 65 t25 75 R
+t24 66 t25 76 R
+t24 67 t25 77 R
+t24 68 t25 68 R
+t24 69 t25 69 R
+t24 70 t25 70 R
+t24 71 t25 71 R
+t24 72 t25 72 R
+t24 73 t25 73 R
+t24 74 t25 74 R
+t24 75 t25 75 R
+t24 76 t25 76 R
+t24 77 t25 77 R
+t24 78 t25 91 R
+t24 79 t25 92 R
+t24 80 t25 93 R
+t24 81 t25 94 R
+t24 82 t25 96 R
+t24 83 t25 97 R
+t24 84 t25 98 R
+t24 85 t25 99 R
+t24 86 t25 101 R
+t24 87 t25 102 R
+t24 88 t25 103 R
+t24 89 t25 104 R
+t24 90 t25 90 R
+t24 91 t25 91 R
+t24 92 t25 92 R
+t24 93 t25 93 R
+t24 94 t25 94 R
+t24 95 t25 95 R
+t24 96 t25 96 R
+t24 97 t25 97 R
+t24 98 t25 98 R
+t24 99 t25 99 R
+t24 100 t25 100 R
+t24 101 t25 101 R
+t24 102 t25 102 R
+t24 103 t25 103 R
+t24 104 t25 104 R
+t21 0 t23 30 R
+t21 1 t23 31 R
+t21 2 t23 32 R
+t21 3 t23 33 R
+t21 4 t23 34 R
+t21 5 t23 35 R
+t21 6 t23 36 R
+t21 7 t23 37 R
+t21 8 t23 38 R
+t21 9 t23 39 R
+t21 10 t23 40 R
+t21 11 t23 41 R
+t21 12 t23 42 R
+t21 13 t23 43 R
+t21 14 t23 44 R
+t21 15 t23 45 R
+t21 16 t23 46 R
+t21 17 t23 47 R
+t21 18 t23 48 R
+t21 19 t23 49 R
+t21 20 t23 50 R
+t21 21 t23 51 R
+t21 22 t23 52 R
+t21 23 t23 53 R
+t21 24 t23 54 R
+t21 25 t23 55 R
+t21 26 t23 56 R
+t21 27 t23 57 R
+t21 28 t23 58 R
+t21 29 t23 59 R
+t21 30 t23 30 R
+t21 31 t23 31 R
+t21 32 t23 32 R
+t21 33 t23 33 R
+t21 34 t23 34 R
+t21 35 t23 35 R
+t21 36 t23 36 R
+t21 37 t23 37 R
+t21 38 t23 38 R
+t21 39 t23 39 R
+t21 40 t23 40 R
+t21 41 t23 41 R
+t21 42 t23 42 R
+t21 43 t23 43 R
+t21 44 t23 44 R
+t21 45 t23 45 R
+t21 46 t23 46 R
+t21 47 t23 47 R
+t21 48 t23 48 R
+t21 49 t23 49 R
+t21 50 t23 50 R
+t21 51 t23 51 R
+t21 52 t23 52 R
+t21 53 t23 53 R
+t21 54 t23 54 R
+t21 55 t23 55 R
+t21 56 t23 56 R
+t21 57 t23 57 R
+t21 58 t23 58 R
+t21 59 t23 59 R
+t21 60 t23 82 R
+t21 61 t23 83 R
+t21 62 t23 84 R
+t21 63 t23 85 R
+t21 64 t23 86 R
+t21 65 t23 87 R
+t21 66 t23 88 R
+t21 67 t23 89 R
+t21 68 t23 95 R
+t21 69 t23 96 R
+t21 70 t23 97 R
+t21 71 t23 98 R
+t21 72 t23 99 R
+t21 73 t23 100 R
+t21 74 t23 101 R
+t21 75 t23 102 R
+t21 76 t23 103 R
+t21 77 t23 104 R
+t21 78 t23 78 R
+t21 79 t23 79 R
+t21 80 t23 80 R
+t21 81 t23 81 R
+t21 82 t23 82 R
+t21 83 t23 83 R
+t21 84 t23 84 R
+t21 85 t23 85 R
+t21 86 t23 86 R
+t21 87 t23 87 R
+t21 88 t23 88 R
+t21 89 t23 89 R
+t21 90 t23 90 R
+t21 91 t23 91 R
+t21 92 t23 92 R
+t21 93 t23 93 R
+t21 94 t23 94 R
+t21 95 t23 95 R
+t21 96 t23 96 R
+t21 97 t23 97 R
+t21 98 t23 98 R
+t21 99 t23 99 R
+t21 100 t23 100 R
+t21 101 t23 101 R
+t21 102 t23 102 R
+t21 103 t23 103 R
+t21 104 t23 104 R
+t26 0 t25 30 R
+t26 1 t25 31 R
+t26 2 t25 32 R
+t26 3 t25 33 R
+t26 4 t25 34 R
+t26 5 t25 35 R
+t26 6 t25 36 R
+t26 7 t25 37 R
+t26 8 t25 38 R
+t26 9 t25 39 R
+t26 10 t25 40 R
+t26 11 t25 41 R
+t26 12 t25 42 R
+t26 13 t25 43 R
+t26 14 t25 44 R
+t26 15 t25 45 R
+t26 16 t25 46 R
+t26 17 t25 47 R
+t26 18 t25 48 R
+t26 19 t25 49 R
+t26 20 t25 50 R
+t26 21 t25 51 R
+t26 22 t25 52 R
+t26 23 t25 53 R
+t26 24 t25 54 R
+t26 25 t25 55 R
+t26 26 t25 56 R
+t26 27 t25 57 R
+t26 28 t25 58 R
+t26 29 t25 59 R
+t26 30 t25 30 R
+t26 31 t25 31 R
+t26 32 t25 32 R
+t26 33 t25 33 R
+t26 34 t25 34 R
+t26 35 t25 35 R
+t26 36 t25 36 R
+t26 37 t25 37 R
+t26 38 t25 38 R
+t26 39 t25 39 R
+t26 40 t25 40 R
+t26 41 t25 41 R
+t26 42 t25 42 R
+t26 43 t25 43 R
+t26 44 t25 44 R
+t26 45 t25 45 R
+t26 46 t25 46 R
+t26 47 t25 47 R
+t26 48 t25 48 R
+t26 49 t25 49 R
+t26 50 t25 50 R
+t26 51 t25 51 R
+t26 52 t25 52 R
+t26 53 t25 53 R
+t26 54 t25 54 R
+t26 55 t25 55 R
+t26 56 t25 56 R
+t26 57 t25 57 R
+t26 58 t25 58 R
+t26 59 t25 59 R
+t26 60 t25 82 R
+t26 61 t25 83 R
+t26 62 t25 84 R
+t26 63 t25 85 R
+t26 64 t25 86 R
+t26 65 t25 87 R
+t26 66 t25 88 R
+t26 67 t25 89 R
+t26 68 t25 95 R
+t26 69 t25 96 R
+t26 70 t25 97 R
+t26 71 t25 98 R
+t26 72 t25 99 R
+t26 73 t25 100 R
+t26 74 t25 101 R
+t26 75 t25 102 R
+t26 76 t25 103 R
+t26 77 t25 104 R
+t26 78 t25 78 R
+t26 79 t25 79 R
+t26 80 t25 80 R
+t26 81 t25 81 R
+t26 82 t25 82 R
+t26 83 t25 83 R
+t26 84 t25 84 R
+t26 85 t25 85 R
+t26 86 t25 86 R
+t26 87 t25 87 R
+t26 88 t25 88 R
+t26 89 t25 89 R
+t26 90 t25 90 R
+t26 91 t25 91 R
+t26 92 t25 92 R
+t26 93 t25 93 R
+t26 94 t25 94 R
+t26 95 t25 95 R
+t26 96 t25 96 R
+t26 97 t25 97 R
+t26 98 t25 98 R
+t26 99 t25 99 R
+t26 100 t25 100 R
+t26 101 t25 101 R
+t26 102 t25 102 R
+t26 103 t25 103 R
+t26 104 t25 104 R
+t12 18 t27 18 S
+t12 23 t27 23 S
+t12 28 t27 28 S
+t12 35 t28 35 S
+t12 36 t28 36 S
+t12 37 t28 37 S
+t12 38 t28 38 S
+t12 39 t28 39 S
+t12 48 t27 48 S
+t12 50 t27 53 S
+t12 51 t27 53 S
+t12 52 t27 53 S
+t12 53 t27 53 S
+t12 54 t27 53 S
+t12 58 t27 58 S
+t12 98 t27 98 S
+t28 0 t28 0 L
+t28 1 t28 1 L
+t28 2 t28 2 L
+t28 3 t28 3 L
+t28 4 t28 4 L
+t28 5 t28 5 L
+t28 6 t28 6 L
+t28 7 t28 7 L
+t28 8 t28 8 L
+t28 9 t28 9 L
+t28 10 t28 10 L
+t28 11 t28 11 L
+t28 12 t28 12 L
+t28 13 t28 13 L
+t28 14 t28 14 L
+t28 15 t29 18 S
+t28 16 t29 18 S
+t28 17 t29 18 S
+t28 18 t29 18 S
+t28 19 t29 18 S
+t28 20 t29 23 S
+t28 21 t29 23 S
+t28 22 t29 23 S
+t28 23 t29 23 S
+t28 24 t29 23 S
+t28 25 t29 28 S
+t28 26 t29 28 S
+t28 27 t29 28 S
+t28 28 t29 28 S
+t28 29 t29 28 S
+t28 30 t28 30 L
+t28 31 t28 31 L
+t28 32 t28 32 L
+t28 33 t28 33 L
+t28 34 t28 34 L
+t28 35 t28 35 L
+t28 36 t28 36 L
+t28 37 t28 37 L
+t28 38 t28 38 L
+t28 39 t28 39 L
+t28 40 t28 40 L
+t28 41 t28 41 L
+t28 42 t28 42 L
+t28 43 t28 43 L
+t28 44 t28 44 L
+t28 45 t29 48 S
+t28 46 t29 48 S
+t28 47 t29 48 S
+t28 48 t29 48 S
+t28 49 t29 48 S
+t28 50 t29 53 S
+t28 51 t29 53 S
+t28 52 t29 53 S
+t28 53 t29 53 S
+t28 54 t29 53 S
+t28 55 t29 58 S
+t28 56 t29 58 S
+t28 57 t29 58 S
+t28 58 t29 58 S
+t28 59 t29 58 S
+t28 60 t30 60 S
+t28 61 t30 61 S
+t28 62 t30 62 S
+t28 63 t30 63 S
+t28 64 t30 64 S
+t28 65 t30 65 S
+t28 66 t30 66 S
+t28 67 t30 67 S
+t28 68 t29 71 S
+t28 69 t29 71 S
+t28 70 t29 71 S
+t28 71 t29 71 S
+t28 72 t29 71 S
+t28 73 t29 76 S
+t28 74 t29 76 S
+t28 75 t29 76 S
+t28 76 t29 76 S
+t28 77 t29 76 S
+t28 78 t30 78 S
+t28 79 t30 79 S
+t28 80 t30 80 S
+t28 81 t30 81 S
+t28 82 t30 82 S
+t28 83 t30 83 S
+t28 84 t30 84 S
+t28 85 t30 85 S
+t28 86 t30 86 S
+t28 87 t30 87 S
+t28 88 t30 88 S
+t28 89 t30 89 S
+t28 90 t29 93 S
+t28 91 t29 93 S
+t28 92 t29 93 S
+t28 93 t29 93 S
+t28 94 t29 93 S
+t28 95 t29 98 S
+t28 96 t29 98 S
+t28 97 t29 98 S
+t28 98 t29 98 S
+t28 99 t29 98 S
+t28 100 t29 103 S
+t28 101 t29 103 S
+t28 102 t29 103 S
+t28 103 t29 103 S
+t28 104 t29 103 S
+t27 0 t27 0 L
+t27 1 t27 1 L
+t27 2 t27 2 L
+t27 3 t27 3 L
+t27 4 t27 4 L
+t27 5 t27 5 L
+t27 6 t27 6 L
+t27 7 t27 7 L
+t27 8 t27 8 L
+t27 9 t27 9 L
+t27 10 t27 10 L
+t27 11 t27 11 L
+t27 12 t27 12 L
+t27 13 t27 13 L
+t27 14 t27 14 L
+t27 15 t27 15 L
+t27 16 t27 16 L
+t27 17 t27 17 L
+t27 18 t27 18 L
+t27 19 t27 19 L
+t27 20 t27 20 L
+t27 21 t27 21 L
+t27 22 t27 22 L
+t27 23 t27 23 L
+t27 24 t27 24 L
+t27 25 t27 25 L
+t27 26 t27 26 L
+t27 27 t27 27 L
+t27 28 t27 28 L
+t27 29 t27 29 L
+t27 30 t29 35 S
+t27 31 t29 36 S
+t27 32 t29 37 S
+t27 33 t29 38 S
+t27 34 t29 39 S
+t27 35 t29 35 S
+t27 36 t29 36 S
+t27 37 t29 37 S
+t27 38 t29 38 S
+t27 39 t29 39 S
+t27 40 t29 35 S
+t27 41 t29 36 S
+t27 42 t29 37 S
+t27 43 t29 38 S
+t27 44 t29 39 S
+t27 45 t29 50 S
+t27 46 t29 51 S
+t27 47 t29 52 S
+t27 48 t29 53 S
+t27 49 t29 54 S
+t27 50 t29 50 S
+t27 51 t29 51 S
+t27 52 t29 52 S
+t27 53 t29 53 S
+t27 54 t29 54 S
+t27 55 t29 50 S
+t27 56 t29 51 S
+t27 57 t29 52 S
+t27 58 t29 53 S
+t27 59 t29 54 S
+t27 60 t30 60 S
+t27 61 t30 61 S
+t27 62 t30 62 S
+t27 63 t30 63 S
+t27 64 t30 64 S
+t27 65 t30 65 S
+t27 66 t30 66 S
+t27 67 t30 67 S
+t27 68 t30 68 S
+t27 69 t30 69 S
+t27 70 t30 70 S
+t27 71 t30 71 S
+t27 72 t30 72 S
+t27 73 t30 73 S
+t27 74 t30 74 S
+t27 75 t30 75 S
+t27 76 t30 76 S
+t27 77 t30 77 S
+t27 78 t29 82 S
+t27 79 t29 83 S
+t27 80 t29 84 S
+t27 81 t29 85 S
+t27 82 t29 82 S
+t27 83 t29 83 S
+t27 84 t29 84 S
+t27 85 t29 85 S
+t27 86 t29 82 S
+t27 87 t29 83 S
+t27 88 t29 84 S
+t27 89 t29 85 S
+t27 90 t29 95 S
+t27 91 t29 96 S
+t27 92 t29 97 S
+t27 93 t29 98 S
+t27 94 t29 99 S
+t27 95 t29 95 S
+t27 96 t29 96 S
+t27 97 t29 97 S
+t27 98 t29 98 S
+t27 99 t29 99 S
+t27 100 t29 95 S
+t27 101 t29 96 S
+t27 102 t29 97 S
+t27 103 t29 98 S
+t27 104 t29 99 S
+t29 0 t29 0 L
+t29 1 t29 1 L
+t29 2 t29 2 L
+t29 3 t29 3 L
+t29 4 t29 4 L
+t29 5 t29 5 L
+t29 6 t29 6 L
+t29 7 t29 7 L
+t29 8 t29 8 L
+t29 9 t29 9 L
+t29 10 t29 10 L
+t29 11 t29 11 L
+t29 12 t29 12 L
+t29 13 t29 13 L
+t29 14 t29 14 L
+t29 15 t29 15 L
+t29 16 t29 16 L
+t29 17 t29 17 L
+t29 18 t29 18 L
+t29 19 t29 19 L
+t29 20 t29 20 L
+t29 21 t29 21 L
+t29 22 t29 22 L
+t29 23 t29 23 L
+t29 24 t29 24 L
+t29 25 t29 25 L
+t29 26 t29 26 L
+t29 27 t29 27 L
+t29 28 t29 28 L
+t29 29 t29 29 L
+t29 30 t29 30 L
+t29 31 t29 31 L
+t29 32 t29 32 L
+t29 33 t29 33 L
+t29 34 t29 34 L
+t29 35 t29 35 L
+t29 36 t29 36 L
+t29 37 t29 37 L
+t29 38 t29 38 L
+t29 39 t29 39 L
+t29 40 t29 40 L
+t29 41 t29 41 L
+t29 42 t29 42 L
+t29 43 t29 43 L
+t29 44 t29 44 L
+t29 45 t29 45 L
+t29 46 t29 46 L
+t29 47 t29 47 L
+t29 48 t29 48 L
+t29 49 t29 49 L
+t29 50 t29 50 L
+t29 51 t29 51 L
+t29 52 t29 52 L
+t29 53 t29 53 L
+t29 54 t29 54 L
+t29 55 t29 55 L
+t29 56 t29 56 L
+t29 57 t29 57 L
+t29 58 t29 58 L
+t29 59 t29 59 L
+t29 60 t30 60 S
+t29 61 t30 61 S
+t29 62 t30 62 S
+t29 63 t30 63 S
+t29 64 t30 64 S
+t29 65 t30 65 S
+t29 66 t30 66 S
+t29 67 t30 67 S
+t29 68 t30 68 S
+t29 69 t30 69 S
+t29 70 t30 70 S
+t29 71 t30 71 S
+t29 72 t30 72 S
+t29 73 t30 73 S
+t29 74 t30 74 S
+t29 75 t30 75 S
+t29 76 t30 76 S
+t29 77 t30 77 S
+t29 78 t30 78 S
+t29 79 t30 79 S
+t29 80 t30 80 S
+t29 81 t30 81 S
+t29 82 t30 82 S
+t29 83 t30 83 S
+t29 84 t30 84 S
+t29 85 t30 85 S
+t29 86 t30 86 S
+t29 87 t30 87 S
+t29 88 t30 88 S
+t29 89 t30 89 S
+t29 90 t30 90 S
+t29 91 t30 91 S
+t29 92 t30 92 S
+t29 93 t30 93 S
+t29 94 t30 94 S
+t29 95 t30 95 S
+t29 96 t30 96 S
+t29 97 t30 97 S
+t29 98 t30 98 S
+t29 99 t30 99 S
+t29 100 t30 100 S
+t29 101 t30 101 S
+t29 102 t30 102 S
+t29 103 t30 103 S
+t29 104 t30 104 S
+t15 0 t15 0 R
+t15 1 t15 1 R
+t15 2 t15 2 R
+t15 3 t15 3 R
+t15 4 t15 4 R
+t15 5 t15 5 R
+t15 6 t15 6 R
+t15 7 t15 7 R
+t15 8 t15 8 R
+t15 9 t15 9 R
+t15 10 t15 10 R
+t15 11 t15 11 R
+t15 12 t15 12 R
+t15 13 t15 13 R
+t15 14 t15 14 R
+t15 15 t15 15 R
+t15 16 t15 16 R
+t15 17 t15 17 R
+t15 18 t15 18 R
+t15 19 t15 19 R
+t15 20 t15 20 R
+t15 21 t15 21 R
+t15 22 t15 22 R
+t15 23 t15 23 R
+t15 24 t15 24 R
+t15 25 t15 25 R
+t15 26 t15 26 R
+t15 27 t15 27 R
+t15 28 t15 28 R
+t15 29 t15 29 R
+t15 35 t12 35 S
+t15 36 t12 36 S
+t15 37 t12 37 S
+t15 38 t12 38 S
+t15 39 t12 39 S
+t15 50 t12 50 S
+t15 51 t12 51 S
+t15 52 t12 52 S
+t15 53 t12 53 S
+t15 54 t12 54 S
+t19 19 t31 19 S
+t19 24 t31 24 S
+t19 29 t31 29 S
+t19 40 t32 40 S
+t19 41 t32 41 S
+t19 42 t32 42 S
+t19 43 t32 43 S
+t19 44 t32 44 S
+t19 49 t31 49 S
+t19 54 t31 54 S
+t19 55 t31 59 S
+t19 56 t31 59 S
+t19 57 t31 59 S
+t19 58 t31 59 S
+t19 59 t31 59 S
+t19 104 t31 104 S
+t32 0 t32 0 L
+t32 1 t32 1 L
+t32 2 t32 2 L
+t32 3 t32 3 L
+t32 4 t32 4 L
+t32 5 t32 5 L
+t32 6 t32 6 L
+t32 7 t32 7 L
+t32 8 t32 8 L
+t32 9 t32 9 L
+t32 10 t32 10 L
+t32 11 t32 11 L
+t32 12 t32 12 L
+t32 13 t32 13 L
+t32 14 t32 14 L
+t32 15 t33 19 S
+t32 16 t33 19 S
+t32 17 t33 19 S
+t32 18 t33 19 S
+t32 19 t33 19 S
+t32 20 t33 24 S
+t32 21 t33 24 S
+t32 22 t33 24 S
+t32 23 t33 24 S
+t32 24 t33 24 S
+t32 25 t33 29 S
+t32 26 t33 29 S
+t32 27 t33 29 S
+t32 28 t33 29 S
+t32 29 t33 29 S
+t32 30 t32 30 L
+t32 31 t32 31 L
+t32 32 t32 32 L
+t32 33 t32 33 L
+t32 34 t32 34 L
+t32 35 t32 35 L
+t32 36 t32 36 L
+t32 37 t32 37 L
+t32 38 t32 38 L
+t32 39 t32 39 L
+t32 40 t32 40 L
+t32 41 t32 41 L
+t32 42 t32 42 L
+t32 43 t32 43 L
+t32 44 t32 44 L
+t32 45 t33 49 S
+t32 46 t33 49 S
+t32 47 t33 49 S
+t32 48 t33 49 S
+t32 49 t33 49 S
+t32 50 t33 54 S
+t32 51 t33 54 S
+t32 52 t33 54 S
+t32 53 t33 54 S
+t32 54 t33 54 S
+t32 55 t33 59 S
+t32 56 t33 59 S
+t32 57 t33 59 S
+t32 58 t33 59 S
+t32 59 t33 59 S
+t32 60 t30 60 S
+t32 61 t30 61 S
+t32 62 t30 62 S
+t32 63 t30 63 S
+t32 64 t30 64 S
+t32 65 t30 65 S
+t32 66 t30 66 S
+t32 67 t30 67 S
+t32 68 t33 72 S
+t32 69 t33 72 S
+t32 70 t33 72 S
+t32 71 t33 72 S
+t32 72 t33 72 S
+t32 73 t33 77 S
+t32 74 t33 77 S
+t32 75 t33 77 S
+t32 76 t33 77 S
+t32 77 t33 77 S
+t32 78 t30 78 S
+t32 79 t30 79 S
+t32 80 t30 80 S
+t32 81 t30 81 S
+t32 82 t30 82 S
+t32 83 t30 83 S
+t32 84 t30 84 S
+t32 85 t30 85 S
+t32 86 t30 86 S
+t32 87 t30 87 S
+t32 88 t30 88 S
+t32 89 t30 89 S
+t32 90 t33 94 S
+t32 91 t33 94 S
+t32 92 t33 94 S
+t32 93 t33 94 S
+t32 94 t33 94 S
+t32 95 t33 99 S
+t32 96 t33 99 S
+t32 97 t33 99 S
+t32 98 t33 99 S
+t32 99 t33 99 S
+t32 100 t33 104 S
+t32 101 t33 104 S
+t32 102 t33 104 S
+t32 103 t33 104 S
+t32 104 t33 104 S
+t31 0 t31 0 L
+t31 1 t31 1 L
+t31 2 t31 2 L
+t31 3 t31 3 L
+t31 4 t31 4 L
+t31 5 t31 5 L
+t31 6 t31 6 L
+t31 7 t31 7 L
+t31 8 t31 8 L
+t31 9 t31 9 L
+t31 10 t31 10 L
+t31 11 t31 11 L
+t31 12 t31 12 L
+t31 13 t31 13 L
+t31 14 t31 14 L
+t31 15 t31 15 L
+t31 16 t31 16 L
+t31 17 t31 17 L
+t31 18 t31 18 L
+t31 19 t31 19 L
+t31 20 t31 20 L
+t31 21 t31 21 L
+t31 22 t31 22 L
+t31 23 t31 23 L
+t31 24 t31 24 L
+t31 25 t31 25 L
+t31 26 t31 26 L
+t31 27 t31 27 L
+t31 28 t31 28 L
+t31 29 t31 29 L
+t31 30 t33 40 S
+t31 31 t33 41 S
+t31 32 t33 42 S
+t31 33 t33 43 S
+t31 34 t33 44 S
+t31 35 t33 40 S
+t31 36 t33 41 S
+t31 37 t33 42 S
+t31 38 t33 43 S
+t31 39 t33 44 S
+t31 40 t33 40 S
+t31 41 t33 41 S
+t31 42 t33 42 S
+t31 43 t33 43 S
+t31 44 t33 44 S
+t31 45 t33 55 S
+t31 46 t33 56 S
+t31 47 t33 57 S
+t31 48 t33 58 S
+t31 49 t33 59 S
+t31 50 t33 55 S
+t31 51 t33 56 S
+t31 52 t33 57 S
+t31 53 t33 58 S
+t31 54 t33 59 S
+t31 55 t33 55 S
+t31 56 t33 56 S
+t31 57 t33 57 S
+t31 58 t33 58 S
+t31 59 t33 59 S
+t31 60 t30 60 S
+t31 61 t30 61 S
+t31 62 t30 62 S
+t31 63 t30 63 S
+t31 64 t30 64 S
+t31 65 t30 65 S
+t31 66 t30 66 S
+t31 67 t30 67 S
+t31 68 t30 68 S
+t31 69 t30 69 S
+t31 70 t30 70 S
+t31 71 t30 71 S
+t31 72 t30 72 S
+t31 73 t30 73 S
+t31 74 t30 74 S
+t31 75 t30 75 S
+t31 76 t30 76 S
+t31 77 t30 77 S
+t31 78 t33 86 S
+t31 79 t33 87 S
+t31 80 t33 88 S
+t31 81 t33 89 S
+t31 82 t33 86 S
+t31 83 t33 87 S
+t31 84 t33 88 S
+t31 85 t33 89 S
+t31 86 t33 86 S
+t31 87 t33 87 S
+t31 88 t33 88 S
+t31 89 t33 89 S
+t31 90 t33 100 S
+t31 91 t33 101 S
+t31 92 t33 102 S
+t31 93 t33 103 S
+t31 94 t33 104 S
+t31 95 t33 100 S
+t31 96 t33 101 S
+t31 97 t33 102 S
+t31 98 t33 103 S
+t31 99 t33 104 S
+t31 100 t33 100 S
+t31 101 t33 101 S
+t31 102 t33 102 S
+t31 103 t33 103 S
+t31 104 t33 104 S
+t33 0 t33 0 L
+t33 1 t33 1 L
+t33 2 t33 2 L
+t33 3 t33 3 L
+t33 4 t33 4 L
+t33 5 t33 5 L
+t33 6 t33 6 L
+t33 7 t33 7 L
+t33 8 t33 8 L
+t33 9 t33 9 L
+t33 10 t33 10 L
+t33 11 t33 11 L
+t33 12 t33 12 L
+t33 13 t33 13 L
+t33 14 t33 14 L
+t33 15 t33 15 L
+t33 16 t33 16 L
+t33 17 t33 17 L
+t33 18 t33 18 L
+t33 19 t33 19 L
+t33 20 t33 20 L
+t33 21 t33 21 L
+t33 22 t33 22 L
+t33 23 t33 23 L
+t33 24 t33 24 L
+t33 25 t33 25 L
+t33 26 t33 26 L
+t33 27 t33 27 L
+t33 28 t33 28 L
+t33 29 t33 29 L
+t33 30 t33 30 L
+t33 31 t33 31 L
+t33 32 t33 32 L
+t33 33 t33 33 L
+t33 34 t33 34 L
+t33 35 t33 35 L
+t33 36 t33 36 L
+t33 37 t33 37 L
+t33 38 t33 38 L
+t33 39 t33 39 L
+t33 40 t33 40 L
+t33 41 t33 41 L
+t33 42 t33 42 L
+t33 43 t33 43 L
+t33 44 t33 44 L
+t33 45 t33 45 L
+t33 46 t33 46 L
+t33 47 t33 47 L
+t33 48 t33 48 L
+t33 49 t33 49 L
+t33 50 t33 50 L
+t33 51 t33 51 L
+t33 52 t33 52 L
+t33 53 t33 53 L
+t33 54 t33 54 L
+t33 55 t33 55 L
+t33 56 t33 56 L
+t33 57 t33 57 L
+t33 58 t33 58 L
+t33 59 t33 59 L
+t33 60 t30 60 S
+t33 61 t30 61 S
+t33 62 t30 62 S
+t33 63 t30 63 S
+t33 64 t30 64 S
+t33 65 t30 65 S
+t33 66 t30 66 S
+t33 67 t30 67 S
+t33 68 t30 68 S
+t33 69 t30 69 S
+t33 70 t30 70 S
+t33 71 t30 71 S
+t33 72 t30 72 S
+t33 73 t30 73 S
+t33 74 t30 74 S
+t33 75 t30 75 S
+t33 76 t30 76 S
+t33 77 t30 77 S
+t33 78 t30 78 S
+t33 79 t30 79 S
+t33 80 t30 80 S
+t33 81 t30 81 S
+t33 82 t30 82 S
+t33 83 t30 83 S
+t33 84 t30 84 S
+t33 85 t30 85 S
+t33 86 t30 86 S
+t33 87 t30 87 S
+t33 88 t30 88 S
+t33 89 t30 89 S
+t33 90 t30 90 S
+t33 91 t30 91 S
+t33 92 t30 92 S
+t33 93 t30 93 S
+t33 94 t30 94 S
+t33 95 t30 95 S
+t33 96 t30 96 S
+t33 97 t30 97 S
+t33 98 t30 98 S
+t33 99 t30 99 S
+t33 100 t30 100 S
+t33 101 t30 101 S
+t33 102 t30 102 S
+t33 103 t30 103 S
+t33 104 t30 104 S
+t16 0 t16 0 R
+t16 1 t16 1 R
+t16 2 t16 2 R
+t16 3 t16 3 R
+t16 4 t16 4 R
+t16 5 t16 5 R
+t16 6 t16 6 R
+t16 7 t16 7 R
+t16 8 t16 8 R
+t16 9 t16 9 R
+t16 10 t16 10 R
+t16 11 t16 11 R
+t16 12 t16 12 R
+t16 13 t16 13 R
+t16 14 t16 14 R
+t16 15 t16 15 R
+t16 16 t16 16 R
+t16 17 t16 17 R
+t16 18 t16 18 R
+t16 19 t16 19 R
+t16 20 t16 20 R
+t16 21 t16 21 R
+t16 22 t16 22 R
+t16 23 t16 23 R
+t16 24 t16 24 R
+t16 25 t16 25 R
+t16 26 t16 26 R
+t16 27 t16 27 R
+t16 28 t16 28 R
+t16 29 t16 29 R
+t16 40 t19 40 S
+t16 41 t19 41 S
+t16 42 t19 42 S
+t16 43 t19 43 S
+t16 44 t19 44 S
+t16 55 t19 55 S
+t16 56 t19 56 S
+t16 57 t19 57 S
+t16 58 t19 58 S
+t16 59 t19 59 S
+t34 16 t35 1 L
+t34 21 t35 6 L
+t34 26 t35 11 L
+t34 30 t36 5 R
+t34 31 t36 6 R
+t34 32 t36 7 R
+t34 33 t36 8 R
+t34 34 t36 9 R
+t34 45 t35 31 L
+t34 46 t35 31 L
+t34 47 t35 31 L
+t34 48 t35 31 L
+t34 49 t35 31 L
+t34 51 t35 36 L
+t34 56 t35 41 L
+t34 91 t37 91 S
+t38 0 t38 0 L
+t38 1 t38 1 L
+t38 2 t38 2 L
+t38 3 t38 3 L
+t38 4 t38 4 L
+t38 5 t38 5 L
+t38 6 t38 6 L
+t38 7 t38 7 L
+t38 8 t38 8 L
+t38 9 t38 9 L
+t38 10 t38 10 L
+t38 11 t38 11 L
+t38 12 t38 12 L
+t38 13 t38 13 L
+t38 14 t38 14 L
+t38 15 t39 1 L
+t38 16 t39 1 L
+t38 17 t39 1 L
+t38 18 t39 1 L
+t38 19 t39 1 L
+t38 20 t39 6 L
+t38 21 t39 6 L
+t38 22 t39 6 L
+t38 23 t39 6 L
+t38 24 t39 6 L
+t38 25 t39 11 L
+t38 26 t39 11 L
+t38 27 t39 11 L
+t38 28 t39 11 L
+t38 29 t39 11 L
+t38 30 t38 30 L
+t38 31 t38 31 L
+t38 32 t38 32 L
+t38 33 t38 33 L
+t38 34 t38 34 L
+t38 35 t38 35 L
+t38 36 t38 36 L
+t38 37 t38 37 L
+t38 38 t38 38 L
+t38 39 t38 39 L
+t38 40 t38 40 L
+t38 41 t38 41 L
+t38 42 t38 42 L
+t38 43 t38 43 L
+t38 44 t38 44 L
+t38 45 t39 31 L
+t38 46 t39 31 L
+t38 47 t39 31 L
+t38 48 t39 31 L
+t38 49 t39 31 L
+t38 50 t39 36 L
+t38 51 t39 36 L
+t38 52 t39 36 L
+t38 53 t39 36 L
+t38 54 t39 36 L
+t38 55 t39 41 L
+t38 56 t39 41 L
+t38 57 t39 41 L
+t38 58 t39 41 L
+t38 59 t39 41 L
+t38 60 t40 60 S
+t38 61 t40 61 S
+t38 62 t40 62 S
+t38 63 t40 63 S
+t38 64 t40 64 S
+t38 65 t40 65 S
+t38 66 t40 66 S
+t38 67 t40 67 S
+t38 68 t41 69 S
+t38 69 t41 69 S
+t38 70 t41 69 S
+t38 71 t41 69 S
+t38 72 t41 69 S
+t38 73 t41 74 S
+t38 74 t41 74 S
+t38 75 t41 74 S
+t38 76 t41 74 S
+t38 77 t41 74 S
+t38 78 t40 78 S
+t38 79 t40 79 S
+t38 80 t40 80 S
+t38 81 t40 81 S
+t38 90 t41 91 S
+t38 91 t41 91 S
+t38 92 t41 91 S
+t38 93 t41 91 S
+t38 94 t41 91 S
+t37 0 t37 0 L
+t37 1 t37 1 L
+t37 2 t37 2 L
+t37 3 t37 3 L
+t37 4 t37 4 L
+t37 5 t37 5 L
+t37 6 t37 6 L
+t37 7 t37 7 L
+t37 8 t37 8 L
+t37 9 t37 9 L
+t37 10 t37 10 L
+t37 11 t37 11 L
+t37 12 t37 12 L
+t37 13 t37 13 L
+t37 14 t37 14 L
+t37 15 t37 15 L
+t37 16 t37 16 L
+t37 17 t37 17 L
+t37 18 t37 18 L
+t37 19 t37 19 L
+t37 20 t37 20 L
+t37 21 t37 21 L
+t37 22 t37 22 L
+t37 23 t37 23 L
+t37 24 t37 24 L
+t37 25 t37 25 L
+t37 26 t37 26 L
+t37 27 t37 27 L
+t37 28 t37 28 L
+t37 29 t37 29 L
+t37 30 t42 5 R
+t37 31 t42 6 R
+t37 32 t42 7 R
+t37 33 t42 8 R
+t37 34 t42 9 R
+t37 35 t42 5 R
+t37 36 t42 6 R
+t37 37 t42 7 R
+t37 38 t42 8 R
+t37 39 t42 9 R
+t37 40 t42 5 R
+t37 41 t42 6 R
+t37 42 t42 7 R
+t37 43 t42 8 R
+t37 44 t42 9 R
+t37 45 t42 20 R
+t37 46 t42 21 R
+t37 47 t42 22 R
+t37 48 t42 23 R
+t37 49 t42 24 R
+t37 50 t42 20 R
+t37 51 t42 21 R
+t37 52 t42 22 R
+t37 53 t42 23 R
+t37 54 t42 24 R
+t37 55 t42 20 R
+t37 56 t42 21 R
+t37 57 t42 22 R
+t37 58 t42 23 R
+t37 59 t42 24 R
+t37 60 t40 60 S
+t37 61 t40 61 S
+t37 62 t40 62 S
+t37 63 t40 63 S
+t37 64 t40 64 S
+t37 65 t40 65 S
+t37 66 t40 66 S
+t37 67 t40 67 S
+t37 68 t40 68 S
+t37 69 t40 69 S
+t37 70 t40 70 S
+t37 71 t40 71 S
+t37 72 t40 72 S
+t37 73 t40 73 S
+t37 74 t40 74 S
+t37 75 t40 75 S
+t37 76 t40 76 S
+t37 77 t40 77 S
+t37 78 t42 60 R
+t37 79 t42 61 R
+t37 80 t42 62 R
+t37 81 t42 63 R
+t37 90 t42 68 R
+t37 91 t42 69 R
+t37 92 t42 70 R
+t37 93 t42 71 R
+t37 94 t42 72 R
+t41 0 t41 0 L
+t41 1 t41 1 L
+t41 2 t41 2 L
+t41 3 t41 3 L
+t41 4 t41 4 L
+t41 5 t41 5 L
+t41 6 t41 6 L
+t41 7 t41 7 L
+t41 8 t41 8 L
+t41 9 t41 9 L
+t41 10 t41 10 L
+t41 11 t41 11 L
+t41 12 t41 12 L
+t41 13 t41 13 L
+t41 14 t41 14 L
+t41 15 t41 15 L
+t41 16 t41 16 L
+t41 17 t41 17 L
+t41 18 t41 18 L
+t41 19 t41 19 L
+t41 20 t41 20 L
+t41 21 t41 21 L
+t41 22 t41 22 L
+t41 23 t41 23 L
+t41 24 t41 24 L
+t41 25 t41 25 L
+t41 26 t41 26 L
+t41 27 t41 27 L
+t41 28 t41 28 L
+t41 29 t41 29 L
+t41 30 t41 30 L
+t41 31 t41 31 L
+t41 32 t41 32 L
+t41 33 t41 33 L
+t41 34 t41 34 L
+t41 35 t41 35 L
+t41 36 t41 36 L
+t41 37 t41 37 L
+t41 38 t41 38 L
+t41 39 t41 39 L
+t41 40 t41 40 L
+t41 41 t41 41 L
+t41 42 t41 42 L
+t41 43 t41 43 L
+t41 44 t41 44 L
+t41 45 t41 45 L
+t41 46 t41 46 L
+t41 47 t41 47 L
+t41 48 t41 48 L
+t41 49 t41 49 L
+t41 50 t41 50 L
+t41 51 t41 51 L
+t41 52 t41 52 L
+t41 53 t41 53 L
+t41 54 t41 54 L
+t41 55 t41 55 L
+t41 56 t41 56 L
+t41 57 t41 57 L
+t41 58 t41 58 L
+t41 59 t41 59 L
+t41 60 t40 60 S
+t41 61 t40 61 S
+t41 62 t40 62 S
+t41 63 t40 63 S
+t41 64 t40 64 S
+t41 65 t40 65 S
+t41 66 t40 66 S
+t41 67 t40 67 S
+t41 68 t40 68 S
+t41 69 t40 69 S
+t41 70 t40 70 S
+t41 71 t40 71 S
+t41 72 t40 72 S
+t41 73 t40 73 S
+t41 74 t40 74 S
+t41 75 t40 75 S
+t41 76 t40 76 S
+t41 77 t40 77 S
+t41 78 t40 78 S
+t41 79 t40 79 S
+t41 80 t40 80 S
+t41 81 t40 81 S
+t41 90 t40 90 S
+t41 91 t40 91 S
+t41 92 t40 92 S
+t41 93 t40 93 S
+t41 94 t40 94 S
+t43 0 t43 0 R
+t43 1 t43 1 R
+t43 2 t43 2 R
+t43 3 t43 3 R
+t43 4 t43 4 R
+t43 5 t43 5 R
+t43 6 t43 6 R
+t43 7 t43 7 R
+t43 8 t43 8 R
+t43 9 t43 9 R
+t43 10 t43 10 R
+t43 11 t43 11 R
+t43 12 t43 12 R
+t43 13 t43 13 R
+t43 14 t43 14 R
+t43 15 t43 15 R
+t43 16 t43 16 R
+t43 17 t43 17 R
+t43 18 t43 18 R
+t43 19 t43 19 R
+t43 20 t43 20 R
+t43 21 t43 21 R
+t43 22 t43 22 R
+t43 23 t43 23 R
+t43 24 t43 24 R
+t43 25 t43 25 R
+t43 26 t43 26 R
+t43 27 t43 27 R
+t43 28 t43 28 R
+t43 29 t43 29 R
+t43 30 t34 30 S
+t43 31 t34 31 S
+t43 32 t34 32 S
+t43 33 t34 33 S
+t43 34 t34 34 S
+t43 45 t34 45 S
+t43 46 t34 46 S
+t43 47 t34 47 S
+t43 48 t34 48 S
+t43 49 t34 49 S
+t44 0 t44 0 R
+t44 1 t44 1 R
+t44 2 t44 2 R
+t44 3 t44 3 R
+t44 4 t44 4 R
+t44 5 t44 5 R
+t44 6 t44 6 R
+t44 7 t44 7 R
+t44 8 t44 8 R
+t44 9 t44 9 R
+t44 10 t44 10 R
+t44 11 t44 11 R
+t44 12 t44 12 R
+t44 13 t44 13 R
+t44 14 t44 14 R
+t44 16 t34 16 S
+t44 17 t45 17 S
+t44 18 t46 18 S
+t44 19 t47 19 S
+t44 21 t34 21 S
+t44 22 t45 22 S
+t44 23 t46 23 S
+t44 24 t47 24 S
+t44 26 t34 26 S
+t44 27 t45 27 S
+t44 28 t46 28 S
+t44 29 t47 29 S
+t44 30 t44 30 R
+t44 31 t44 31 R
+t44 32 t44 32 R
+t44 33 t44 33 R
+t44 34 t44 34 R
+t44 35 t44 35 R
+t44 36 t44 36 R
+t44 37 t44 37 R
+t44 38 t44 38 R
+t44 39 t44 39 R
+t44 40 t44 40 R
+t44 41 t44 41 R
+t44 42 t44 42 R
+t44 43 t44 43 R
+t44 44 t44 44 R
+t44 46 t34 46 S
+t44 47 t45 47 S
+t44 48 t46 48 S
+t44 49 t47 49 S
+t44 51 t34 51 S
+t44 52 t45 52 S
+t44 53 t46 53 S
+t44 54 t47 54 S
+t44 56 t34 56 S
+t44 57 t45 57 S
+t44 58 t46 58 S
+t44 59 t47 59 S
+t48 0 t48 0 R
+t48 1 t48 1 R
+t48 2 t48 2 R
+t48 3 t48 3 R
+t48 4 t48 4 R
+t48 5 t48 5 R
+t48 6 t48 6 R
+t48 7 t48 7 R
+t48 8 t48 8 R
+t48 9 t48 9 R
+t48 10 t48 10 R
+t48 11 t48 11 R
+t48 12 t48 12 R
+t48 13 t48 13 R
+t48 14 t48 14 R
+t48 16 t43 16 R
+t48 17 t49 17 R
+t48 18 t50 18 R
+t48 19 t51 19 R
+t48 21 t43 21 R
+t48 22 t49 22 R
+t48 23 t50 23 R
+t48 24 t51 24 R
+t48 26 t43 26 R
+t48 27 t49 27 R
+t48 28 t50 28 R
+t48 29 t51 29 R
+t48 30 t44 30 R
+t48 31 t44 31 R
+t48 32 t44 32 R
+t48 33 t44 33 R
+t48 34 t44 34 R
+t48 46 t34 46 S
+t48 47 t45 47 S
+t48 48 t46 48 S
+t48 49 t47 49 S
+t48 60 t48 60 R
+t48 61 t48 61 R
+t48 62 t48 62 R
+t48 63 t48 63 R
+t48 64 t48 64 R
+t48 65 t48 65 R
+t48 66 t48 66 R
+t48 67 t48 67 R
+t48 69 t43 69 R
+t48 70 t49 70 R
+t48 71 t50 71 R
+t48 72 t51 72 R
+t48 74 t43 74 R
+t48 75 t49 75 R
+t48 76 t50 76 R
+t48 77 t51 77 R
+t48 78 t44 78 R
+t48 79 t44 79 R
+t48 80 t44 80 R
+t48 81 t44 81 R
+t48 91 t34 91 S
+t48 92 t45 92 S
+t48 93 t46 93 S
+t48 94 t47 94 S
+t35 0 t37 15 R
+t35 1 t37 16 R
+t35 2 t37 17 R
+t35 3 t37 18 R
+t35 4 t37 19 R
+t35 5 t37 20 R
+t35 6 t37 21 R
+t35 7 t37 22 R
+t35 8 t37 23 R
+t35 9 t37 24 R
+t35 10 t37 25 R
+t35 11 t37 26 R
+t35 12 t37 27 R
+t35 13 t37 28 R
+t35 14 t37 29 R
+t35 15 t37 15 R
+t35 16 t37 16 R
+t35 17 t37 17 R
+t35 18 t37 18 R
+t35 19 t37 19 R
+t35 20 t37 20 R
+t35 21 t37 21 R
+t35 22 t37 22 R
+t35 23 t37 23 R
+t35 24 t37 24 R
+t35 25 t37 25 R
+t35 26 t37 26 R
+t35 27 t37 27 R
+t35 28 t37 28 R
+t35 29 t37 29 R
+t35 30 t37 45 R
+t35 31 t37 46 R
+t35 32 t37 47 R
+t35 33 t37 48 R
+t35 34 t37 49 R
+t35 35 t37 50 R
+t35 36 t37 51 R
+t35 37 t37 52 R
+t35 38 t37 53 R
+t35 39 t37 54 R
+t35 40 t37 55 R
+t35 41 t37 56 R
+t35 42 t37 57 R
+t35 43 t37 58 R
+t35 44 t37 59 R
+t35 45 t37 45 R
+t35 46 t37 46 R
+t35 47 t37 47 R
+t35 48 t37 48 R
+t35 49 t37 49 R
+t35 50 t37 50 R
+t35 51 t37 51 R
+t35 52 t37 52 R
+t35 53 t37 53 R
+t35 54 t37 54 R
+t35 55 t37 55 R
+t35 56 t37 56 R
+t35 57 t37 57 R
+t35 58 t37 58 R
+t35 59 t37 59 R
+t35 60 t37 69 R
+t35 61 t37 70 R
+t35 62 t37 71 R
+t35 63 t37 72 R
+t35 64 t37 74 R
+t35 65 t37 75 R
+t35 66 t37 76 R
+t35 67 t37 77 R
+t35 68 t37 68 R
+t35 69 t37 69 R
+t35 70 t37 70 R
+t35 71 t37 71 R
+t35 72 t37 72 R
+t35 73 t37 73 R
+t35 74 t37 74 R
+t35 75 t37 75 R
+t35 76 t37 76 R
+t35 77 t37 77 R
+t35 78 t37 91 R
+t35 79 t37 92 R
+t35 80 t37 93 R
+t35 81 t37 94 R
+t35 90 t37 90 R
+t35 91 t37 91 R
+t35 92 t37 92 R
+t35 93 t37 93 R
+t35 94 t37 94 R
+t39 0 t41 15 R
+t39 1 t41 16 R
+t39 2 t41 17 R
+t39 3 t41 18 R
+t39 4 t41 19 R
+t39 5 t41 20 R
+t39 6 t41 21 R
+t39 7 t41 22 R
+t39 8 t41 23 R
+t39 9 t41 24 R
+t39 10 t41 25 R
+t39 11 t41 26 R
+t39 12 t41 27 R
+t39 13 t41 28 R
+t39 14 t41 29 R
+t39 15 t41 15 R
+t39 16 t41 16 R
+t39 17 t41 17 R
+t39 18 t41 18 R
+t39 19 t41 19 R
+t39 20 t41 20 R
+t39 21 t41 21 R
+t39 22 t41 22 R
+t39 23 t41 23 R
+t39 24 t41 24 R
+t39 25 t41 25 R
+t39 26 t41 26 R
+t39 27 t41 27 R
+t39 28 t41 28 R
+t39 29 t41 29 R
+t39 30 t41 45 R
+t39 31 t41 46 R
+t39 32 t41 47 R
+t39 33 t41 48 R
+t39 34 t41 49 R
+t39 35 t41 50 R
+t39 36 t41 51 R
+t39 37 t41 52 R
+t39 38 t41 53 R
+t39 39 t41 54 R
+t39 40 t41 55 R
+t39 41 t41 56 R
+t39 42 t41 57 R
+t39 43 t41 58 R
+t39 44 t41 59 R
+t39 45 t41 45 R
+t39 46 t41 46 R
+t39 47 t41 47 R
+t39 48 t41 48 R
+t39 49 t41 49 R
+t39 50 t41 50 R
+t39 51 t41 51 R
+t39 52 t41 52 R
+t39 53 t41 53 R
+t39 54 t41 54 R
+t39 55 t41 55 R
+t39 56 t41 56 R
+t39 57 t41 57 R
+t39 58 t41 58 R
+t39 59 t41 59 R
+t39 60 t41 69 R
+t39 61 t41 70 R
+t39 62 t41 71 R
+t39 63 t41 72 R
+t39 64 t41 74 R
+t39 65 t41 75 R
+t39 66 t41 76 R
+t39 67 t41 77 R
+t39 68 t41 68 R
+t39 69 t41 69 R
+t39 70 t41 70 R
+t39 71 t41 71 R
+t39 72 t41 72 R
+t39 73 t41 73 R
+t39 74 t41 74 R
+t39 75 t41 75 R
+t39 76 t41 76 R
+t39 77 t41 77 R
+t39 78 t41 91 R
+t39 79 t41 92 R
+t39 80 t41 93 R
+t39 81 t41 94 R
+t39 90 t41 90 R
+t39 91 t41 91 R
+t39 92 t41 92 R
+t39 93 t41 93 R
+t39 94 t41 94 R
+t36 0 t38 30 L
+t36 1 t38 31 L
+t36 2 t38 32 L
+t36 3 t38 33 L
+t36 4 t38 34 L
+t36 5 t38 35 L
+t36 6 t38 36 L
+t36 7 t38 37 L
+t36 8 t38 38 L
+t36 9 t38 39 L
+t36 10 t38 40 L
+t36 11 t38 41 L
+t36 12 t38 42 L
+t36 13 t38 43 L
+t36 14 t38 44 L
+t36 15 t38 45 L
+t36 16 t38 46 L
+t36 17 t38 47 L
+t36 18 t38 48 L
+t36 19 t38 49 L
+t36 20 t38 50 L
+t36 21 t38 51 L
+t36 22 t38 52 L
+t36 23 t38 53 L
+t36 24 t38 54 L
+t36 25 t38 55 L
+t36 26 t38 56 L
+t36 27 t38 57 L
+t36 28 t38 58 L
+t36 29 t38 59 L
+t36 30 t38 30 L
+t36 31 t38 31 L
+t36 32 t38 32 L
+t36 33 t38 33 L
+t36 34 t38 34 L
+t36 35 t38 35 L
+t36 36 t38 36 L
+t36 37 t38 37 L
+t36 38 t38 38 L
+t36 39 t38 39 L
+t36 40 t38 40 L
+t36 41 t38 41 L
+t36 42 t38 42 L
+t36 43 t38 43 L
+t36 44 t38 44 L
+t36 45 t38 45 L
+t36 46 t38 46 L
+t36 47 t38 47 L
+t36 48 t38 48 L
+t36 49 t38 49 L
+t36 50 t38 50 L
+t36 51 t38 51 L
+t36 52 t38 52 L
+t36 53 t38 53 L
+t36 54 t38 54 L
+t36 55 t38 55 L
+t36 56 t38 56 L
+t36 57 t38 57 L
+t36 58 t38 58 L
+t36 59 t38 59 L
+t42 0 t41 30 L
+t42 1 t41 31 L
+t42 2 t41 32 L
+t42 3 t41 33 L
+t42 4 t41 34 L
+t42 5 t41 35 L
+t42 6 t41 36 L
+t42 7 t41 37 L
+t42 8 t41 38 L
+t42 9 t41 39 L
+t42 10 t41 40 L
+t42 11 t41 41 L
+t42 12 t41 42 L
+t42 13 t41 43 L
+t42 14 t41 44 L
+t42 15 t41 45 L
+t42 16 t41 46 L
+t42 17 t41 47 L
+t42 18 t41 48 L
+t42 19 t41 49 L
+t42 20 t41 50 L
+t42 21 t41 51 L
+t42 22 t41 52 L
+t42 23 t41 53 L
+t42 24 t41 54 L
+t42 25 t41 55 L
+t42 26 t41 56 L
+t42 27 t41 57 L
+t42 28 t41 58 L
+t42 29 t41 59 L
+t42 30 t41 30 L
+t42 31 t41 31 L
+t42 32 t41 32 L
+t42 33 t41 33 L
+t42 34 t41 34 L
+t42 35 t41 35 L
+t42 36 t41 36 L
+t42 37 t41 37 L
+t42 38 t41 38 L
+t42 39 t41 39 L
+t42 40 t41 40 L
+t42 41 t41 41 L
+t42 42 t41 42 L
+t42 43 t41 43 L
+t42 44 t41 44 L
+t42 45 t41 45 L
+t42 46 t41 46 L
+t42 47 t41 47 L
+t42 48 t41 48 L
+t42 49 t41 49 L
+t42 50 t41 50 L
+t42 51 t41 51 L
+t42 52 t41 52 L
+t42 53 t41 53 L
+t42 54 t41 54 L
+t42 55 t41 55 L
+t42 56 t41 56 L
+t42 57 t41 57 L
+t42 58 t41 58 L
+t42 59 t41 59 L
+t40 60 t48 60 S
+t40 61 t48 61 S
+t40 62 t48 62 S
+t40 63 t48 63 S
+t40 64 t48 64 S
+t40 65 t48 65 S
+t40 66 t48 66 S
+t40 67 t48 67 S
+t40 68 t48 68 S
+t40 69 t48 69 S
+t40 70 t48 70 S
+t40 71 t48 71 S
+t40 72 t48 72 S
+t40 73 t48 73 S
+t40 74 t48 74 S
+t40 75 t48 75 S
+t40 76 t48 76 S
+t40 77 t48 77 S
+t40 78 t48 78 S
+t40 79 t48 79 S
+t40 80 t48 80 S
+t40 81 t48 81 S
+t40 90 t48 90 S
+t40 91 t48 91 S
+t40 92 t48 92 S
+t40 93 t48 93 S
+t40 94 t48 94 S
+t45 17 t52 2 L
+t45 22 t52 7 L
+t45 27 t52 12 L
+t45 30 t53 10 R
+t45 31 t53 11 R
+t45 32 t53 12 R
+t45 33 t53 13 R
+t45 34 t53 14 R
+t45 45 t52 32 L
+t45 46 t52 32 L
+t45 47 t52 32 L
+t45 48 t52 32 L
+t45 49 t52 32 L
+t45 52 t52 37 L
+t45 57 t52 42 L
+t45 92 t54 92 S
+t55 0 t55 0 L
+t55 1 t55 1 L
+t55 2 t55 2 L
+t55 3 t55 3 L
+t55 4 t55 4 L
+t55 5 t55 5 L
+t55 6 t55 6 L
+t55 7 t55 7 L
+t55 8 t55 8 L
+t55 9 t55 9 L
+t55 10 t55 10 L
+t55 11 t55 11 L
+t55 12 t55 12 L
+t55 13 t55 13 L
+t55 14 t55 14 L
+t55 15 t56 2 L
+t55 16 t56 2 L
+t55 17 t56 2 L
+t55 18 t56 2 L
+t55 19 t56 2 L
+t55 20 t56 7 L
+t55 21 t56 7 L
+t55 22 t56 7 L
+t55 23 t56 7 L
+t55 24 t56 7 L
+t55 25 t56 12 L
+t55 26 t56 12 L
+t55 27 t56 12 L
+t55 28 t56 12 L
+t55 29 t56 12 L
+t55 30 t55 30 L
+t55 31 t55 31 L
+t55 32 t55 32 L
+t55 33 t55 33 L
+t55 34 t55 34 L
+t55 35 t55 35 L
+t55 36 t55 36 L
+t55 37 t55 37 L
+t55 38 t55 38 L
+t55 39 t55 39 L
+t55 40 t55 40 L
+t55 41 t55 41 L
+t55 42 t55 42 L
+t55 43 t55 43 L
+t55 44 t55 44 L
+t55 45 t56 32 L
+t55 46 t56 32 L
+t55 47 t56 32 L
+t55 48 t56 32 L
+t55 49 t56 32 L
+t55 50 t56 37 L
+t55 51 t56 37 L
+t55 52 t56 37 L
+t55 53 t56 37 L
+t55 54 t56 37 L
+t55 55 t56 42 L
+t55 56 t56 42 L
+t55 57 t56 42 L
+t55 58 t56 42 L
+t55 59 t56 42 L
+t55 60 t40 60 S
+t55 61 t40 61 S
+t55 62 t40 62 S
+t55 63 t40 63 S
+t55 64 t40 64 S
+t55 65 t40 65 S
+t55 66 t40 66 S
+t55 67 t40 67 S
+t55 68 t57 70 S
+t55 69 t57 70 S
+t55 70 t57 70 S
+t55 71 t57 70 S
+t55 72 t57 70 S
+t55 73 t57 75 S
+t55 74 t57 75 S
+t55 75 t57 75 S
+t55 76 t57 75 S
+t55 77 t57 75 S
+t55 78 t40 78 S
+t55 79 t40 79 S
+t55 80 t40 80 S
+t55 81 t40 81 S
+t55 90 t57 92 S
+t55 91 t57 92 S
+t55 92 t57 92 S
+t55 93 t57 92 S
+t55 94 t57 92 S
+t54 0 t54 0 L
+t54 1 t54 1 L
+t54 2 t54 2 L
+t54 3 t54 3 L
+t54 4 t54 4 L
+t54 5 t54 5 L
+t54 6 t54 6 L
+t54 7 t54 7 L
+t54 8 t54 8 L
+t54 9 t54 9 L
+t54 10 t54 10 L
+t54 11 t54 11 L
+t54 12 t54 12 L
+t54 13 t54 13 L
+t54 14 t54 14 L
+t54 15 t54 15 L
+t54 16 t54 16 L
+t54 17 t54 17 L
+t54 18 t54 18 L
+t54 19 t54 19 L
+t54 20 t54 20 L
+t54 21 t54 21 L
+t54 22 t54 22 L
+t54 23 t54 23 L
+t54 24 t54 24 L
+t54 25 t54 25 L
+t54 26 t54 26 L
+t54 27 t54 27 L
+t54 28 t54 28 L
+t54 29 t54 29 L
+t54 30 t58 10 R
+t54 31 t58 11 R
+t54 32 t58 12 R
+t54 33 t58 13 R
+t54 34 t58 14 R
+t54 35 t58 10 R
+t54 36 t58 11 R
+t54 37 t58 12 R
+t54 38 t58 13 R
+t54 39 t58 14 R
+t54 40 t58 10 R
+t54 41 t58 11 R
+t54 42 t58 12 R
+t54 43 t58 13 R
+t54 44 t58 14 R
+t54 45 t58 25 R
+t54 46 t58 26 R
+t54 47 t58 27 R
+t54 48 t58 28 R
+t54 49 t58 29 R
+t54 50 t58 25 R
+t54 51 t58 26 R
+t54 52 t58 27 R
+t54 53 t58 28 R
+t54 54 t58 29 R
+t54 55 t58 25 R
+t54 56 t58 26 R
+t54 57 t58 27 R
+t54 58 t58 28 R
+t54 59 t58 29 R
+t54 60 t40 60 S
+t54 61 t40 61 S
+t54 62 t40 62 S
+t54 63 t40 63 S
+t54 64 t40 64 S
+t54 65 t40 65 S
+t54 66 t40 66 S
+t54 67 t40 67 S
+t54 68 t40 68 S
+t54 69 t40 69 S
+t54 70 t40 70 S
+t54 71 t40 71 S
+t54 72 t40 72 S
+t54 73 t40 73 S
+t54 74 t40 74 S
+t54 75 t40 75 S
+t54 76 t40 76 S
+t54 77 t40 77 S
+t54 78 t58 64 R
+t54 79 t58 65 R
+t54 80 t58 66 R
+t54 81 t58 67 R
+t54 90 t58 73 R
+t54 91 t58 74 R
+t54 92 t58 75 R
+t54 93 t58 76 R
+t54 94 t58 77 R
+t57 0 t57 0 L
+t57 1 t57 1 L
+t57 2 t57 2 L
+t57 3 t57 3 L
+t57 4 t57 4 L
+t57 5 t57 5 L
+t57 6 t57 6 L
+t57 7 t57 7 L
+t57 8 t57 8 L
+t57 9 t57 9 L
+t57 10 t57 10 L
+t57 11 t57 11 L
+t57 12 t57 12 L
+t57 13 t57 13 L
+t57 14 t57 14 L
+t57 15 t57 15 L
+t57 16 t57 16 L
+t57 17 t57 17 L
+t57 18 t57 18 L
+t57 19 t57 19 L
+t57 20 t57 20 L
+t57 21 t57 21 L
+t57 22 t57 22 L
+t57 23 t57 23 L
+t57 24 t57 24 L
+t57 25 t57 25 L
+t57 26 t57 26 L
+t57 27 t57 27 L
+t57 28 t57 28 L
+t57 29 t57 29 L
+t57 30 t57 30 L
+t57 31 t57 31 L
+t57 32 t57 32 L
+t57 33 t57 33 L
+t57 34 t57 34 L
+t57 35 t57 35 L
+t57 36 t57 36 L
+t57 37 t57 37 L
+t57 38 t57 38 L
+t57 39 t57 39 L
+t57 40 t57 40 L
+t57 41 t57 41 L
+t57 42 t57 42 L
+t57 43 t57 43 L
+t57 44 t57 44 L
+t57 45 t57 45 L
+t57 46 t57 46 L
+t57 47 t57 47 L
+t57 48 t57 48 L
+t57 49 t57 49 L
+t57 50 t57 50 L
+t57 51 t57 51 L
+t57 52 t57 52 L
+t57 53 t57 53 L
+t57 54 t57 54 L
+t57 55 t57 55 L
+t57 56 t57 56 L
+t57 57 t57 57 L
+t57 58 t57 58 L
+t57 59 t57 59 L
+t57 60 t40 60 S
+t57 61 t40 61 S
+t57 62 t40 62 S
+t57 63 t40 63 S
+t57 64 t40 64 S
+t57 65 t40 65 S
+t57 66 t40 66 S
+t57 67 t40 67 S
+t57 68 t40 68 S
+t57 69 t40 69 S
+t57 70 t40 70 S
+t57 71 t40 71 S
+t57 72 t40 72 S
+t57 73 t40 73 S
+t57 74 t40 74 S
+t57 75 t40 75 S
+t57 76 t40 76 S
+t57 77 t40 77 S
+t57 78 t40 78 S
+t57 79 t40 79 S
+t57 80 t40 80 S
+t57 81 t40 81 S
+t57 90 t40 90 S
+t57 91 t40 91 S
+t57 92 t40 92 S
+t57 93 t40 93 S
+t57 94 t40 94 S
+t49 0 t49 0 R
+t49 1 t49 1 R
+t49 2 t49 2 R
+t49 3 t49 3 R
+t49 4 t49 4 R
+t49 5 t49 5 R
+t49 6 t49 6 R
+t49 7 t49 7 R
+t49 8 t49 8 R
+t49 9 t49 9 R
+t49 10 t49 10 R
+t49 11 t49 11 R
+t49 12 t49 12 R
+t49 13 t49 13 R
+t49 14 t49 14 R
+t49 15 t49 15 R
+t49 16 t49 16 R
+t49 17 t49 17 R
+t49 18 t49 18 R
+t49 19 t49 19 R
+t49 20 t49 20 R
+t49 21 t49 21 R
+t49 22 t49 22 R
+t49 23 t49 23 R
+t49 24 t49 24 R
+t49 25 t49 25 R
+t49 26 t49 26 R
+t49 27 t49 27 R
+t49 28 t49 28 R
+t49 29 t49 29 R
+t49 30 t45 30 S
+t49 31 t45 31 S
+t49 32 t45 32 S
+t49 33 t45 33 S
+t49 34 t45 34 S
+t49 45 t45 45 S
+t49 46 t45 46 S
+t49 47 t45 47 S
+t49 48 t45 48 S
+t49 49 t45 49 S
+t52 0 t54 15 R
+t52 1 t54 16 R
+t52 2 t54 17 R
+t52 3 t54 18 R
+t52 4 t54 19 R
+t52 5 t54 20 R
+t52 6 t54 21 R
+t52 7 t54 22 R
+t52 8 t54 23 R
+t52 9 t54 24 R
+t52 10 t54 25 R
+t52 11 t54 26 R
+t52 12 t54 27 R
+t52 13 t54 28 R
+t52 14 t54 29 R
+t52 15 t54 15 R
+t52 16 t54 16 R
+t52 17 t54 17 R
+t52 18 t54 18 R
+t52 19 t54 19 R
+t52 20 t54 20 R
+t52 21 t54 21 R
+t52 22 t54 22 R
+t52 23 t54 23 R
+t52 24 t54 24 R
+t52 25 t54 25 R
+t52 26 t54 26 R
+t52 27 t54 27 R
+t52 28 t54 28 R
+t52 29 t54 29 R
+t52 30 t54 45 R
+t52 31 t54 46 R
+t52 32 t54 47 R
+t52 33 t54 48 R
+t52 34 t54 49 R
+t52 35 t54 50 R
+t52 36 t54 51 R
+t52 37 t54 52 R
+t52 38 t54 53 R
+t52 39 t54 54 R
+t52 40 t54 55 R
+t52 41 t54 56 R
+t52 42 t54 57 R
+t52 43 t54 58 R
+t52 44 t54 59 R
+t52 45 t54 45 R
+t52 46 t54 46 R
+t52 47 t54 47 R
+t52 48 t54 48 R
+t52 49 t54 49 R
+t52 50 t54 50 R
+t52 51 t54 51 R
+t52 52 t54 52 R
+t52 53 t54 53 R
+t52 54 t54 54 R
+t52 55 t54 55 R
+t52 56 t54 56 R
+t52 57 t54 57 R
+t52 58 t54 58 R
+t52 59 t54 59 R
+t52 60 t54 69 R
+t52 61 t54 70 R
+t52 62 t54 71 R
+t52 63 t54 72 R
+t52 64 t54 74 R
+t52 65 t54 75 R
+t52 66 t54 76 R
+t52 67 t54 77 R
+t52 68 t54 68 R
+t52 69 t54 69 R
+t52 70 t54 70 R
+t52 71 t54 71 R
+t52 72 t54 72 R
+t52 73 t54 73 R
+t52 74 t54 74 R
+t52 75 t54 75 R
+t52 76 t54 76 R
+t52 77 t54 77 R
+t52 78 t54 91 R
+t52 79 t54 92 R
+t52 80 t54 93 R
+t52 81 t54 94 R
+t52 90 t54 90 R
+t52 91 t54 91 R
+t52 92 t54 92 R
+t52 93 t54 93 R
+t52 94 t54 94 R
+t56 0 t57 15 R
+t56 1 t57 16 R
+t56 2 t57 17 R
+t56 3 t57 18 R
+t56 4 t57 19 R
+t56 5 t57 20 R
+t56 6 t57 21 R
+t56 7 t57 22 R
+t56 8 t57 23 R
+t56 9 t57 24 R
+t56 10 t57 25 R
+t56 11 t57 26 R
+t56 12 t57 27 R
+t56 13 t57 28 R
+t56 14 t57 29 R
+t56 15 t57 15 R
+t56 16 t57 16 R
+t56 17 t57 17 R
+t56 18 t57 18 R
+t56 19 t57 19 R
+t56 20 t57 20 R
+t56 21 t57 21 R
+t56 22 t57 22 R
+t56 23 t57 23 R
+t56 24 t57 24 R
+t56 25 t57 25 R
+t56 26 t57 26 R
+t56 27 t57 27 R
+t56 28 t57 28 R
+t56 29 t57 29 R
+t56 30 t57 45 R
+t56 31 t57 46 R
+t56 32 t57 47 R
+t56 33 t57 48 R
+t56 34 t57 49 R
+t56 35 t57 50 R
+t56 36 t57 51 R
+t56 37 t57 52 R
+t56 38 t57 53 R
+t56 39 t57 54 R
+t56 40 t57 55 R
+t56 41 t57 56 R
+t56 42 t57 57 R
+t56 43 t57 58 R
+t56 44 t57 59 R
+t56 45 t57 45 R
+t56 46 t57 46 R
+t56 47 t57 47 R
+t56 48 t57 48 R
+t56 49 t57 49 R
+t56 50 t57 50 R
+t56 51 t57 51 R
+t56 52 t57 52 R
+t56 53 t57 53 R
+t56 54 t57 54 R
+t56 55 t57 55 R
+t56 56 t57 56 R
+t56 57 t57 57 R
+t56 58 t57 58 R
+t56 59 t57 59 R
+t56 60 t57 69 R
+t56 61 t57 70 R
+t56 62 t57 71 R
+t56 63 t57 72 R
+t56 64 t57 74 R
+t56 65 t57 75 R
+t56 66 t57 76 R
+t56 67 t57 77 R
+t56 68 t57 68 R
+t56 69 t57 69 R
+t56 70 t57 70 R
+t56 71 t57 71 R
+t56 72 t57 72 R
+t56 73 t57 73 R
+t56 74 t57 74 R
+t56 75 t57 75 R
+t56 76 t57 76 R
+t56 77 t57 77 R
+t56 78 t57 91 R
+t56 79 t57 92 R
+t56 80 t57 93 R
+t56 81 t57 94 R
+t56 90 t57 90 R
+t56 91 t57 91 R
+t56 92 t57 92 R
+t56 93 t57 93 R
+t56 94 t57 94 R
+t53 0 t55 30 L
+t53 1 t55 31 L
+t53 2 t55 32 L
+t53 3 t55 33 L
+t53 4 t55 34 L
+t53 5 t55 35 L
+t53 6 t55 36 L
+t53 7 t55 37 L
+t53 8 t55 38 L
+t53 9 t55 39 L
+t53 10 t55 40 L
+t53 11 t55 41 L
+t53 12 t55 42 L
+t53 13 t55 43 L
+t53 14 t55 44 L
+t53 15 t55 45 L
+t53 16 t55 46 L
+t53 17 t55 47 L
+t53 18 t55 48 L
+t53 19 t55 49 L
+t53 20 t55 50 L
+t53 21 t55 51 L
+t53 22 t55 52 L
+t53 23 t55 53 L
+t53 24 t55 54 L
+t53 25 t55 55 L
+t53 26 t55 56 L
+t53 27 t55 57 L
+t53 28 t55 58 L
+t53 29 t55 59 L
+t53 30 t55 30 L
+t53 31 t55 31 L
+t53 32 t55 32 L
+t53 33 t55 33 L
+t53 34 t55 34 L
+t53 35 t55 35 L
+t53 36 t55 36 L
+t53 37 t55 37 L
+t53 38 t55 38 L
+t53 39 t55 39 L
+t53 40 t55 40 L
+t53 41 t55 41 L
+t53 42 t55 42 L
+t53 43 t55 43 L
+t53 44 t55 44 L
+t53 45 t55 45 L
+t53 46 t55 46 L
+t53 47 t55 47 L
+t53 48 t55 48 L
+t53 49 t55 49 L
+t53 50 t55 50 L
+t53 51 t55 51 L
+t53 52 t55 52 L
+t53 53 t55 53 L
+t53 54 t55 54 L
+t53 55 t55 55 L
+t53 56 t55 56 L
+t53 57 t55 57 L
+t53 58 t55 58 L
+t53 59 t55 59 L
+t58 0 t57 30 L
+t58 1 t57 31 L
+t58 2 t57 32 L
+t58 3 t57 33 L
+t58 4 t57 34 L
+t58 5 t57 35 L
+t58 6 t57 36 L
+t58 7 t57 37 L
+t58 8 t57 38 L
+t58 9 t57 39 L
+t58 10 t57 40 L
+t58 11 t57 41 L
+t58 12 t57 42 L
+t58 13 t57 43 L
+t58 14 t57 44 L
+t58 15 t57 45 L
+t58 16 t57 46 L
+t58 17 t57 47 L
+t58 18 t57 48 L
+t58 19 t57 49 L
+t58 20 t57 50 L
+t58 21 t57 51 L
+t58 22 t57 52 L
+t58 23 t57 53 L
+t58 24 t57 54 L
+t58 25 t57 55 L
+t58 26 t57 56 L
+t58 27 t57 57 L
+t58 28 t57 58 L
+t58 29 t57 59 L
+t58 30 t57 30 L
+t58 31 t57 31 L
+t58 32 t57 32 L
+t58 33 t57 33 L
+t58 34 t57 34 L
+t58 35 t57 35 L
+t58 36 t57 36 L
+t58 37 t57 37 L
+t58 38 t57 38 L
+t58 39 t57 39 L
+t58 40 t57 40 L
+t58 41 t57 41 L
+t58 42 t57 42 L
+t58 43 t57 43 L
+t58 44 t57 44 L
+t58 45 t57 45 L
+t58 46 t57 46 L
+t58 47 t57 47 L
+t58 48 t57 48 L
+t58 49 t57 49 L
+t58 50 t57 50 L
+t58 51 t57 51 L
+t58 52 t57 52 L
+t58 53 t57 53 L
+t58 54 t57 54 L
+t58 55 t57 55 L
+t58 56 t57 56 L
+t58 57 t57 57 L
+t58 58 t57 58 L
+t58 59 t57 59 L
+t46 18 t59 3 L
+t46 23 t59 8 L
+t46 28 t59 13 L
+t46 30 t60 5 R
+t46 31 t60 6 R
+t46 32 t60 7 R
+t46 33 t60 8 R
+t46 34 t60 9 R
+t46 45 t59 33 L
+t46 46 t59 33 L
+t46 47 t59 33 L
+t46 48 t59 33 L
+t46 49 t59 33 L
+t46 53 t59 38 L
+t46 58 t59 43 L
+t46 93 t61 93 S
+t62 0 t62 0 L
+t62 1 t62 1 L
+t62 2 t62 2 L
+t62 3 t62 3 L
+t62 4 t62 4 L
+t62 5 t62 5 L
+t62 6 t62 6 L
+t62 7 t62 7 L
+t62 8 t62 8 L
+t62 9 t62 9 L
+t62 10 t62 10 L
+t62 11 t62 11 L
+t62 12 t62 12 L
+t62 13 t62 13 L
+t62 14 t62 14 L
+t62 15 t63 3 L
+t62 16 t63 3 L
+t62 17 t63 3 L
+t62 18 t63 3 L
+t62 19 t63 3 L
+t62 20 t63 8 L
+t62 21 t63 8 L
+t62 22 t63 8 L
+t62 23 t63 8 L
+t62 24 t63 8 L
+t62 25 t63 13 L
+t62 26 t63 13 L
+t62 27 t63 13 L
+t62 28 t63 13 L
+t62 29 t63 13 L
+t62 30 t62 30 L
+t62 31 t62 31 L
+t62 32 t62 32 L
+t62 33 t62 33 L
+t62 34 t62 34 L
+t62 35 t62 35 L
+t62 36 t62 36 L
+t62 37 t62 37 L
+t62 38 t62 38 L
+t62 39 t62 39 L
+t62 40 t62 40 L
+t62 41 t62 41 L
+t62 42 t62 42 L
+t62 43 t62 43 L
+t62 44 t62 44 L
+t62 45 t63 33 L
+t62 46 t63 33 L
+t62 47 t63 33 L
+t62 48 t63 33 L
+t62 49 t63 33 L
+t62 50 t63 38 L
+t62 51 t63 38 L
+t62 52 t63 38 L
+t62 53 t63 38 L
+t62 54 t63 38 L
+t62 55 t63 43 L
+t62 56 t63 43 L
+t62 57 t63 43 L
+t62 58 t63 43 L
+t62 59 t63 43 L
+t62 60 t64 60 S
+t62 61 t64 61 S
+t62 62 t64 62 S
+t62 63 t64 63 S
+t62 64 t64 64 S
+t62 65 t64 65 S
+t62 66 t64 66 S
+t62 67 t64 67 S
+t62 68 t65 71 S
+t62 69 t65 71 S
+t62 70 t65 71 S
+t62 71 t65 71 S
+t62 72 t65 71 S
+t62 73 t65 76 S
+t62 74 t65 76 S
+t62 75 t65 76 S
+t62 76 t65 76 S
+t62 77 t65 76 S
+t62 78 t64 78 S
+t62 79 t64 79 S
+t62 80 t64 80 S
+t62 81 t64 81 S
+t62 90 t65 93 S
+t62 91 t65 93 S
+t62 92 t65 93 S
+t62 93 t65 93 S
+t62 94 t65 93 S
+t61 0 t61 0 L
+t61 1 t61 1 L
+t61 2 t61 2 L
+t61 3 t61 3 L
+t61 4 t61 4 L
+t61 5 t61 5 L
+t61 6 t61 6 L
+t61 7 t61 7 L
+t61 8 t61 8 L
+t61 9 t61 9 L
+t61 10 t61 10 L
+t61 11 t61 11 L
+t61 12 t61 12 L
+t61 13 t61 13 L
+t61 14 t61 14 L
+t61 15 t61 15 L
+t61 16 t61 16 L
+t61 17 t61 17 L
+t61 18 t61 18 L
+t61 19 t61 19 L
+t61 20 t61 20 L
+t61 21 t61 21 L
+t61 22 t61 22 L
+t61 23 t61 23 L
+t61 24 t61 24 L
+t61 25 t61 25 L
+t61 26 t61 26 L
+t61 27 t61 27 L
+t61 28 t61 28 L
+t61 29 t61 29 L
+t61 30 t66 5 R
+t61 31 t66 6 R
+t61 32 t66 7 R
+t61 33 t66 8 R
+t61 34 t66 9 R
+t61 35 t66 5 R
+t61 36 t66 6 R
+t61 37 t66 7 R
+t61 38 t66 8 R
+t61 39 t66 9 R
+t61 40 t66 5 R
+t61 41 t66 6 R
+t61 42 t66 7 R
+t61 43 t66 8 R
+t61 44 t66 9 R
+t61 45 t66 20 R
+t61 46 t66 21 R
+t61 47 t66 22 R
+t61 48 t66 23 R
+t61 49 t66 24 R
+t61 50 t66 20 R
+t61 51 t66 21 R
+t61 52 t66 22 R
+t61 53 t66 23 R
+t61 54 t66 24 R
+t61 55 t66 20 R
+t61 56 t66 21 R
+t61 57 t66 22 R
+t61 58 t66 23 R
+t61 59 t66 24 R
+t61 60 t64 60 S
+t61 61 t64 61 S
+t61 62 t64 62 S
+t61 63 t64 63 S
+t61 64 t64 64 S
+t61 65 t64 65 S
+t61 66 t64 66 S
+t61 67 t64 67 S
+t61 68 t64 68 S
+t61 69 t64 69 S
+t61 70 t64 70 S
+t61 71 t64 71 S
+t61 72 t64 72 S
+t61 73 t64 73 S
+t61 74 t64 74 S
+t61 75 t64 75 S
+t61 76 t64 76 S
+t61 77 t64 77 S
+t61 78 t66 60 R
+t61 79 t66 61 R
+t61 80 t66 62 R
+t61 81 t66 63 R
+t61 90 t66 68 R
+t61 91 t66 69 R
+t61 92 t66 70 R
+t61 93 t66 71 R
+t61 94 t66 72 R
+t65 0 t65 0 L
+t65 1 t65 1 L
+t65 2 t65 2 L
+t65 3 t65 3 L
+t65 4 t65 4 L
+t65 5 t65 5 L
+t65 6 t65 6 L
+t65 7 t65 7 L
+t65 8 t65 8 L
+t65 9 t65 9 L
+t65 10 t65 10 L
+t65 11 t65 11 L
+t65 12 t65 12 L
+t65 13 t65 13 L
+t65 14 t65 14 L
+t65 15 t65 15 L
+t65 16 t65 16 L
+t65 17 t65 17 L
+t65 18 t65 18 L
+t65 19 t65 19 L
+t65 20 t65 20 L
+t65 21 t65 21 L
+t65 22 t65 22 L
+t65 23 t65 23 L
+t65 24 t65 24 L
+t65 25 t65 25 L
+t65 26 t65 26 L
+t65 27 t65 27 L
+t65 28 t65 28 L
+t65 29 t65 29 L
+t65 30 t65 30 L
+t65 31 t65 31 L
+t65 32 t65 32 L
+t65 33 t65 33 L
+t65 34 t65 34 L
+t65 35 t65 35 L
+t65 36 t65 36 L
+t65 37 t65 37 L
+t65 38 t65 38 L
+t65 39 t65 39 L
+t65 40 t65 40 L
+t65 41 t65 41 L
+t65 42 t65 42 L
+t65 43 t65 43 L
+t65 44 t65 44 L
+t65 45 t65 45 L
+t65 46 t65 46 L
+t65 47 t65 47 L
+t65 48 t65 48 L
+t65 49 t65 49 L
+t65 50 t65 50 L
+t65 51 t65 51 L
+t65 52 t65 52 L
+t65 53 t65 53 L
+t65 54 t65 54 L
+t65 55 t65 55 L
+t65 56 t65 56 L
+t65 57 t65 57 L
+t65 58 t65 58 L
+t65 59 t65 59 L
+t65 60 t64 60 S
+t65 61 t64 61 S
+t65 62 t64 62 S
+t65 63 t64 63 S
+t65 64 t64 64 S
+t65 65 t64 65 S
+t65 66 t64 66 S
+t65 67 t64 67 S
+t65 68 t64 68 S
+t65 69 t64 69 S
+t65 70 t64 70 S
+t65 71 t64 71 S
+t65 72 t64 72 S
+t65 73 t64 73 S
+t65 74 t64 74 S
+t65 75 t64 75 S
+t65 76 t64 76 S
+t65 77 t64 77 S
+t65 78 t64 78 S
+t65 79 t64 79 S
+t65 80 t64 80 S
+t65 81 t64 81 S
+t65 90 t64 90 S
+t65 91 t64 91 S
+t65 92 t64 92 S
+t65 93 t64 93 S
+t65 94 t64 94 S
+t50 0 t50 0 R
+t50 1 t50 1 R
+t50 2 t50 2 R
+t50 3 t50 3 R
+t50 4 t50 4 R
+t50 5 t50 5 R
+t50 6 t50 6 R
+t50 7 t50 7 R
+t50 8 t50 8 R
+t50 9 t50 9 R
+t50 10 t50 10 R
+t50 11 t50 11 R
+t50 12 t50 12 R
+t50 13 t50 13 R
+t50 14 t50 14 R
+t50 15 t50 15 R
+t50 16 t50 16 R
+t50 17 t50 17 R
+t50 18 t50 18 R
+t50 19 t50 19 R
+t50 20 t50 20 R
+t50 21 t50 21 R
+t50 22 t50 22 R
+t50 23 t50 23 R
+t50 24 t50 24 R
+t50 25 t50 25 R
+t50 26 t50 26 R
+t50 27 t50 27 R
+t50 28 t50 28 R
+t50 29 t50 29 R
+t50 30 t46 30 S
+t50 31 t46 31 S
+t50 32 t46 32 S
+t50 33 t46 33 S
+t50 34 t46 34 S
+t50 45 t46 45 S
+t50 46 t46 46 S
+t50 47 t46 47 S
+t50 48 t46 48 S
+t50 49 t46 49 S
+t59 0 t61 15 R
+t59 1 t61 16 R
+t59 2 t61 17 R
+t59 3 t61 18 R
+t59 4 t61 19 R
+t59 5 t61 20 R
+t59 6 t61 21 R
+t59 7 t61 22 R
+t59 8 t61 23 R
+t59 9 t61 24 R
+t59 10 t61 25 R
+t59 11 t61 26 R
+t59 12 t61 27 R
+t59 13 t61 28 R
+t59 14 t61 29 R
+t59 15 t61 15 R
+t59 16 t61 16 R
+t59 17 t61 17 R
+t59 18 t61 18 R
+t59 19 t61 19 R
+t59 20 t61 20 R
+t59 21 t61 21 R
+t59 22 t61 22 R
+t59 23 t61 23 R
+t59 24 t61 24 R
+t59 25 t61 25 R
+t59 26 t61 26 R
+t59 27 t61 27 R
+t59 28 t61 28 R
+t59 29 t61 29 R
+t59 30 t61 45 R
+t59 31 t61 46 R
+t59 32 t61 47 R
+t59 33 t61 48 R
+t59 34 t61 49 R
+t59 35 t61 50 R
+t59 36 t61 51 R
+t59 37 t61 52 R
+t59 38 t61 53 R
+t59 39 t61 54 R
+t59 40 t61 55 R
+t59 41 t61 56 R
+t59 42 t61 57 R
+t59 43 t61 58 R
+t59 44 t61 59 R
+t59 45 t61 45 R
+t59 46 t61 46 R
+t59 47 t61 47 R
+t59 48 t61 48 R
+t59 49 t61 49 R
+t59 50 t61 50 R
+t59 51 t61 51 R
+t59 52 t61 52 R
+t59 53 t61 53 R
+t59 54 t61 54 R
+t59 55 t61 55 R
+t59 56 t61 56 R
+t59 57 t61 57 R
+t59 58 t61 58 R
+t59 59 t61 59 R
+t59 60 t61 69 R
+t59 61 t61 70 R
+t59 62 t61 71 R
+t59 63 t61 72 R
+t59 64 t61 74 R
+t59 65 t61 75 R
+t59 66 t61 76 R
+t59 67 t61 77 R
+t59 68 t61 68 R
+t59 69 t61 69 R
+t59 70 t61 70 R
+t59 71 t61 71 R
+t59 72 t61 72 R
+t59 73 t61 73 R
+t59 74 t61 74 R
+t59 75 t61 75 R
+t59 76 t61 76 R
+t59 77 t61 77 R
+t59 78 t61 91 R
+t59 79 t61 92 R
+t59 80 t61 93 R
+t59 81 t61 94 R
+t59 90 t61 90 R
+t59 91 t61 91 R
+t59 92 t61 92 R
+t59 93 t61 93 R
+t59 94 t61 94 R
+t63 0 t65 15 R
+t63 1 t65 16 R
+t63 2 t65 17 R
+t63 3 t65 18 R
+t63 4 t65 19 R
+t63 5 t65 20 R
+t63 6 t65 21 R
+t63 7 t65 22 R
+t63 8 t65 23 R
+t63 9 t65 24 R
+t63 10 t65 25 R
+t63 11 t65 26 R
+t63 12 t65 27 R
+t63 13 t65 28 R
+t63 14 t65 29 R
+t63 15 t65 15 R
+t63 16 t65 16 R
+t63 17 t65 17 R
+t63 18 t65 18 R
+t63 19 t65 19 R
+t63 20 t65 20 R
+t63 21 t65 21 R
+t63 22 t65 22 R
+t63 23 t65 23 R
+t63 24 t65 24 R
+t63 25 t65 25 R
+t63 26 t65 26 R
+t63 27 t65 27 R
+t63 28 t65 28 R
+t63 29 t65 29 R
+t63 30 t65 45 R
+t63 31 t65 46 R
+t63 32 t65 47 R
+t63 33 t65 48 R
+t63 34 t65 49 R
+t63 35 t65 50 R
+t63 36 t65 51 R
+t63 37 t65 52 R
+t63 38 t65 53 R
+t63 39 t65 54 R
+t63 40 t65 55 R
+t63 41 t65 56 R
+t63 42 t65 57 R
+t63 43 t65 58 R
+t63 44 t65 59 R
+t63 45 t65 45 R
+t63 46 t65 46 R
+t63 47 t65 47 R
+t63 48 t65 48 R
+t63 49 t65 49 R
+t63 50 t65 50 R
+t63 51 t65 51 R
+t63 52 t65 52 R
+t63 53 t65 53 R
+t63 54 t65 54 R
+t63 55 t65 55 R
+t63 56 t65 56 R
+t63 57 t65 57 R
+t63 58 t65 58 R
+t63 59 t65 59 R
+t63 60 t65 69 R
+t63 61 t65 70 R
+t63 62 t65 71 R
+t63 63 t65 72 R
+t63 64 t65 74 R
+t63 65 t65 75 R
+t63 66 t65 76 R
+t63 67 t65 77 R
+t63 68 t65 68 R
+t63 69 t65 69 R
+t63 70 t65 70 R
+t63 71 t65 71 R
+t63 72 t65 72 R
+t63 73 t65 73 R
+t63 74 t65 74 R
+t63 75 t65 75 R
+t63 76 t65 76 R
+t63 77 t65 77 R
+t63 78 t65 91 R
+t63 79 t65 92 R
+t63 80 t65 93 R
+t63 81 t65 94 R
+t63 90 t65 90 R
+t63 91 t65 91 R
+t63 92 t65 92 R
+t63 93 t65 93 R
+t63 94 t65 94 R
+t60 0 t62 30 L
+t60 1 t62 31 L
+t60 2 t62 32 L
+t60 3 t62 33 L
+t60 4 t62 34 L
+t60 5 t62 35 L
+t60 6 t62 36 L
+t60 7 t62 37 L
+t60 8 t62 38 L
+t60 9 t62 39 L
+t60 10 t62 40 L
+t60 11 t62 41 L
+t60 12 t62 42 L
+t60 13 t62 43 L
+t60 14 t62 44 L
+t60 15 t62 45 L
+t60 16 t62 46 L
+t60 17 t62 47 L
+t60 18 t62 48 L
+t60 19 t62 49 L
+t60 20 t62 50 L
+t60 21 t62 51 L
+t60 22 t62 52 L
+t60 23 t62 53 L
+t60 24 t62 54 L
+t60 25 t62 55 L
+t60 26 t62 56 L
+t60 27 t62 57 L
+t60 28 t62 58 L
+t60 29 t62 59 L
+t60 30 t62 30 L
+t60 31 t62 31 L
+t60 32 t62 32 L
+t60 33 t62 33 L
+t60 34 t62 34 L
+t60 35 t62 35 L
+t60 36 t62 36 L
+t60 37 t62 37 L
+t60 38 t62 38 L
+t60 39 t62 39 L
+t60 40 t62 40 L
+t60 41 t62 41 L
+t60 42 t62 42 L
+t60 43 t62 43 L
+t60 44 t62 44 L
+t60 45 t62 45 L
+t60 46 t62 46 L
+t60 47 t62 47 L
+t60 48 t62 48 L
+t60 49 t62 49 L
+t60 50 t62 50 L
+t60 51 t62 51 L
+t60 52 t62 52 L
+t60 53 t62 53 L
+t60 54 t62 54 L
+t60 55 t62 55 L
+t60 56 t62 56 L
+t60 57 t62 57 L
+t60 58 t62 58 L
+t60 59 t62 59 L
+t66 0 t65 30 L
+t66 1 t65 31 L
+t66 2 t65 32 L
+t66 3 t65 33 L
+t66 4 t65 34 L
+t66 5 t65 35 L
+t66 6 t65 36 L
+t66 7 t65 37 L
+t66 8 t65 38 L
+t66 9 t65 39 L
+t66 10 t65 40 L
+t66 11 t65 41 L
+t66 12 t65 42 L
+t66 13 t65 43 L
+t66 14 t65 44 L
+t66 15 t65 45 L
+t66 16 t65 46 L
+t66 17 t65 47 L
+t66 18 t65 48 L
+t66 19 t65 49 L
+t66 20 t65 50 L
+t66 21 t65 51 L
+t66 22 t65 52 L
+t66 23 t65 53 L
+t66 24 t65 54 L
+t66 25 t65 55 L
+t66 26 t65 56 L
+t66 27 t65 57 L
+t66 28 t65 58 L
+t66 29 t65 59 L
+t66 30 t65 30 L
+t66 31 t65 31 L
+t66 32 t65 32 L
+t66 33 t65 33 L
+t66 34 t65 34 L
+t66 35 t65 35 L
+t66 36 t65 36 L
+t66 37 t65 37 L
+t66 38 t65 38 L
+t66 39 t65 39 L
+t66 40 t65 40 L
+t66 41 t65 41 L
+t66 42 t65 42 L
+t66 43 t65 43 L
+t66 44 t65 44 L
+t66 45 t65 45 L
+t66 46 t65 46 L
+t66 47 t65 47 L
+t66 48 t65 48 L
+t66 49 t65 49 L
+t66 50 t65 50 L
+t66 51 t65 51 L
+t66 52 t65 52 L
+t66 53 t65 53 L
+t66 54 t65 54 L
+t66 55 t65 55 L
+t66 56 t65 56 L
+t66 57 t65 57 L
+t66 58 t65 58 L
+t66 59 t65 59 L
+t47 19 t67 4 L
+t47 24 t67 9 L
+t47 29 t67 14 L
+t47 30 t68 10 R
+t47 31 t68 11 R
+t47 32 t68 12 R
+t47 33 t68 13 R
+t47 34 t68 14 R
+t47 45 t67 34 L
+t47 46 t67 34 L
+t47 47 t67 34 L
+t47 48 t67 34 L
+t47 49 t67 34 L
+t47 54 t67 39 L
+t47 59 t67 44 L
+t47 94 t69 94 S
+t70 0 t70 0 L
+t70 1 t70 1 L
+t70 2 t70 2 L
+t70 3 t70 3 L
+t70 4 t70 4 L
+t70 5 t70 5 L
+t70 6 t70 6 L
+t70 7 t70 7 L
+t70 8 t70 8 L
+t70 9 t70 9 L
+t70 10 t70 10 L
+t70 11 t70 11 L
+t70 12 t70 12 L
+t70 13 t70 13 L
+t70 14 t70 14 L
+t70 15 t71 4 L
+t70 16 t71 4 L
+t70 17 t71 4 L
+t70 18 t71 4 L
+t70 19 t71 4 L
+t70 20 t71 9 L
+t70 21 t71 9 L
+t70 22 t71 9 L
+t70 23 t71 9 L
+t70 24 t71 9 L
+t70 25 t71 14 L
+t70 26 t71 14 L
+t70 27 t71 14 L
+t70 28 t71 14 L
+t70 29 t71 14 L
+t70 30 t70 30 L
+t70 31 t70 31 L
+t70 32 t70 32 L
+t70 33 t70 33 L
+t70 34 t70 34 L
+t70 35 t70 35 L
+t70 36 t70 36 L
+t70 37 t70 37 L
+t70 38 t70 38 L
+t70 39 t70 39 L
+t70 40 t70 40 L
+t70 41 t70 41 L
+t70 42 t70 42 L
+t70 43 t70 43 L
+t70 44 t70 44 L
+t70 45 t71 34 L
+t70 46 t71 34 L
+t70 47 t71 34 L
+t70 48 t71 34 L
+t70 49 t71 34 L
+t70 50 t71 39 L
+t70 51 t71 39 L
+t70 52 t71 39 L
+t70 53 t71 39 L
+t70 54 t71 39 L
+t70 55 t71 44 L
+t70 56 t71 44 L
+t70 57 t71 44 L
+t70 58 t71 44 L
+t70 59 t71 44 L
+t70 60 t64 60 S
+t70 61 t64 61 S
+t70 62 t64 62 S
+t70 63 t64 63 S
+t70 64 t64 64 S
+t70 65 t64 65 S
+t70 66 t64 66 S
+t70 67 t64 67 S
+t70 68 t72 72 S
+t70 69 t72 72 S
+t70 70 t72 72 S
+t70 71 t72 72 S
+t70 72 t72 72 S
+t70 73 t72 77 S
+t70 74 t72 77 S
+t70 75 t72 77 S
+t70 76 t72 77 S
+t70 77 t72 77 S
+t70 78 t64 78 S
+t70 79 t64 79 S
+t70 80 t64 80 S
+t70 81 t64 81 S
+t70 90 t72 94 S
+t70 91 t72 94 S
+t70 92 t72 94 S
+t70 93 t72 94 S
+t70 94 t72 94 S
+t69 0 t69 0 L
+t69 1 t69 1 L
+t69 2 t69 2 L
+t69 3 t69 3 L
+t69 4 t69 4 L
+t69 5 t69 5 L
+t69 6 t69 6 L
+t69 7 t69 7 L
+t69 8 t69 8 L
+t69 9 t69 9 L
+t69 10 t69 10 L
+t69 11 t69 11 L
+t69 12 t69 12 L
+t69 13 t69 13 L
+t69 14 t69 14 L
+t69 15 t69 15 L
+t69 16 t69 16 L
+t69 17 t69 17 L
+t69 18 t69 18 L
+t69 19 t69 19 L
+t69 20 t69 20 L
+t69 21 t69 21 L
+t69 22 t69 22 L
+t69 23 t69 23 L
+t69 24 t69 24 L
+t69 25 t69 25 L
+t69 26 t69 26 L
+t69 27 t69 27 L
+t69 28 t69 28 L
+t69 29 t69 29 L
+t69 30 t73 10 R
+t69 31 t73 11 R
+t69 32 t73 12 R
+t69 33 t73 13 R
+t69 34 t73 14 R
+t69 35 t73 10 R
+t69 36 t73 11 R
+t69 37 t73 12 R
+t69 38 t73 13 R
+t69 39 t73 14 R
+t69 40 t73 10 R
+t69 41 t73 11 R
+t69 42 t73 12 R
+t69 43 t73 13 R
+t69 44 t73 14 R
+t69 45 t73 25 R
+t69 46 t73 26 R
+t69 47 t73 27 R
+t69 48 t73 28 R
+t69 49 t73 29 R
+t69 50 t73 25 R
+t69 51 t73 26 R
+t69 52 t73 27 R
+t69 53 t73 28 R
+t69 54 t73 29 R
+t69 55 t73 25 R
+t69 56 t73 26 R
+t69 57 t73 27 R
+t69 58 t73 28 R
+t69 59 t73 29 R
+t69 60 t64 60 S
+t69 61 t64 61 S
+t69 62 t64 62 S
+t69 63 t64 63 S
+t69 64 t64 64 S
+t69 65 t64 65 S
+t69 66 t64 66 S
+t69 67 t64 67 S
+t69 68 t64 68 S
+t69 69 t64 69 S
+t69 70 t64 70 S
+t69 71 t64 71 S
+t69 72 t64 72 S
+t69 73 t64 73 S
+t69 74 t64 74 S
+t69 75 t64 75 S
+t69 76 t64 76 S
+t69 77 t64 77 S
+t69 78 t73 64 R
+t69 79 t73 65 R
+t69 80 t73 66 R
+t69 81 t73 67 R
+t69 90 t73 73 R
+t69 91 t73 74 R
+t69 92 t73 75 R
+t69 93 t73 76 R
+t69 94 t73 77 R
+t72 0 t72 0 L
+t72 1 t72 1 L
+t72 2 t72 2 L
+t72 3 t72 3 L
+t72 4 t72 4 L
+t72 5 t72 5 L
+t72 6 t72 6 L
+t72 7 t72 7 L
+t72 8 t72 8 L
+t72 9 t72 9 L
+t72 10 t72 10 L
+t72 11 t72 11 L
+t72 12 t72 12 L
+t72 13 t72 13 L
+t72 14 t72 14 L
+t72 15 t72 15 L
+t72 16 t72 16 L
+t72 17 t72 17 L
+t72 18 t72 18 L
+t72 19 t72 19 L
+t72 20 t72 20 L
+t72 21 t72 21 L
+t72 22 t72 22 L
+t72 23 t72 23 L
+t72 24 t72 24 L
+t72 25 t72 25 L
+t72 26 t72 26 L
+t72 27 t72 27 L
+t72 28 t72 28 L
+t72 29 t72 29 L
+t72 30 t72 30 L
+t72 31 t72 31 L
+t72 32 t72 32 L
+t72 33 t72 33 L
+t72 34 t72 34 L
+t72 35 t72 35 L
+t72 36 t72 36 L
+t72 37 t72 37 L
+t72 38 t72 38 L
+t72 39 t72 39 L
+t72 40 t72 40 L
+t72 41 t72 41 L
+t72 42 t72 42 L
+t72 43 t72 43 L
+t72 44 t72 44 L
+t72 45 t72 45 L
+t72 46 t72 46 L
+t72 47 t72 47 L
+t72 48 t72 48 L
+t72 49 t72 49 L
+t72 50 t72 50 L
+t72 51 t72 51 L
+t72 52 t72 52 L
+t72 53 t72 53 L
+t72 54 t72 54 L
+t72 55 t72 55 L
+t72 56 t72 56 L
+t72 57 t72 57 L
+t72 58 t72 58 L
+t72 59 t72 59 L
+t72 60 t64 60 S
+t72 61 t64 61 S
+t72 62 t64 62 S
+t72 63 t64 63 S
+t72 64 t64 64 S
+t72 65 t64 65 S
+t72 66 t64 66 S
+t72 67 t64 67 S
+t72 68 t64 68 S
+t72 69 t64 69 S
+t72 70 t64 70 S
+t72 71 t64 71 S
+t72 72 t64 72 S
+t72 73 t64 73 S
+t72 74 t64 74 S
+t72 75 t64 75 S
+t72 76 t64 76 S
+t72 77 t64 77 S
+t72 78 t64 78 S
+t72 79 t64 79 S
+t72 80 t64 80 S
+t72 81 t64 81 S
+t72 90 t64 90 S
+t72 91 t64 91 S
+t72 92 t64 92 S
+t72 93 t64 93 S
+t72 94 t64 94 S
+t51 0 t51 0 R
+t51 1 t51 1 R
+t51 2 t51 2 R
+t51 3 t51 3 R
+t51 4 t51 4 R
+t51 5 t51 5 R
+t51 6 t51 6 R
+t51 7 t51 7 R
+t51 8 t51 8 R
+t51 9 t51 9 R
+t51 10 t51 10 R
+t51 11 t51 11 R
+t51 12 t51 12 R
+t51 13 t51 13 R
+t51 14 t51 14 R
+t51 15 t51 15 R
+t51 16 t51 16 R
+t51 17 t51 17 R
+t51 18 t51 18 R
+t51 19 t51 19 R
+t51 20 t51 20 R
+t51 21 t51 21 R
+t51 22 t51 22 R
+t51 23 t51 23 R
+t51 24 t51 24 R
+t51 25 t51 25 R
+t51 26 t51 26 R
+t51 27 t51 27 R
+t51 28 t51 28 R
+t51 29 t51 29 R
+t51 30 t47 30 S
+t51 31 t47 31 S
+t51 32 t47 32 S
+t51 33 t47 33 S
+t51 34 t47 34 S
+t51 45 t47 45 S
+t51 46 t47 46 S
+t51 47 t47 47 S
+t51 48 t47 48 S
+t51 49 t47 49 S
+t67 0 t69 15 R
+t67 1 t69 16 R
+t67 2 t69 17 R
+t67 3 t69 18 R
+t67 4 t69 19 R
+t67 5 t69 20 R
+t67 6 t69 21 R
+t67 7 t69 22 R
+t67 8 t69 23 R
+t67 9 t69 24 R
+t67 10 t69 25 R
+t67 11 t69 26 R
+t67 12 t69 27 R
+t67 13 t69 28 R
+t67 14 t69 29 R
+t67 15 t69 15 R
+t67 16 t69 16 R
+t67 17 t69 17 R
+t67 18 t69 18 R
+t67 19 t69 19 R
+t67 20 t69 20 R
+t67 21 t69 21 R
+t67 22 t69 22 R
+t67 23 t69 23 R
+t67 24 t69 24 R
+t67 25 t69 25 R
+t67 26 t69 26 R
+t67 27 t69 27 R
+t67 28 t69 28 R
+t67 29 t69 29 R
+t67 30 t69 45 R
+t67 31 t69 46 R
+t67 32 t69 47 R
+t67 33 t69 48 R
+t67 34 t69 49 R
+t67 35 t69 50 R
+t67 36 t69 51 R
+t67 37 t69 52 R
+t67 38 t69 53 R
+t67 39 t69 54 R
+t67 40 t69 55 R
+t67 41 t69 56 R
+t67 42 t69 57 R
+t67 43 t69 58 R
+t67 44 t69 59 R
+t67 45 t69 45 R
+t67 46 t69 46 R
+t67 47 t69 47 R
+t67 48 t69 48 R
+t67 49 t69 49 R
+t67 50 t69 50 R
+t67 51 t69 51 R
+t67 52 t69 52 R
+t67 53 t69 53 R
+t67 54 t69 54 R
+t67 55 t69 55 R
+t67 56 t69 56 R
+t67 57 t69 57 R
+t67 58 t69 58 R
+t67 59 t69 59 R
+t67 60 t69 69 R
+t67 61 t69 70 R
+t67 62 t69 71 R
+t67 63 t69 72 R
+t67 64 t69 74 R
+t67 65 t69 75 R
+t67 66 t69 76 R
+t67 67 t69 77 R
+t67 68 t69 68 R
+t67 69 t69 69 R
+t67 70 t69 70 R
+t67 71 t69 71 R
+t67 72 t69 72 R
+t67 73 t69 73 R
+t67 74 t69 74 R
+t67 75 t69 75 R
+t67 76 t69 76 R
+t67 77 t69 77 R
+t67 78 t69 91 R
+t67 79 t69 92 R
+t67 80 t69 93 R
+t67 81 t69 94 R
+t67 90 t69 90 R
+t67 91 t69 91 R
+t67 92 t69 92 R
+t67 93 t69 93 R
+t67 94 t69 94 R
+t71 0 t72 15 R
+t71 1 t72 16 R
+t71 2 t72 17 R
+t71 3 t72 18 R
+t71 4 t72 19 R
+t71 5 t72 20 R
+t71 6 t72 21 R
+t71 7 t72 22 R
+t71 8 t72 23 R
+t71 9 t72 24 R
+t71 10 t72 25 R
+t71 11 t72 26 R
+t71 12 t72 27 R
+t71 13 t72 28 R
+t71 14 t72 29 R
+t71 15 t72 15 R
+t71 16 t72 16 R
+t71 17 t72 17 R
+t71 18 t72 18 R
+t71 19 t72 19 R
+t71 20 t72 20 R
+t71 21 t72 21 R
+t71 22 t72 22 R
+t71 23 t72 23 R
+t71 24 t72 24 R
+t71 25 t72 25 R
+t71 26 t72 26 R
+t71 27 t72 27 R
+t71 28 t72 28 R
+t71 29 t72 29 R
+t71 30 t72 45 R
+t71 31 t72 46 R
+t71 32 t72 47 R
+t71 33 t72 48 R
+t71 34 t72 49 R
+t71 35 t72 50 R
+t71 36 t72 51 R
+t71 37 t72 52 R
+t71 38 t72 53 R
+t71 39 t72 54 R
+t71 40 t72 55 R
+t71 41 t72 56 R
+t71 42 t72 57 R
+t71 43 t72 58 R
+t71 44 t72 59 R
+t71 45 t72 45 R
+t71 46 t72 46 R
+t71 47 t72 47 R
+t71 48 t72 48 R
+t71 49 t72 49 R
+t71 50 t72 50 R
+t71 51 t72 51 R
+t71 52 t72 52 R
+t71 53 t72 53 R
+t71 54 t72 54 R
+t71 55 t72 55 R
+t71 56 t72 56 R
+t71 57 t72 57 R
+t71 58 t72 58 R
+t71 59 t72 59 R
+t71 60 t72 69 R
+t71 61 t72 70 R
+t71 62 t72 71 R
+t71 63 t72 72 R
+t71 64 t72 74 R
+t71 65 t72 75 R
+t71 66 t72 76 R
+t71 67 t72 77 R
+t71 68 t72 68 R
+t71 69 t72 69 R
+t71 70 t72 70 R
+t71 71 t72 71 R
+t71 72 t72 72 R
+t71 73 t72 73 R
+t71 74 t72 74 R
+t71 75 t72 75 R
+t71 76 t72 76 R
+t71 77 t72 77 R
+t71 78 t72 91 R
+t71 79 t72 92 R
+t71 80 t72 93 R
+t71 81 t72 94 R
+t71 90 t72 90 R
+t71 91 t72 91 R
+t71 92 t72 92 R
+t71 93 t72 93 R
+t71 94 t72 94 R
+t68 0 t70 30 L
+t68 1 t70 31 L
+t68 2 t70 32 L
+t68 3 t70 33 L
+t68 4 t70 34 L
+t68 5 t70 35 L
+t68 6 t70 36 L
+t68 7 t70 37 L
+t68 8 t70 38 L
+t68 9 t70 39 L
+t68 10 t70 40 L
+t68 11 t70 41 L
+t68 12 t70 42 L
+t68 13 t70 43 L
+t68 14 t70 44 L
+t68 15 t70 45 L
+t68 16 t70 46 L
+t68 17 t70 47 L
+t68 18 t70 48 L
+t68 19 t70 49 L
+t68 20 t70 50 L
+t68 21 t70 51 L
+t68 22 t70 52 L
+t68 23 t70 53 L
+t68 24 t70 54 L
+t68 25 t70 55 L
+t68 26 t70 56 L
+t68 27 t70 57 L
+t68 28 t70 58 L
+t68 29 t70 59 L
+t68 30 t70 30 L
+t68 31 t70 31 L
+t68 32 t70 32 L
+t68 33 t70 33 L
+t68 34 t70 34 L
+t68 35 t70 35 L
+t68 36 t70 36 L
+t68 37 t70 37 L
+t68 38 t70 38 L
+t68 39 t70 39 L
+t68 40 t70 40 L
+t68 41 t70 41 L
+t68 42 t70 42 L
+t68 43 t70 43 L
+t68 44 t70 44 L
+t68 45 t70 45 L
+t68 46 t70 46 L
+t68 47 t70 47 L
+t68 48 t70 48 L
+t68 49 t70 49 L
+t68 50 t70 50 L
+t68 51 t70 51 L
+t68 52 t70 52 L
+t68 53 t70 53 L
+t68 54 t70 54 L
+t68 55 t70 55 L
+t68 56 t70 56 L
+t68 57 t70 57 L
+t68 58 t70 58 L
+t68 59 t70 59 L
+t73 0 t72 30 L
+t73 1 t72 31 L
+t73 2 t72 32 L
+t73 3 t72 33 L
+t73 4 t72 34 L
+t73 5 t72 35 L
+t73 6 t72 36 L
+t73 7 t72 37 L
+t73 8 t72 38 L
+t73 9 t72 39 L
+t73 10 t72 40 L
+t73 11 t72 41 L
+t73 12 t72 42 L
+t73 13 t72 43 L
+t73 14 t72 44 L
+t73 15 t72 45 L
+t73 16 t72 46 L
+t73 17 t72 47 L
+t73 18 t72 48 L
+t73 19 t72 49 L
+t73 20 t72 50 L
+t73 21 t72 51 L
+t73 22 t72 52 L
+t73 23 t72 53 L
+t73 24 t72 54 L
+t73 25 t72 55 L
+t73 26 t72 56 L
+t73 27 t72 57 L
+t73 28 t72 58 L
+t73 29 t72 59 L
+t73 30 t72 30 L
+t73 31 t72 31 L
+t73 32 t72 32 L
+t73 33 t72 33 L
+t73 34 t72 34 L
+t73 35 t72 35 L
+t73 36 t72 36 L
+t73 37 t72 37 L
+t73 38 t72 38 L
+t73 39 t72 39 L
+t73 40 t72 40 L
+t73 41 t72 41 L
+t73 42 t72 42 L
+t73 43 t72 43 L
+t73 44 t72 44 L
+t73 45 t72 45 L
+t73 46 t72 46 L
+t73 47 t72 47 L
+t73 48 t72 48 L
+t73 49 t72 49 L
+t73 50 t72 50 L
+t73 51 t72 51 L
+t73 52 t72 52 L
+t73 53 t72 53 L
+t73 54 t72 54 L
+t73 55 t72 55 L
+t73 56 t72 56 L
+t73 57 t72 57 L
+t73 58 t72 58 L
+t73 59 t72 59 L
+t74 15 t75 0 L
+t74 90 t76 90 S
+t76 0 t76 0 L
+t76 1 t76 1 L
+t76 2 t76 2 L
+t76 3 t76 3 L
+t76 4 t76 4 L
+t76 15 t76 15 L
+t76 16 t76 16 L
+t76 17 t76 17 L
+t76 18 t76 18 L
+t76 19 t76 19 L
+t76 78 t77 78 S
+t76 79 t77 79 S
+t76 80 t77 80 S
+t76 81 t77 81 S
+t76 90 t77 90 S
+t76 91 t77 91 S
+t76 92 t77 92 S
+t76 93 t77 93 S
+t76 94 t77 94 S
+t77 78 t40 78 S
+t77 79 t40 79 S
+t77 80 t40 80 S
+t77 81 t40 81 S
+t77 90 t40 90 S
+t77 91 t40 91 S
+t77 92 t40 92 S
+t77 93 t40 93 S
+t77 94 t40 94 S
+t78 0 t78 0 R
+t78 1 t78 1 R
+t78 2 t78 2 R
+t78 3 t78 3 R
+t78 4 t78 4 R
+t78 15 t74 15 S
+t78 16 t79 16 S
+t78 17 t80 17 S
+t78 18 t81 18 S
+t78 19 t82 19 S
+t83 78 t78 78 R
+t83 79 t78 79 R
+t83 80 t78 80 R
+t83 81 t78 81 R
+t83 90 t74 90 S
+t83 91 t79 91 S
+t83 92 t80 92 S
+t83 93 t81 93 S
+t83 94 t82 94 S
+t75 0 t76 15 R
+t75 1 t76 16 R
+t75 2 t76 17 R
+t75 3 t76 18 R
+t75 4 t76 19 R
+t75 15 t76 15 R
+t75 16 t76 16 R
+t75 17 t76 17 R
+t75 18 t76 18 R
+t75 19 t76 19 R
+t75 78 t76 91 R
+t75 79 t76 92 R
+t75 80 t76 93 R
+t75 81 t76 94 R
+t75 90 t76 90 R
+t75 91 t76 91 R
+t75 92 t76 92 R
+t75 93 t76 93 R
+t75 94 t76 94 R
+t84 78 t83 78 S
+t84 79 t83 79 S
+t84 80 t83 80 S
+t84 81 t83 81 S
+t84 90 t83 90 S
+t84 91 t83 91 S
+t84 92 t83 92 S
+t84 93 t83 93 S
+t84 94 t83 94 S
+t79 16 t85 1 R
+t79 91 t85 78 R
+t86 0 t86 0 L
+t86 1 t86 1 L
+t86 2 t86 2 L
+t86 3 t86 3 L
+t86 4 t86 4 L
+t86 15 t86 15 L
+t86 16 t86 16 L
+t86 17 t86 17 L
+t86 18 t86 18 L
+t86 19 t86 19 L
+t86 78 t87 78 S
+t86 79 t87 79 S
+t86 80 t87 80 S
+t86 81 t87 81 S
+t86 90 t87 90 S
+t86 91 t87 91 S
+t86 92 t87 92 S
+t86 93 t87 93 S
+t86 94 t87 94 S
+t87 78 t84 78 S
+t87 79 t84 79 S
+t87 80 t84 80 S
+t87 81 t84 81 S
+t87 90 t84 90 S
+t87 91 t84 91 S
+t87 92 t84 92 S
+t87 93 t84 93 S
+t87 94 t84 94 S
+t85 0 t86 15 L
+t85 1 t86 16 L
+t85 2 t86 17 L
+t85 3 t86 18 L
+t85 4 t86 19 L
+t85 15 t86 15 L
+t85 16 t86 16 L
+t85 17 t86 17 L
+t85 18 t86 18 L
+t85 19 t86 19 L
+t80 17 t88 2 R
+t80 92 t88 79 R
+t89 0 t89 0 L
+t89 1 t89 1 L
+t89 2 t89 2 L
+t89 3 t89 3 L
+t89 4 t89 4 L
+t89 15 t89 15 L
+t89 16 t89 16 L
+t89 17 t89 17 L
+t89 18 t89 18 L
+t89 19 t89 19 L
+t89 78 t90 78 S
+t89 79 t90 79 S
+t89 80 t90 80 S
+t89 81 t90 81 S
+t89 90 t90 90 S
+t89 91 t90 91 S
+t89 92 t90 92 S
+t89 93 t90 93 S
+t89 94 t90 94 S
+t90 78 t84 78 S
+t90 79 t84 79 S
+t90 80 t84 80 S
+t90 81 t84 81 S
+t90 90 t84 90 S
+t90 91 t84 91 S
+t90 92 t84 92 S
+t90 93 t84 93 S
+t90 94 t84 94 S
+t88 0 t89 15 L
+t88 1 t89 16 L
+t88 2 t89 17 L
+t88 3 t89 18 L
+t88 4 t89 19 L
+t88 15 t89 15 L
+t88 16 t89 16 L
+t88 17 t89 17 L
+t88 18 t89 18 L
+t88 19 t89 19 L
+t81 18 t91 3 R
+t81 93 t91 80 R
+t92 0 t92 0 L
+t92 1 t92 1 L
+t92 2 t92 2 L
+t92 3 t92 3 L
+t92 4 t92 4 L
+t92 15 t92 15 L
+t92 16 t92 16 L
+t92 17 t92 17 L
+t92 18 t92 18 L
+t92 19 t92 19 L
+t92 78 t93 78 S
+t92 79 t93 79 S
+t92 80 t93 80 S
+t92 81 t93 81 S
+t92 90 t93 90 S
+t92 91 t93 91 S
+t92 92 t93 92 S
+t92 93 t93 93 S
+t92 94 t93 94 S
+t93 78 t84 78 S
+t93 79 t84 79 S
+t93 80 t84 80 S
+t93 81 t84 81 S
+t93 90 t84 90 S
+t93 91 t84 91 S
+t93 92 t84 92 S
+t93 93 t84 93 S
+t93 94 t84 94 S
+t91 0 t92 15 L
+t91 1 t92 16 L
+t91 2 t92 17 L
+t91 3 t92 18 L
+t91 4 t92 19 L
+t91 15 t92 15 L
+t91 16 t92 16 L
+t91 17 t92 17 L
+t91 18 t92 18 L
+t91 19 t92 19 L
+t82 19 t94 4 R
+t82 94 t94 81 R
+t95 0 t95 0 L
+t95 1 t95 1 L
+t95 2 t95 2 L
+t95 3 t95 3 L
+t95 4 t95 4 L
+t95 15 t95 15 L
+t95 16 t95 16 L
+t95 17 t95 17 L
+t95 18 t95 18 L
+t95 19 t95 19 L
+t95 78 t96 78 S
+t95 79 t96 79 S
+t95 80 t96 80 S
+t95 81 t96 81 S
+t95 90 t96 90 S
+t95 91 t96 91 S
+t95 92 t96 92 S
+t95 93 t96 93 S
+t95 94 t96 94 S
+t96 78 t84 78 S
+t96 79 t84 79 S
+t96 80 t84 80 S
+t96 81 t84 81 S
+t96 90 t84 90 S
+t96 91 t84 91 S
+t96 92 t84 92 S
+t96 93 t84 93 S
+t96 94 t84 94 S
+t94 0 t95 15 L
+t94 1 t95 16 L
+t94 2 t95 17 L
+t94 3 t95 18 L
+t94 4 t95 19 L
+t94 15 t95 15 L
+t94 16 t95 16 L
+t94 17 t95 17 L
+t94 18 t95 18 L
+t94 19 t95 19 L
+t97 15 t98 0 L
+t97 20 t98 5 L
+t97 25 t98 10 L
+t97 30 t99 0 L
+t97 31 t99 1 L
+t97 32 t99 2 L
+t97 33 t99 3 L
+t97 34 t99 4 L
+t97 45 t98 30 L
+t97 46 t98 30 L
+t97 47 t98 30 L
+t97 48 t98 30 L
+t97 49 t98 30 L
+t97 50 t98 35 L
+t97 55 t98 40 L
+t97 90 t100 90 S
+t101 0 t101 0 L
+t101 1 t101 1 L
+t101 2 t101 2 L
+t101 3 t101 3 L
+t101 4 t101 4 L
+t101 5 t101 5 L
+t101 6 t101 6 L
+t101 7 t101 7 L
+t101 8 t101 8 L
+t101 9 t101 9 L
+t101 10 t101 10 L
+t101 11 t101 11 L
+t101 12 t101 12 L
+t101 13 t101 13 L
+t101 14 t101 14 L
+t101 15 t102 0 L
+t101 16 t102 0 L
+t101 17 t102 0 L
+t101 18 t102 0 L
+t101 19 t102 0 L
+t101 20 t102 5 L
+t101 21 t102 5 L
+t101 22 t102 5 L
+t101 23 t102 5 L
+t101 24 t102 5 L
+t101 25 t102 10 L
+t101 26 t102 10 L
+t101 27 t102 10 L
+t101 28 t102 10 L
+t101 29 t102 10 L
+t101 30 t101 30 L
+t101 31 t101 31 L
+t101 32 t101 32 L
+t101 33 t101 33 L
+t101 34 t101 34 L
+t101 35 t101 35 L
+t101 36 t101 36 L
+t101 37 t101 37 L
+t101 38 t101 38 L
+t101 39 t101 39 L
+t101 40 t101 40 L
+t101 41 t101 41 L
+t101 42 t101 42 L
+t101 43 t101 43 L
+t101 44 t101 44 L
+t101 45 t102 30 L
+t101 46 t102 30 L
+t101 47 t102 30 L
+t101 48 t102 30 L
+t101 49 t102 30 L
+t101 50 t102 35 L
+t101 51 t102 35 L
+t101 52 t102 35 L
+t101 53 t102 35 L
+t101 54 t102 35 L
+t101 55 t102 40 L
+t101 56 t102 40 L
+t101 57 t102 40 L
+t101 58 t102 40 L
+t101 59 t102 40 L
+t101 60 t7 60 S
+t101 61 t7 61 S
+t101 62 t7 62 S
+t101 63 t7 63 S
+t101 64 t7 64 S
+t101 65 t7 65 S
+t101 66 t7 66 S
+t101 67 t7 67 S
+t101 68 t103 68 S
+t101 69 t103 68 S
+t101 70 t103 68 S
+t101 71 t103 68 S
+t101 72 t103 68 S
+t101 73 t103 73 S
+t101 74 t103 73 S
+t101 75 t103 73 S
+t101 76 t103 73 S
+t101 77 t103 73 S
+t101 78 t7 78 S
+t101 79 t7 79 S
+t101 80 t7 80 S
+t101 81 t7 81 S
+t101 82 t7 82 S
+t101 83 t7 83 S
+t101 84 t7 84 S
+t101 85 t7 85 S
+t101 86 t7 86 S
+t101 87 t7 87 S
+t101 88 t7 88 S
+t101 89 t7 89 S
+t101 90 t103 90 S
+t101 91 t103 90 S
+t101 92 t103 90 S
+t101 93 t103 90 S
+t101 94 t103 90 S
+t101 95 t103 95 S
+t101 96 t103 95 S
+t101 97 t103 95 S
+t101 98 t103 95 S
+t101 99 t103 95 S
+t101 100 t103 100 S
+t101 101 t103 100 S
+t101 102 t103 100 S
+t101 103 t103 100 S
+t101 104 t103 100 S
+t100 0 t100 0 L
+t100 1 t100 1 L
+t100 2 t100 2 L
+t100 3 t100 3 L
+t100 4 t100 4 L
+t100 5 t100 5 L
+t100 6 t100 6 L
+t100 7 t100 7 L
+t100 8 t100 8 L
+t100 9 t100 9 L
+t100 10 t100 10 L
+t100 11 t100 11 L
+t100 12 t100 12 L
+t100 13 t100 13 L
+t100 14 t100 14 L
+t100 15 t100 15 L
+t100 16 t100 16 L
+t100 17 t100 17 L
+t100 18 t100 18 L
+t100 19 t100 19 L
+t100 20 t100 20 L
+t100 21 t100 21 L
+t100 22 t100 22 L
+t100 23 t100 23 L
+t100 24 t100 24 L
+t100 25 t100 25 L
+t100 26 t100 26 L
+t100 27 t100 27 L
+t100 28 t100 28 L
+t100 29 t100 29 L
+t100 30 t104 0 L
+t100 31 t104 1 L
+t100 32 t104 2 L
+t100 33 t104 3 L
+t100 34 t104 4 L
+t100 35 t104 0 L
+t100 36 t104 1 L
+t100 37 t104 2 L
+t100 38 t104 3 L
+t100 39 t104 4 L
+t100 40 t104 0 L
+t100 41 t104 1 L
+t100 42 t104 2 L
+t100 43 t104 3 L
+t100 44 t104 4 L
+t100 45 t104 15 L
+t100 46 t104 16 L
+t100 47 t104 17 L
+t100 48 t104 18 L
+t100 49 t104 19 L
+t100 50 t104 15 L
+t100 51 t104 16 L
+t100 52 t104 17 L
+t100 53 t104 18 L
+t100 54 t104 19 L
+t100 55 t104 15 L
+t100 56 t104 16 L
+t100 57 t104 17 L
+t100 58 t104 18 L
+t100 59 t104 19 L
+t100 60 t7 60 S
+t100 61 t7 61 S
+t100 62 t7 62 S
+t100 63 t7 63 S
+t100 64 t7 64 S
+t100 65 t7 65 S
+t100 66 t7 66 S
+t100 67 t7 67 S
+t100 68 t7 68 S
+t100 69 t7 69 S
+t100 70 t7 70 S
+t100 71 t7 71 S
+t100 72 t7 72 S
+t100 73 t7 73 S
+t100 74 t7 74 S
+t100 75 t7 75 S
+t100 76 t7 76 S
+t100 77 t7 77 S
+t100 78 t103 78 S
+t100 79 t103 79 S
+t100 80 t103 80 S
+t100 81 t103 81 S
+t100 90 t103 90 S
+t100 91 t103 91 S
+t100 92 t103 92 S
+t100 93 t103 93 S
+t100 94 t103 94 S
+t103 0 t103 0 L
+t103 1 t103 1 L
+t103 2 t103 2 L
+t103 3 t103 3 L
+t103 4 t103 4 L
+t103 5 t103 5 L
+t103 6 t103 6 L
+t103 7 t103 7 L
+t103 8 t103 8 L
+t103 9 t103 9 L
+t103 10 t103 10 L
+t103 11 t103 11 L
+t103 12 t103 12 L
+t103 13 t103 13 L
+t103 14 t103 14 L
+t103 15 t103 15 L
+t103 16 t103 16 L
+t103 17 t103 17 L
+t103 18 t103 18 L
+t103 19 t103 19 L
+t103 20 t103 20 L
+t103 21 t103 21 L
+t103 22 t103 22 L
+t103 23 t103 23 L
+t103 24 t103 24 L
+t103 25 t103 25 L
+t103 26 t103 26 L
+t103 27 t103 27 L
+t103 28 t103 28 L
+t103 29 t103 29 L
+t103 30 t103 30 L
+t103 31 t103 31 L
+t103 32 t103 32 L
+t103 33 t103 33 L
+t103 34 t103 34 L
+t103 35 t103 35 L
+t103 36 t103 36 L
+t103 37 t103 37 L
+t103 38 t103 38 L
+t103 39 t103 39 L
+t103 40 t103 40 L
+t103 41 t103 41 L
+t103 42 t103 42 L
+t103 43 t103 43 L
+t103 44 t103 44 L
+t103 45 t103 45 L
+t103 46 t103 46 L
+t103 47 t103 47 L
+t103 48 t103 48 L
+t103 49 t103 49 L
+t103 50 t103 50 L
+t103 51 t103 51 L
+t103 52 t103 52 L
+t103 53 t103 53 L
+t103 54 t103 54 L
+t103 55 t103 55 L
+t103 56 t103 56 L
+t103 57 t103 57 L
+t103 58 t103 58 L
+t103 59 t103 59 L
+t103 60 t7 60 S
+t103 61 t7 61 S
+t103 62 t7 62 S
+t103 63 t7 63 S
+t103 64 t7 64 S
+t103 65 t7 65 S
+t103 66 t7 66 S
+t103 67 t7 67 S
+t103 68 t7 68 S
+t103 69 t7 69 S
+t103 70 t7 70 S
+t103 71 t7 71 S
+t103 72 t7 72 S
+t103 73 t7 73 S
+t103 74 t7 74 S
+t103 75 t7 75 S
+t103 76 t7 76 S
+t103 77 t7 77 S
+t103 78 t7 78 S
+t103 79 t7 79 S
+t103 80 t7 80 S
+t103 81 t7 81 S
+t103 82 t7 82 S
+t103 83 t7 83 S
+t103 84 t7 84 S
+t103 85 t7 85 S
+t103 86 t7 86 S
+t103 87 t7 87 S
+t103 88 t7 88 S
+t103 89 t7 89 S
+t103 90 t7 90 S
+t103 91 t7 91 S
+t103 92 t7 92 S
+t103 93 t7 93 S
+t103 94 t7 94 S
+t103 95 t7 95 S
+t103 96 t7 96 S
+t103 97 t7 97 S
+t103 98 t7 98 S
+t103 99 t7 99 S
+t103 100 t7 100 S
+t103 101 t7 101 S
+t103 102 t7 102 S
+t103 103 t7 103 S
+t103 104 t7 104 S
+t105 0 t105 0 R
+t105 1 t105 1 R
+t105 2 t105 2 R
+t105 3 t105 3 R
+t105 4 t105 4 R
+t105 5 t105 5 R
+t105 6 t105 6 R
+t105 7 t105 7 R
+t105 8 t105 8 R
+t105 9 t105 9 R
+t105 10 t105 10 R
+t105 11 t105 11 R
+t105 12 t105 12 R
+t105 13 t105 13 R
+t105 14 t105 14 R
+t105 15 t105 15 R
+t105 16 t105 16 R
+t105 17 t105 17 R
+t105 18 t105 18 R
+t105 19 t105 19 R
+t105 20 t105 20 R
+t105 21 t105 21 R
+t105 22 t105 22 R
+t105 23 t105 23 R
+t105 24 t105 24 R
+t105 25 t105 25 R
+t105 26 t105 26 R
+t105 27 t105 27 R
+t105 28 t105 28 R
+t105 29 t105 29 R
+t105 30 t97 30 S
+t105 31 t97 31 S
+t105 32 t97 32 S
+t105 33 t97 33 S
+t105 34 t97 34 S
+t105 45 t97 45 S
+t105 46 t97 46 S
+t105 47 t97 47 S
+t105 48 t97 48 S
+t105 49 t97 49 S
+t106 0 t106 0 R
+t106 1 t106 1 R
+t106 2 t106 2 R
+t106 3 t106 3 R
+t106 4 t106 4 R
+t106 5 t106 5 R
+t106 6 t106 6 R
+t106 7 t106 7 R
+t106 8 t106 8 R
+t106 9 t106 9 R
+t106 10 t106 10 R
+t106 11 t106 11 R
+t106 12 t106 12 R
+t106 13 t106 13 R
+t106 14 t106 14 R
+t106 15 t97 15 S
+t106 16 t107 16 S
+t106 17 t108 17 S
+t106 18 t109 18 S
+t106 19 t110 19 S
+t106 20 t97 20 S
+t106 21 t107 21 S
+t106 22 t108 22 S
+t106 23 t109 23 S
+t106 24 t110 24 S
+t106 25 t97 25 S
+t106 26 t107 26 S
+t106 27 t108 27 S
+t106 28 t109 28 S
+t106 29 t110 29 S
+t106 30 t106 30 R
+t106 31 t106 31 R
+t106 32 t106 32 R
+t106 33 t106 33 R
+t106 34 t106 34 R
+t106 35 t106 35 R
+t106 36 t106 36 R
+t106 37 t106 37 R
+t106 38 t106 38 R
+t106 39 t106 39 R
+t106 40 t106 40 R
+t106 41 t106 41 R
+t106 42 t106 42 R
+t106 43 t106 43 R
+t106 44 t106 44 R
+t106 45 t97 45 S
+t106 46 t107 46 S
+t106 47 t108 47 S
+t106 48 t109 48 S
+t106 49 t110 49 S
+t106 50 t97 50 S
+t106 51 t107 51 S
+t106 52 t108 52 S
+t106 53 t109 53 S
+t106 54 t110 54 S
+t106 55 t97 55 S
+t106 56 t107 56 S
+t106 57 t108 57 S
+t106 58 t109 58 S
+t106 59 t110 59 S
+t111 0 t111 0 R
+t111 1 t111 1 R
+t111 2 t111 2 R
+t111 3 t111 3 R
+t111 4 t111 4 R
+t111 5 t111 5 R
+t111 6 t111 6 R
+t111 7 t111 7 R
+t111 8 t111 8 R
+t111 9 t111 9 R
+t111 10 t111 10 R
+t111 11 t111 11 R
+t111 12 t111 12 R
+t111 13 t111 13 R
+t111 14 t111 14 R
+t111 15 t105 15 R
+t111 16 t112 16 R
+t111 17 t113 17 R
+t111 18 t114 18 R
+t111 19 t115 19 R
+t111 20 t105 20 R
+t111 21 t112 21 R
+t111 22 t113 22 R
+t111 23 t114 23 R
+t111 24 t115 24 R
+t111 25 t105 25 R
+t111 26 t112 26 R
+t111 27 t113 27 R
+t111 28 t114 28 R
+t111 29 t115 29 R
+t111 30 t106 30 R
+t111 31 t106 31 R
+t111 32 t106 32 R
+t111 33 t106 33 R
+t111 34 t106 34 R
+t111 45 t97 45 S
+t111 46 t107 46 S
+t111 47 t108 47 S
+t111 48 t109 48 S
+t111 49 t110 49 S
+t111 60 t111 60 R
+t111 61 t111 61 R
+t111 62 t111 62 R
+t111 63 t111 63 R
+t111 64 t111 64 R
+t111 65 t111 65 R
+t111 66 t111 66 R
+t111 67 t111 67 R
+t111 68 t105 68 R
+t111 69 t112 69 R
+t111 70 t113 70 R
+t111 71 t114 71 R
+t111 72 t115 72 R
+t111 73 t105 73 R
+t111 74 t112 74 R
+t111 75 t113 75 R
+t111 76 t114 76 R
+t111 77 t115 77 R
+t111 78 t106 78 R
+t111 79 t106 79 R
+t111 80 t106 80 R
+t111 81 t106 81 R
+t111 90 t97 90 S
+t111 91 t107 91 S
+t111 92 t108 92 S
+t111 93 t109 93 S
+t111 94 t110 94 S
+t98 0 t100 15 R
+t98 1 t100 16 R
+t98 2 t100 17 R
+t98 3 t100 18 R
+t98 4 t100 19 R
+t98 5 t100 20 R
+t98 6 t100 21 R
+t98 7 t100 22 R
+t98 8 t100 23 R
+t98 9 t100 24 R
+t98 10 t100 25 R
+t98 11 t100 26 R
+t98 12 t100 27 R
+t98 13 t100 28 R
+t98 14 t100 29 R
+t98 15 t100 15 R
+t98 16 t100 16 R
+t98 17 t100 17 R
+t98 18 t100 18 R
+t98 19 t100 19 R
+t98 20 t100 20 R
+t98 21 t100 21 R
+t98 22 t100 22 R
+t98 23 t100 23 R
+t98 24 t100 24 R
+t98 25 t100 25 R
+t98 26 t100 26 R
+t98 27 t100 27 R
+t98 28 t100 28 R
+t98 29 t100 29 R
+t98 30 t100 45 R
+t98 31 t100 46 R
+t98 32 t100 47 R
+t98 33 t100 48 R
+t98 34 t100 49 R
+t98 35 t100 50 R
+t98 36 t100 51 R
+t98 37 t100 52 R
+t98 38 t100 53 R
+t98 39 t100 54 R
+t98 40 t100 55 R
+t98 41 t100 56 R
+t98 42 t100 57 R
+t98 43 t100 58 R
+t98 44 t100 59 R
+t98 45 t100 45 R
+t98 46 t100 46 R
+t98 47 t100 47 R
+t98 48 t100 48 R
+t98 49 t100 49 R
+t98 50 t100 50 R
+t98 51 t100 51 R
+t98 52 t100 52 R
+t98 53 t100 53 R
+t98 54 t100 54 R
+t98 55 t100 55 R
+t98 56 t100 56 R
+t98 57 t100 57 R
+t98 58 t100 58 R
+t98 59 t100 59 R
+t98 60 t100 69 R
+t98 61 t100 70 R
+t98 62 t100 71 R
+t98 63 t100 72 R
+t98 64 t100 74 R
+t98 65 t100 75 R
+t98 66 t100 76 R
+t98 67 t100 77 R
+t98 68 t100 68 R
+t98 69 t100 69 R
+t98 70 t100 70 R
+t98 71 t100 71 R
+t98 72 t100 72 R
+t98 73 t100 73 R
+t98 74 t100 74 R
+t98 75 t100 75 R
+t98 76 t100 76 R
+t98 77 t100 77 R
+t98 78 t100 91 R
+t98 79 t100 92 R
+t98 80 t100 93 R
+t98 81 t100 94 R
+t98 90 t100 90 R
+t98 91 t100 91 R
+t98 92 t100 92 R
+t98 93 t100 93 R
+t98 94 t100 94 R
+t102 0 t103 15 R
+t102 1 t103 16 R
+t102 2 t103 17 R
+t102 3 t103 18 R
+t102 4 t103 19 R
+t102 5 t103 20 R
+t102 6 t103 21 R
+t102 7 t103 22 R
+t102 8 t103 23 R
+t102 9 t103 24 R
+t102 10 t103 25 R
+t102 11 t103 26 R
+t102 12 t103 27 R
+t102 13 t103 28 R
+t102 14 t103 29 R
+t102 15 t103 15 R
+t102 16 t103 16 R
+t102 17 t103 17 R
+t102 18 t103 18 R
+t102 19 t103 19 R
+t102 20 t103 20 R
+t102 21 t103 21 R
+t102 22 t103 22 R
+t102 23 t103 23 R
+t102 24 t103 24 R
+t102 25 t103 25 R
+t102 26 t103 26 R
+t102 27 t103 27 R
+t102 28 t103 28 R
+t102 29 t103 29 R
+t102 30 t103 45 R
+t102 31 t103 46 R
+t102 32 t103 47 R
+t102 33 t103 48 R
+t102 34 t103 49 R
+t102 35 t103 50 R
+t102 36 t103 51 R
+t102 37 t103 52 R
+t102 38 t103 53 R
+t102 39 t103 54 R
+t102 40 t103 55 R
+t102 41 t103 56 R
+t102 42 t103 57 R
+t102 43 t103 58 R
+t102 44 t103 59 R
+t102 45 t103 45 R
+t102 46 t103 46 R
+t102 47 t103 47 R
+t102 48 t103 48 R
+t102 49 t103 49 R
+t102 50 t103 50 R
+t102 51 t103 51 R
+t102 52 t103 52 R
+t102 53 t103 53 R
+t102 54 t103 54 R
+t102 55 t103 55 R
+t102 56 t103 56 R
+t102 57 t103 57 R
+t102 58 t103 58 R
+t102 59 t103 59 R
+t102 60 t103 69 R
+t102 61 t103 70 R
+t102 62 t103 71 R
+t102 63 t103 72 R
+t102 64 t103 74 R
+t102 65 t103 75 R
+t102 66 t103 76 R
+t102 67 t103 77 R
+t102 68 t103 68 R
+t102 69 t103 69 R
+t102 70 t103 70 R
+t102 71 t103 71 R
+t102 72 t103 72 R
+t102 73 t103 73 R
+t102 74 t103 74 R
+t102 75 t103 75 R
+t102 76 t103 76 R
+t102 77 t103 77 R
+t102 78 t103 91 R
+t102 79 t103 92 R
+t102 80 t103 93 R
+t102 81 t103 94 R
+t102 82 t103 96 R
+t102 83 t103 97 R
+t102 84 t103 98 R
+t102 85 t103 99 R
+t102 86 t103 101 R
+t102 87 t103 102 R
+t102 88 t103 103 R
+t102 89 t103 104 R
+t102 90 t103 90 R
+t102 91 t103 91 R
+t102 92 t103 92 R
+t102 93 t103 93 R
+t102 94 t103 94 R
+t102 95 t103 95 R
+t102 96 t103 96 R
+t102 97 t103 97 R
+t102 98 t103 98 R
+t102 99 t103 99 R
+t102 100 t103 100 R
+t102 101 t103 101 R
+t102 102 t103 102 R
+t102 103 t103 103 R
+t102 104 t103 104 R
+t99 0 t101 30 R
+t99 1 t101 31 R
+t99 2 t101 32 R
+t99 3 t101 33 R
+t99 4 t101 34 R
+t99 5 t101 35 R
+t99 6 t101 36 R
+t99 7 t101 37 R
+t99 8 t101 38 R
+t99 9 t101 39 R
+t99 10 t101 40 R
+t99 11 t101 41 R
+t99 12 t101 42 R
+t99 13 t101 43 R
+t99 14 t101 44 R
+t99 15 t101 45 R
+t99 16 t101 46 R
+t99 17 t101 47 R
+t99 18 t101 48 R
+t99 19 t101 49 R
+t99 20 t101 50 R
+t99 21 t101 51 R
+t99 22 t101 52 R
+t99 23 t101 53 R
+t99 24 t101 54 R
+t99 25 t101 55 R
+t99 26 t101 56 R
+t99 27 t101 57 R
+t99 28 t101 58 R
+t99 29 t101 59 R
+t99 30 t101 30 R
+t99 31 t101 31 R
+t99 32 t101 32 R
+t99 33 t101 33 R
+t99 34 t101 34 R
+t99 35 t101 35 R
+t99 36 t101 36 R
+t99 37 t101 37 R
+t99 38 t101 38 R
+t99 39 t101 39 R
+t99 40 t101 40 R
+t99 41 t101 41 R
+t99 42 t101 42 R
+t99 43 t101 43 R
+t99 44 t101 44 R
+t99 45 t101 45 R
+t99 46 t101 46 R
+t99 47 t101 47 R
+t99 48 t101 48 R
+t99 49 t101 49 R
+t99 50 t101 50 R
+t99 51 t101 51 R
+t99 52 t101 52 R
+t99 53 t101 53 R
+t99 54 t101 54 R
+t99 55 t101 55 R
+t99 56 t101 56 R
+t99 57 t101 57 R
+t99 58 t101 58 R
+t99 59 t101 59 R
+t99 60 t101 82 R
+t99 61 t101 83 R
+t99 62 t101 84 R
+t99 63 t101 85 R
+t99 64 t101 86 R
+t99 65 t101 87 R
+t99 66 t101 88 R
+t99 67 t101 89 R
+t99 68 t101 95 R
+t99 69 t101 96 R
+t99 70 t101 97 R
+t99 71 t101 98 R
+t99 72 t101 99 R
+t99 73 t101 100 R
+t99 74 t101 101 R
+t99 75 t101 102 R
+t99 76 t101 103 R
+t99 77 t101 104 R
+t99 78 t101 78 R
+t99 79 t101 79 R
+t99 80 t101 80 R
+t99 81 t101 81 R
+t99 90 t101 90 R
+t99 91 t101 91 R
+t99 92 t101 92 R
+t99 93 t101 93 R
+t99 94 t101 94 R
+t104 0 t103 30 R
+t104 1 t103 31 R
+t104 2 t103 32 R
+t104 3 t103 33 R
+t104 4 t103 34 R
+t104 5 t103 35 R
+t104 6 t103 36 R
+t104 7 t103 37 R
+t104 8 t103 38 R
+t104 9 t103 39 R
+t104 10 t103 40 R
+t104 11 t103 41 R
+t104 12 t103 42 R
+t104 13 t103 43 R
+t104 14 t103 44 R
+t104 15 t103 45 R
+t104 16 t103 46 R
+t104 17 t103 47 R
+t104 18 t103 48 R
+t104 19 t103 49 R
+t104 20 t103 50 R
+t104 21 t103 51 R
+t104 22 t103 52 R
+t104 23 t103 53 R
+t104 24 t103 54 R
+t104 25 t103 55 R
+t104 26 t103 56 R
+t104 27 t103 57 R
+t104 28 t103 58 R
+t104 29 t103 59 R
+t104 30 t103 30 R
+t104 31 t103 31 R
+t104 32 t103 32 R
+t104 33 t103 33 R
+t104 34 t103 34 R
+t104 35 t103 35 R
+t104 36 t103 36 R
+t104 37 t103 37 R
+t104 38 t103 38 R
+t104 39 t103 39 R
+t104 40 t103 40 R
+t104 41 t103 41 R
+t104 42 t103 42 R
+t104 43 t103 43 R
+t104 44 t103 44 R
+t104 45 t103 45 R
+t104 46 t103 46 R
+t104 47 t103 47 R
+t104 48 t103 48 R
+t104 49 t103 49 R
+t104 50 t103 50 R
+t104 51 t103 51 R
+t104 52 t103 52 R
+t104 53 t103 53 R
+t104 54 t103 54 R
+t104 55 t103 55 R
+t104 56 t103 56 R
+t104 57 t103 57 R
+t104 58 t103 58 R
+t104 59 t103 59 R
+t104 60 t103 82 R
+t104 61 t103 83 R
+t104 62 t103 84 R
+t104 63 t103 85 R
+t104 64 t103 86 R
+t104 65 t103 87 R
+t104 66 t103 88 R
+t104 67 t103 89 R
+t104 68 t103 95 R
+t104 69 t103 96 R
+t104 70 t103 97 R
+t104 71 t103 98 R
+t104 72 t103 99 R
+t104 73 t103 100 R
+t104 74 t103 101 R
+t104 75 t103 102 R
+t104 76 t103 103 R
+t104 77 t103 104 R
+t104 78 t103 78 R
+t104 79 t103 79 R
+t104 80 t103 80 R
+t104 81 t103 81 R
+t104 90 t103 90 R
+t104 91 t103 91 R
+t104 92 t103 92 R
+t104 93 t103 93 R
+t104 94 t103 94 R
+t64 60 t111 60 S
+t64 61 t111 61 S
+t64 62 t111 62 S
+t64 63 t111 63 S
+t64 64 t111 64 S
+t64 65 t111 65 S
+t64 66 t111 66 S
+t64 67 t111 67 S
+t64 68 t111 68 S
+t64 69 t111 69 S
+t64 70 t111 70 S
+t64 71 t111 71 S
+t64 72 t111 72 S
+t64 73 t111 73 S
+t64 74 t111 74 S
+t64 75 t111 75 S
+t64 76 t111 76 S
+t64 77 t111 77 S
+t64 78 t111 78 S
+t64 79 t111 79 S
+t64 80 t111 80 S
+t64 81 t111 81 S
+t64 90 t111 90 S
+t64 91 t111 91 S
+t64 92 t111 92 S
+t64 93 t111 93 S
+t64 94 t111 94 S
+t107 16 t116 1 R
+t107 21 t116 6 R
+t107 26 t116 11 R
+t107 30 t117 30 S
+t107 31 t117 31 S
+t107 32 t117 32 S
+t107 33 t117 33 S
+t107 34 t117 34 S
+t107 45 t116 31 R
+t107 46 t116 31 R
+t107 47 t116 31 R
+t107 48 t116 31 R
+t107 49 t116 31 R
+t107 51 t116 36 R
+t107 56 t116 41 R
+t107 91 t116 78 R
+t117 0 t117 0 L
+t117 1 t117 1 L
+t117 2 t117 2 L
+t117 3 t117 3 L
+t117 4 t117 4 L
+t117 5 t117 5 L
+t117 6 t117 6 L
+t117 7 t117 7 L
+t117 8 t117 8 L
+t117 9 t117 9 L
+t117 10 t117 10 L
+t117 11 t117 11 L
+t117 12 t117 12 L
+t117 13 t117 13 L
+t117 14 t117 14 L
+t117 15 t118 1 R
+t117 16 t118 1 R
+t117 17 t118 1 R
+t117 18 t118 1 R
+t117 19 t118 1 R
+t117 20 t118 6 R
+t117 21 t118 6 R
+t117 22 t118 6 R
+t117 23 t118 6 R
+t117 24 t118 6 R
+t117 25 t118 11 R
+t117 26 t118 11 R
+t117 27 t118 11 R
+t117 28 t118 11 R
+t117 29 t118 11 R
+t117 30 t117 30 L
+t117 31 t117 31 L
+t117 32 t117 32 L
+t117 33 t117 33 L
+t117 34 t117 34 L
+t117 35 t117 35 L
+t117 36 t117 36 L
+t117 37 t117 37 L
+t117 38 t117 38 L
+t117 39 t117 39 L
+t117 40 t117 40 L
+t117 41 t117 41 L
+t117 42 t117 42 L
+t117 43 t117 43 L
+t117 44 t117 44 L
+t117 45 t118 31 R
+t117 46 t118 31 R
+t117 47 t118 31 R
+t117 48 t118 31 R
+t117 49 t118 31 R
+t117 50 t118 36 R
+t117 51 t118 36 R
+t117 52 t118 36 R
+t117 53 t118 36 R
+t117 54 t118 36 R
+t117 55 t118 41 R
+t117 56 t118 41 R
+t117 57 t118 41 R
+t117 58 t118 41 R
+t117 59 t118 41 R
+t117 60 t64 60 S
+t117 61 t64 61 S
+t117 62 t64 62 S
+t117 63 t64 63 S
+t117 64 t64 64 S
+t117 65 t64 65 S
+t117 66 t64 66 S
+t117 67 t64 67 S
+t117 68 t118 60 R
+t117 69 t118 60 R
+t117 70 t118 60 R
+t117 71 t118 60 R
+t117 72 t118 60 R
+t117 73 t118 64 R
+t117 74 t118 64 R
+t117 75 t118 64 R
+t117 76 t118 64 R
+t117 77 t118 64 R
+t117 78 t64 78 S
+t117 79 t64 79 S
+t117 80 t64 80 S
+t117 81 t64 81 S
+t117 90 t118 78 R
+t117 91 t118 78 R
+t117 92 t118 78 R
+t117 93 t118 78 R
+t117 94 t118 78 R
+t119 0 t119 0 L
+t119 1 t119 1 L
+t119 2 t119 2 L
+t119 3 t119 3 L
+t119 4 t119 4 L
+t119 5 t119 5 L
+t119 6 t119 6 L
+t119 7 t119 7 L
+t119 8 t119 8 L
+t119 9 t119 9 L
+t119 10 t119 10 L
+t119 11 t119 11 L
+t119 12 t119 12 L
+t119 13 t119 13 L
+t119 14 t119 14 L
+t119 15 t119 15 L
+t119 16 t119 16 L
+t119 17 t119 17 L
+t119 18 t119 18 L
+t119 19 t119 19 L
+t119 20 t119 20 L
+t119 21 t119 21 L
+t119 22 t119 22 L
+t119 23 t119 23 L
+t119 24 t119 24 L
+t119 25 t119 25 L
+t119 26 t119 26 L
+t119 27 t119 27 L
+t119 28 t119 28 L
+t119 29 t119 29 L
+t119 30 t120 30 S
+t119 31 t120 31 S
+t119 32 t120 32 S
+t119 33 t120 33 S
+t119 34 t120 34 S
+t119 35 t120 30 S
+t119 36 t120 31 S
+t119 37 t120 32 S
+t119 38 t120 33 S
+t119 39 t120 34 S
+t119 40 t120 30 S
+t119 41 t120 31 S
+t119 42 t120 32 S
+t119 43 t120 33 S
+t119 44 t120 34 S
+t119 45 t120 45 S
+t119 46 t120 46 S
+t119 47 t120 47 S
+t119 48 t120 48 S
+t119 49 t120 49 S
+t119 50 t120 45 S
+t119 51 t120 46 S
+t119 52 t120 47 S
+t119 53 t120 48 S
+t119 54 t120 49 S
+t119 55 t120 45 S
+t119 56 t120 46 S
+t119 57 t120 47 S
+t119 58 t120 48 S
+t119 59 t120 49 S
+t119 60 t64 60 S
+t119 61 t64 61 S
+t119 62 t64 62 S
+t119 63 t64 63 S
+t119 64 t64 64 S
+t119 65 t64 65 S
+t119 66 t64 66 S
+t119 67 t64 67 S
+t119 68 t64 68 S
+t119 69 t64 69 S
+t119 70 t64 70 S
+t119 71 t64 71 S
+t119 72 t64 72 S
+t119 73 t64 73 S
+t119 74 t64 74 S
+t119 75 t64 75 S
+t119 76 t64 76 S
+t119 77 t64 77 S
+t119 78 t120 78 S
+t119 79 t120 79 S
+t119 80 t120 80 S
+t119 81 t120 81 S
+t119 90 t120 90 S
+t119 91 t120 91 S
+t119 92 t120 92 S
+t119 93 t120 93 S
+t119 94 t120 94 S
+t120 0 t120 0 L
+t120 1 t120 1 L
+t120 2 t120 2 L
+t120 3 t120 3 L
+t120 4 t120 4 L
+t120 5 t120 5 L
+t120 6 t120 6 L
+t120 7 t120 7 L
+t120 8 t120 8 L
+t120 9 t120 9 L
+t120 10 t120 10 L
+t120 11 t120 11 L
+t120 12 t120 12 L
+t120 13 t120 13 L
+t120 14 t120 14 L
+t120 15 t120 15 L
+t120 16 t120 16 L
+t120 17 t120 17 L
+t120 18 t120 18 L
+t120 19 t120 19 L
+t120 20 t120 20 L
+t120 21 t120 21 L
+t120 22 t120 22 L
+t120 23 t120 23 L
+t120 24 t120 24 L
+t120 25 t120 25 L
+t120 26 t120 26 L
+t120 27 t120 27 L
+t120 28 t120 28 L
+t120 29 t120 29 L
+t120 30 t120 30 L
+t120 31 t120 31 L
+t120 32 t120 32 L
+t120 33 t120 33 L
+t120 34 t120 34 L
+t120 35 t120 35 L
+t120 36 t120 36 L
+t120 37 t120 37 L
+t120 38 t120 38 L
+t120 39 t120 39 L
+t120 40 t120 40 L
+t120 41 t120 41 L
+t120 42 t120 42 L
+t120 43 t120 43 L
+t120 44 t120 44 L
+t120 45 t120 45 L
+t120 46 t120 46 L
+t120 47 t120 47 L
+t120 48 t120 48 L
+t120 49 t120 49 L
+t120 50 t120 50 L
+t120 51 t120 51 L
+t120 52 t120 52 L
+t120 53 t120 53 L
+t120 54 t120 54 L
+t120 55 t120 55 L
+t120 56 t120 56 L
+t120 57 t120 57 L
+t120 58 t120 58 L
+t120 59 t120 59 L
+t120 60 t64 60 S
+t120 61 t64 61 S
+t120 62 t64 62 S
+t120 63 t64 63 S
+t120 64 t64 64 S
+t120 65 t64 65 S
+t120 66 t64 66 S
+t120 67 t64 67 S
+t120 68 t64 68 S
+t120 69 t64 69 S
+t120 70 t64 70 S
+t120 71 t64 71 S
+t120 72 t64 72 S
+t120 73 t64 73 S
+t120 74 t64 74 S
+t120 75 t64 75 S
+t120 76 t64 76 S
+t120 77 t64 77 S
+t120 78 t64 78 S
+t120 79 t64 79 S
+t120 80 t64 80 S
+t120 81 t64 81 S
+t120 90 t64 90 S
+t120 91 t64 91 S
+t120 92 t64 92 S
+t120 93 t64 93 S
+t120 94 t64 94 S
+t112 0 t112 0 R
+t112 1 t112 1 R
+t112 2 t112 2 R
+t112 3 t112 3 R
+t112 4 t112 4 R
+t112 5 t112 5 R
+t112 6 t112 6 R
+t112 7 t112 7 R
+t112 8 t112 8 R
+t112 9 t112 9 R
+t112 10 t112 10 R
+t112 11 t112 11 R
+t112 12 t112 12 R
+t112 13 t112 13 R
+t112 14 t112 14 R
+t112 15 t112 15 R
+t112 16 t112 16 R
+t112 17 t112 17 R
+t112 18 t112 18 R
+t112 19 t112 19 R
+t112 20 t112 20 R
+t112 21 t112 21 R
+t112 22 t112 22 R
+t112 23 t112 23 R
+t112 24 t112 24 R
+t112 25 t112 25 R
+t112 26 t112 26 R
+t112 27 t112 27 R
+t112 28 t112 28 R
+t112 29 t112 29 R
+t112 30 t107 30 S
+t112 31 t107 31 S
+t112 32 t107 32 S
+t112 33 t107 33 S
+t112 34 t107 34 S
+t112 45 t107 45 S
+t112 46 t107 46 S
+t112 47 t107 47 S
+t112 48 t107 48 S
+t112 49 t107 49 S
+t116 0 t119 15 L
+t116 1 t119 16 L
+t116 2 t119 17 L
+t116 3 t119 18 L
+t116 4 t119 19 L
+t116 5 t119 20 L
+t116 6 t119 21 L
+t116 7 t119 22 L
+t116 8 t119 23 L
+t116 9 t119 24 L
+t116 10 t119 25 L
+t116 11 t119 26 L
+t116 12 t119 27 L
+t116 13 t119 28 L
+t116 14 t119 29 L
+t116 15 t119 15 L
+t116 16 t119 16 L
+t116 17 t119 17 L
+t116 18 t119 18 L
+t116 19 t119 19 L
+t116 20 t119 20 L
+t116 21 t119 21 L
+t116 22 t119 22 L
+t116 23 t119 23 L
+t116 24 t119 24 L
+t116 25 t119 25 L
+t116 26 t119 26 L
+t116 27 t119 27 L
+t116 28 t119 28 L
+t116 29 t119 29 L
+t116 30 t119 45 L
+t116 31 t119 46 L
+t116 32 t119 47 L
+t116 33 t119 48 L
+t116 34 t119 49 L
+t116 35 t119 50 L
+t116 36 t119 51 L
+t116 37 t119 52 L
+t116 38 t119 53 L
+t116 39 t119 54 L
+t116 40 t119 55 L
+t116 41 t119 56 L
+t116 42 t119 57 L
+t116 43 t119 58 L
+t116 44 t119 59 L
+t116 45 t119 45 L
+t116 46 t119 46 L
+t116 47 t119 47 L
+t116 48 t119 48 L
+t116 49 t119 49 L
+t116 50 t119 50 L
+t116 51 t119 51 L
+t116 52 t119 52 L
+t116 53 t119 53 L
+t116 54 t119 54 L
+t116 55 t119 55 L
+t116 56 t119 56 L
+t116 57 t119 57 L
+t116 58 t119 58 L
+t116 59 t119 59 L
+t118 0 t120 15 L
+t118 1 t120 16 L
+t118 2 t120 17 L
+t118 3 t120 18 L
+t118 4 t120 19 L
+t118 5 t120 20 L
+t118 6 t120 21 L
+t118 7 t120 22 L
+t118 8 t120 23 L
+t118 9 t120 24 L
+t118 10 t120 25 L
+t118 11 t120 26 L
+t118 12 t120 27 L
+t118 13 t120 28 L
+t118 14 t120 29 L
+t118 15 t120 15 L
+t118 16 t120 16 L
+t118 17 t120 17 L
+t118 18 t120 18 L
+t118 19 t120 19 L
+t118 20 t120 20 L
+t118 21 t120 21 L
+t118 22 t120 22 L
+t118 23 t120 23 L
+t118 24 t120 24 L
+t118 25 t120 25 L
+t118 26 t120 26 L
+t118 27 t120 27 L
+t118 28 t120 28 L
+t118 29 t120 29 L
+t118 30 t120 45 L
+t118 31 t120 46 L
+t118 32 t120 47 L
+t118 33 t120 48 L
+t118 34 t120 49 L
+t118 35 t120 50 L
+t118 36 t120 51 L
+t118 37 t120 52 L
+t118 38 t120 53 L
+t118 39 t120 54 L
+t118 40 t120 55 L
+t118 41 t120 56 L
+t118 42 t120 57 L
+t118 43 t120 58 L
+t118 44 t120 59 L
+t118 45 t120 45 L
+t118 46 t120 46 L
+t118 47 t120 47 L
+t118 48 t120 48 L
+t118 49 t120 49 L
+t118 50 t120 50 L
+t118 51 t120 51 L
+t118 52 t120 52 L
+t118 53 t120 53 L
+t118 54 t120 54 L
+t118 55 t120 55 L
+t118 56 t120 56 L
+t118 57 t120 57 L
+t118 58 t120 58 L
+t118 59 t120 59 L
+t108 17 t121 2 R
+t108 22 t121 7 R
+t108 27 t121 12 R
+t108 30 t122 30 S
+t108 31 t122 31 S
+t108 32 t122 32 S
+t108 33 t122 33 S
+t108 34 t122 34 S
+t108 45 t121 32 R
+t108 46 t121 32 R
+t108 47 t121 32 R
+t108 48 t121 32 R
+t108 49 t121 32 R
+t108 52 t121 37 R
+t108 57 t121 42 R
+t108 92 t121 79 R
+t122 0 t122 0 L
+t122 1 t122 1 L
+t122 2 t122 2 L
+t122 3 t122 3 L
+t122 4 t122 4 L
+t122 5 t122 5 L
+t122 6 t122 6 L
+t122 7 t122 7 L
+t122 8 t122 8 L
+t122 9 t122 9 L
+t122 10 t122 10 L
+t122 11 t122 11 L
+t122 12 t122 12 L
+t122 13 t122 13 L
+t122 14 t122 14 L
+t122 15 t123 2 R
+t122 16 t123 2 R
+t122 17 t123 2 R
+t122 18 t123 2 R
+t122 19 t123 2 R
+t122 20 t123 7 R
+t122 21 t123 7 R
+t122 22 t123 7 R
+t122 23 t123 7 R
+t122 24 t123 7 R
+t122 25 t123 12 R
+t122 26 t123 12 R
+t122 27 t123 12 R
+t122 28 t123 12 R
+t122 29 t123 12 R
+t122 30 t122 30 L
+t122 31 t122 31 L
+t122 32 t122 32 L
+t122 33 t122 33 L
+t122 34 t122 34 L
+t122 35 t122 35 L
+t122 36 t122 36 L
+t122 37 t122 37 L
+t122 38 t122 38 L
+t122 39 t122 39 L
+t122 40 t122 40 L
+t122 41 t122 41 L
+t122 42 t122 42 L
+t122 43 t122 43 L
+t122 44 t122 44 L
+t122 45 t123 32 R
+t122 46 t123 32 R
+t122 47 t123 32 R
+t122 48 t123 32 R
+t122 49 t123 32 R
+t122 50 t123 37 R
+t122 51 t123 37 R
+t122 52 t123 37 R
+t122 53 t123 37 R
+t122 54 t123 37 R
+t122 55 t123 42 R
+t122 56 t123 42 R
+t122 57 t123 42 R
+t122 58 t123 42 R
+t122 59 t123 42 R
+t122 60 t64 60 S
+t122 61 t64 61 S
+t122 62 t64 62 S
+t122 63 t64 63 S
+t122 64 t64 64 S
+t122 65 t64 65 S
+t122 66 t64 66 S
+t122 67 t64 67 S
+t122 68 t123 61 R
+t122 69 t123 61 R
+t122 70 t123 61 R
+t122 71 t123 61 R
+t122 72 t123 61 R
+t122 73 t123 65 R
+t122 74 t123 65 R
+t122 75 t123 65 R
+t122 76 t123 65 R
+t122 77 t123 65 R
+t122 78 t64 78 S
+t122 79 t64 79 S
+t122 80 t64 80 S
+t122 81 t64 81 S
+t122 90 t123 79 R
+t122 91 t123 79 R
+t122 92 t123 79 R
+t122 93 t123 79 R
+t122 94 t123 79 R
+t124 0 t124 0 L
+t124 1 t124 1 L
+t124 2 t124 2 L
+t124 3 t124 3 L
+t124 4 t124 4 L
+t124 5 t124 5 L
+t124 6 t124 6 L
+t124 7 t124 7 L
+t124 8 t124 8 L
+t124 9 t124 9 L
+t124 10 t124 10 L
+t124 11 t124 11 L
+t124 12 t124 12 L
+t124 13 t124 13 L
+t124 14 t124 14 L
+t124 15 t124 15 L
+t124 16 t124 16 L
+t124 17 t124 17 L
+t124 18 t124 18 L
+t124 19 t124 19 L
+t124 20 t124 20 L
+t124 21 t124 21 L
+t124 22 t124 22 L
+t124 23 t124 23 L
+t124 24 t124 24 L
+t124 25 t124 25 L
+t124 26 t124 26 L
+t124 27 t124 27 L
+t124 28 t124 28 L
+t124 29 t124 29 L
+t124 30 t125 30 S
+t124 31 t125 31 S
+t124 32 t125 32 S
+t124 33 t125 33 S
+t124 34 t125 34 S
+t124 35 t125 30 S
+t124 36 t125 31 S
+t124 37 t125 32 S
+t124 38 t125 33 S
+t124 39 t125 34 S
+t124 40 t125 30 S
+t124 41 t125 31 S
+t124 42 t125 32 S
+t124 43 t125 33 S
+t124 44 t125 34 S
+t124 45 t125 45 S
+t124 46 t125 46 S
+t124 47 t125 47 S
+t124 48 t125 48 S
+t124 49 t125 49 S
+t124 50 t125 45 S
+t124 51 t125 46 S
+t124 52 t125 47 S
+t124 53 t125 48 S
+t124 54 t125 49 S
+t124 55 t125 45 S
+t124 56 t125 46 S
+t124 57 t125 47 S
+t124 58 t125 48 S
+t124 59 t125 49 S
+t124 60 t64 60 S
+t124 61 t64 61 S
+t124 62 t64 62 S
+t124 63 t64 63 S
+t124 64 t64 64 S
+t124 65 t64 65 S
+t124 66 t64 66 S
+t124 67 t64 67 S
+t124 68 t64 68 S
+t124 69 t64 69 S
+t124 70 t64 70 S
+t124 71 t64 71 S
+t124 72 t64 72 S
+t124 73 t64 73 S
+t124 74 t64 74 S
+t124 75 t64 75 S
+t124 76 t64 76 S
+t124 77 t64 77 S
+t124 78 t125 78 S
+t124 79 t125 79 S
+t124 80 t125 80 S
+t124 81 t125 81 S
+t124 90 t125 90 S
+t124 91 t125 91 S
+t124 92 t125 92 S
+t124 93 t125 93 S
+t124 94 t125 94 S
+t125 0 t125 0 L
+t125 1 t125 1 L
+t125 2 t125 2 L
+t125 3 t125 3 L
+t125 4 t125 4 L
+t125 5 t125 5 L
+t125 6 t125 6 L
+t125 7 t125 7 L
+t125 8 t125 8 L
+t125 9 t125 9 L
+t125 10 t125 10 L
+t125 11 t125 11 L
+t125 12 t125 12 L
+t125 13 t125 13 L
+t125 14 t125 14 L
+t125 15 t125 15 L
+t125 16 t125 16 L
+t125 17 t125 17 L
+t125 18 t125 18 L
+t125 19 t125 19 L
+t125 20 t125 20 L
+t125 21 t125 21 L
+t125 22 t125 22 L
+t125 23 t125 23 L
+t125 24 t125 24 L
+t125 25 t125 25 L
+t125 26 t125 26 L
+t125 27 t125 27 L
+t125 28 t125 28 L
+t125 29 t125 29 L
+t125 30 t125 30 L
+t125 31 t125 31 L
+t125 32 t125 32 L
+t125 33 t125 33 L
+t125 34 t125 34 L
+t125 35 t125 35 L
+t125 36 t125 36 L
+t125 37 t125 37 L
+t125 38 t125 38 L
+t125 39 t125 39 L
+t125 40 t125 40 L
+t125 41 t125 41 L
+t125 42 t125 42 L
+t125 43 t125 43 L
+t125 44 t125 44 L
+t125 45 t125 45 L
+t125 46 t125 46 L
+t125 47 t125 47 L
+t125 48 t125 48 L
+t125 49 t125 49 L
+t125 50 t125 50 L
+t125 51 t125 51 L
+t125 52 t125 52 L
+t125 53 t125 53 L
+t125 54 t125 54 L
+t125 55 t125 55 L
+t125 56 t125 56 L
+t125 57 t125 57 L
+t125 58 t125 58 L
+t125 59 t125 59 L
+t125 60 t64 60 S
+t125 61 t64 61 S
+t125 62 t64 62 S
+t125 63 t64 63 S
+t125 64 t64 64 S
+t125 65 t64 65 S
+t125 66 t64 66 S
+t125 67 t64 67 S
+t125 68 t64 68 S
+t125 69 t64 69 S
+t125 70 t64 70 S
+t125 71 t64 71 S
+t125 72 t64 72 S
+t125 73 t64 73 S
+t125 74 t64 74 S
+t125 75 t64 75 S
+t125 76 t64 76 S
+t125 77 t64 77 S
+t125 78 t64 78 S
+t125 79 t64 79 S
+t125 80 t64 80 S
+t125 81 t64 81 S
+t125 90 t64 90 S
+t125 91 t64 91 S
+t125 92 t64 92 S
+t125 93 t64 93 S
+t125 94 t64 94 S
+t113 0 t113 0 R
+t113 1 t113 1 R
+t113 2 t113 2 R
+t113 3 t113 3 R
+t113 4 t113 4 R
+t113 5 t113 5 R
+t113 6 t113 6 R
+t113 7 t113 7 R
+t113 8 t113 8 R
+t113 9 t113 9 R
+t113 10 t113 10 R
+t113 11 t113 11 R
+t113 12 t113 12 R
+t113 13 t113 13 R
+t113 14 t113 14 R
+t113 15 t113 15 R
+t113 16 t113 16 R
+t113 17 t113 17 R
+t113 18 t113 18 R
+t113 19 t113 19 R
+t113 20 t113 20 R
+t113 21 t113 21 R
+t113 22 t113 22 R
+t113 23 t113 23 R
+t113 24 t113 24 R
+t113 25 t113 25 R
+t113 26 t113 26 R
+t113 27 t113 27 R
+t113 28 t113 28 R
+t113 29 t113 29 R
+t113 30 t108 30 S
+t113 31 t108 31 S
+t113 32 t108 32 S
+t113 33 t108 33 S
+t113 34 t108 34 S
+t113 45 t108 45 S
+t113 46 t108 46 S
+t113 47 t108 47 S
+t113 48 t108 48 S
+t113 49 t108 49 S
+t121 0 t124 15 L
+t121 1 t124 16 L
+t121 2 t124 17 L
+t121 3 t124 18 L
+t121 4 t124 19 L
+t121 5 t124 20 L
+t121 6 t124 21 L
+t121 7 t124 22 L
+t121 8 t124 23 L
+t121 9 t124 24 L
+t121 10 t124 25 L
+t121 11 t124 26 L
+t121 12 t124 27 L
+t121 13 t124 28 L
+t121 14 t124 29 L
+t121 15 t124 15 L
+t121 16 t124 16 L
+t121 17 t124 17 L
+t121 18 t124 18 L
+t121 19 t124 19 L
+t121 20 t124 20 L
+t121 21 t124 21 L
+t121 22 t124 22 L
+t121 23 t124 23 L
+t121 24 t124 24 L
+t121 25 t124 25 L
+t121 26 t124 26 L
+t121 27 t124 27 L
+t121 28 t124 28 L
+t121 29 t124 29 L
+t121 30 t124 45 L
+t121 31 t124 46 L
+t121 32 t124 47 L
+t121 33 t124 48 L
+t121 34 t124 49 L
+t121 35 t124 50 L
+t121 36 t124 51 L
+t121 37 t124 52 L
+t121 38 t124 53 L
+t121 39 t124 54 L
+t121 40 t124 55 L
+t121 41 t124 56 L
+t121 42 t124 57 L
+t121 43 t124 58 L
+t121 44 t124 59 L
+t121 45 t124 45 L
+t121 46 t124 46 L
+t121 47 t124 47 L
+t121 48 t124 48 L
+t121 49 t124 49 L
+t121 50 t124 50 L
+t121 51 t124 51 L
+t121 52 t124 52 L
+t121 53 t124 53 L
+t121 54 t124 54 L
+t121 55 t124 55 L
+t121 56 t124 56 L
+t121 57 t124 57 L
+t121 58 t124 58 L
+t121 59 t124 59 L
+t123 0 t125 15 L
+t123 1 t125 16 L
+t123 2 t125 17 L
+t123 3 t125 18 L
+t123 4 t125 19 L
+t123 5 t125 20 L
+t123 6 t125 21 L
+t123 7 t125 22 L
+t123 8 t125 23 L
+t123 9 t125 24 L
+t123 10 t125 25 L
+t123 11 t125 26 L
+t123 12 t125 27 L
+t123 13 t125 28 L
+t123 14 t125 29 L
+t123 15 t125 15 L
+t123 16 t125 16 L
+t123 17 t125 17 L
+t123 18 t125 18 L
+t123 19 t125 19 L
+t123 20 t125 20 L
+t123 21 t125 21 L
+t123 22 t125 22 L
+t123 23 t125 23 L
+t123 24 t125 24 L
+t123 25 t125 25 L
+t123 26 t125 26 L
+t123 27 t125 27 L
+t123 28 t125 28 L
+t123 29 t125 29 L
+t123 30 t125 45 L
+t123 31 t125 46 L
+t123 32 t125 47 L
+t123 33 t125 48 L
+t123 34 t125 49 L
+t123 35 t125 50 L
+t123 36 t125 51 L
+t123 37 t125 52 L
+t123 38 t125 53 L
+t123 39 t125 54 L
+t123 40 t125 55 L
+t123 41 t125 56 L
+t123 42 t125 57 L
+t123 43 t125 58 L
+t123 44 t125 59 L
+t123 45 t125 45 L
+t123 46 t125 46 L
+t123 47 t125 47 L
+t123 48 t125 48 L
+t123 49 t125 49 L
+t123 50 t125 50 L
+t123 51 t125 51 L
+t123 52 t125 52 L
+t123 53 t125 53 L
+t123 54 t125 54 L
+t123 55 t125 55 L
+t123 56 t125 56 L
+t123 57 t125 57 L
+t123 58 t125 58 L
+t123 59 t125 59 L
+t109 18 t126 3 R
+t109 23 t126 8 R
+t109 28 t126 13 R
+t109 30 t127 30 S
+t109 31 t127 31 S
+t109 32 t127 32 S
+t109 33 t127 33 S
+t109 34 t127 34 S
+t109 45 t126 33 R
+t109 46 t126 33 R
+t109 47 t126 33 R
+t109 48 t126 33 R
+t109 49 t126 33 R
+t109 53 t126 38 R
+t109 58 t126 43 R
+t109 93 t126 80 R
+t127 0 t127 0 L
+t127 1 t127 1 L
+t127 2 t127 2 L
+t127 3 t127 3 L
+t127 4 t127 4 L
+t127 5 t127 5 L
+t127 6 t127 6 L
+t127 7 t127 7 L
+t127 8 t127 8 L
+t127 9 t127 9 L
+t127 10 t127 10 L
+t127 11 t127 11 L
+t127 12 t127 12 L
+t127 13 t127 13 L
+t127 14 t127 14 L
+t127 15 t128 3 R
+t127 16 t128 3 R
+t127 17 t128 3 R
+t127 18 t128 3 R
+t127 19 t128 3 R
+t127 20 t128 8 R
+t127 21 t128 8 R
+t127 22 t128 8 R
+t127 23 t128 8 R
+t127 24 t128 8 R
+t127 25 t128 13 R
+t127 26 t128 13 R
+t127 27 t128 13 R
+t127 28 t128 13 R
+t127 29 t128 13 R
+t127 30 t127 30 L
+t127 31 t127 31 L
+t127 32 t127 32 L
+t127 33 t127 33 L
+t127 34 t127 34 L
+t127 35 t127 35 L
+t127 36 t127 36 L
+t127 37 t127 37 L
+t127 38 t127 38 L
+t127 39 t127 39 L
+t127 40 t127 40 L
+t127 41 t127 41 L
+t127 42 t127 42 L
+t127 43 t127 43 L
+t127 44 t127 44 L
+t127 45 t128 33 R
+t127 46 t128 33 R
+t127 47 t128 33 R
+t127 48 t128 33 R
+t127 49 t128 33 R
+t127 50 t128 38 R
+t127 51 t128 38 R
+t127 52 t128 38 R
+t127 53 t128 38 R
+t127 54 t128 38 R
+t127 55 t128 43 R
+t127 56 t128 43 R
+t127 57 t128 43 R
+t127 58 t128 43 R
+t127 59 t128 43 R
+t127 60 t64 60 S
+t127 61 t64 61 S
+t127 62 t64 62 S
+t127 63 t64 63 S
+t127 64 t64 64 S
+t127 65 t64 65 S
+t127 66 t64 66 S
+t127 67 t64 67 S
+t127 68 t128 62 R
+t127 69 t128 62 R
+t127 70 t128 62 R
+t127 71 t128 62 R
+t127 72 t128 62 R
+t127 73 t128 66 R
+t127 74 t128 66 R
+t127 75 t128 66 R
+t127 76 t128 66 R
+t127 77 t128 66 R
+t127 78 t64 78 S
+t127 79 t64 79 S
+t127 80 t64 80 S
+t127 81 t64 81 S
+t127 90 t128 80 R
+t127 91 t128 80 R
+t127 92 t128 80 R
+t127 93 t128 80 R
+t127 94 t128 80 R
+t129 0 t129 0 L
+t129 1 t129 1 L
+t129 2 t129 2 L
+t129 3 t129 3 L
+t129 4 t129 4 L
+t129 5 t129 5 L
+t129 6 t129 6 L
+t129 7 t129 7 L
+t129 8 t129 8 L
+t129 9 t129 9 L
+t129 10 t129 10 L
+t129 11 t129 11 L
+t129 12 t129 12 L
+t129 13 t129 13 L
+t129 14 t129 14 L
+t129 15 t129 15 L
+t129 16 t129 16 L
+t129 17 t129 17 L
+t129 18 t129 18 L
+t129 19 t129 19 L
+t129 20 t129 20 L
+t129 21 t129 21 L
+t129 22 t129 22 L
+t129 23 t129 23 L
+t129 24 t129 24 L
+t129 25 t129 25 L
+t129 26 t129 26 L
+t129 27 t129 27 L
+t129 28 t129 28 L
+t129 29 t129 29 L
+t129 30 t130 30 S
+t129 31 t130 31 S
+t129 32 t130 32 S
+t129 33 t130 33 S
+t129 34 t130 34 S
+t129 35 t130 30 S
+t129 36 t130 31 S
+t129 37 t130 32 S
+t129 38 t130 33 S
+t129 39 t130 34 S
+t129 40 t130 30 S
+t129 41 t130 31 S
+t129 42 t130 32 S
+t129 43 t130 33 S
+t129 44 t130 34 S
+t129 45 t130 45 S
+t129 46 t130 46 S
+t129 47 t130 47 S
+t129 48 t130 48 S
+t129 49 t130 49 S
+t129 50 t130 45 S
+t129 51 t130 46 S
+t129 52 t130 47 S
+t129 53 t130 48 S
+t129 54 t130 49 S
+t129 55 t130 45 S
+t129 56 t130 46 S
+t129 57 t130 47 S
+t129 58 t130 48 S
+t129 59 t130 49 S
+t129 60 t64 60 S
+t129 61 t64 61 S
+t129 62 t64 62 S
+t129 63 t64 63 S
+t129 64 t64 64 S
+t129 65 t64 65 S
+t129 66 t64 66 S
+t129 67 t64 67 S
+t129 68 t64 68 S
+t129 69 t64 69 S
+t129 70 t64 70 S
+t129 71 t64 71 S
+t129 72 t64 72 S
+t129 73 t64 73 S
+t129 74 t64 74 S
+t129 75 t64 75 S
+t129 76 t64 76 S
+t129 77 t64 77 S
+t129 78 t130 78 S
+t129 79 t130 79 S
+t129 80 t130 80 S
+t129 81 t130 81 S
+t129 90 t130 90 S
+t129 91 t130 91 S
+t129 92 t130 92 S
+t129 93 t130 93 S
+t129 94 t130 94 S
+t130 0 t130 0 L
+t130 1 t130 1 L
+t130 2 t130 2 L
+t130 3 t130 3 L
+t130 4 t130 4 L
+t130 5 t130 5 L
+t130 6 t130 6 L
+t130 7 t130 7 L
+t130 8 t130 8 L
+t130 9 t130 9 L
+t130 10 t130 10 L
+t130 11 t130 11 L
+t130 12 t130 12 L
+t130 13 t130 13 L
+t130 14 t130 14 L
+t130 15 t130 15 L
+t130 16 t130 16 L
+t130 17 t130 17 L
+t130 18 t130 18 L
+t130 19 t130 19 L
+t130 20 t130 20 L
+t130 21 t130 21 L
+t130 22 t130 22 L
+t130 23 t130 23 L
+t130 24 t130 24 L
+t130 25 t130 25 L
+t130 26 t130 26 L
+t130 27 t130 27 L
+t130 28 t130 28 L
+t130 29 t130 29 L
+t130 30 t130 30 L
+t130 31 t130 31 L
+t130 32 t130 32 L
+t130 33 t130 33 L
+t130 34 t130 34 L
+t130 35 t130 35 L
+t130 36 t130 36 L
+t130 37 t130 37 L
+t130 38 t130 38 L
+t130 39 t130 39 L
+t130 40 t130 40 L
+t130 41 t130 41 L
+t130 42 t130 42 L
+t130 43 t130 43 L
+t130 44 t130 44 L
+t130 45 t130 45 L
+t130 46 t130 46 L
+t130 47 t130 47 L
+t130 48 t130 48 L
+t130 49 t130 49 L
+t130 50 t130 50 L
+t130 51 t130 51 L
+t130 52 t130 52 L
+t130 53 t130 53 L
+t130 54 t130 54 L
+t130 55 t130 55 L
+t130 56 t130 56 L
+t130 57 t130 57 L
+t130 58 t130 58 L
+t130 59 t130 59 L
+t130 60 t64 60 S
+t130 61 t64 61 S
+t130 62 t64 62 S
+t130 63 t64 63 S
+t130 64 t64 64 S
+t130 65 t64 65 S
+t130 66 t64 66 S
+t130 67 t64 67 S
+t130 68 t64 68 S
+t130 69 t64 69 S
+t130 70 t64 70 S
+t130 71 t64 71 S
+t130 72 t64 72 S
+t130 73 t64 73 S
+t130 74 t64 74 S
+t130 75 t64 75 S
+t130 76 t64 76 S
+t130 77 t64 77 S
+t130 78 t64 78 S
+t130 79 t64 79 S
+t130 80 t64 80 S
+t130 81 t64 81 S
+t130 90 t64 90 S
+t130 91 t64 91 S
+t130 92 t64 92 S
+t130 93 t64 93 S
+t130 94 t64 94 S
+t114 0 t114 0 R
+t114 1 t114 1 R
+t114 2 t114 2 R
+t114 3 t114 3 R
+t114 4 t114 4 R
+t114 5 t114 5 R
+t114 6 t114 6 R
+t114 7 t114 7 R
+t114 8 t114 8 R
+t114 9 t114 9 R
+t114 10 t114 10 R
+t114 11 t114 11 R
+t114 12 t114 12 R
+t114 13 t114 13 R
+t114 14 t114 14 R
+t114 15 t114 15 R
+t114 16 t114 16 R
+t114 17 t114 17 R
+t114 18 t114 18 R
+t114 19 t114 19 R
+t114 20 t114 20 R
+t114 21 t114 21 R
+t114 22 t114 22 R
+t114 23 t114 23 R
+t114 24 t114 24 R
+t114 25 t114 25 R
+t114 26 t114 26 R
+t114 27 t114 27 R
+t114 28 t114 28 R
+t114 29 t114 29 R
+t114 30 t109 30 S
+t114 31 t109 31 S
+t114 32 t109 32 S
+t114 33 t109 33 S
+t114 34 t109 34 S
+t114 45 t109 45 S
+t114 46 t109 46 S
+t114 47 t109 47 S
+t114 48 t109 48 S
+t114 49 t109 49 S
+t126 0 t129 15 L
+t126 1 t129 16 L
+t126 2 t129 17 L
+t126 3 t129 18 L
+t126 4 t129 19 L
+t126 5 t129 20 L
+t126 6 t129 21 L
+t126 7 t129 22 L
+t126 8 t129 23 L
+t126 9 t129 24 L
+t126 10 t129 25 L
+t126 11 t129 26 L
+t126 12 t129 27 L
+t126 13 t129 28 L
+t126 14 t129 29 L
+t126 15 t129 15 L
+t126 16 t129 16 L
+t126 17 t129 17 L
+t126 18 t129 18 L
+t126 19 t129 19 L
+t126 20 t129 20 L
+t126 21 t129 21 L
+t126 22 t129 22 L
+t126 23 t129 23 L
+t126 24 t129 24 L
+t126 25 t129 25 L
+t126 26 t129 26 L
+t126 27 t129 27 L
+t126 28 t129 28 L
+t126 29 t129 29 L
+t126 30 t129 45 L
+t126 31 t129 46 L
+t126 32 t129 47 L
+t126 33 t129 48 L
+t126 34 t129 49 L
+t126 35 t129 50 L
+t126 36 t129 51 L
+t126 37 t129 52 L
+t126 38 t129 53 L
+t126 39 t129 54 L
+t126 40 t129 55 L
+t126 41 t129 56 L
+t126 42 t129 57 L
+t126 43 t129 58 L
+t126 44 t129 59 L
+t126 45 t129 45 L
+t126 46 t129 46 L
+t126 47 t129 47 L
+t126 48 t129 48 L
+t126 49 t129 49 L
+t126 50 t129 50 L
+t126 51 t129 51 L
+t126 52 t129 52 L
+t126 53 t129 53 L
+t126 54 t129 54 L
+t126 55 t129 55 L
+t126 56 t129 56 L
+t126 57 t129 57 L
+t126 58 t129 58 L
+t126 59 t129 59 L
+t128 0 t130 15 L
+t128 1 t130 16 L
+t128 2 t130 17 L
+t128 3 t130 18 L
+t128 4 t130 19 L
+t128 5 t130 20 L
+t128 6 t130 21 L
+t128 7 t130 22 L
+t128 8 t130 23 L
+t128 9 t130 24 L
+t128 10 t130 25 L
+t128 11 t130 26 L
+t128 12 t130 27 L
+t128 13 t130 28 L
+t128 14 t130 29 L
+t128 15 t130 15 L
+t128 16 t130 16 L
+t128 17 t130 17 L
+t128 18 t130 18 L
+t128 19 t130 19 L
+t128 20 t130 20 L
+t128 21 t130 21 L
+t128 22 t130 22 L
+t128 23 t130 23 L
+t128 24 t130 24 L
+t128 25 t130 25 L
+t128 26 t130 26 L
+t128 27 t130 27 L
+t128 28 t130 28 L
+t128 29 t130 29 L
+t128 30 t130 45 L
+t128 31 t130 46 L
+t128 32 t130 47 L
+t128 33 t130 48 L
+t128 34 t130 49 L
+t128 35 t130 50 L
+t128 36 t130 51 L
+t128 37 t130 52 L
+t128 38 t130 53 L
+t128 39 t130 54 L
+t128 40 t130 55 L
+t128 41 t130 56 L
+t128 42 t130 57 L
+t128 43 t130 58 L
+t128 44 t130 59 L
+t128 45 t130 45 L
+t128 46 t130 46 L
+t128 47 t130 47 L
+t128 48 t130 48 L
+t128 49 t130 49 L
+t128 50 t130 50 L
+t128 51 t130 51 L
+t128 52 t130 52 L
+t128 53 t130 53 L
+t128 54 t130 54 L
+t128 55 t130 55 L
+t128 56 t130 56 L
+t128 57 t130 57 L
+t128 58 t130 58 L
+t128 59 t130 59 L
+t110 19 t131 4 R
+t110 24 t131 9 R
+t110 29 t131 14 R
+t110 30 t132 30 S
+t110 31 t132 31 S
+t110 32 t132 32 S
+t110 33 t132 33 S
+t110 34 t132 34 S
+t110 45 t131 34 R
+t110 46 t131 34 R
+t110 47 t131 34 R
+t110 48 t131 34 R
+t110 49 t131 34 R
+t110 54 t131 39 R
+t110 59 t131 44 R
+t110 94 t131 81 R
+t132 0 t132 0 L
+t132 1 t132 1 L
+t132 2 t132 2 L
+t132 3 t132 3 L
+t132 4 t132 4 L
+t132 5 t132 5 L
+t132 6 t132 6 L
+t132 7 t132 7 L
+t132 8 t132 8 L
+t132 9 t132 9 L
+t132 10 t132 10 L
+t132 11 t132 11 L
+t132 12 t132 12 L
+t132 13 t132 13 L
+t132 14 t132 14 L
+t132 15 t133 4 R
+t132 16 t133 4 R
+t132 17 t133 4 R
+t132 18 t133 4 R
+t132 19 t133 4 R
+t132 20 t133 9 R
+t132 21 t133 9 R
+t132 22 t133 9 R
+t132 23 t133 9 R
+t132 24 t133 9 R
+t132 25 t133 14 R
+t132 26 t133 14 R
+t132 27 t133 14 R
+t132 28 t133 14 R
+t132 29 t133 14 R
+t132 30 t132 30 L
+t132 31 t132 31 L
+t132 32 t132 32 L
+t132 33 t132 33 L
+t132 34 t132 34 L
+t132 35 t132 35 L
+t132 36 t132 36 L
+t132 37 t132 37 L
+t132 38 t132 38 L
+t132 39 t132 39 L
+t132 40 t132 40 L
+t132 41 t132 41 L
+t132 42 t132 42 L
+t132 43 t132 43 L
+t132 44 t132 44 L
+t132 45 t133 34 R
+t132 46 t133 34 R
+t132 47 t133 34 R
+t132 48 t133 34 R
+t132 49 t133 34 R
+t132 50 t133 39 R
+t132 51 t133 39 R
+t132 52 t133 39 R
+t132 53 t133 39 R
+t132 54 t133 39 R
+t132 55 t133 44 R
+t132 56 t133 44 R
+t132 57 t133 44 R
+t132 58 t133 44 R
+t132 59 t133 44 R
+t132 60 t64 60 S
+t132 61 t64 61 S
+t132 62 t64 62 S
+t132 63 t64 63 S
+t132 64 t64 64 S
+t132 65 t64 65 S
+t132 66 t64 66 S
+t132 67 t64 67 S
+t132 68 t133 63 R
+t132 69 t133 63 R
+t132 70 t133 63 R
+t132 71 t133 63 R
+t132 72 t133 63 R
+t132 73 t133 67 R
+t132 74 t133 67 R
+t132 75 t133 67 R
+t132 76 t133 67 R
+t132 77 t133 67 R
+t132 78 t64 78 S
+t132 79 t64 79 S
+t132 80 t64 80 S
+t132 81 t64 81 S
+t132 90 t133 81 R
+t132 91 t133 81 R
+t132 92 t133 81 R
+t132 93 t133 81 R
+t132 94 t133 81 R
+t134 0 t134 0 L
+t134 1 t134 1 L
+t134 2 t134 2 L
+t134 3 t134 3 L
+t134 4 t134 4 L
+t134 5 t134 5 L
+t134 6 t134 6 L
+t134 7 t134 7 L
+t134 8 t134 8 L
+t134 9 t134 9 L
+t134 10 t134 10 L
+t134 11 t134 11 L
+t134 12 t134 12 L
+t134 13 t134 13 L
+t134 14 t134 14 L
+t134 15 t134 15 L
+t134 16 t134 16 L
+t134 17 t134 17 L
+t134 18 t134 18 L
+t134 19 t134 19 L
+t134 20 t134 20 L
+t134 21 t134 21 L
+t134 22 t134 22 L
+t134 23 t134 23 L
+t134 24 t134 24 L
+t134 25 t134 25 L
+t134 26 t134 26 L
+t134 27 t134 27 L
+t134 28 t134 28 L
+t134 29 t134 29 L
+t134 30 t135 30 S
+t134 31 t135 31 S
+t134 32 t135 32 S
+t134 33 t135 33 S
+t134 34 t135 34 S
+t134 35 t135 30 S
+t134 36 t135 31 S
+t134 37 t135 32 S
+t134 38 t135 33 S
+t134 39 t135 34 S
+t134 40 t135 30 S
+t134 41 t135 31 S
+t134 42 t135 32 S
+t134 43 t135 33 S
+t134 44 t135 34 S
+t134 45 t135 45 S
+t134 46 t135 46 S
+t134 47 t135 47 S
+t134 48 t135 48 S
+t134 49 t135 49 S
+t134 50 t135 45 S
+t134 51 t135 46 S
+t134 52 t135 47 S
+t134 53 t135 48 S
+t134 54 t135 49 S
+t134 55 t135 45 S
+t134 56 t135 46 S
+t134 57 t135 47 S
+t134 58 t135 48 S
+t134 59 t135 49 S
+t134 60 t64 60 S
+t134 61 t64 61 S
+t134 62 t64 62 S
+t134 63 t64 63 S
+t134 64 t64 64 S
+t134 65 t64 65 S
+t134 66 t64 66 S
+t134 67 t64 67 S
+t134 68 t64 68 S
+t134 69 t64 69 S
+t134 70 t64 70 S
+t134 71 t64 71 S
+t134 72 t64 72 S
+t134 73 t64 73 S
+t134 74 t64 74 S
+t134 75 t64 75 S
+t134 76 t64 76 S
+t134 77 t64 77 S
+t134 78 t135 78 S
+t134 79 t135 79 S
+t134 80 t135 80 S
+t134 81 t135 81 S
+t134 90 t135 90 S
+t134 91 t135 91 S
+t134 92 t135 92 S
+t134 93 t135 93 S
+t134 94 t135 94 S
+t135 0 t135 0 L
+t135 1 t135 1 L
+t135 2 t135 2 L
+t135 3 t135 3 L
+t135 4 t135 4 L
+t135 5 t135 5 L
+t135 6 t135 6 L
+t135 7 t135 7 L
+t135 8 t135 8 L
+t135 9 t135 9 L
+t135 10 t135 10 L
+t135 11 t135 11 L
+t135 12 t135 12 L
+t135 13 t135 13 L
+t135 14 t135 14 L
+t135 15 t135 15 L
+t135 16 t135 16 L
+t135 17 t135 17 L
+t135 18 t135 18 L
+t135 19 t135 19 L
+t135 20 t135 20 L
+t135 21 t135 21 L
+t135 22 t135 22 L
+t135 23 t135 23 L
+t135 24 t135 24 L
+t135 25 t135 25 L
+t135 26 t135 26 L
+t135 27 t135 27 L
+t135 28 t135 28 L
+t135 29 t135 29 L
+t135 30 t135 30 L
+t135 31 t135 31 L
+t135 32 t135 32 L
+t135 33 t135 33 L
+t135 34 t135 34 L
+t135 35 t135 35 L
+t135 36 t135 36 L
+t135 37 t135 37 L
+t135 38 t135 38 L
+t135 39 t135 39 L
+t135 40 t135 40 L
+t135 41 t135 41 L
+t135 42 t135 42 L
+t135 43 t135 43 L
+t135 44 t135 44 L
+t135 45 t135 45 L
+t135 46 t135 46 L
+t135 47 t135 47 L
+t135 48 t135 48 L
+t135 49 t135 49 L
+t135 50 t135 50 L
+t135 51 t135 51 L
+t135 52 t135 52 L
+t135 53 t135 53 L
+t135 54 t135 54 L
+t135 55 t135 55 L
+t135 56 t135 56 L
+t135 57 t135 57 L
+t135 58 t135 58 L
+t135 59 t135 59 L
+t135 60 t64 60 S
+t135 61 t64 61 S
+t135 62 t64 62 S
+t135 63 t64 63 S
+t135 64 t64 64 S
+t135 65 t64 65 S
+t135 66 t64 66 S
+t135 67 t64 67 S
+t135 68 t64 68 S
+t135 69 t64 69 S
+t135 70 t64 70 S
+t135 71 t64 71 S
+t135 72 t64 72 S
+t135 73 t64 73 S
+t135 74 t64 74 S
+t135 75 t64 75 S
+t135 76 t64 76 S
+t135 77 t64 77 S
+t135 78 t64 78 S
+t135 79 t64 79 S
+t135 80 t64 80 S
+t135 81 t64 81 S
+t135 90 t64 90 S
+t135 91 t64 91 S
+t135 92 t64 92 S
+t135 93 t64 93 S
+t135 94 t64 94 S
+t115 0 t115 0 R
+t115 1 t115 1 R
+t115 2 t115 2 R
+t115 3 t115 3 R
+t115 4 t115 4 R
+t115 5 t115 5 R
+t115 6 t115 6 R
+t115 7 t115 7 R
+t115 8 t115 8 R
+t115 9 t115 9 R
+t115 10 t115 10 R
+t115 11 t115 11 R
+t115 12 t115 12 R
+t115 13 t115 13 R
+t115 14 t115 14 R
+t115 15 t115 15 R
+t115 16 t115 16 R
+t115 17 t115 17 R
+t115 18 t115 18 R
+t115 19 t115 19 R
+t115 20 t115 20 R
+t115 21 t115 21 R
+t115 22 t115 22 R
+t115 23 t115 23 R
+t115 24 t115 24 R
+t115 25 t115 25 R
+t115 26 t115 26 R
+t115 27 t115 27 R
+t115 28 t115 28 R
+t115 29 t115 29 R
+t115 30 t110 30 S
+t115 31 t110 31 S
+t115 32 t110 32 S
+t115 33 t110 33 S
+t115 34 t110 34 S
+t115 45 t110 45 S
+t115 46 t110 46 S
+t115 47 t110 47 S
+t115 48 t110 48 S
+t115 49 t110 49 S
+t131 0 t134 15 L
+t131 1 t134 16 L
+t131 2 t134 17 L
+t131 3 t134 18 L
+t131 4 t134 19 L
+t131 5 t134 20 L
+t131 6 t134 21 L
+t131 7 t134 22 L
+t131 8 t134 23 L
+t131 9 t134 24 L
+t131 10 t134 25 L
+t131 11 t134 26 L
+t131 12 t134 27 L
+t131 13 t134 28 L
+t131 14 t134 29 L
+t131 15 t134 15 L
+t131 16 t134 16 L
+t131 17 t134 17 L
+t131 18 t134 18 L
+t131 19 t134 19 L
+t131 20 t134 20 L
+t131 21 t134 21 L
+t131 22 t134 22 L
+t131 23 t134 23 L
+t131 24 t134 24 L
+t131 25 t134 25 L
+t131 26 t134 26 L
+t131 27 t134 27 L
+t131 28 t134 28 L
+t131 29 t134 29 L
+t131 30 t134 45 L
+t131 31 t134 46 L
+t131 32 t134 47 L
+t131 33 t134 48 L
+t131 34 t134 49 L
+t131 35 t134 50 L
+t131 36 t134 51 L
+t131 37 t134 52 L
+t131 38 t134 53 L
+t131 39 t134 54 L
+t131 40 t134 55 L
+t131 41 t134 56 L
+t131 42 t134 57 L
+t131 43 t134 58 L
+t131 44 t134 59 L
+t131 45 t134 45 L
+t131 46 t134 46 L
+t131 47 t134 47 L
+t131 48 t134 48 L
+t131 49 t134 49 L
+t131 50 t134 50 L
+t131 51 t134 51 L
+t131 52 t134 52 L
+t131 53 t134 53 L
+t131 54 t134 54 L
+t131 55 t134 55 L
+t131 56 t134 56 L
+t131 57 t134 57 L
+t131 58 t134 58 L
+t131 59 t134 59 L
+t133 0 t135 15 L
+t133 1 t135 16 L
+t133 2 t135 17 L
+t133 3 t135 18 L
+t133 4 t135 19 L
+t133 5 t135 20 L
+t133 6 t135 21 L
+t133 7 t135 22 L
+t133 8 t135 23 L
+t133 9 t135 24 L
+t133 10 t135 25 L
+t133 11 t135 26 L
+t133 12 t135 27 L
+t133 13 t135 28 L
+t133 14 t135 29 L
+t133 15 t135 15 L
+t133 16 t135 16 L
+t133 17 t135 17 L
+t133 18 t135 18 L
+t133 19 t135 19 L
+t133 20 t135 20 L
+t133 21 t135 21 L
+t133 22 t135 22 L
+t133 23 t135 23 L
+t133 24 t135 24 L
+t133 25 t135 25 L
+t133 26 t135 26 L
+t133 27 t135 27 L
+t133 28 t135 28 L
+t133 29 t135 29 L
+t133 30 t135 45 L
+t133 31 t135 46 L
+t133 32 t135 47 L
+t133 33 t135 48 L
+t133 34 t135 49 L
+t133 35 t135 50 L
+t133 36 t135 51 L
+t133 37 t135 52 L
+t133 38 t135 53 L
+t133 39 t135 54 L
+t133 40 t135 55 L
+t133 41 t135 56 L
+t133 42 t135 57 L
+t133 43 t135 58 L
+t133 44 t135 59 L
+t133 45 t135 45 L
+t133 46 t135 46 L
+t133 47 t135 47 L
+t133 48 t135 48 L
+t133 49 t135 49 L
+t133 50 t135 50 L
+t133 51 t135 51 L
+t133 52 t135 52 L
+t133 53 t135 53 L
+t133 54 t135 54 L
+t133 55 t135 55 L
+t133 56 t135 56 L
+t133 57 t135 57 L
+t133 58 t135 58 L
+t133 59 t135 59 L
+t136 90 t137 90 S
+t137 90 t138 90 S
+t138 90 t30 90 S
+t139 90 t136 90 S
+t139 91 t140 91 S
+t139 92 t141 92 S
+t0 90 t139 90 S
+t0 91 t139 91 S
+t0 92 t139 92 S
+t0 93 t139 93 S
+t0 94 t139 94 S
+t140 91 t142 93 S
+t142 93 t143 93 S
+t143 93 t84 93 S
+t141 92 t144 94 S
+t144 94 t145 94 S
+t145 94 t84 94 S
+t30 60 accept 60 S
+t30 61 accept 61 S
+t30 62 accept 62 S
+t30 63 accept 63 S
+t30 64 accept 64 S
+t30 65 accept 65 S
+t30 66 accept 66 S
+t30 67 accept 67 S
+t30 68 accept 68 S
+t30 69 accept 69 S
+t30 70 accept 70 S
+t30 71 accept 71 S
+t30 72 accept 72 S
+t30 73 accept 73 S
+t30 74 accept 74 S
+t30 75 accept 75 S
+t30 76 accept 76 S
+t30 77 accept 77 S
+t30 78 accept 78 S
+t30 79 accept 79 S
+t30 80 accept 80 S
+t30 81 accept 81 S
+t30 82 accept 82 S
+t30 83 accept 83 S
+t30 84 accept 84 S
+t30 85 accept 85 S
+t30 86 accept 86 S
+t30 87 accept 87 S
+t30 88 accept 88 S
+t30 89 accept 89 S
+t30 90 accept 90 S
+t30 91 accept 91 S
+t30 92 accept 92 S
+t30 93 accept 93 S
+t30 94 accept 94 S
+t30 95 accept 95 S
+t30 96 accept 96 S
+t30 97 accept 97 S
+t30 98 accept 98 S
+t30 99 accept 99 S
+t30 100 accept 100 S
+t30 101 accept 101 S
+t30 102 accept 102 S
+t30 103 accept 103 S
+t30 104 accept 104 S
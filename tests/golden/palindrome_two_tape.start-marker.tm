@@ -0,0 +1,8319 @@
+start 0 t0 105 S
+start 1 t0 106 S
+start 2 t0 107 S
+start 3 t0 108 S
+start 4 t0 109 S
+t1 0 t1 0 L
+t1 1 t1 1 L
+t1 2 t1 2 L
+t1 3 t1 3 L
+t1 4 t1 4 L
+t1 5 t1 5 L
+t1 6 t1 6 L
+t1 7 t1 7 L
+t1 8 t1 8 L
+t1 9 t1 9 L
+t1 10 t1 10 L
+t1 11 t1 11 L
+t1 12 t1 12 L
+t1 13 t1 13 L
+t1 14 t1 14 L
+t1 15 t1 15 L
+t1 16 t1 16 L
+t1 17 t1 17 L
+t1 18 t1 18 L
+t1 19 t1 19 L
+t1 20 t1 20 L
+t1 21 t1 21 L
+t1 22 t1 22 L
+t1 23 t1 23 L
+t1 24 t1 24 L
+t1 25 t1 25 L
+t1 26 t1 26 L
+t1 27 t1 27 L
+t1 28 t1 28 L
+t1 29 t1 29 L
+t1 30 t1 30 L
+t1 31 t1 31 L
+t1 32 t1 32 L
+t1 33 t1 33 L
+t1 34 t1 34 L
+t1 35 t1 35 L
+t1 36 t1 36 L
+t1 37 t1 37 L
+t1 38 t1 38 L
+t1 39 t1 39 L
+t1 40 t1 40 L
+t1 41 t1 41 L
+t1 42 t1 42 L
+t1 43 t1 43 L
+t1 44 t1 44 L
+t1 45 t1 45 L
+t1 46 t1 46 L
+t1 47 t1 47 L
+t1 48 t1 48 L
+t1 49 t1 49 L
+t1 50 t1 50 L
+t1 51 t1 51 L
+t1 52 t1 52 L
+t1 53 t1 53 L
+t1 54 t1 54 L
+t1 55 t1 55 L
+t1 56 t1 56 L
+t1 57 t1 57 L
+t1 58 t1 58 L
+t1 59 t1 59 L
+t1 60 t2 60 S
+t1 61 t2 61 S
+t1 62 t2 62 S
+t1 63 t2 63 S
+t1 64 t2 64 S
+t1 65 t2 65 S
+t1 66 t2 66 S
+t1 67 t2 67 S
+t1 68 t2 68 S
+t1 69 t2 69 S
+t1 70 t2 70 S
+t1 71 t2 71 S
+t1 72 t2 72 S
+t1 73 t2 73 S
+t1 74 t2 74 S
+t1 75 t2 75 S
+t1 76 t2 76 S
+t1 77 t2 77 S
+t1 78 t2 78 S
+t1 79 t2 79 S
+t1 80 t2 80 S
+t1 81 t2 81 S
+t1 82 t2 82 S
+t1 83 t2 83 S
+t1 84 t2 84 S
+t1 85 t2 85 S
+t1 86 t2 86 S
+t1 87 t2 87 S
+t1 88 t2 88 S
+t1 89 t2 89 S
+t1 90 t2 90 S
+t1 91 t2 91 S
+t1 92 t2 92 S
+t1 93 t2 93 S
+t1 94 t2 94 S
+t1 95 t2 95 S
+t1 96 t2 96 S
+t1 97 t2 97 S
+t1 98 t2 98 S
+t1 99 t2 99 S
+t1 100 t2 100 S
+t1 101 t2 101 S
+t1 102 t2 102 S
+t1 103 t2 103 S
+t1 104 t2 104 S
+t1 105 t2 105 S
+t1 106 t2 106 S
+t1 107 t2 107 S
+t1 108 t2 108 S
+t1 109 t2 109 S
+t1 110 t2 110 S
+t1 111 t2 111 S
+t1 112 t2 112 S
+t1 113 t2 113 S
+t1 114 t2 114 S
+t1 115 t2 115 S
+t1 116 t2 116 S
+t1 117 t2 117 S
+t1 118 t2 118 S
+t1 119 t2 119 S
+t3 0 t3 0 L
+t3 1 t3 1 L
+t3 2 t3 2 L
+t3 3 t3 3 L
+t3 4 t3 4 L
+t3 5 t3 5 L
+t3 6 t3 6 L
+t3 7 t3 7 L
+t3 8 t3 8 L
+t3 9 t3 9 L
+t3 10 t3 10 L
+t3 11 t3 11 L
+t3 12 t3 12 L
+t3 13 t3 13 L
+t3 14 t3 14 L
+t3 15 t3 15 L
+t3 16 t3 16 L
+t3 17 t3 17 L
+t3 18 t3 18 L
+t3 19 t3 19 L
+t3 20 t3 20 L
+t3 21 t3 21 L
+t3 22 t3 22 L
+t3 23 t3 23 L
+t3 24 t3 24 L
+t3 25 t3 25 L
+t3 26 t3 26 L
+t3 27 t3 27 L
+t3 28 t3 28 L
+t3 29 t3 29 L
+t3 30 t3 30 L
+t3 31 t3 31 L
+t3 32 t3 32 L
+t3 33 t3 33 L
+t3 34 t3 34 L
+t3 35 t3 35 L
+t3 36 t3 36 L
+t3 37 t3 37 L
+t3 38 t3 38 L
+t3 39 t3 39 L
+t3 40 t3 40 L
+t3 41 t3 41 L
+t3 42 t3 42 L
+t3 43 t3 43 L
+t3 44 t3 44 L
+t3 45 t3 45 L
+t3 46 t3 46 L
+t3 47 t3 47 L
+t3 48 t3 48 L
+t3 49 t3 49 L
+t3 50 t3 50 L
+t3 51 t3 51 L
+t3 52 t3 52 L
+t3 53 t3 53 L
+t3 54 t3 54 L
+t3 55 t3 55 L
+t3 56 t3 56 L
+t3 57 t3 57 L
+t3 58 t3 58 L
+t3 59 t3 59 L
+t3 60 t4 60 S
+t3 61 t4 61 S
+t3 62 t4 62 S
+t3 63 t4 63 S
+t3 64 t4 64 S
+t3 65 t4 65 S
+t3 66 t4 66 S
+t3 67 t4 67 S
+t3 68 t4 68 S
+t3 69 t4 69 S
+t3 70 t4 70 S
+t3 71 t4 71 S
+t3 72 t4 72 S
+t3 73 t4 73 S
+t3 74 t4 74 S
+t3 75 t4 75 S
+t3 76 t4 76 S
+t3 77 t4 77 S
+t3 78 t4 78 S
+t3 79 t4 79 S
+t3 80 t4 80 S
+t3 81 t4 81 S
+t3 82 t4 82 S
+t3 83 t4 83 S
+t3 84 t4 84 S
+t3 85 t4 85 S
+t3 86 t4 86 S
+t3 87 t4 87 S
+t3 88 t4 88 S
+t3 89 t4 89 S
+t3 90 t4 90 S
+t3 91 t4 91 S
+t3 92 t4 92 S
+t3 93 t4 93 S
+t3 94 t4 94 S
+t3 95 t4 95 S
+t3 96 t4 96 S
+t3 97 t4 97 S
+t3 98 t4 98 S
+t3 99 t4 99 S
+t3 100 t4 100 S
+t3 101 t4 101 S
+t3 102 t4 102 S
+t3 103 t4 103 S
+t3 104 t4 104 S
+t3 105 t4 105 S
+t3 106 t4 106 S
+t3 107 t4 107 S
+t3 108 t4 108 S
+t3 109 t4 109 S
+t3 110 t4 110 S
+t3 111 t4 111 S
+t3 112 t4 112 S
+t3 113 t4 113 S
+t3 114 t4 114 S
+t3 115 t4 115 S
+t3 116 t4 116 S
+t3 117 t4 117 S
+t3 118 t4 118 S
+t3 119 t4 119 S
+t5 0 t6 15 S
+t5 1 t6 16 S
+t5 2 t6 17 S
+t5 3 t6 18 S
+t5 4 t6 19 S
+t5 5 t6 20 S
+t5 6 t6 21 S
+t5 7 t6 22 S
+t5 8 t6 23 S
+t5 9 t6 24 S
+t5 10 t6 25 S
+t5 11 t6 26 S
+t5 12 t6 27 S
+t5 13 t6 28 S
+t5 14 t6 29 S
+t5 15 t6 15 S
+t5 16 t6 16 S
+t5 17 t6 17 S
+t5 18 t6 18 S
+t5 19 t6 19 S
+t5 20 t6 20 S
+t5 21 t6 21 S
+t5 22 t6 22 S
+t5 23 t6 23 S
+t5 24 t6 24 S
+t5 25 t6 25 S
+t5 26 t6 26 S
+t5 27 t6 27 S
+t5 28 t6 28 S
+t5 29 t6 29 S
+t5 30 t6 45 S
+t5 31 t6 46 S
+t5 32 t6 47 S
+t5 33 t6 48 S
+t5 34 t6 49 S
+t5 35 t6 50 S
+t5 36 t6 51 S
+t5 37 t6 52 S
+t5 38 t6 53 S
+t5 39 t6 54 S
+t5 40 t6 55 S
+t5 41 t6 56 S
+t5 42 t6 57 S
+t5 43 t6 58 S
+t5 44 t6 59 S
+t5 45 t6 45 S
+t5 46 t6 46 S
+t5 47 t6 47 S
+t5 48 t6 48 S
+t5 49 t6 49 S
+t5 50 t6 50 S
+t5 51 t6 51 S
+t5 52 t6 52 S
+t5 53 t6 53 S
+t5 54 t6 54 S
+t5 55 t6 55 S
+t5 56 t6 56 S
+t5 57 t6 57 S
+t5 58 t6 58 S
+t5 59 t6 59 S
+t5 60 t6 75 S
+t5 61 t6 76 S
+t5 62 t6 77 S
+t5 63 t6 78 S
+t5 64 t6 79 S
+t5 65 t6 80 S
+t5 66 t6 81 S
+t5 67 t6 82 S
+t5 68 t6 83 S
+t5 69 t6 84 S
+t5 70 t6 85 S
+t5 71 t6 86 S
+t5 72 t6 87 S
+t5 73 t6 88 S
+t5 74 t6 89 S
+t5 75 t6 75 S
+t5 76 t6 76 S
+t5 77 t6 77 S
+t5 78 t6 78 S
+t5 79 t6 79 S
+t5 80 t6 80 S
+t5 81 t6 81 S
+t5 82 t6 82 S
+t5 83 t6 83 S
+t5 84 t6 84 S
+t5 85 t6 85 S
+t5 86 t6 86 S
+t5 87 t6 87 S
+t5 88 t6 88 S
+t5 89 t6 89 S
+t5 90 t6 105 S
+t5 91 t6 106 S
+t5 92 t6 107 S
+t5 93 t6 108 S
+t5 94 t6 109 S
+t5 95 t6 110 S
+t5 96 t6 111 S
+t5 97 t6 112 S
+t5 98 t6 113 S
+t5 99 t6 114 S
+t5 100 t6 115 S
+t5 101 t6 116 S
+t5 102 t6 117 S
+t5 103 t6 118 S
+t5 104 t6 119 S
+t5 105 t6 105 S
+t5 106 t6 106 S
+t5 107 t6 107 S
+t5 108 t6 108 S
+t5 109 t6 109 S
+t5 110 t6 110 S
+t5 111 t6 111 S
+t5 112 t6 112 S
+t5 113 t6 113 S
+t5 114 t6 114 S
+t5 115 t6 115 S
+t5 116 t6 116 S
+t5 117 t6 117 S
+t5 118 t6 118 S
+t5 119 t6 119 S
+t7 0 t3 30 S
+t7 1 t3 31 S
+t7 2 t3 32 S
+t7 3 t3 33 S
+t7 4 t3 34 S
+t7 5 t3 35 S
+t7 6 t3 36 S
+t7 7 t3 37 S
+t7 8 t3 38 S
+t7 9 t3 39 S
+t7 10 t3 40 S
+t7 11 t3 41 S
+t7 12 t3 42 S
+t7 13 t3 43 S
+t7 14 t3 44 S
+t7 15 t3 45 S
+t7 16 t3 46 S
+t7 17 t3 47 S
+t7 18 t3 48 S
+t7 19 t3 49 S
+t7 20 t3 50 S
+t7 21 t3 51 S
+t7 22 t3 52 S
+t7 23 t3 53 S
+t7 24 t3 54 S
+t7 25 t3 55 S
+t7 26 t3 56 S
+t7 27 t3 57 S
+t7 28 t3 58 S
+t7 29 t3 59 S
+t7 30 t3 30 S
+t7 31 t3 31 S
+t7 32 t3 32 S
+t7 33 t3 33 S
+t7 34 t3 34 S
+t7 35 t3 35 S
+t7 36 t3 36 S
+t7 37 t3 37 S
+t7 38 t3 38 S
+t7 39 t3 39 S
+t7 40 t3 40 S
+t7 41 t3 41 S
+t7 42 t3 42 S
+t7 43 t3 43 S
+t7 44 t3 44 S
+t7 45 t3 45 S
+t7 46 t3 46 S
+t7 47 t3 47 S
+t7 48 t3 48 S
+t7 49 t3 49 S
+t7 50 t3 50 S
+t7 51 t3 51 S
+t7 52 t3 52 S
+t7 53 t3 53 S
+t7 54 t3 54 S
+t7 55 t3 55 S
+t7 56 t3 56 S
+t7 57 t3 57 S
+t7 58 t3 58 S
+t7 59 t3 59 S
+t7 60 t3 90 S
+t7 61 t3 91 S
+t7 62 t3 92 S
+t7 63 t3 93 S
+t7 64 t3 94 S
+t7 65 t3 95 S
+t7 66 t3 96 S
+t7 67 t3 97 S
+t7 68 t3 98 S
+t7 69 t3 99 S
+t7 70 t3 100 S
+t7 71 t3 101 S
+t7 72 t3 102 S
+t7 73 t3 103 S
+t7 74 t3 104 S
+t7 75 t3 105 S
+t7 76 t3 106 S
+t7 77 t3 107 S
+t7 78 t3 108 S
+t7 79 t3 109 S
+t7 80 t3 110 S
+t7 81 t3 111 S
+t7 82 t3 112 S
+t7 83 t3 113 S
+t7 84 t3 114 S
+t7 85 t3 115 S
+t7 86 t3 116 S
+t7 87 t3 117 S
+t7 88 t3 118 S
+t7 89 t3 119 S
+t7 90 t3 90 S
+t7 91 t3 91 S
+t7 92 t3 92 S
+t7 93 t3 93 S
+t7 94 t3 94 S
+t7 95 t3 95 S
+t7 96 t3 96 S
+t7 97 t3 97 S
+t7 98 t3 98 S
+t7 99 t3 99 S
+t7 100 t3 100 S
+t7 101 t3 101 S
+t7 102 t3 102 S
+t7 103 t3 103 S
+t7 104 t3 104 S
+t7 105 t3 105 S
+t7 106 t3 106 S
+t7 107 t3 107 S
+t7 108 t3 108 S
+t7 109 t3 109 S
+t7 110 t3 110 S
+t7 111 t3 111 S
+t7 112 t3 112 S
+t7 113 t3 113 S
+t7 114 t3 114 S
+t7 115 t3 115 S
+t7 116 t3 116 S
+t7 117 t3 117 S
+t7 118 t3 118 S
+t7 119 t3 119 S
+t2 0 t2 0 R
+t2 1 t2 1 R
+t2 2 t2 2 R
+t2 3 t2 3 R
+t2 4 t2 4 R
+t2 5 t2 5 R
+t2 6 t2 6 R
+t2 7 t2 7 R
+t2 8 t2 8 R
+t2 9 t2 9 R
+t2 10 t2 10 R
+t2 11 t2 11 R
+t2 12 t2 12 R
+t2 13 t2 13 R
+t2 14 t2 14 R
+t2 15 t2 15 R
+t2 16 t2 16 R
+t2 17 t2 17 R
+t2 18 t2 18 R
+t2 19 t2 19 R
+t2 20 t2 20 R
+t2 21 t2 21 R
+t2 22 t2 22 R
+t2 23 t2 23 R
+t2 24 t2 24 R
+t2 25 t2 25 R
+t2 26 t2 26 R
+t2 27 t2 27 R
+t2 28 t2 28 R
+t2 29 t2 29 R
+t2 30 t8 30 S
+t2 31 t8 31 S
+t2 32 t8 32 S
+t2 33 t8 33 S
+t2 34 t8 34 S
+t2 35 t8 35 S
+t2 36 t8 36 S
+t2 37 t8 37 S
+t2 38 t8 38 S
+t2 39 t8 39 S
+t2 40 t8 40 S
+t2 41 t8 41 S
+t2 42 t8 42 S
+t2 43 t8 43 S
+t2 44 t8 44 S
+t2 45 t8 45 S
+t2 46 t8 46 S
+t2 47 t8 47 S
+t2 48 t8 48 S
+t2 49 t8 49 S
+t2 50 t8 50 S
+t2 51 t8 51 S
+t2 52 t8 52 S
+t2 53 t8 53 S
+t2 54 t8 54 S
+t2 55 t8 55 S
+t2 56 t8 56 S
+t2 57 t8 57 S
+t2 58 t8 58 S
+t2 59 t8 59 S
+t2 60 t2 60 R
+t2 61 t2 61 R
+t2 62 t2 62 R
+t2 63 t2 63 R
+t2 64 t2 64 R
+t2 65 t2 65 R
+t2 66 t2 66 R
+t2 67 t2 67 R
+t2 68 t2 68 R
+t2 69 t2 69 R
+t2 70 t2 70 R
+t2 71 t2 71 R
+t2 72 t2 72 R
+t2 73 t2 73 R
+t2 74 t2 74 R
+t2 75 t2 75 R
+t2 76 t2 76 R
+t2 77 t2 77 R
+t2 78 t2 78 R
+t2 79 t2 79 R
+t2 80 t2 80 R
+t2 81 t2 81 R
+t2 82 t2 82 R
+t2 83 t2 83 R
+t2 84 t2 84 R
+t2 85 t2 85 R
+t2 86 t2 86 R
+t2 87 t2 87 R
+t2 88 t2 88 R
+t2 89 t2 89 R
+t2 90 t8 90 S
+t2 91 t8 91 S
+t2 92 t8 92 S
+t2 93 t8 93 S
+t2 94 t8 94 S
+t2 95 t8 95 S
+t2 96 t8 96 S
+t2 97 t8 97 S
+t2 98 t8 98 S
+t2 99 t8 99 S
+t2 100 t8 100 S
+t2 101 t8 101 S
+t2 102 t8 102 S
+t2 103 t8 103 S
+t2 104 t8 104 S
+t2 105 t8 105 S
+t2 106 t8 106 S
+t2 107 t8 107 S
+t2 108 t8 108 S
+t2 109 t8 109 S
+t2 110 t8 110 S
+t2 111 t8 111 S
+t2 112 t8 112 S
+t2 113 t8 113 S
+t2 114 t8 114 S
+t2 115 t8 115 S
+t2 116 t8 116 S
+t2 117 t8 117 S
+t2 118 t8 118 S
+t2 119 t8 119 S
+t4 0 t4 0 R
+t4 1 t4 1 R
+t4 2 t4 2 R
+t4 3 t4 3 R
+t4 4 t4 4 R
+t4 5 t4 5 R
+t4 6 t4 6 R
+t4 7 t4 7 R
+t4 8 t4 8 R
+t4 9 t4 9 R
+t4 10 t4 10 R
+t4 11 t4 11 R
+t4 12 t4 12 R
+t4 13 t4 13 R
+t4 14 t4 14 R
+t4 15 t9 15 S
+t4 16 t9 16 S
+t4 17 t9 17 S
+t4 18 t9 18 S
+t4 19 t9 19 S
+t4 20 t9 20 S
+t4 21 t9 21 S
+t4 22 t9 22 S
+t4 23 t9 23 S
+t4 24 t9 24 S
+t4 25 t9 25 S
+t4 26 t9 26 S
+t4 27 t9 27 S
+t4 28 t9 28 S
+t4 29 t9 29 S
+t4 30 t4 30 R
+t4 31 t4 31 R
+t4 32 t4 32 R
+t4 33 t4 33 R
+t4 34 t4 34 R
+t4 35 t4 35 R
+t4 36 t4 36 R
+t4 37 t4 37 R
+t4 38 t4 38 R
+t4 39 t4 39 R
+t4 40 t4 40 R
+t4 41 t4 41 R
+t4 42 t4 42 R
+t4 43 t4 43 R
+t4 44 t4 44 R
+t4 45 t9 45 S
+t4 46 t9 46 S
+t4 47 t9 47 S
+t4 48 t9 48 S
+t4 49 t9 49 S
+t4 50 t9 50 S
+t4 51 t9 51 S
+t4 52 t9 52 S
+t4 53 t9 53 S
+t4 54 t9 54 S
+t4 55 t9 55 S
+t4 56 t9 56 S
+t4 57 t9 57 S
+t4 58 t9 58 S
+t4 59 t9 59 S
+t4 60 t4 60 R
+t4 61 t4 61 R
+t4 62 t4 62 R
+t4 63 t4 63 R
+t4 64 t4 64 R
+t4 65 t4 65 R
+t4 66 t4 66 R
+t4 67 t4 67 R
+t4 68 t4 68 R
+t4 69 t4 69 R
+t4 70 t4 70 R
+t4 71 t4 71 R
+t4 72 t4 72 R
+t4 73 t4 73 R
+t4 74 t4 74 R
+t4 75 t9 75 S
+t4 76 t9 76 S
+t4 77 t9 77 S
+t4 78 t9 78 S
+t4 79 t9 79 S
+t4 80 t9 80 S
+t4 81 t9 81 S
+t4 82 t9 82 S
+t4 83 t9 83 S
+t4 84 t9 84 S
+t4 85 t9 85 S
+t4 86 t9 86 S
+t4 87 t9 87 S
+t4 88 t9 88 S
+t4 89 t9 89 S
+t4 90 t4 90 R
+t4 91 t4 91 R
+t4 92 t4 92 R
+t4 93 t4 93 R
+t4 94 t4 94 R
+t4 95 t4 95 R
+t4 96 t4 96 R
+t4 97 t4 97 R
+t4 98 t4 98 R
+t4 99 t4 99 R
+t4 100 t4 100 R
+t4 101 t4 101 R
+t4 102 t4 102 R
+t4 103 t4 103 R
+t4 104 t4 104 R
+t4 105 t9 105 S
+t4 106 t9 106 S
+t4 107 t9 107 S
+t4 108 t9 108 S
+t4 109 t9 109 S
+t4 110 t9 110 S
+t4 111 t9 111 S
+t4 112 t9 112 S
+t4 113 t9 113 S
+t4 114 t9 114 S
+t4 115 t9 115 S
+t4 116 t9 116 S
+t4 117 t9 117 S
+t4 118 t9 118 S
+t4 119 t9 119 S
+t8 35 t7 5 L
+t8 36 t7 6 L
+t8 37 t7 7 L
+t8 38 t7 8 L
+t8 39 t7 9 L
+t8 50 t7 20 L
+t8 51 t7 21 L
+t8 52 t7 22 L
+t8 53 t7 23 L
+t8 54 t7 24 L
+t8 95 t7 65 L
+t8 96 t7 66 L
+t8 97 t7 67 L
+t8 98 t7 68 L
+t8 99 t7 69 L
+t8 110 t7 80 L
+t8 111 t7 81 L
+t8 112 t7 82 L
+t8 113 t7 83 L
+t8 114 t7 84 L
+t9 15 t5 1 L
+t9 16 t5 1 L
+t9 17 t5 1 L
+t9 18 t5 1 L
+t9 19 t5 1 L
+t9 20 t5 6 L
+t9 21 t5 6 L
+t9 22 t5 6 L
+t9 23 t5 6 L
+t9 24 t5 6 L
+t9 25 t5 11 L
+t9 26 t5 11 L
+t9 27 t5 11 L
+t9 28 t5 11 L
+t9 29 t5 11 L
+t9 45 t5 31 L
+t9 46 t5 31 L
+t9 47 t5 31 L
+t9 48 t5 31 L
+t9 49 t5 31 L
+t9 50 t5 36 L
+t9 51 t5 36 L
+t9 52 t5 36 L
+t9 53 t5 36 L
+t9 54 t5 36 L
+t9 55 t5 41 L
+t9 56 t5 41 L
+t9 57 t5 41 L
+t9 58 t5 41 L
+t9 59 t5 41 L
+t9 75 t5 61 L
+t9 76 t5 61 L
+t9 77 t5 61 L
+t9 78 t5 61 L
+t9 79 t5 61 L
+t9 80 t5 66 L
+t9 81 t5 66 L
+t9 82 t5 66 L
+t9 83 t5 66 L
+t9 84 t5 66 L
+t9 85 t5 71 L
+t9 86 t5 71 L
+t9 87 t5 71 L
+t9 88 t5 71 L
+t9 89 t5 71 L
+t9 105 t5 91 L
+t9 106 t5 91 L
+t9 107 t5 91 L
+t9 108 t5 91 L
+t9 109 t5 91 L
+t9 110 t5 96 L
+t9 111 t5 96 L
+t9 112 t5 96 L
+t9 113 t5 96 L
+t9 114 t5 96 L
+t9 115 t5 101 L
+t9 116 t5 101 L
+t9 117 t5 101 L
+t9 118 t5 101 L
+t9 119 t5 101 L
+t6 16 t1 16 S
+t6 17 t10 17 S
+t6 18 t11 18 S
+t6 19 t12 19 S
+t6 21 t1 21 S
+t6 22 t10 22 S
+t6 23 t11 23 S
+t6 24 t12 24 S
+t6 26 t1 26 S
+t6 27 t10 27 S
+t6 28 t11 28 S
+t6 29 t12 29 S
+t6 46 t1 46 S
+t6 47 t10 47 S
+t6 48 t11 48 S
+t6 49 t12 49 S
+t6 51 t1 51 S
+t6 52 t10 52 S
+t6 53 t11 53 S
+t6 54 t12 54 S
+t6 56 t1 56 S
+t6 57 t10 57 S
+t6 58 t11 58 S
+t6 59 t12 59 S
+t6 76 t1 76 S
+t6 77 t10 77 S
+t6 78 t11 78 S
+t6 79 t12 79 S
+t6 81 t1 81 S
+t6 82 t10 82 S
+t6 83 t11 83 S
+t6 84 t12 84 S
+t6 86 t1 86 S
+t6 87 t10 87 S
+t6 88 t11 88 S
+t6 89 t12 89 S
+t6 106 t1 106 S
+t6 107 t10 107 S
+t6 108 t11 108 S
+t6 109 t12 109 S
+t6 111 t1 111 S
+t6 112 t10 112 S
+t6 113 t11 113 S
+t6 114 t12 114 S
+t6 116 t1 116 S
+t6 117 t10 117 S
+t6 118 t11 118 S
+t6 119 t12 119 S
+t10 0 t10 0 L
+t10 1 t10 1 L
+t10 2 t10 2 L
+t10 3 t10 3 L
+t10 4 t10 4 L
+t10 5 t10 5 L
+t10 6 t10 6 L
+t10 7 t10 7 L
+t10 8 t10 8 L
+t10 9 t10 9 L
+t10 10 t10 10 L
+t10 11 t10 11 L
+t10 12 t10 12 L
+t10 13 t10 13 L
+t10 14 t10 14 L
+t10 15 t10 15 L
+t10 16 t10 16 L
+t10 17 t10 17 L
+t10 18 t10 18 L
+t10 19 t10 19 L
+t10 20 t10 20 L
+t10 21 t10 21 L
+t10 22 t10 22 L
+t10 23 t10 23 L
+t10 24 t10 24 L
+t10 25 t10 25 L
+t10 26 t10 26 L
+t10 27 t10 27 L
+t10 28 t10 28 L
+t10 29 t10 29 L
+t10 30 t10 30 L
+t10 31 t10 31 L
+t10 32 t10 32 L
+t10 33 t10 33 L
+t10 34 t10 34 L
+t10 35 t10 35 L
+t10 36 t10 36 L
+t10 37 t10 37 L
+t10 38 t10 38 L
+t10 39 t10 39 L
+t10 40 t10 40 L
+t10 41 t10 41 L
+t10 42 t10 42 L
+t10 43 t10 43 L
+t10 44 t10 44 L
+t10 45 t10 45 L
+t10 46 t10 46 L
+t10 47 t10 47 L
+t10 48 t10 48 L
+t10 49 t10 49 L
+t10 50 t10 50 L
+t10 51 t10 51 L
+t10 52 t10 52 L
+t10 53 t10 53 L
+t10 54 t10 54 L
+t10 55 t10 55 L
+t10 56 t10 56 L
+t10 57 t10 57 L
+t10 58 t10 58 L
+t10 59 t10 59 L
+t10 60 t13 60 S
+t10 61 t13 61 S
+t10 62 t13 62 S
+t10 63 t13 63 S
+t10 64 t13 64 S
+t10 65 t13 65 S
+t10 66 t13 66 S
+t10 67 t13 67 S
+t10 68 t13 68 S
+t10 69 t13 69 S
+t10 70 t13 70 S
+t10 71 t13 71 S
+t10 72 t13 72 S
+t10 73 t13 73 S
+t10 74 t13 74 S
+t10 75 t13 75 S
+t10 76 t13 76 S
+t10 77 t13 77 S
+t10 78 t13 78 S
+t10 79 t13 79 S
+t10 80 t13 80 S
+t10 81 t13 81 S
+t10 82 t13 82 S
+t10 83 t13 83 S
+t10 84 t13 84 S
+t10 85 t13 85 S
+t10 86 t13 86 S
+t10 87 t13 87 S
+t10 88 t13 88 S
+t10 89 t13 89 S
+t10 90 t13 90 S
+t10 91 t13 91 S
+t10 92 t13 92 S
+t10 93 t13 93 S
+t10 94 t13 94 S
+t10 95 t13 95 S
+t10 96 t13 96 S
+t10 97 t13 97 S
+t10 98 t13 98 S
+t10 99 t13 99 S
+t10 100 t13 100 S
+t10 101 t13 101 S
+t10 102 t13 102 S
+t10 103 t13 103 S
+t10 104 t13 104 S
+t10 105 t13 105 S
+t10 106 t13 106 S
+t10 107 t13 107 S
+t10 108 t13 108 S
+t10 109 t13 109 S
+t10 110 t13 110 S
+t10 111 t13 111 S
+t10 112 t13 112 S
+t10 113 t13 113 S
+t10 114 t13 114 S
+t10 115 t13 115 S
+t10 116 t13 116 S
+t10 117 t13 117 S
+t10 118 t13 118 S
+t10 119 t13 119 S
+t14 0 t14 0 L
+t14 1 t14 1 L
+t14 2 t14 2 L
+t14 3 t14 3 L
+t14 4 t14 4 L
+t14 5 t14 5 L
+t14 6 t14 6 L
+t14 7 t14 7 L
+t14 8 t14 8 L
+t14 9 t14 9 L
+t14 10 t14 10 L
+t14 11 t14 11 L
+t14 12 t14 12 L
+t14 13 t14 13 L
+t14 14 t14 14 L
+t14 15 t14 15 L
+t14 16 t14 16 L
+t14 17 t14 17 L
+t14 18 t14 18 L
+t14 19 t14 19 L
+t14 20 t14 20 L
+t14 21 t14 21 L
+t14 22 t14 22 L
+t14 23 t14 23 L
+t14 24 t14 24 L
+t14 25 t14 25 L
+t14 26 t14 26 L
+t14 27 t14 27 L
+t14 28 t14 28 L
+t14 29 t14 29 L
+t14 30 t14 30 L
+t14 31 t14 31 L
+t14 32 t14 32 L
+t14 33 t14 33 L
+t14 34 t14 34 L
+t14 35 t14 35 L
+t14 36 t14 36 L
+t14 37 t14 37 L
+t14 38 t14 38 L
+t14 39 t14 39 L
+t14 40 t14 40 L
+t14 41 t14 41 L
+t14 42 t14 42 L
+t14 43 t14 43 L
+t14 44 t14 44 L
+t14 45 t14 45 L
+t14 46 t14 46 L
+t14 47 t14 47 L
+t14 48 t14 48 L
+t14 49 t14 49 L
+t14 50 t14 50 L
+t14 51 t14 51 L
+t14 52 t14 52 L
+t14 53 t14 53 L
+t14 54 t14 54 L
+t14 55 t14 55 L
+t14 56 t14 56 L
+t14 57 t14 57 L
+t14 58 t14 58 L
+t14 59 t14 59 L
+t14 60 t15 60 S
+t14 61 t15 61 S
+t14 62 t15 62 S
+t14 63 t15 63 S
+t14 64 t15 64 S
+t14 65 t15 65 S
+t14 66 t15 66 S
+t14 67 t15 67 S
+t14 68 t15 68 S
+t14 69 t15 69 S
+t14 70 t15 70 S
+t14 71 t15 71 S
+t14 72 t15 72 S
+t14 73 t15 73 S
+t14 74 t15 74 S
+t14 75 t15 75 S
+t14 76 t15 76 S
+t14 77 t15 77 S
+t14 78 t15 78 S
+t14 79 t15 79 S
+t14 80 t15 80 S
+t14 81 t15 81 S
+t14 82 t15 82 S
+t14 83 t15 83 S
+t14 84 t15 84 S
+t14 85 t15 85 S
+t14 86 t15 86 S
+t14 87 t15 87 S
+t14 88 t15 88 S
+t14 89 t15 89 S
+t14 90 t15 90 S
+t14 91 t15 91 S
+t14 92 t15 92 S
+t14 93 t15 93 S
+t14 94 t15 94 S
+t14 95 t15 95 S
+t14 96 t15 96 S
+t14 97 t15 97 S
+t14 98 t15 98 S
+t14 99 t15 99 S
+t14 100 t15 100 S
+t14 101 t15 101 S
+t14 102 t15 102 S
+t14 103 t15 103 S
+t14 104 t15 104 S
+t14 105 t15 105 S
+t14 106 t15 106 S
+t14 107 t15 107 S
+t14 108 t15 108 S
+t14 109 t15 109 S
+t14 110 t15 110 S
+t14 111 t15 111 S
+t14 112 t15 112 S
+t14 113 t15 113 S
+t14 114 t15 114 S
+t14 115 t15 115 S
+t14 116 t15 116 S
+t14 117 t15 117 S
+t14 118 t15 118 S
+t14 119 t15 119 S
+t16 0 t14 30 S
+t16 1 t14 31 S
+t16 2 t14 32 S
+t16 3 t14 33 S
+t16 4 t14 34 S
+t16 5 t14 35 S
+t16 6 t14 36 S
+t16 7 t14 37 S
+t16 8 t14 38 S
+t16 9 t14 39 S
+t16 10 t14 40 S
+t16 11 t14 41 S
+t16 12 t14 42 S
+t16 13 t14 43 S
+t16 14 t14 44 S
+t16 15 t14 45 S
+t16 16 t14 46 S
+t16 17 t14 47 S
+t16 18 t14 48 S
+t16 19 t14 49 S
+t16 20 t14 50 S
+t16 21 t14 51 S
+t16 22 t14 52 S
+t16 23 t14 53 S
+t16 24 t14 54 S
+t16 25 t14 55 S
+t16 26 t14 56 S
+t16 27 t14 57 S
+t16 28 t14 58 S
+t16 29 t14 59 S
+t16 30 t14 30 S
+t16 31 t14 31 S
+t16 32 t14 32 S
+t16 33 t14 33 S
+t16 34 t14 34 S
+t16 35 t14 35 S
+t16 36 t14 36 S
+t16 37 t14 37 S
+t16 38 t14 38 S
+t16 39 t14 39 S
+t16 40 t14 40 S
+t16 41 t14 41 S
+t16 42 t14 42 S
+t16 43 t14 43 S
+t16 44 t14 44 S
+t16 45 t14 45 S
+t16 46 t14 46 S
+t16 47 t14 47 S
+t16 48 t14 48 S
+t16 49 t14 49 S
+t16 50 t14 50 S
+t16 51 t14 51 S
+t16 52 t14 52 S
+t16 53 t14 53 S
+t16 54 t14 54 S
+t16 55 t14 55 S
+t16 56 t14 56 S
+t16 57 t14 57 S
+t16 58 t14 58 S
+t16 59 t14 59 S
+t16 60 t14 90 S
+t16 61 t14 91 S
+t16 62 t14 92 S
+t16 63 t14 93 S
+t16 64 t14 94 S
+t16 65 t14 95 S
+t16 66 t14 96 S
+t16 67 t14 97 S
+t16 68 t14 98 S
+t16 69 t14 99 S
+t16 70 t14 100 S
+t16 71 t14 101 S
+t16 72 t14 102 S
+t16 73 t14 103 S
+t16 74 t14 104 S
+t16 75 t14 105 S
+t16 76 t14 106 S
+t16 77 t14 107 S
+t16 78 t14 108 S
+t16 79 t14 109 S
+t16 80 t14 110 S
+t16 81 t14 111 S
+t16 82 t14 112 S
+t16 83 t14 113 S
+t16 84 t14 114 S
+t16 85 t14 115 S
+t16 86 t14 116 S
+t16 87 t14 117 S
+t16 88 t14 118 S
+t16 89 t14 119 S
+t16 90 t14 90 S
+t16 91 t14 91 S
+t16 92 t14 92 S
+t16 93 t14 93 S
+t16 94 t14 94 S
+t16 95 t14 95 S
+t16 96 t14 96 S
+t16 97 t14 97 S
+t16 98 t14 98 S
+t16 99 t14 99 S
+t16 100 t14 100 S
+t16 101 t14 101 S
+t16 102 t14 102 S
+t16 103 t14 103 S
+t16 104 t14 104 S
+t16 105 t14 105 S
+t16 106 t14 106 S
+t16 107 t14 107 S
+t16 108 t14 108 S
+t16 109 t14 109 S
+t16 110 t14 110 S
+t16 111 t14 111 S
+t16 112 t14 112 S
+t16 113 t14 113 S
+t16 114 t14 114 S
+t16 115 t14 115 S
+t16 116 t14 116 S
+t16 117 t14 117 S
+t16 118 t14 118 S
+t16 119 t14 119 S
+t13 0 t13 0 R
+t13 1 t13 1 R
+t13 2 t13 2 R
+t13 3 t13 3 R
+t13 4 t13 4 R
+t13 5 t13 5 R
+t13 6 t13 6 R
+t13 7 t13 7 R
+t13 8 t13 8 R
+t13 9 t13 9 R
+t13 10 t13 10 R
+t13 11 t13 11 R
+t13 12 t13 12 R
+t13 13 t13 13 R
+t13 14 t13 14 R
+t13 15 t13 15 R
+t13 16 t13 16 R
+t13 17 t13 17 R
+t13 18 t13 18 R
+t13 19 t13 19 R
+t13 20 t13 20 R
+t13 21 t13 21 R
+t13 22 t13 22 R
+t13 23 t13 23 R
+t13 24 t13 24 R
+t13 25 t13 25 R
+t13 26 t13 26 R
+t13 27 t13 27 R
+t13 28 t13 28 R
+t13 29 t13 29 R
+t13 30 t17 30 S
+t13 31 t17 31 S
+t13 32 t17 32 S
+t13 33 t17 33 S
+t13 34 t17 34 S
+t13 35 t17 35 S
+t13 36 t17 36 S
+t13 37 t17 37 S
+t13 38 t17 38 S
+t13 39 t17 39 S
+t13 40 t17 40 S
+t13 41 t17 41 S
+t13 42 t17 42 S
+t13 43 t17 43 S
+t13 44 t17 44 S
+t13 45 t17 45 S
+t13 46 t17 46 S
+t13 47 t17 47 S
+t13 48 t17 48 S
+t13 49 t17 49 S
+t13 50 t17 50 S
+t13 51 t17 51 S
+t13 52 t17 52 S
+t13 53 t17 53 S
+t13 54 t17 54 S
+t13 55 t17 55 S
+t13 56 t17 56 S
+t13 57 t17 57 S
+t13 58 t17 58 S
+t13 59 t17 59 S
+t13 60 t13 60 R
+t13 61 t13 61 R
+t13 62 t13 62 R
+t13 63 t13 63 R
+t13 64 t13 64 R
+t13 65 t13 65 R
+t13 66 t13 66 R
+t13 67 t13 67 R
+t13 68 t13 68 R
+t13 69 t13 69 R
+t13 70 t13 70 R
+t13 71 t13 71 R
+t13 72 t13 72 R
+t13 73 t13 73 R
+t13 74 t13 74 R
+t13 75 t13 75 R
+t13 76 t13 76 R
+t13 77 t13 77 R
+t13 78 t13 78 R
+t13 79 t13 79 R
+t13 80 t13 80 R
+t13 81 t13 81 R
+t13 82 t13 82 R
+t13 83 t13 83 R
+t13 84 t13 84 R
+t13 85 t13 85 R
+t13 86 t13 86 R
+t13 87 t13 87 R
+t13 88 t13 88 R
+t13 89 t13 89 R
+t13 90 t17 90 S
+t13 91 t17 91 S
+t13 92 t17 92 S
+t13 93 t17 93 S
+t13 94 t17 94 S
+t13 95 t17 95 S
+t13 96 t17 96 S
+t13 97 t17 97 S
+t13 98 t17 98 S
+t13 99 t17 99 S
+t13 100 t17 100 S
+t13 101 t17 101 S
+t13 102 t17 102 S
+t13 103 t17 103 S
+t13 104 t17 104 S
+t13 105 t17 105 S
+t13 106 t17 106 S
+t13 107 t17 107 S
+t13 108 t17 108 S
+t13 109 t17 109 S
+t13 110 t17 110 S
+t13 111 t17 111 S
+t13 112 t17 112 S
+t13 113 t17 113 S
+t13 114 t17 114 S
+t13 115 t17 115 S
+t13 116 t17 116 S
+t13 117 t17 117 S
+t13 118 t17 118 S
+t13 119 t17 119 S
+t15 0 t15 0 R
+t15 1 t15 1 R
+t15 2 t15 2 R
+t15 3 t15 3 R
+t15 4 t15 4 R
+t15 5 t15 5 R
+t15 6 t15 6 R
+t15 7 t15 7 R
+t15 8 t15 8 R
+t15 9 t15 9 R
+t15 10 t15 10 R
+t15 11 t15 11 R
+t15 12 t15 12 R
+t15 13 t15 13 R
+t15 14 t15 14 R
+t15 15 t18 15 S
+t15 16 t18 16 S
+t15 17 t18 17 S
+t15 18 t18 18 S
+t15 19 t18 19 S
+t15 20 t18 20 S
+t15 21 t18 21 S
+t15 22 t18 22 S
+t15 23 t18 23 S
+t15 24 t18 24 S
+t15 25 t18 25 S
+t15 26 t18 26 S
+t15 27 t18 27 S
+t15 28 t18 28 S
+t15 29 t18 29 S
+t15 30 t15 30 R
+t15 31 t15 31 R
+t15 32 t15 32 R
+t15 33 t15 33 R
+t15 34 t15 34 R
+t15 35 t15 35 R
+t15 36 t15 36 R
+t15 37 t15 37 R
+t15 38 t15 38 R
+t15 39 t15 39 R
+t15 40 t15 40 R
+t15 41 t15 41 R
+t15 42 t15 42 R
+t15 43 t15 43 R
+t15 44 t15 44 R
+t15 45 t18 45 S
+t15 46 t18 46 S
+t15 47 t18 47 S
+t15 48 t18 48 S
+t15 49 t18 49 S
+t15 50 t18 50 S
+t15 51 t18 51 S
+t15 52 t18 52 S
+t15 53 t18 53 S
+t15 54 t18 54 S
+t15 55 t18 55 S
+t15 56 t18 56 S
+t15 57 t18 57 S
+t15 58 t18 58 S
+t15 59 t18 59 S
+t15 60 t15 60 R
+t15 61 t15 61 R
+t15 62 t15 62 R
+t15 63 t15 63 R
+t15 64 t15 64 R
+t15 65 t15 65 R
+t15 66 t15 66 R
+t15 67 t15 67 R
+t15 68 t15 68 R
+t15 69 t15 69 R
+t15 70 t15 70 R
+t15 71 t15 71 R
+t15 72 t15 72 R
+t15 73 t15 73 R
+t15 74 t15 74 R
+t15 75 t18 75 S
+t15 76 t18 76 S
+t15 77 t18 77 S
+t15 78 t18 78 S
+t15 79 t18 79 S
+t15 80 t18 80 S
+t15 81 t18 81 S
+t15 82 t18 82 S
+t15 83 t18 83 S
+t15 84 t18 84 S
+t15 85 t18 85 S
+t15 86 t18 86 S
+t15 87 t18 87 S
+t15 88 t18 88 S
+t15 89 t18 89 S
+t15 90 t15 90 R
+t15 91 t15 91 R
+t15 92 t15 92 R
+t15 93 t15 93 R
+t15 94 t15 94 R
+t15 95 t15 95 R
+t15 96 t15 96 R
+t15 97 t15 97 R
+t15 98 t15 98 R
+t15 99 t15 99 R
+t15 100 t15 100 R
+t15 101 t15 101 R
+t15 102 t15 102 R
+t15 103 t15 103 R
+t15 104 t15 104 R
+t15 105 t18 105 S
+t15 106 t18 106 S
+t15 107 t18 107 S
+t15 108 t18 108 S
+t15 109 t18 109 S
+t15 110 t18 110 S
+t15 111 t18 111 S
+t15 112 t18 112 S
+t15 113 t18 113 S
+t15 114 t18 114 S
+t15 115 t18 115 S
+t15 116 t18 116 S
+t15 117 t18 117 S
+t15 118 t18 118 S
+t15 119 t18 119 S
+t17 40 t16 10 L
+t17 41 t16 11 L
+t17 42 t16 12 L
+t17 43 t16 13 L
+t17 44 t16 14 L
+t17 55 t16 25 L
+t17 56 t16 26 L
+t17 57 t16 27 L
+t17 58 t16 28 L
+t17 59 t16 29 L
+t17 100 t16 70 L
+t17 101 t16 71 L
+t17 102 t16 72 L
+t17 103 t16 73 L
+t17 104 t16 74 L
+t17 115 t16 85 L
+t17 116 t16 86 L
+t17 117 t16 87 L
+t17 118 t16 88 L
+t17 119 t16 89 L
+t18 15 t5 2 L
+t18 16 t5 2 L
+t18 17 t5 2 L
+t18 18 t5 2 L
+t18 19 t5 2 L
+t18 20 t5 7 L
+t18 21 t5 7 L
+t18 22 t5 7 L
+t18 23 t5 7 L
+t18 24 t5 7 L
+t18 25 t5 12 L
+t18 26 t5 12 L
+t18 27 t5 12 L
+t18 28 t5 12 L
+t18 29 t5 12 L
+t18 45 t5 32 L
+t18 46 t5 32 L
+t18 47 t5 32 L
+t18 48 t5 32 L
+t18 49 t5 32 L
+t18 50 t5 37 L
+t18 51 t5 37 L
+t18 52 t5 37 L
+t18 53 t5 37 L
+t18 54 t5 37 L
+t18 55 t5 42 L
+t18 56 t5 42 L
+t18 57 t5 42 L
+t18 58 t5 42 L
+t18 59 t5 42 L
+t18 75 t5 62 L
+t18 76 t5 62 L
+t18 77 t5 62 L
+t18 78 t5 62 L
+t18 79 t5 62 L
+t18 80 t5 67 L
+t18 81 t5 67 L
+t18 82 t5 67 L
+t18 83 t5 67 L
+t18 84 t5 67 L
+t18 85 t5 72 L
+t18 86 t5 72 L
+t18 87 t5 72 L
+t18 88 t5 72 L
+t18 89 t5 72 L
+t18 105 t5 92 L
+t18 106 t5 92 L
+t18 107 t5 92 L
+t18 108 t5 92 L
+t18 109 t5 92 L
+t18 110 t5 97 L
+t18 111 t5 97 L
+t18 112 t5 97 L
+t18 113 t5 97 L
+t18 114 t5 97 L
+t18 115 t5 102 L
+t18 116 t5 102 L
+t18 117 t5 102 L
+t18 118 t5 102 L
+t18 119 t5 102 L
+t11 0 t11 0 L
+t11 1 t11 1 L
+t11 2 t11 2 L
+t11 3 t11 3 L
+t11 4 t11 4 L
+t11 5 t11 5 L
+t11 6 t11 6 L
+t11 7 t11 7 L
+t11 8 t11 8 L
+t11 9 t11 9 L
+t11 10 t11 10 L
+t11 11 t11 11 L
+t11 12 t11 12 L
+t11 13 t11 13 L
+t11 14 t11 14 L
+t11 15 t11 15 L
+t11 16 t11 16 L
+t11 17 t11 17 L
+t11 18 t11 18 L
+t11 19 t11 19 L
+t11 20 t11 20 L
+t11 21 t11 21 L
+t11 22 t11 22 L
+t11 23 t11 23 L
+t11 24 t11 24 L
+t11 25 t11 25 L
+t11 26 t11 26 L
+t11 27 t11 27 L
+t11 28 t11 28 L
+t11 29 t11 29 L
+t11 30 t11 30 L
+t11 31 t11 31 L
+t11 32 t11 32 L
+t11 33 t11 33 L
+t11 34 t11 34 L
+t11 35 t11 35 L
+t11 36 t11 36 L
+t11 37 t11 37 L
+t11 38 t11 38 L
+t11 39 t11 39 L
+t11 40 t11 40 L
+t11 41 t11 41 L
+t11 42 t11 42 L
+t11 43 t11 43 L
+t11 44 t11 44 L
+t11 45 t11 45 L
+t11 46 t11 46 L
+t11 47 t11 47 L
+t11 48 t11 48 L
+t11 49 t11 49 L
+t11 50 t11 50 L
+t11 51 t11 51 L
+t11 52 t11 52 L
+t11 53 t11 53 L
+t11 54 t11 54 L
+t11 55 t11 55 L
+t11 56 t11 56 L
+t11 57 t11 57 L
+t11 58 t11 58 L
+t11 59 t11 59 L
+t11 60 t19 60 S
+t11 61 t19 61 S
+t11 62 t19 62 S
+t11 63 t19 63 S
+t11 64 t19 64 S
+t11 65 t19 65 S
+t11 66 t19 66 S
+t11 67 t19 67 S
+t11 68 t19 68 S
+t11 69 t19 69 S
+t11 70 t19 70 S
+t11 71 t19 71 S
+t11 72 t19 72 S
+t11 73 t19 73 S
+t11 74 t19 74 S
+t11 75 t19 75 S
+t11 76 t19 76 S
+t11 77 t19 77 S
+t11 78 t19 78 S
+t11 79 t19 79 S
+t11 80 t19 80 S
+t11 81 t19 81 S
+t11 82 t19 82 S
+t11 83 t19 83 S
+t11 84 t19 84 S
+t11 85 t19 85 S
+t11 86 t19 86 S
+t11 87 t19 87 S
+t11 88 t19 88 S
+t11 89 t19 89 S
+t11 90 t19 90 S
+t11 91 t19 91 S
+t11 92 t19 92 S
+t11 93 t19 93 S
+t11 94 t19 94 S
+t11 95 t19 95 S
+t11 96 t19 96 S
+t11 97 t19 97 S
+t11 98 t19 98 S
+t11 99 t19 99 S
+t11 100 t19 100 S
+t11 101 t19 101 S
+t11 102 t19 102 S
+t11 103 t19 103 S
+t11 104 t19 104 S
+t11 105 t19 105 S
+t11 106 t19 106 S
+t11 107 t19 107 S
+t11 108 t19 108 S
+t11 109 t19 109 S
+t11 110 t19 110 S
+t11 111 t19 111 S
+t11 112 t19 112 S
+t11 113 t19 113 S
+t11 114 t19 114 S
+t11 115 t19 115 S
+t11 116 t19 116 S
+t11 117 t19 117 S
+t11 118 t19 118 S
+t11 119 t19 119 S
+t20 0 t20 0 L
+t20 1 t20 1 L
+t20 2 t20 2 L
+t20 3 t20 3 L
+t20 4 t20 4 L
+t20 5 t20 5 L
+t20 6 t20 6 L
+t20 7 t20 7 L
+t20 8 t20 8 L
+t20 9 t20 9 L
+t20 10 t20 10 L
+t20 11 t20 11 L
+t20 12 t20 12 L
+t20 13 t20 13 L
+t20 14 t20 14 L
+t20 15 t20 15 L
+t20 16 t20 16 L
+t20 17 t20 17 L
+t20 18 t20 18 L
+t20 19 t20 19 L
+t20 20 t20 20 L
+t20 21 t20 21 L
+t20 22 t20 22 L
+t20 23 t20 23 L
+t20 24 t20 24 L
+t20 25 t20 25 L
+t20 26 t20 26 L
+t20 27 t20 27 L
+t20 28 t20 28 L
+t20 29 t20 29 L
+t20 30 t20 30 L
+t20 31 t20 31 L
+t20 32 t20 32 L
+t20 33 t20 33 L
+t20 34 t20 34 L
+t20 35 t20 35 L
+t20 36 t20 36 L
+t20 37 t20 37 L
+t20 38 t20 38 L
+t20 39 t20 39 L
+t20 40 t20 40 L
+t20 41 t20 41 L
+t20 42 t20 42 L
+t20 43 t20 43 L
+t20 44 t20 44 L
+t20 45 t20 45 L
+t20 46 t20 46 L
+t20 47 t20 47 L
+t20 48 t20 48 L
+t20 49 t20 49 L
+t20 50 t20 50 L
+t20 51 t20 51 L
+t20 52 t20 52 L
+t20 53 t20 53 L
+t20 54 t20 54 L
+t20 55 t20 55 L
+t20 56 t20 56 L
+t20 57 t20 57 L
+t20 58 t20 58 L
+t20 59 t20 59 L
+t20 60 t21 60 S
+t20 61 t21 61 S
+t20 62 t21 62 S
+t20 63 t21 63 S
+t20 64 t21 64 S
+t20 65 t21 65 S
+t20 66 t21 66 S
+t20 67 t21 67 S
+t20 68 t21 68 S
+t20 69 t21 69 S
+t20 70 t21 70 S
+t20 71 t21 71 S
+t20 72 t21 72 S
+t20 73 t21 73 S
+t20 74 t21 74 S
+t20 75 t21 75 S
+t20 76 t21 76 S
+t20 77 t21 77 S
+t20 78 t21 78 S
+t20 79 t21 79 S
+t20 80 t21 80 S
+t20 81 t21 81 S
+t20 82 t21 82 S
+t20 83 t21 83 S
+t20 84 t21 84 S
+t20 85 t21 85 S
+t20 86 t21 86 S
+t20 87 t21 87 S
+t20 88 t21 88 S
+t20 89 t21 89 S
+t20 90 t21 90 S
+t20 91 t21 91 S
+t20 92 t21 92 S
+t20 93 t21 93 S
+t20 94 t21 94 S
+t20 95 t21 95 S
+t20 96 t21 96 S
+t20 97 t21 97 S
+t20 98 t21 98 S
+t20 99 t21 99 S
+t20 100 t21 100 S
+t20 101 t21 101 S
+t20 102 t21 102 S
+t20 103 t21 103 S
+t20 104 t21 104 S
+t20 105 t21 105 S
+t20 106 t21 106 S
+t20 107 t21 107 S
+t20 108 t21 108 S
+t20 109 t21 109 S
+t20 110 t21 110 S
+t20 111 t21 111 S
+t20 112 t21 112 S
+t20 113 t21 113 S
+t20 114 t21 114 S
+t20 115 t21 115 S
+t20 116 t21 116 S
+t20 117 t21 117 S
+t20 118 t21 118 S
+t20 119 t21 119 S
+t22 3 t23 18 S
+t22 4 t23 19 S
+t22 8 t23 23 S
+t22 9 t23 24 S
+t22 13 t23 28 S
+t22 14 t23 29 S
+t22 33 t23 48 S
+t22 34 t23 49 S
+t22 38 t23 53 S
+t22 39 t23 54 S
+t22 43 t23 58 S
+t22 44 t23 59 S
+t22 63 t23 78 S
+t22 64 t23 79 S
+t22 68 t23 83 S
+t22 69 t23 84 S
+t22 73 t23 88 S
+t22 74 t23 89 S
+t22 90 t23 105 S
+t22 93 t23 108 S
+t22 94 t23 109 S
+t22 98 t23 113 S
+t22 99 t23 114 S
+t22 103 t23 118 S
+t22 104 t23 119 S
+t24 5 t20 35 S
+t24 6 t20 36 S
+t24 7 t20 37 S
+t24 8 t20 38 S
+t24 9 t20 39 S
+t24 20 t20 50 S
+t24 21 t20 51 S
+t24 22 t20 52 S
+t24 23 t20 53 S
+t24 24 t20 54 S
+t24 65 t20 95 S
+t24 66 t20 96 S
+t24 67 t20 97 S
+t24 68 t20 98 S
+t24 69 t20 99 S
+t24 80 t20 110 S
+t24 81 t20 111 S
+t24 82 t20 112 S
+t24 83 t20 113 S
+t24 84 t20 114 S
+t19 0 t19 0 R
+t19 1 t19 1 R
+t19 2 t19 2 R
+t19 3 t19 3 R
+t19 4 t19 4 R
+t19 5 t19 5 R
+t19 6 t19 6 R
+t19 7 t19 7 R
+t19 8 t19 8 R
+t19 9 t19 9 R
+t19 10 t19 10 R
+t19 11 t19 11 R
+t19 12 t19 12 R
+t19 13 t19 13 R
+t19 14 t19 14 R
+t19 15 t19 15 R
+t19 16 t19 16 R
+t19 17 t19 17 R
+t19 18 t19 18 R
+t19 19 t19 19 R
+t19 20 t19 20 R
+t19 21 t19 21 R
+t19 22 t19 22 R
+t19 23 t19 23 R
+t19 24 t19 24 R
+t19 25 t19 25 R
+t19 26 t19 26 R
+t19 27 t19 27 R
+t19 28 t19 28 R
+t19 29 t19 29 R
+t19 30 t25 30 S
+t19 31 t25 31 S
+t19 32 t25 32 S
+t19 33 t25 33 S
+t19 34 t25 34 S
+t19 35 t25 35 S
+t19 36 t25 36 S
+t19 37 t25 37 S
+t19 38 t25 38 S
+t19 39 t25 39 S
+t19 40 t25 40 S
+t19 41 t25 41 S
+t19 42 t25 42 S
+t19 43 t25 43 S
+t19 44 t25 44 S
+t19 45 t25 45 S
+t19 46 t25 46 S
+t19 47 t25 47 S
+t19 48 t25 48 S
+t19 49 t25 49 S
+t19 50 t25 50 S
+t19 51 t25 51 S
+t19 52 t25 52 S
+t19 53 t25 53 S
+t19 54 t25 54 S
+t19 55 t25 55 S
+t19 56 t25 56 S
+t19 57 t25 57 S
+t19 58 t25 58 S
+t19 59 t25 59 S
+t19 60 t19 60 R
+t19 61 t19 61 R
+t19 62 t19 62 R
+t19 63 t19 63 R
+t19 64 t19 64 R
+t19 65 t19 65 R
+t19 66 t19 66 R
+t19 67 t19 67 R
+t19 68 t19 68 R
+t19 69 t19 69 R
+t19 70 t19 70 R
+t19 71 t19 71 R
+t19 72 t19 72 R
+t19 73 t19 73 R
+t19 74 t19 74 R
+t19 75 t19 75 R
+t19 76 t19 76 R
+t19 77 t19 77 R
+t19 78 t19 78 R
+t19 79 t19 79 R
+t19 80 t19 80 R
+t19 81 t19 81 R
+t19 82 t19 82 R
+t19 83 t19 83 R
+t19 84 t19 84 R
+t19 85 t19 85 R
+t19 86 t19 86 R
+t19 87 t19 87 R
+t19 88 t19 88 R
+t19 89 t19 89 R
+t19 90 t25 90 S
+t19 91 t25 91 S
+t19 92 t25 92 S
+t19 93 t25 93 S
+t19 94 t25 94 S
+t19 95 t25 95 S
+t19 96 t25 96 S
+t19 97 t25 97 S
+t19 98 t25 98 S
+t19 99 t25 99 S
+t19 100 t25 100 S
+t19 101 t25 101 S
+t19 102 t25 102 S
+t19 103 t25 103 S
+t19 104 t25 104 S
+t19 105 t25 105 S
+t19 106 t25 106 S
+t19 107 t25 107 S
+t19 108 t25 108 S
+t19 109 t25 109 S
+t19 110 t25 110 S
+t19 111 t25 111 S
+t19 112 t25 112 S
+t19 113 t25 113 S
+t19 114 t25 114 S
+t19 115 t25 115 S
+t19 116 t25 116 S
+t19 117 t25 117 S
+t19 118 t25 118 S
+t19 119 t25 119 S
+t21 0 t21 0 R
+t21 1 t21 1 R
+t21 2 t21 2 R
+t21 3 t21 3 R
+t21 4 t21 4 R
+t21 5 t21 5 R
+t21 6 t21 6 R
+t21 7 t21 7 R
+t21 8 t21 8 R
+t21 9 t21 9 R
+t21 10 t21 10 R
+t21 11 t21 11 R
+t21 12 t21 12 R
+t21 13 t21 13 R
+t21 14 t21 14 R
+t21 15 t26 15 S
+t21 16 t26 16 S
+t21 17 t26 17 S
+t21 18 t26 18 S
+t21 19 t26 19 S
+t21 20 t26 20 S
+t21 21 t26 21 S
+t21 22 t26 22 S
+t21 23 t26 23 S
+t21 24 t26 24 S
+t21 25 t26 25 S
+t21 26 t26 26 S
+t21 27 t26 27 S
+t21 28 t26 28 S
+t21 29 t26 29 S
+t21 30 t21 30 R
+t21 31 t21 31 R
+t21 32 t21 32 R
+t21 33 t21 33 R
+t21 34 t21 34 R
+t21 35 t21 35 R
+t21 36 t21 36 R
+t21 37 t21 37 R
+t21 38 t21 38 R
+t21 39 t21 39 R
+t21 40 t21 40 R
+t21 41 t21 41 R
+t21 42 t21 42 R
+t21 43 t21 43 R
+t21 44 t21 44 R
+t21 45 t26 45 S
+t21 46 t26 46 S
+t21 47 t26 47 S
+t21 48 t26 48 S
+t21 49 t26 49 S
+t21 50 t26 50 S
+t21 51 t26 51 S
+t21 52 t26 52 S
+t21 53 t26 53 S
+t21 54 t26 54 S
+t21 55 t26 55 S
+t21 56 t26 56 S
+t21 57 t26 57 S
+t21 58 t26 58 S
+t21 59 t26 59 S
+t21 60 t21 60 R
+t21 61 t21 61 R
+t21 62 t21 62 R
+t21 63 t21 63 R
+t21 64 t21 64 R
+t21 65 t21 65 R
+t21 66 t21 66 R
+t21 67 t21 67 R
+t21 68 t21 68 R
+t21 69 t21 69 R
+t21 70 t21 70 R
+t21 71 t21 71 R
+t21 72 t21 72 R
+t21 73 t21 73 R
+t21 74 t21 74 R
+t21 75 t26 75 S
+t21 76 t26 76 S
+t21 77 t26 77 S
+t21 78 t26 78 S
+t21 79 t26 79 S
+t21 80 t26 80 S
+t21 81 t26 81 S
+t21 82 t26 82 S
+t21 83 t26 83 S
+t21 84 t26 84 S
+t21 85 t26 85 S
+t21 86 t26 86 S
+t21 87 t26 87 S
+t21 88 t26 88 S
+t21 89 t26 89 S
+t21 90 t21 90 R
+t21 91 t21 91 R
+t21 92 t21 92 R
+t21 93 t21 93 R
+t21 94 t21 94 R
+t21 95 t21 95 R
+t21 96 t21 96 R
+t21 97 t21 97 R
+t21 98 t21 98 R
+t21 99 t21 99 R
+t21 100 t21 100 R
+t21 101 t21 101 R
+t21 102 t21 102 R
+t21 103 t21 103 R
+t21 104 t21 104 R
+t21 105 t26 105 S
+t21 106 t26 106 S
+t21 107 t26 107 S
+t21 108 t26 108 S
+t21 109 t26 109 S
+t21 110 t26 110 S
+t21 111 t26 111 S
+t21 112 t26 112 S
+t21 113 t26 113 S
+t21 114 t26 114 S
+t21 115 t26 115 S
+t21 116 t26 116 S
+t21 117 t26 117 S
+t21 118 t26 118 S
+t21 119 t26 119 S
+t25 35 t24 5 S
+t25 36 t24 6 S
+t25 37 t24 7 S
+t25 38 t24 8 S
+t25 39 t24 9 S
+t25 50 t24 20 S
+t25 51 t24 21 S
+t25 52 t24 22 S
+t25 53 t24 23 S
+t25 54 t24 24 S
+t25 95 t24 65 S
+t25 96 t24 66 S
+t25 97 t24 67 S
+t25 98 t24 68 S
+t25 99 t24 69 S
+t25 110 t24 80 S
+t25 111 t24 81 S
+t25 112 t24 82 S
+t25 113 t24 83 S
+t25 114 t24 84 S
+t26 15 t22 3 S
+t26 16 t22 3 S
+t26 17 t22 3 S
+t26 18 t22 3 S
+t26 19 t22 3 S
+t26 20 t22 8 S
+t26 21 t22 8 S
+t26 22 t22 8 S
+t26 23 t22 8 S
+t26 24 t22 8 S
+t26 25 t22 13 S
+t26 26 t22 13 S
+t26 27 t22 13 S
+t26 28 t22 13 S
+t26 29 t22 13 S
+t26 45 t22 33 S
+t26 46 t22 33 S
+t26 47 t22 33 S
+t26 48 t22 33 S
+t26 49 t22 33 S
+t26 50 t22 38 S
+t26 51 t22 38 S
+t26 52 t22 38 S
+t26 53 t22 38 S
+t26 54 t22 38 S
+t26 55 t22 43 S
+t26 56 t22 43 S
+t26 57 t22 43 S
+t26 58 t22 43 S
+t26 59 t22 43 S
+t26 75 t22 63 S
+t26 76 t22 63 S
+t26 77 t22 63 S
+t26 78 t22 63 S
+t26 79 t22 63 S
+t26 80 t22 68 S
+t26 81 t22 68 S
+t26 82 t22 68 S
+t26 83 t22 68 S
+t26 84 t22 68 S
+t26 85 t22 73 S
+t26 86 t22 73 S
+t26 87 t22 73 S
+t26 88 t22 73 S
+t26 89 t22 73 S
+t26 105 t22 93 S
+t26 106 t22 93 S
+t26 107 t22 93 S
+t26 108 t22 93 S
+t26 109 t22 93 S
+t26 110 t22 98 S
+t26 111 t22 98 S
+t26 112 t22 98 S
+t26 113 t22 98 S
+t26 114 t22 98 S
+t26 115 t22 103 S
+t26 116 t22 103 S
+t26 117 t22 103 S
+t26 118 t22 103 S
+t26 119 t22 103 S
+t12 0 t12 0 L
+t12 1 t12 1 L
+t12 2 t12 2 L
+t12 3 t12 3 L
+t12 4 t12 4 L
+t12 5 t12 5 L
+t12 6 t12 6 L
+t12 7 t12 7 L
+t12 8 t12 8 L
+t12 9 t12 9 L
+t12 10 t12 10 L
+t12 11 t12 11 L
+t12 12 t12 12 L
+t12 13 t12 13 L
+t12 14 t12 14 L
+t12 15 t12 15 L
+t12 16 t12 16 L
+t12 17 t12 17 L
+t12 18 t12 18 L
+t12 19 t12 19 L
+t12 20 t12 20 L
+t12 21 t12 21 L
+t12 22 t12 22 L
+t12 23 t12 23 L
+t12 24 t12 24 L
+t12 25 t12 25 L
+t12 26 t12 26 L
+t12 27 t12 27 L
+t12 28 t12 28 L
+t12 29 t12 29 L
+t12 30 t12 30 L
+t12 31 t12 31 L
+t12 32 t12 32 L
+t12 33 t12 33 L
+t12 34 t12 34 L
+t12 35 t12 35 L
+t12 36 t12 36 L
+t12 37 t12 37 L
+t12 38 t12 38 L
+t12 39 t12 39 L
+t12 40 t12 40 L
+t12 41 t12 41 L
+t12 42 t12 42 L
+t12 43 t12 43 L
+t12 44 t12 44 L
+t12 45 t12 45 L
+t12 46 t12 46 L
+t12 47 t12 47 L
+t12 48 t12 48 L
+t12 49 t12 49 L
+t12 50 t12 50 L
+t12 51 t12 51 L
+t12 52 t12 52 L
+t12 53 t12 53 L
+t12 54 t12 54 L
+t12 55 t12 55 L
+t12 56 t12 56 L
+t12 57 t12 57 L
+t12 58 t12 58 L
+t12 59 t12 59 L
+t12 60 t27 60 S
+t12 61 t27 61 S
+t12 62 t27 62 S
+t12 63 t27 63 S
+t12 64 t27 64 S
+t12 65 t27 65 S
+t12 66 t27 66 S
+t12 67 t27 67 S
+t12 68 t27 68 S
+t12 69 t27 69 S
+t12 70 t27 70 S
+t12 71 t27 71 S
+t12 72 t27 72 S
+t12 73 t27 73 S
+t12 74 t27 74 S
+t12 75 t27 75 S
+t12 76 t27 76 S
+t12 77 t27 77 S
+t12 78 t27 78 S
+t12 79 t27 79 S
+t12 80 t27 80 S
+t12 81 t27 81 S
+t12 82 t27 82 S
+t12 83 t27 83 S
+t12 84 t27 84 S
+t12 85 t27 85 S
+t12 86 t27 86 S
+t12 87 t27 87 S
+t12 88 t27 88 S
+t12 89 t27 89 S
+t12 90 t27 90 S
+t12 91 t27 91 S
+t12 92 t27 92 S
+t12 93 t27 93 S
+t12 94 t27 94 S
+t12 95 t27 95 S
+t12 96 t27 96 S
+t12 97 t27 97 S
+t12 98 t27 98 S
+t12 99 t27 99 S
+t12 100 t27 100 S
+t12 101 t27 101 S
+t12 102 t27 102 S
+t12 103 t27 103 S
+t12 104 t27 104 S
+t12 105 t27 105 S
+t12 106 t27 106 S
+t12 107 t27 107 S
+t12 108 t27 108 S
+t12 109 t27 109 S
+t12 110 t27 110 S
+t12 111 t27 111 S
+t12 112 t27 112 S
+t12 113 t27 113 S
+t12 114 t27 114 S
+t12 115 t27 115 S
+t12 116 t27 116 S
+t12 117 t27 117 S
+t12 118 t27 118 S
+t12 119 t27 119 S
+t28 0 t28 0 L
+t28 1 t28 1 L
+t28 2 t28 2 L
+t28 3 t28 3 L
+t28 4 t28 4 L
+t28 5 t28 5 L
+t28 6 t28 6 L
+t28 7 t28 7 L
+t28 8 t28 8 L
+t28 9 t28 9 L
+t28 10 t28 10 L
+t28 11 t28 11 L
+t28 12 t28 12 L
+t28 13 t28 13 L
+t28 14 t28 14 L
+t28 15 t28 15 L
+t28 16 t28 16 L
+t28 17 t28 17 L
+t28 18 t28 18 L
+t28 19 t28 19 L
+t28 20 t28 20 L
+t28 21 t28 21 L
+t28 22 t28 22 L
+t28 23 t28 23 L
+t28 24 t28 24 L
+t28 25 t28 25 L
+t28 26 t28 26 L
+t28 27 t28 27 L
+t28 28 t28 28 L
+t28 29 t28 29 L
+t28 30 t28 30 L
+t28 31 t28 31 L
+t28 32 t28 32 L
+t28 33 t28 33 L
+t28 34 t28 34 L
+t28 35 t28 35 L
+t28 36 t28 36 L
+t28 37 t28 37 L
+t28 38 t28 38 L
+t28 39 t28 39 L
+t28 40 t28 40 L
+t28 41 t28 41 L
+t28 42 t28 42 L
+t28 43 t28 43 L
+t28 44 t28 44 L
+t28 45 t28 45 L
+t28 46 t28 46 L
+t28 47 t28 47 L
+t28 48 t28 48 L
+t28 49 t28 49 L
+t28 50 t28 50 L
+t28 51 t28 51 L
+t28 52 t28 52 L
+t28 53 t28 53 L
+t28 54 t28 54 L
+t28 55 t28 55 L
+t28 56 t28 56 L
+t28 57 t28 57 L
+t28 58 t28 58 L
+t28 59 t28 59 L
+t28 60 t29 60 S
+t28 61 t29 61 S
+t28 62 t29 62 S
+t28 63 t29 63 S
+t28 64 t29 64 S
+t28 65 t29 65 S
+t28 66 t29 66 S
+t28 67 t29 67 S
+t28 68 t29 68 S
+t28 69 t29 69 S
+t28 70 t29 70 S
+t28 71 t29 71 S
+t28 72 t29 72 S
+t28 73 t29 73 S
+t28 74 t29 74 S
+t28 75 t29 75 S
+t28 76 t29 76 S
+t28 77 t29 77 S
+t28 78 t29 78 S
+t28 79 t29 79 S
+t28 80 t29 80 S
+t28 81 t29 81 S
+t28 82 t29 82 S
+t28 83 t29 83 S
+t28 84 t29 84 S
+t28 85 t29 85 S
+t28 86 t29 86 S
+t28 87 t29 87 S
+t28 88 t29 88 S
+t28 89 t29 89 S
+t28 90 t29 90 S
+t28 91 t29 91 S
+t28 92 t29 92 S
+t28 93 t29 93 S
+t28 94 t29 94 S
+t28 95 t29 95 S
+t28 96 t29 96 S
+t28 97 t29 97 S
+t28 98 t29 98 S
+t28 99 t29 99 S
+t28 100 t29 100 S
+t28 101 t29 101 S
+t28 102 t29 102 S
+t28 103 t29 103 S
+t28 104 t29 104 S
+t28 105 t29 105 S
+t28 106 t29 106 S
+t28 107 t29 107 S
+t28 108 t29 108 S
+t28 109 t29 109 S
+t28 110 t29 110 S
+t28 111 t29 111 S
+t28 112 t29 112 S
+t28 113 t29 113 S
+t28 114 t29 114 S
+t28 115 t29 115 S
+t28 116 t29 116 S
+t28 117 t29 117 S
+t28 118 t29 118 S
+t28 119 t29 119 S
+t30 10 t28 40 S
+t30 11 t28 41 S
+t30 12 t28 42 S
+t30 13 t28 43 S
+t30 14 t28 44 S
+t30 25 t28 55 S
+t30 26 t28 56 S
+t30 27 t28 57 S
+t30 28 t28 58 S
+t30 29 t28 59 S
+t30 70 t28 100 S
+t30 71 t28 101 S
+t30 72 t28 102 S
+t30 73 t28 103 S
+t30 74 t28 104 S
+t30 85 t28 115 S
+t30 86 t28 116 S
+t30 87 t28 117 S
+t30 88 t28 118 S
+t30 89 t28 119 S
+t27 0 t27 0 R
+t27 1 t27 1 R
+t27 2 t27 2 R
+t27 3 t27 3 R
+t27 4 t27 4 R
+t27 5 t27 5 R
+t27 6 t27 6 R
+t27 7 t27 7 R
+t27 8 t27 8 R
+t27 9 t27 9 R
+t27 10 t27 10 R
+t27 11 t27 11 R
+t27 12 t27 12 R
+t27 13 t27 13 R
+t27 14 t27 14 R
+t27 15 t27 15 R
+t27 16 t27 16 R
+t27 17 t27 17 R
+t27 18 t27 18 R
+t27 19 t27 19 R
+t27 20 t27 20 R
+t27 21 t27 21 R
+t27 22 t27 22 R
+t27 23 t27 23 R
+t27 24 t27 24 R
+t27 25 t27 25 R
+t27 26 t27 26 R
+t27 27 t27 27 R
+t27 28 t27 28 R
+t27 29 t27 29 R
+t27 30 t31 30 S
+t27 31 t31 31 S
+t27 32 t31 32 S
+t27 33 t31 33 S
+t27 34 t31 34 S
+t27 35 t31 35 S
+t27 36 t31 36 S
+t27 37 t31 37 S
+t27 38 t31 38 S
+t27 39 t31 39 S
+t27 40 t31 40 S
+t27 41 t31 41 S
+t27 42 t31 42 S
+t27 43 t31 43 S
+t27 44 t31 44 S
+t27 45 t31 45 S
+t27 46 t31 46 S
+t27 47 t31 47 S
+t27 48 t31 48 S
+t27 49 t31 49 S
+t27 50 t31 50 S
+t27 51 t31 51 S
+t27 52 t31 52 S
+t27 53 t31 53 S
+t27 54 t31 54 S
+t27 55 t31 55 S
+t27 56 t31 56 S
+t27 57 t31 57 S
+t27 58 t31 58 S
+t27 59 t31 59 S
+t27 60 t27 60 R
+t27 61 t27 61 R
+t27 62 t27 62 R
+t27 63 t27 63 R
+t27 64 t27 64 R
+t27 65 t27 65 R
+t27 66 t27 66 R
+t27 67 t27 67 R
+t27 68 t27 68 R
+t27 69 t27 69 R
+t27 70 t27 70 R
+t27 71 t27 71 R
+t27 72 t27 72 R
+t27 73 t27 73 R
+t27 74 t27 74 R
+t27 75 t27 75 R
+t27 76 t27 76 R
+t27 77 t27 77 R
+t27 78 t27 78 R
+t27 79 t27 79 R
+t27 80 t27 80 R
+t27 81 t27 81 R
+t27 82 t27 82 R
+t27 83 t27 83 R
+t27 84 t27 84 R
+t27 85 t27 85 R
+t27 86 t27 86 R
+t27 87 t27 87 R
+t27 88 t27 88 R
+t27 89 t27 89 R
+t27 90 t31 90 S
+t27 91 t31 91 S
+t27 92 t31 92 S
+t27 93 t31 93 S
+t27 94 t31 94 S
+t27 95 t31 95 S
+t27 96 t31 96 S
+t27 97 t31 97 S
+t27 98 t31 98 S
+t27 99 t31 99 S
+t27 100 t31 100 S
+t27 101 t31 101 S
+t27 102 t31 102 S
+t27 103 t31 103 S
+t27 104 t31 104 S
+t27 105 t31 105 S
+t27 106 t31 106 S
+t27 107 t31 107 S
+t27 108 t31 108 S
+t27 109 t31 109 S
+t27 110 t31 110 S
+t27 111 t31 111 S
+t27 112 t31 112 S
+t27 113 t31 113 S
+t27 114 t31 114 S
+t27 115 t31 115 S
+t27 116 t31 116 S
+t27 117 t31 117 S
+t27 118 t31 118 S
+t27 119 t31 119 S
+t29 0 t29 0 R
+t29 1 t29 1 R
+t29 2 t29 2 R
+t29 3 t29 3 R
+t29 4 t29 4 R
+t29 5 t29 5 R
+t29 6 t29 6 R
+t29 7 t29 7 R
+t29 8 t29 8 R
+t29 9 t29 9 R
+t29 10 t29 10 R
+t29 11 t29 11 R
+t29 12 t29 12 R
+t29 13 t29 13 R
+t29 14 t29 14 R
+t29 15 t32 15 S
+t29 16 t32 16 S
+t29 17 t32 17 S
+t29 18 t32 18 S
+t29 19 t32 19 S
+t29 20 t32 20 S
+t29 21 t32 21 S
+t29 22 t32 22 S
+t29 23 t32 23 S
+t29 24 t32 24 S
+t29 25 t32 25 S
+t29 26 t32 26 S
+t29 27 t32 27 S
+t29 28 t32 28 S
+t29 29 t32 29 S
+t29 30 t29 30 R
+t29 31 t29 31 R
+t29 32 t29 32 R
+t29 33 t29 33 R
+t29 34 t29 34 R
+t29 35 t29 35 R
+t29 36 t29 36 R
+t29 37 t29 37 R
+t29 38 t29 38 R
+t29 39 t29 39 R
+t29 40 t29 40 R
+t29 41 t29 41 R
+t29 42 t29 42 R
+t29 43 t29 43 R
+t29 44 t29 44 R
+t29 45 t32 45 S
+t29 46 t32 46 S
+t29 47 t32 47 S
+t29 48 t32 48 S
+t29 49 t32 49 S
+t29 50 t32 50 S
+t29 51 t32 51 S
+t29 52 t32 52 S
+t29 53 t32 53 S
+t29 54 t32 54 S
+t29 55 t32 55 S
+t29 56 t32 56 S
+t29 57 t32 57 S
+t29 58 t32 58 S
+t29 59 t32 59 S
+t29 60 t29 60 R
+t29 61 t29 61 R
+t29 62 t29 62 R
+t29 63 t29 63 R
+t29 64 t29 64 R
+t29 65 t29 65 R
+t29 66 t29 66 R
+t29 67 t29 67 R
+t29 68 t29 68 R
+t29 69 t29 69 R
+t29 70 t29 70 R
+t29 71 t29 71 R
+t29 72 t29 72 R
+t29 73 t29 73 R
+t29 74 t29 74 R
+t29 75 t32 75 S
+t29 76 t32 76 S
+t29 77 t32 77 S
+t29 78 t32 78 S
+t29 79 t32 79 S
+t29 80 t32 80 S
+t29 81 t32 81 S
+t29 82 t32 82 S
+t29 83 t32 83 S
+t29 84 t32 84 S
+t29 85 t32 85 S
+t29 86 t32 86 S
+t29 87 t32 87 S
+t29 88 t32 88 S
+t29 89 t32 89 S
+t29 90 t29 90 R
+t29 91 t29 91 R
+t29 92 t29 92 R
+t29 93 t29 93 R
+t29 94 t29 94 R
+t29 95 t29 95 R
+t29 96 t29 96 R
+t29 97 t29 97 R
+t29 98 t29 98 R
+t29 99 t29 99 R
+t29 100 t29 100 R
+t29 101 t29 101 R
+t29 102 t29 102 R
+t29 103 t29 103 R
+t29 104 t29 104 R
+t29 105 t32 105 S
+t29 106 t32 106 S
+t29 107 t32 107 S
+t29 108 t32 108 S
+t29 109 t32 109 S
+t29 110 t32 110 S
+t29 111 t32 111 S
+t29 112 t32 112 S
+t29 113 t32 113 S
+t29 114 t32 114 S
+t29 115 t32 115 S
+t29 116 t32 116 S
+t29 117 t32 117 S
+t29 118 t32 118 S
+t29 119 t32 119 S
+t31 40 t30 10 S
+t31 41 t30 11 S
+t31 42 t30 12 S
+t31 43 t30 13 S
+t31 44 t30 14 S
+t31 55 t30 25 S
+t31 56 t30 26 S
+t31 57 t30 27 S
+t31 58 t30 28 S
+t31 59 t30 29 S
+t31 100 t30 70 S
+t31 101 t30 71 S
+t31 102 t30 72 S
+t31 103 t30 73 S
+t31 104 t30 74 S
+t31 115 t30 85 S
+t31 116 t30 86 S
+t31 117 t30 87 S
+t31 118 t30 88 S
+t31 119 t30 89 S
+t32 15 t22 4 S
+t32 16 t22 4 S
+t32 17 t22 4 S
+t32 18 t22 4 S
+t32 19 t22 4 S
+t32 20 t22 9 S
+t32 21 t22 9 S
+t32 22 t22 9 S
+t32 23 t22 9 S
+t32 24 t22 9 S
+t32 25 t22 14 S
+t32 26 t22 14 S
+t32 27 t22 14 S
+t32 28 t22 14 S
+t32 29 t22 14 S
+t32 45 t22 34 S
+t32 46 t22 34 S
+t32 47 t22 34 S
+t32 48 t22 34 S
+t32 49 t22 34 S
+t32 50 t22 39 S
+t32 51 t22 39 S
+t32 52 t22 39 S
+t32 53 t22 39 S
+t32 54 t22 39 S
+t32 55 t22 44 S
+t32 56 t22 44 S
+t32 57 t22 44 S
+t32 58 t22 44 S
+t32 59 t22 44 S
+t32 75 t22 64 S
+t32 76 t22 64 S
+t32 77 t22 64 S
+t32 78 t22 64 S
+t32 79 t22 64 S
+t32 80 t22 69 S
+t32 81 t22 69 S
+t32 82 t22 69 S
+t32 83 t22 69 S
+t32 84 t22 69 S
+t32 85 t22 74 S
+t32 86 t22 74 S
+t32 87 t22 74 S
+t32 88 t22 74 S
+t32 89 t22 74 S
+t32 105 t22 94 S
+t32 106 t22 94 S
+t32 107 t22 94 S
+t32 108 t22 94 S
+t32 109 t22 94 S
+t32 110 t22 99 S
+t32 111 t22 99 S
+t32 112 t22 99 S
+t32 113 t22 99 S
+t32 114 t22 99 S
+t32 115 t22 104 S
+t32 116 t22 104 S
+t32 117 t22 104 S
+t32 118 t22 104 S
+t32 119 t22 104 S
+t33 0 t33 0 L
+t33 1 t33 1 L
+t33 2 t33 2 L
+t33 3 t33 3 L
+t33 4 t33 4 L
+t33 5 t33 5 L
+t33 6 t33 6 L
+t33 7 t33 7 L
+t33 8 t33 8 L
+t33 9 t33 9 L
+t33 10 t33 10 L
+t33 11 t33 11 L
+t33 12 t33 12 L
+t33 13 t33 13 L
+t33 14 t33 14 L
+t33 15 t33 15 L
+t33 16 t33 16 L
+t33 17 t33 17 L
+t33 18 t33 18 L
+t33 19 t33 19 L
+t33 20 t33 20 L
+t33 21 t33 21 L
+t33 22 t33 22 L
+t33 23 t33 23 L
+t33 24 t33 24 L
+t33 25 t33 25 L
+t33 26 t33 26 L
+t33 27 t33 27 L
+t33 28 t33 28 L
+t33 29 t33 29 L
+t33 30 t33 30 L
+t33 31 t33 31 L
+t33 32 t33 32 L
+t33 33 t33 33 L
+t33 34 t33 34 L
+t33 35 t33 35 L
+t33 36 t33 36 L
+t33 37 t33 37 L
+t33 38 t33 38 L
+t33 39 t33 39 L
+t33 40 t33 40 L
+t33 41 t33 41 L
+t33 42 t33 42 L
+t33 43 t33 43 L
+t33 44 t33 44 L
+t33 45 t33 45 L
+t33 46 t33 46 L
+t33 47 t33 47 L
+t33 48 t33 48 L
+t33 49 t33 49 L
+t33 50 t33 50 L
+t33 51 t33 51 L
+t33 52 t33 52 L
+t33 53 t33 53 L
+t33 54 t33 54 L
+t33 55 t33 55 L
+t33 56 t33 56 L
+t33 57 t33 57 L
+t33 58 t33 58 L
+t33 59 t33 59 L
+t33 61 t34 61 S
+t33 62 t34 62 S
+t33 63 t34 63 S
+t33 64 t34 64 S
+t33 65 t34 65 S
+t33 66 t34 66 S
+t33 67 t34 67 S
+t33 68 t34 68 S
+t33 69 t34 69 S
+t33 70 t34 70 S
+t33 71 t34 71 S
+t33 72 t34 72 S
+t33 73 t34 73 S
+t33 74 t34 74 S
+t33 75 t34 75 S
+t33 76 t34 76 S
+t33 77 t34 77 S
+t33 78 t34 78 S
+t33 79 t34 79 S
+t33 80 t34 80 S
+t33 81 t34 81 S
+t33 82 t34 82 S
+t33 83 t34 83 S
+t33 84 t34 84 S
+t33 85 t34 85 S
+t33 86 t34 86 S
+t33 87 t34 87 S
+t33 88 t34 88 S
+t33 89 t34 89 S
+t33 90 t34 90 S
+t33 91 t34 91 S
+t33 92 t34 92 S
+t33 93 t34 93 S
+t33 94 t34 94 S
+t33 105 t34 105 S
+t33 106 t34 106 S
+t33 107 t34 107 S
+t33 108 t34 108 S
+t33 109 t34 109 S
+t35 0 t35 0 L
+t35 1 t35 1 L
+t35 2 t35 2 L
+t35 3 t35 3 L
+t35 4 t35 4 L
+t35 5 t35 5 L
+t35 6 t35 6 L
+t35 7 t35 7 L
+t35 8 t35 8 L
+t35 9 t35 9 L
+t35 10 t35 10 L
+t35 11 t35 11 L
+t35 12 t35 12 L
+t35 13 t35 13 L
+t35 14 t35 14 L
+t35 15 t35 15 L
+t35 16 t35 16 L
+t35 17 t35 17 L
+t35 18 t35 18 L
+t35 19 t35 19 L
+t35 20 t35 20 L
+t35 21 t35 21 L
+t35 22 t35 22 L
+t35 23 t35 23 L
+t35 24 t35 24 L
+t35 25 t35 25 L
+t35 26 t35 26 L
+t35 27 t35 27 L
+t35 28 t35 28 L
+t35 29 t35 29 L
+t35 30 t35 30 L
+t35 31 t35 31 L
+t35 32 t35 32 L
+t35 33 t35 33 L
+t35 34 t35 34 L
+t35 35 t35 35 L
+t35 36 t35 36 L
+t35 37 t35 37 L
+t35 38 t35 38 L
+t35 39 t35 39 L
+t35 40 t35 40 L
+t35 41 t35 41 L
+t35 42 t35 42 L
+t35 43 t35 43 L
+t35 44 t35 44 L
+t35 45 t35 45 L
+t35 46 t35 46 L
+t35 47 t35 47 L
+t35 48 t35 48 L
+t35 49 t35 49 L
+t35 50 t35 50 L
+t35 51 t35 51 L
+t35 52 t35 52 L
+t35 53 t35 53 L
+t35 54 t35 54 L
+t35 55 t35 55 L
+t35 56 t35 56 L
+t35 57 t35 57 L
+t35 58 t35 58 L
+t35 59 t35 59 L
+t35 61 t36 61 S
+t35 62 t36 62 S
+t35 63 t36 63 S
+t35 64 t36 64 S
+t35 65 t36 65 S
+t35 66 t36 66 S
+t35 67 t36 67 S
+t35 68 t36 68 S
+t35 69 t36 69 S
+t35 70 t36 70 S
+t35 71 t36 71 S
+t35 72 t36 72 S
+t35 73 t36 73 S
+t35 74 t36 74 S
+t35 75 t36 75 S
+t35 76 t36 76 S
+t35 77 t36 77 S
+t35 78 t36 78 S
+t35 79 t36 79 S
+t35 80 t36 80 S
+t35 81 t36 81 S
+t35 82 t36 82 S
+t35 83 t36 83 S
+t35 84 t36 84 S
+t35 85 t36 85 S
+t35 86 t36 86 S
+t35 87 t36 87 S
+t35 88 t36 88 S
+t35 89 t36 89 S
+t35 90 t36 90 S
+t35 91 t36 91 S
+t35 92 t36 92 S
+t35 93 t36 93 S
+t35 94 t36 94 S
+t35 105 t36 105 S
+t35 106 t36 106 S
+t35 107 t36 107 S
+t35 108 t36 108 S
+t35 109 t36 109 S
+t37 0 t38 15 S
+t37 1 t38 16 S
+t37 2 t38 17 S
+t37 3 t38 18 S
+t37 4 t38 19 S
+t37 5 t38 20 S
+t37 6 t38 21 S
+t37 7 t38 22 S
+t37 8 t38 23 S
+t37 9 t38 24 S
+t37 10 t38 25 S
+t37 11 t38 26 S
+t37 12 t38 27 S
+t37 13 t38 28 S
+t37 14 t38 29 S
+t37 15 t38 15 S
+t37 16 t38 16 S
+t37 17 t38 17 S
+t37 18 t38 18 S
+t37 19 t38 19 S
+t37 20 t38 20 S
+t37 21 t38 21 S
+t37 22 t38 22 S
+t37 23 t38 23 S
+t37 24 t38 24 S
+t37 25 t38 25 S
+t37 26 t38 26 S
+t37 27 t38 27 S
+t37 28 t38 28 S
+t37 29 t38 29 S
+t37 30 t38 45 S
+t37 31 t38 46 S
+t37 32 t38 47 S
+t37 33 t38 48 S
+t37 34 t38 49 S
+t37 35 t38 50 S
+t37 36 t38 51 S
+t37 37 t38 52 S
+t37 38 t38 53 S
+t37 39 t38 54 S
+t37 40 t38 55 S
+t37 41 t38 56 S
+t37 42 t38 57 S
+t37 43 t38 58 S
+t37 44 t38 59 S
+t37 45 t38 45 S
+t37 46 t38 46 S
+t37 47 t38 47 S
+t37 48 t38 48 S
+t37 49 t38 49 S
+t37 50 t38 50 S
+t37 51 t38 51 S
+t37 52 t38 52 S
+t37 53 t38 53 S
+t37 54 t38 54 S
+t37 55 t38 55 S
+t37 56 t38 56 S
+t37 57 t38 57 S
+t37 58 t38 58 S
+t37 59 t38 59 S
+t37 61 t38 76 S
+t37 62 t38 77 S
+t37 63 t38 78 S
+t37 64 t38 79 S
+t37 65 t38 80 S
+t37 66 t38 81 S
+t37 67 t38 82 S
+t37 68 t38 83 S
+t37 69 t38 84 S
+t37 70 t38 85 S
+t37 71 t38 86 S
+t37 72 t38 87 S
+t37 73 t38 88 S
+t37 74 t38 89 S
+t37 75 t38 75 S
+t37 76 t38 76 S
+t37 77 t38 77 S
+t37 78 t38 78 S
+t37 79 t38 79 S
+t37 80 t38 80 S
+t37 81 t38 81 S
+t37 82 t38 82 S
+t37 83 t38 83 S
+t37 84 t38 84 S
+t37 85 t38 85 S
+t37 86 t38 86 S
+t37 87 t38 87 S
+t37 88 t38 88 S
+t37 89 t38 89 S
+t37 90 t38 105 S
+t37 91 t38 106 S
+t37 92 t38 107 S
+t37 93 t38 108 S
+t37 94 t38 109 S
+t37 105 t38 105 S
+t37 106 t38 106 S
+t37 107 t38 107 S
+t37 108 t38 108 S
+t37 109 t38 109 S
+t39 0 t35 30 S
+t39 1 t35 31 S
+t39 2 t35 32 S
+t39 3 t35 33 S
+t39 4 t35 34 S
+t39 5 t35 35 S
+t39 6 t35 36 S
+t39 7 t35 37 S
+t39 8 t35 38 S
+t39 9 t35 39 S
+t39 10 t35 40 S
+t39 11 t35 41 S
+t39 12 t35 42 S
+t39 13 t35 43 S
+t39 14 t35 44 S
+t39 15 t35 45 S
+t39 16 t35 46 S
+t39 17 t35 47 S
+t39 18 t35 48 S
+t39 19 t35 49 S
+t39 20 t35 50 S
+t39 21 t35 51 S
+t39 22 t35 52 S
+t39 23 t35 53 S
+t39 24 t35 54 S
+t39 25 t35 55 S
+t39 26 t35 56 S
+t39 27 t35 57 S
+t39 28 t35 58 S
+t39 29 t35 59 S
+t39 30 t35 30 S
+t39 31 t35 31 S
+t39 32 t35 32 S
+t39 33 t35 33 S
+t39 34 t35 34 S
+t39 35 t35 35 S
+t39 36 t35 36 S
+t39 37 t35 37 S
+t39 38 t35 38 S
+t39 39 t35 39 S
+t39 40 t35 40 S
+t39 41 t35 41 S
+t39 42 t35 42 S
+t39 43 t35 43 S
+t39 44 t35 44 S
+t39 45 t35 45 S
+t39 46 t35 46 S
+t39 47 t35 47 S
+t39 48 t35 48 S
+t39 49 t35 49 S
+t39 50 t35 50 S
+t39 51 t35 51 S
+t39 52 t35 52 S
+t39 53 t35 53 S
+t39 54 t35 54 S
+t39 55 t35 55 S
+t39 56 t35 56 S
+t39 57 t35 57 S
+t39 58 t35 58 S
+t39 59 t35 59 S
+t34 0 t34 0 R
+t34 1 t34 1 R
+t34 2 t34 2 R
+t34 3 t34 3 R
+t34 4 t34 4 R
+t34 5 t34 5 R
+t34 6 t34 6 R
+t34 7 t34 7 R
+t34 8 t34 8 R
+t34 9 t34 9 R
+t34 10 t34 10 R
+t34 11 t34 11 R
+t34 12 t34 12 R
+t34 13 t34 13 R
+t34 14 t34 14 R
+t34 15 t34 15 R
+t34 16 t34 16 R
+t34 17 t34 17 R
+t34 18 t34 18 R
+t34 19 t34 19 R
+t34 20 t34 20 R
+t34 21 t34 21 R
+t34 22 t34 22 R
+t34 23 t34 23 R
+t34 24 t34 24 R
+t34 25 t34 25 R
+t34 26 t34 26 R
+t34 27 t34 27 R
+t34 28 t34 28 R
+t34 29 t34 29 R
+t34 30 t40 30 S
+t34 31 t40 31 S
+t34 32 t40 32 S
+t34 33 t40 33 S
+t34 34 t40 34 S
+t34 35 t40 35 S
+t34 36 t40 36 S
+t34 37 t40 37 S
+t34 38 t40 38 S
+t34 39 t40 39 S
+t34 40 t40 40 S
+t34 41 t40 41 S
+t34 42 t40 42 S
+t34 43 t40 43 S
+t34 44 t40 44 S
+t34 45 t40 45 S
+t34 46 t40 46 S
+t34 47 t40 47 S
+t34 48 t40 48 S
+t34 49 t40 49 S
+t34 50 t40 50 S
+t34 51 t40 51 S
+t34 52 t40 52 S
+t34 53 t40 53 S
+t34 54 t40 54 S
+t34 55 t40 55 S
+t34 56 t40 56 S
+t34 57 t40 57 S
+t34 58 t40 58 S
+t34 59 t40 59 S
+t34 61 t34 61 R
+t34 62 t34 62 R
+t34 63 t34 63 R
+t34 64 t34 64 R
+t34 65 t34 65 R
+t34 66 t34 66 R
+t34 67 t34 67 R
+t34 68 t34 68 R
+t34 69 t34 69 R
+t34 70 t34 70 R
+t34 71 t34 71 R
+t34 72 t34 72 R
+t34 73 t34 73 R
+t34 74 t34 74 R
+t34 75 t34 75 R
+t34 76 t34 76 R
+t34 77 t34 77 R
+t34 78 t34 78 R
+t34 79 t34 79 R
+t34 80 t34 80 R
+t34 81 t34 81 R
+t34 82 t34 82 R
+t34 83 t34 83 R
+t34 84 t34 84 R
+t34 85 t34 85 R
+t34 86 t34 86 R
+t34 87 t34 87 R
+t34 88 t34 88 R
+t34 89 t34 89 R
+t34 90 t40 90 S
+t34 91 t40 91 S
+t34 92 t40 92 S
+t34 93 t40 93 S
+t34 94 t40 94 S
+t34 105 t40 105 S
+t34 106 t40 106 S
+t34 107 t40 107 S
+t34 108 t40 108 S
+t34 109 t40 109 S
+t36 0 t36 0 R
+t36 1 t36 1 R
+t36 2 t36 2 R
+t36 3 t36 3 R
+t36 4 t36 4 R
+t36 5 t36 5 R
+t36 6 t36 6 R
+t36 7 t36 7 R
+t36 8 t36 8 R
+t36 9 t36 9 R
+t36 10 t36 10 R
+t36 11 t36 11 R
+t36 12 t36 12 R
+t36 13 t36 13 R
+t36 14 t36 14 R
+t36 15 t41 15 S
+t36 16 t41 16 S
+t36 17 t41 17 S
+t36 18 t41 18 S
+t36 19 t41 19 S
+t36 20 t41 20 S
+t36 21 t41 21 S
+t36 22 t41 22 S
+t36 23 t41 23 S
+t36 24 t41 24 S
+t36 25 t41 25 S
+t36 26 t41 26 S
+t36 27 t41 27 S
+t36 28 t41 28 S
+t36 29 t41 29 S
+t36 30 t36 30 R
+t36 31 t36 31 R
+t36 32 t36 32 R
+t36 33 t36 33 R
+t36 34 t36 34 R
+t36 35 t36 35 R
+t36 36 t36 36 R
+t36 37 t36 37 R
+t36 38 t36 38 R
+t36 39 t36 39 R
+t36 40 t36 40 R
+t36 41 t36 41 R
+t36 42 t36 42 R
+t36 43 t36 43 R
+t36 44 t36 44 R
+t36 45 t41 45 S
+t36 46 t41 46 S
+t36 47 t41 47 S
+t36 48 t41 48 S
+t36 49 t41 49 S
+t36 50 t41 50 S
+t36 51 t41 51 S
+t36 52 t41 52 S
+t36 53 t41 53 S
+t36 54 t41 54 S
+t36 55 t41 55 S
+t36 56 t41 56 S
+t36 57 t41 57 S
+t36 58 t41 58 S
+t36 59 t41 59 S
+t36 61 t36 61 R
+t36 62 t36 62 R
+t36 63 t36 63 R
+t36 64 t36 64 R
+t36 65 t36 65 R
+t36 66 t36 66 R
+t36 67 t36 67 R
+t36 68 t36 68 R
+t36 69 t36 69 R
+t36 70 t36 70 R
+t36 71 t36 71 R
+t36 72 t36 72 R
+t36 73 t36 73 R
+t36 74 t36 74 R
+t36 75 t41 75 S
+t36 76 t41 76 S
+t36 77 t41 77 S
+t36 78 t41 78 S
+t36 79 t41 79 S
+t36 80 t41 80 S
+t36 81 t41 81 S
+t36 82 t41 82 S
+t36 83 t41 83 S
+t36 84 t41 84 S
+t36 85 t41 85 S
+t36 86 t41 86 S
+t36 87 t41 87 S
+t36 88 t41 88 S
+t36 89 t41 89 S
+t36 90 t36 90 R
+t36 91 t36 91 R
+t36 92 t36 92 R
+t36 93 t36 93 R
+t36 94 t36 94 R
+t36 105 t41 105 S
+t36 106 t41 106 S
+t36 107 t41 107 S
+t36 108 t41 108 S
+t36 109 t41 109 S
+t40 30 t39 5 R
+t40 31 t39 6 R
+t40 32 t39 7 R
+t40 33 t39 8 R
+t40 34 t39 9 R
+t40 45 t39 20 R
+t40 46 t39 21 R
+t40 47 t39 22 R
+t40 48 t39 23 R
+t40 49 t39 24 R
+t40 90 t39 65 R
+t40 91 t39 66 R
+t40 92 t39 67 R
+t40 93 t39 68 R
+t40 94 t39 69 R
+t40 105 t39 80 R
+t40 106 t39 81 R
+t40 107 t39 82 R
+t40 108 t39 83 R
+t40 109 t39 84 R
+t41 15 t37 1 L
+t41 16 t37 1 L
+t41 17 t37 1 L
+t41 18 t37 1 L
+t41 19 t37 1 L
+t41 20 t37 6 L
+t41 21 t37 6 L
+t41 22 t37 6 L
+t41 23 t37 6 L
+t41 24 t37 6 L
+t41 25 t37 11 L
+t41 26 t37 11 L
+t41 27 t37 11 L
+t41 28 t37 11 L
+t41 29 t37 11 L
+t41 45 t37 31 L
+t41 46 t37 31 L
+t41 47 t37 31 L
+t41 48 t37 31 L
+t41 49 t37 31 L
+t41 50 t37 36 L
+t41 51 t37 36 L
+t41 52 t37 36 L
+t41 53 t37 36 L
+t41 54 t37 36 L
+t41 55 t37 41 L
+t41 56 t37 41 L
+t41 57 t37 41 L
+t41 58 t37 41 L
+t41 59 t37 41 L
+t41 75 t37 61 L
+t41 76 t37 61 L
+t41 77 t37 61 L
+t41 78 t37 61 L
+t41 79 t37 61 L
+t41 80 t37 66 L
+t41 81 t37 66 L
+t41 82 t37 66 L
+t41 83 t37 66 L
+t41 84 t37 66 L
+t41 85 t37 71 L
+t41 86 t37 71 L
+t41 87 t37 71 L
+t41 88 t37 71 L
+t41 89 t37 71 L
+t41 105 t37 91 L
+t41 106 t37 91 L
+t41 107 t37 91 L
+t41 108 t37 91 L
+t41 109 t37 91 L
+t38 16 t33 16 S
+t38 17 t42 17 S
+t38 18 t43 18 S
+t38 19 t44 19 S
+t38 21 t33 21 S
+t38 22 t42 22 S
+t38 23 t43 23 S
+t38 24 t44 24 S
+t38 26 t33 26 S
+t38 27 t42 27 S
+t38 28 t43 28 S
+t38 29 t44 29 S
+t38 46 t33 46 S
+t38 47 t42 47 S
+t38 48 t43 48 S
+t38 49 t44 49 S
+t38 51 t33 51 S
+t38 52 t42 52 S
+t38 53 t43 53 S
+t38 54 t44 54 S
+t38 56 t33 56 S
+t38 57 t42 57 S
+t38 58 t43 58 S
+t38 59 t44 59 S
+t38 76 t33 76 S
+t38 77 t42 77 S
+t38 78 t43 78 S
+t38 79 t44 79 S
+t38 81 t33 81 S
+t38 82 t42 82 S
+t38 83 t43 83 S
+t38 84 t44 84 S
+t38 86 t33 86 S
+t38 87 t42 87 S
+t38 88 t43 88 S
+t38 89 t44 89 S
+t38 106 t33 106 S
+t38 107 t42 107 S
+t38 108 t43 108 S
+t38 109 t44 109 S
+t42 0 t42 0 L
+t42 1 t42 1 L
+t42 2 t42 2 L
+t42 3 t42 3 L
+t42 4 t42 4 L
+t42 5 t42 5 L
+t42 6 t42 6 L
+t42 7 t42 7 L
+t42 8 t42 8 L
+t42 9 t42 9 L
+t42 10 t42 10 L
+t42 11 t42 11 L
+t42 12 t42 12 L
+t42 13 t42 13 L
+t42 14 t42 14 L
+t42 15 t42 15 L
+t42 16 t42 16 L
+t42 17 t42 17 L
+t42 18 t42 18 L
+t42 19 t42 19 L
+t42 20 t42 20 L
+t42 21 t42 21 L
+t42 22 t42 22 L
+t42 23 t42 23 L
+t42 24 t42 24 L
+t42 25 t42 25 L
+t42 26 t42 26 L
+t42 27 t42 27 L
+t42 28 t42 28 L
+t42 29 t42 29 L
+t42 30 t42 30 L
+t42 31 t42 31 L
+t42 32 t42 32 L
+t42 33 t42 33 L
+t42 34 t42 34 L
+t42 35 t42 35 L
+t42 36 t42 36 L
+t42 37 t42 37 L
+t42 38 t42 38 L
+t42 39 t42 39 L
+t42 40 t42 40 L
+t42 41 t42 41 L
+t42 42 t42 42 L
+t42 43 t42 43 L
+t42 44 t42 44 L
+t42 45 t42 45 L
+t42 46 t42 46 L
+t42 47 t42 47 L
+t42 48 t42 48 L
+t42 49 t42 49 L
+t42 50 t42 50 L
+t42 51 t42 51 L
+t42 52 t42 52 L
+t42 53 t42 53 L
+t42 54 t42 54 L
+t42 55 t42 55 L
+t42 56 t42 56 L
+t42 57 t42 57 L
+t42 58 t42 58 L
+t42 59 t42 59 L
+t42 61 t45 61 S
+t42 62 t45 62 S
+t42 63 t45 63 S
+t42 64 t45 64 S
+t42 65 t45 65 S
+t42 66 t45 66 S
+t42 67 t45 67 S
+t42 68 t45 68 S
+t42 69 t45 69 S
+t42 70 t45 70 S
+t42 71 t45 71 S
+t42 72 t45 72 S
+t42 73 t45 73 S
+t42 74 t45 74 S
+t42 75 t45 75 S
+t42 76 t45 76 S
+t42 77 t45 77 S
+t42 78 t45 78 S
+t42 79 t45 79 S
+t42 80 t45 80 S
+t42 81 t45 81 S
+t42 82 t45 82 S
+t42 83 t45 83 S
+t42 84 t45 84 S
+t42 85 t45 85 S
+t42 86 t45 86 S
+t42 87 t45 87 S
+t42 88 t45 88 S
+t42 89 t45 89 S
+t42 90 t45 90 S
+t42 91 t45 91 S
+t42 92 t45 92 S
+t42 93 t45 93 S
+t42 94 t45 94 S
+t42 105 t45 105 S
+t42 106 t45 106 S
+t42 107 t45 107 S
+t42 108 t45 108 S
+t42 109 t45 109 S
+t46 0 t46 0 L
+t46 1 t46 1 L
+t46 2 t46 2 L
+t46 3 t46 3 L
+t46 4 t46 4 L
+t46 5 t46 5 L
+t46 6 t46 6 L
+t46 7 t46 7 L
+t46 8 t46 8 L
+t46 9 t46 9 L
+t46 10 t46 10 L
+t46 11 t46 11 L
+t46 12 t46 12 L
+t46 13 t46 13 L
+t46 14 t46 14 L
+t46 15 t46 15 L
+t46 16 t46 16 L
+t46 17 t46 17 L
+t46 18 t46 18 L
+t46 19 t46 19 L
+t46 20 t46 20 L
+t46 21 t46 21 L
+t46 22 t46 22 L
+t46 23 t46 23 L
+t46 24 t46 24 L
+t46 25 t46 25 L
+t46 26 t46 26 L
+t46 27 t46 27 L
+t46 28 t46 28 L
+t46 29 t46 29 L
+t46 30 t46 30 L
+t46 31 t46 31 L
+t46 32 t46 32 L
+t46 33 t46 33 L
+t46 34 t46 34 L
+t46 35 t46 35 L
+t46 36 t46 36 L
+t46 37 t46 37 L
+t46 38 t46 38 L
+t46 39 t46 39 L
+t46 40 t46 40 L
+t46 41 t46 41 L
+t46 42 t46 42 L
+t46 43 t46 43 L
+t46 44 t46 44 L
+t46 45 t46 45 L
+t46 46 t46 46 L
+t46 47 t46 47 L
+t46 48 t46 48 L
+t46 49 t46 49 L
+t46 50 t46 50 L
+t46 51 t46 51 L
+t46 52 t46 52 L
+t46 53 t46 53 L
+t46 54 t46 54 L
+t46 55 t46 55 L
+t46 56 t46 56 L
+t46 57 t46 57 L
+t46 58 t46 58 L
+t46 59 t46 59 L
+t46 61 t47 61 S
+t46 62 t47 62 S
+t46 63 t47 63 S
+t46 64 t47 64 S
+t46 65 t47 65 S
+t46 66 t47 66 S
+t46 67 t47 67 S
+t46 68 t47 68 S
+t46 69 t47 69 S
+t46 70 t47 70 S
+t46 71 t47 71 S
+t46 72 t47 72 S
+t46 73 t47 73 S
+t46 74 t47 74 S
+t46 75 t47 75 S
+t46 76 t47 76 S
+t46 77 t47 77 S
+t46 78 t47 78 S
+t46 79 t47 79 S
+t46 80 t47 80 S
+t46 81 t47 81 S
+t46 82 t47 82 S
+t46 83 t47 83 S
+t46 84 t47 84 S
+t46 85 t47 85 S
+t46 86 t47 86 S
+t46 87 t47 87 S
+t46 88 t47 88 S
+t46 89 t47 89 S
+t46 90 t47 90 S
+t46 91 t47 91 S
+t46 92 t47 92 S
+t46 93 t47 93 S
+t46 94 t47 94 S
+t46 105 t47 105 S
+t46 106 t47 106 S
+t46 107 t47 107 S
+t46 108 t47 108 S
+t46 109 t47 109 S
+t48 0 t46 30 S
+t48 1 t46 31 S
+t48 2 t46 32 S
+t48 3 t46 33 S
+t48 4 t46 34 S
+t48 5 t46 35 S
+t48 6 t46 36 S
+t48 7 t46 37 S
+t48 8 t46 38 S
+t48 9 t46 39 S
+t48 10 t46 40 S
+t48 11 t46 41 S
+t48 12 t46 42 S
+t48 13 t46 43 S
+t48 14 t46 44 S
+t48 15 t46 45 S
+t48 16 t46 46 S
+t48 17 t46 47 S
+t48 18 t46 48 S
+t48 19 t46 49 S
+t48 20 t46 50 S
+t48 21 t46 51 S
+t48 22 t46 52 S
+t48 23 t46 53 S
+t48 24 t46 54 S
+t48 25 t46 55 S
+t48 26 t46 56 S
+t48 27 t46 57 S
+t48 28 t46 58 S
+t48 29 t46 59 S
+t48 30 t46 30 S
+t48 31 t46 31 S
+t48 32 t46 32 S
+t48 33 t46 33 S
+t48 34 t46 34 S
+t48 35 t46 35 S
+t48 36 t46 36 S
+t48 37 t46 37 S
+t48 38 t46 38 S
+t48 39 t46 39 S
+t48 40 t46 40 S
+t48 41 t46 41 S
+t48 42 t46 42 S
+t48 43 t46 43 S
+t48 44 t46 44 S
+t48 45 t46 45 S
+t48 46 t46 46 S
+t48 47 t46 47 S
+t48 48 t46 48 S
+t48 49 t46 49 S
+t48 50 t46 50 S
+t48 51 t46 51 S
+t48 52 t46 52 S
+t48 53 t46 53 S
+t48 54 t46 54 S
+t48 55 t46 55 S
+t48 56 t46 56 S
+t48 57 t46 57 S
+t48 58 t46 58 S
+t48 59 t46 59 S
+t45 0 t45 0 R
+t45 1 t45 1 R
+t45 2 t45 2 R
+t45 3 t45 3 R
+t45 4 t45 4 R
+t45 5 t45 5 R
+t45 6 t45 6 R
+t45 7 t45 7 R
+t45 8 t45 8 R
+t45 9 t45 9 R
+t45 10 t45 10 R
+t45 11 t45 11 R
+t45 12 t45 12 R
+t45 13 t45 13 R
+t45 14 t45 14 R
+t45 15 t45 15 R
+t45 16 t45 16 R
+t45 17 t45 17 R
+t45 18 t45 18 R
+t45 19 t45 19 R
+t45 20 t45 20 R
+t45 21 t45 21 R
+t45 22 t45 22 R
+t45 23 t45 23 R
+t45 24 t45 24 R
+t45 25 t45 25 R
+t45 26 t45 26 R
+t45 27 t45 27 R
+t45 28 t45 28 R
+t45 29 t45 29 R
+t45 30 t49 30 S
+t45 31 t49 31 S
+t45 32 t49 32 S
+t45 33 t49 33 S
+t45 34 t49 34 S
+t45 35 t49 35 S
+t45 36 t49 36 S
+t45 37 t49 37 S
+t45 38 t49 38 S
+t45 39 t49 39 S
+t45 40 t49 40 S
+t45 41 t49 41 S
+t45 42 t49 42 S
+t45 43 t49 43 S
+t45 44 t49 44 S
+t45 45 t49 45 S
+t45 46 t49 46 S
+t45 47 t49 47 S
+t45 48 t49 48 S
+t45 49 t49 49 S
+t45 50 t49 50 S
+t45 51 t49 51 S
+t45 52 t49 52 S
+t45 53 t49 53 S
+t45 54 t49 54 S
+t45 55 t49 55 S
+t45 56 t49 56 S
+t45 57 t49 57 S
+t45 58 t49 58 S
+t45 59 t49 59 S
+t45 61 t45 61 R
+t45 62 t45 62 R
+t45 63 t45 63 R
+t45 64 t45 64 R
+t45 65 t45 65 R
+t45 66 t45 66 R
+t45 67 t45 67 R
+t45 68 t45 68 R
+t45 69 t45 69 R
+t45 70 t45 70 R
+t45 71 t45 71 R
+t45 72 t45 72 R
+t45 73 t45 73 R
+t45 74 t45 74 R
+t45 75 t45 75 R
+t45 76 t45 76 R
+t45 77 t45 77 R
+t45 78 t45 78 R
+t45 79 t45 79 R
+t45 80 t45 80 R
+t45 81 t45 81 R
+t45 82 t45 82 R
+t45 83 t45 83 R
+t45 84 t45 84 R
+t45 85 t45 85 R
+t45 86 t45 86 R
+t45 87 t45 87 R
+t45 88 t45 88 R
+t45 89 t45 89 R
+t45 90 t49 90 S
+t45 91 t49 91 S
+t45 92 t49 92 S
+t45 93 t49 93 S
+t45 94 t49 94 S
+t45 105 t49 105 S
+t45 106 t49 106 S
+t45 107 t49 107 S
+t45 108 t49 108 S
+t45 109 t49 109 S
+t47 0 t47 0 R
+t47 1 t47 1 R
+t47 2 t47 2 R
+t47 3 t47 3 R
+t47 4 t47 4 R
+t47 5 t47 5 R
+t47 6 t47 6 R
+t47 7 t47 7 R
+t47 8 t47 8 R
+t47 9 t47 9 R
+t47 10 t47 10 R
+t47 11 t47 11 R
+t47 12 t47 12 R
+t47 13 t47 13 R
+t47 14 t47 14 R
+t47 15 t50 15 S
+t47 16 t50 16 S
+t47 17 t50 17 S
+t47 18 t50 18 S
+t47 19 t50 19 S
+t47 20 t50 20 S
+t47 21 t50 21 S
+t47 22 t50 22 S
+t47 23 t50 23 S
+t47 24 t50 24 S
+t47 25 t50 25 S
+t47 26 t50 26 S
+t47 27 t50 27 S
+t47 28 t50 28 S
+t47 29 t50 29 S
+t47 30 t47 30 R
+t47 31 t47 31 R
+t47 32 t47 32 R
+t47 33 t47 33 R
+t47 34 t47 34 R
+t47 35 t47 35 R
+t47 36 t47 36 R
+t47 37 t47 37 R
+t47 38 t47 38 R
+t47 39 t47 39 R
+t47 40 t47 40 R
+t47 41 t47 41 R
+t47 42 t47 42 R
+t47 43 t47 43 R
+t47 44 t47 44 R
+t47 45 t50 45 S
+t47 46 t50 46 S
+t47 47 t50 47 S
+t47 48 t50 48 S
+t47 49 t50 49 S
+t47 50 t50 50 S
+t47 51 t50 51 S
+t47 52 t50 52 S
+t47 53 t50 53 S
+t47 54 t50 54 S
+t47 55 t50 55 S
+t47 56 t50 56 S
+t47 57 t50 57 S
+t47 58 t50 58 S
+t47 59 t50 59 S
+t47 61 t47 61 R
+t47 62 t47 62 R
+t47 63 t47 63 R
+t47 64 t47 64 R
+t47 65 t47 65 R
+t47 66 t47 66 R
+t47 67 t47 67 R
+t47 68 t47 68 R
+t47 69 t47 69 R
+t47 70 t47 70 R
+t47 71 t47 71 R
+t47 72 t47 72 R
+t47 73 t47 73 R
+t47 74 t47 74 R
+t47 75 t50 75 S
+t47 76 t50 76 S
+t47 77 t50 77 S
+t47 78 t50 78 S
+t47 79 t50 79 S
+t47 80 t50 80 S
+t47 81 t50 81 S
+t47 82 t50 82 S
+t47 83 t50 83 S
+t47 84 t50 84 S
+t47 85 t50 85 S
+t47 86 t50 86 S
+t47 87 t50 87 S
+t47 88 t50 88 S
+t47 89 t50 89 S
+t47 90 t47 90 R
+t47 91 t47 91 R
+t47 92 t47 92 R
+t47 93 t47 93 R
+t47 94 t47 94 R
+t47 105 t50 105 S
+t47 106 t50 106 S
+t47 107 t50 107 S
+t47 108 t50 108 S
+t47 109 t50 109 S
+t49 30 t48 10 R
+t49 31 t48 11 R
+t49 32 t48 12 R
+t49 33 t48 13 R
+t49 34 t48 14 R
+t49 45 t48 25 R
+t49 46 t48 26 R
+t49 47 t48 27 R
+t49 48 t48 28 R
+t49 49 t48 29 R
+t49 90 t48 70 R
+t49 91 t48 71 R
+t49 92 t48 72 R
+t49 93 t48 73 R
+t49 94 t48 74 R
+t49 105 t48 85 R
+t49 106 t48 86 R
+t49 107 t48 87 R
+t49 108 t48 88 R
+t49 109 t48 89 R
+t50 15 t37 2 L
+t50 16 t37 2 L
+t50 17 t37 2 L
+t50 18 t37 2 L
+t50 19 t37 2 L
+t50 20 t37 7 L
+t50 21 t37 7 L
+t50 22 t37 7 L
+t50 23 t37 7 L
+t50 24 t37 7 L
+t50 25 t37 12 L
+t50 26 t37 12 L
+t50 27 t37 12 L
+t50 28 t37 12 L
+t50 29 t37 12 L
+t50 45 t37 32 L
+t50 46 t37 32 L
+t50 47 t37 32 L
+t50 48 t37 32 L
+t50 49 t37 32 L
+t50 50 t37 37 L
+t50 51 t37 37 L
+t50 52 t37 37 L
+t50 53 t37 37 L
+t50 54 t37 37 L
+t50 55 t37 42 L
+t50 56 t37 42 L
+t50 57 t37 42 L
+t50 58 t37 42 L
+t50 59 t37 42 L
+t50 75 t37 62 L
+t50 76 t37 62 L
+t50 77 t37 62 L
+t50 78 t37 62 L
+t50 79 t37 62 L
+t50 80 t37 67 L
+t50 81 t37 67 L
+t50 82 t37 67 L
+t50 83 t37 67 L
+t50 84 t37 67 L
+t50 85 t37 72 L
+t50 86 t37 72 L
+t50 87 t37 72 L
+t50 88 t37 72 L
+t50 89 t37 72 L
+t50 105 t37 92 L
+t50 106 t37 92 L
+t50 107 t37 92 L
+t50 108 t37 92 L
+t50 109 t37 92 L
+t43 0 t43 0 L
+t43 1 t43 1 L
+t43 2 t43 2 L
+t43 3 t43 3 L
+t43 4 t43 4 L
+t43 5 t43 5 L
+t43 6 t43 6 L
+t43 7 t43 7 L
+t43 8 t43 8 L
+t43 9 t43 9 L
+t43 10 t43 10 L
+t43 11 t43 11 L
+t43 12 t43 12 L
+t43 13 t43 13 L
+t43 14 t43 14 L
+t43 15 t43 15 L
+t43 16 t43 16 L
+t43 17 t43 17 L
+t43 18 t43 18 L
+t43 19 t43 19 L
+t43 20 t43 20 L
+t43 21 t43 21 L
+t43 22 t43 22 L
+t43 23 t43 23 L
+t43 24 t43 24 L
+t43 25 t43 25 L
+t43 26 t43 26 L
+t43 27 t43 27 L
+t43 28 t43 28 L
+t43 29 t43 29 L
+t43 30 t43 30 L
+t43 31 t43 31 L
+t43 32 t43 32 L
+t43 33 t43 33 L
+t43 34 t43 34 L
+t43 35 t43 35 L
+t43 36 t43 36 L
+t43 37 t43 37 L
+t43 38 t43 38 L
+t43 39 t43 39 L
+t43 40 t43 40 L
+t43 41 t43 41 L
+t43 42 t43 42 L
+t43 43 t43 43 L
+t43 44 t43 44 L
+t43 45 t43 45 L
+t43 46 t43 46 L
+t43 47 t43 47 L
+t43 48 t43 48 L
+t43 49 t43 49 L
+t43 50 t43 50 L
+t43 51 t43 51 L
+t43 52 t43 52 L
+t43 53 t43 53 L
+t43 54 t43 54 L
+t43 55 t43 55 L
+t43 56 t43 56 L
+t43 57 t43 57 L
+t43 58 t43 58 L
+t43 59 t43 59 L
+t43 61 t51 61 S
+t43 62 t51 62 S
+t43 63 t51 63 S
+t43 64 t51 64 S
+t43 65 t51 65 S
+t43 66 t51 66 S
+t43 67 t51 67 S
+t43 68 t51 68 S
+t43 69 t51 69 S
+t43 70 t51 70 S
+t43 71 t51 71 S
+t43 72 t51 72 S
+t43 73 t51 73 S
+t43 74 t51 74 S
+t43 75 t51 75 S
+t43 76 t51 76 S
+t43 77 t51 77 S
+t43 78 t51 78 S
+t43 79 t51 79 S
+t43 80 t51 80 S
+t43 81 t51 81 S
+t43 82 t51 82 S
+t43 83 t51 83 S
+t43 84 t51 84 S
+t43 85 t51 85 S
+t43 86 t51 86 S
+t43 87 t51 87 S
+t43 88 t51 88 S
+t43 89 t51 89 S
+t43 90 t51 90 S
+t43 91 t51 91 S
+t43 92 t51 92 S
+t43 93 t51 93 S
+t43 94 t51 94 S
+t43 105 t51 105 S
+t43 106 t51 106 S
+t43 107 t51 107 S
+t43 108 t51 108 S
+t43 109 t51 109 S
+t52 0 t52 0 L
+t52 1 t52 1 L
+t52 2 t52 2 L
+t52 3 t52 3 L
+t52 4 t52 4 L
+t52 5 t52 5 L
+t52 6 t52 6 L
+t52 7 t52 7 L
+t52 8 t52 8 L
+t52 9 t52 9 L
+t52 10 t52 10 L
+t52 11 t52 11 L
+t52 12 t52 12 L
+t52 13 t52 13 L
+t52 14 t52 14 L
+t52 15 t52 15 L
+t52 16 t52 16 L
+t52 17 t52 17 L
+t52 18 t52 18 L
+t52 19 t52 19 L
+t52 20 t52 20 L
+t52 21 t52 21 L
+t52 22 t52 22 L
+t52 23 t52 23 L
+t52 24 t52 24 L
+t52 25 t52 25 L
+t52 26 t52 26 L
+t52 27 t52 27 L
+t52 28 t52 28 L
+t52 29 t52 29 L
+t52 30 t52 30 L
+t52 31 t52 31 L
+t52 32 t52 32 L
+t52 33 t52 33 L
+t52 34 t52 34 L
+t52 35 t52 35 L
+t52 36 t52 36 L
+t52 37 t52 37 L
+t52 38 t52 38 L
+t52 39 t52 39 L
+t52 40 t52 40 L
+t52 41 t52 41 L
+t52 42 t52 42 L
+t52 43 t52 43 L
+t52 44 t52 44 L
+t52 45 t52 45 L
+t52 46 t52 46 L
+t52 47 t52 47 L
+t52 48 t52 48 L
+t52 49 t52 49 L
+t52 50 t52 50 L
+t52 51 t52 51 L
+t52 52 t52 52 L
+t52 53 t52 53 L
+t52 54 t52 54 L
+t52 55 t52 55 L
+t52 56 t52 56 L
+t52 57 t52 57 L
+t52 58 t52 58 L
+t52 59 t52 59 L
+t52 61 t53 61 S
+t52 62 t53 62 S
+t52 63 t53 63 S
+t52 64 t53 64 S
+t52 65 t53 65 S
+t52 66 t53 66 S
+t52 67 t53 67 S
+t52 68 t53 68 S
+t52 69 t53 69 S
+t52 70 t53 70 S
+t52 71 t53 71 S
+t52 72 t53 72 S
+t52 73 t53 73 S
+t52 74 t53 74 S
+t52 75 t53 75 S
+t52 76 t53 76 S
+t52 77 t53 77 S
+t52 78 t53 78 S
+t52 79 t53 79 S
+t52 80 t53 80 S
+t52 81 t53 81 S
+t52 82 t53 82 S
+t52 83 t53 83 S
+t52 84 t53 84 S
+t52 85 t53 85 S
+t52 86 t53 86 S
+t52 87 t53 87 S
+t52 88 t53 88 S
+t52 89 t53 89 S
+t52 90 t53 90 S
+t52 91 t53 91 S
+t52 92 t53 92 S
+t52 93 t53 93 S
+t52 94 t53 94 S
+t52 105 t53 105 S
+t52 106 t53 106 S
+t52 107 t53 107 S
+t52 108 t53 108 S
+t52 109 t53 109 S
+t54 0 t55 15 S
+t54 1 t55 16 S
+t54 2 t55 17 S
+t54 3 t55 18 S
+t54 4 t55 19 S
+t54 5 t55 20 S
+t54 6 t55 21 S
+t54 7 t55 22 S
+t54 8 t55 23 S
+t54 9 t55 24 S
+t54 10 t55 25 S
+t54 11 t55 26 S
+t54 12 t55 27 S
+t54 13 t55 28 S
+t54 14 t55 29 S
+t54 15 t55 15 S
+t54 16 t55 16 S
+t54 17 t55 17 S
+t54 18 t55 18 S
+t54 19 t55 19 S
+t54 20 t55 20 S
+t54 21 t55 21 S
+t54 22 t55 22 S
+t54 23 t55 23 S
+t54 24 t55 24 S
+t54 25 t55 25 S
+t54 26 t55 26 S
+t54 27 t55 27 S
+t54 28 t55 28 S
+t54 29 t55 29 S
+t54 30 t55 45 S
+t54 31 t55 46 S
+t54 32 t55 47 S
+t54 33 t55 48 S
+t54 34 t55 49 S
+t54 35 t55 50 S
+t54 36 t55 51 S
+t54 37 t55 52 S
+t54 38 t55 53 S
+t54 39 t55 54 S
+t54 40 t55 55 S
+t54 41 t55 56 S
+t54 42 t55 57 S
+t54 43 t55 58 S
+t54 44 t55 59 S
+t54 45 t55 45 S
+t54 46 t55 46 S
+t54 47 t55 47 S
+t54 48 t55 48 S
+t54 49 t55 49 S
+t54 50 t55 50 S
+t54 51 t55 51 S
+t54 52 t55 52 S
+t54 53 t55 53 S
+t54 54 t55 54 S
+t54 55 t55 55 S
+t54 56 t55 56 S
+t54 57 t55 57 S
+t54 58 t55 58 S
+t54 59 t55 59 S
+t54 60 t55 75 S
+t54 61 t55 76 S
+t54 62 t55 77 S
+t54 63 t55 78 S
+t54 64 t55 79 S
+t54 65 t55 80 S
+t54 66 t55 81 S
+t54 67 t55 82 S
+t54 68 t55 83 S
+t54 69 t55 84 S
+t54 70 t55 85 S
+t54 71 t55 86 S
+t54 72 t55 87 S
+t54 73 t55 88 S
+t54 74 t55 89 S
+t54 75 t55 75 S
+t54 76 t55 76 S
+t54 77 t55 77 S
+t54 78 t55 78 S
+t54 79 t55 79 S
+t54 80 t55 80 S
+t54 81 t55 81 S
+t54 82 t55 82 S
+t54 83 t55 83 S
+t54 84 t55 84 S
+t54 85 t55 85 S
+t54 86 t55 86 S
+t54 87 t55 87 S
+t54 88 t55 88 S
+t54 89 t55 89 S
+t54 90 t55 105 S
+t54 91 t55 106 S
+t54 92 t55 107 S
+t54 93 t55 108 S
+t54 94 t55 109 S
+t54 105 t55 105 S
+t54 106 t55 106 S
+t54 107 t55 107 S
+t54 108 t55 108 S
+t54 109 t55 109 S
+t56 0 t52 30 S
+t56 1 t52 31 S
+t56 2 t52 32 S
+t56 3 t52 33 S
+t56 4 t52 34 S
+t56 5 t52 35 S
+t56 6 t52 36 S
+t56 7 t52 37 S
+t56 8 t52 38 S
+t56 9 t52 39 S
+t56 10 t52 40 S
+t56 11 t52 41 S
+t56 12 t52 42 S
+t56 13 t52 43 S
+t56 14 t52 44 S
+t56 15 t52 45 S
+t56 16 t52 46 S
+t56 17 t52 47 S
+t56 18 t52 48 S
+t56 19 t52 49 S
+t56 20 t52 50 S
+t56 21 t52 51 S
+t56 22 t52 52 S
+t56 23 t52 53 S
+t56 24 t52 54 S
+t56 25 t52 55 S
+t56 26 t52 56 S
+t56 27 t52 57 S
+t56 28 t52 58 S
+t56 29 t52 59 S
+t56 30 t52 30 S
+t56 31 t52 31 S
+t56 32 t52 32 S
+t56 33 t52 33 S
+t56 34 t52 34 S
+t56 35 t52 35 S
+t56 36 t52 36 S
+t56 37 t52 37 S
+t56 38 t52 38 S
+t56 39 t52 39 S
+t56 40 t52 40 S
+t56 41 t52 41 S
+t56 42 t52 42 S
+t56 43 t52 43 S
+t56 44 t52 44 S
+t56 45 t52 45 S
+t56 46 t52 46 S
+t56 47 t52 47 S
+t56 48 t52 48 S
+t56 49 t52 49 S
+t56 50 t52 50 S
+t56 51 t52 51 S
+t56 52 t52 52 S
+t56 53 t52 53 S
+t56 54 t52 54 S
+t56 55 t52 55 S
+t56 56 t52 56 S
+t56 57 t52 57 S
+t56 58 t52 58 S
+t56 59 t52 59 S
+t51 0 t51 0 R
+t51 1 t51 1 R
+t51 2 t51 2 R
+t51 3 t51 3 R
+t51 4 t51 4 R
+t51 5 t51 5 R
+t51 6 t51 6 R
+t51 7 t51 7 R
+t51 8 t51 8 R
+t51 9 t51 9 R
+t51 10 t51 10 R
+t51 11 t51 11 R
+t51 12 t51 12 R
+t51 13 t51 13 R
+t51 14 t51 14 R
+t51 15 t51 15 R
+t51 16 t51 16 R
+t51 17 t51 17 R
+t51 18 t51 18 R
+t51 19 t51 19 R
+t51 20 t51 20 R
+t51 21 t51 21 R
+t51 22 t51 22 R
+t51 23 t51 23 R
+t51 24 t51 24 R
+t51 25 t51 25 R
+t51 26 t51 26 R
+t51 27 t51 27 R
+t51 28 t51 28 R
+t51 29 t51 29 R
+t51 30 t57 30 S
+t51 31 t57 31 S
+t51 32 t57 32 S
+t51 33 t57 33 S
+t51 34 t57 34 S
+t51 35 t57 35 S
+t51 36 t57 36 S
+t51 37 t57 37 S
+t51 38 t57 38 S
+t51 39 t57 39 S
+t51 40 t57 40 S
+t51 41 t57 41 S
+t51 42 t57 42 S
+t51 43 t57 43 S
+t51 44 t57 44 S
+t51 45 t57 45 S
+t51 46 t57 46 S
+t51 47 t57 47 S
+t51 48 t57 48 S
+t51 49 t57 49 S
+t51 50 t57 50 S
+t51 51 t57 51 S
+t51 52 t57 52 S
+t51 53 t57 53 S
+t51 54 t57 54 S
+t51 55 t57 55 S
+t51 56 t57 56 S
+t51 57 t57 57 S
+t51 58 t57 58 S
+t51 59 t57 59 S
+t51 61 t51 61 R
+t51 62 t51 62 R
+t51 63 t51 63 R
+t51 64 t51 64 R
+t51 65 t51 65 R
+t51 66 t51 66 R
+t51 67 t51 67 R
+t51 68 t51 68 R
+t51 69 t51 69 R
+t51 70 t51 70 R
+t51 71 t51 71 R
+t51 72 t51 72 R
+t51 73 t51 73 R
+t51 74 t51 74 R
+t51 75 t51 75 R
+t51 76 t51 76 R
+t51 77 t51 77 R
+t51 78 t51 78 R
+t51 79 t51 79 R
+t51 80 t51 80 R
+t51 81 t51 81 R
+t51 82 t51 82 R
+t51 83 t51 83 R
+t51 84 t51 84 R
+t51 85 t51 85 R
+t51 86 t51 86 R
+t51 87 t51 87 R
+t51 88 t51 88 R
+t51 89 t51 89 R
+t51 90 t57 90 S
+t51 91 t57 91 S
+t51 92 t57 92 S
+t51 93 t57 93 S
+t51 94 t57 94 S
+t51 105 t57 105 S
+t51 106 t57 106 S
+t51 107 t57 107 S
+t51 108 t57 108 S
+t51 109 t57 109 S
+t53 0 t53 0 R
+t53 1 t53 1 R
+t53 2 t53 2 R
+t53 3 t53 3 R
+t53 4 t53 4 R
+t53 5 t53 5 R
+t53 6 t53 6 R
+t53 7 t53 7 R
+t53 8 t53 8 R
+t53 9 t53 9 R
+t53 10 t53 10 R
+t53 11 t53 11 R
+t53 12 t53 12 R
+t53 13 t53 13 R
+t53 14 t53 14 R
+t53 15 t58 15 S
+t53 16 t58 16 S
+t53 17 t58 17 S
+t53 18 t58 18 S
+t53 19 t58 19 S
+t53 20 t58 20 S
+t53 21 t58 21 S
+t53 22 t58 22 S
+t53 23 t58 23 S
+t53 24 t58 24 S
+t53 25 t58 25 S
+t53 26 t58 26 S
+t53 27 t58 27 S
+t53 28 t58 28 S
+t53 29 t58 29 S
+t53 30 t53 30 R
+t53 31 t53 31 R
+t53 32 t53 32 R
+t53 33 t53 33 R
+t53 34 t53 34 R
+t53 35 t53 35 R
+t53 36 t53 36 R
+t53 37 t53 37 R
+t53 38 t53 38 R
+t53 39 t53 39 R
+t53 40 t53 40 R
+t53 41 t53 41 R
+t53 42 t53 42 R
+t53 43 t53 43 R
+t53 44 t53 44 R
+t53 45 t58 45 S
+t53 46 t58 46 S
+t53 47 t58 47 S
+t53 48 t58 48 S
+t53 49 t58 49 S
+t53 50 t58 50 S
+t53 51 t58 51 S
+t53 52 t58 52 S
+t53 53 t58 53 S
+t53 54 t58 54 S
+t53 55 t58 55 S
+t53 56 t58 56 S
+t53 57 t58 57 S
+t53 58 t58 58 S
+t53 59 t58 59 S
+t53 61 t53 61 R
+t53 62 t53 62 R
+t53 63 t53 63 R
+t53 64 t53 64 R
+t53 65 t53 65 R
+t53 66 t53 66 R
+t53 67 t53 67 R
+t53 68 t53 68 R
+t53 69 t53 69 R
+t53 70 t53 70 R
+t53 71 t53 71 R
+t53 72 t53 72 R
+t53 73 t53 73 R
+t53 74 t53 74 R
+t53 75 t58 75 S
+t53 76 t58 76 S
+t53 77 t58 77 S
+t53 78 t58 78 S
+t53 79 t58 79 S
+t53 80 t58 80 S
+t53 81 t58 81 S
+t53 82 t58 82 S
+t53 83 t58 83 S
+t53 84 t58 84 S
+t53 85 t58 85 S
+t53 86 t58 86 S
+t53 87 t58 87 S
+t53 88 t58 88 S
+t53 89 t58 89 S
+t53 90 t53 90 R
+t53 91 t53 91 R
+t53 92 t53 92 R
+t53 93 t53 93 R
+t53 94 t53 94 R
+t53 105 t58 105 S
+t53 106 t58 106 S
+t53 107 t58 107 S
+t53 108 t58 108 S
+t53 109 t58 109 S
+t57 30 t56 5 R
+t57 31 t56 6 R
+t57 32 t56 7 R
+t57 33 t56 8 R
+t57 34 t56 9 R
+t57 45 t56 20 R
+t57 46 t56 21 R
+t57 47 t56 22 R
+t57 48 t56 23 R
+t57 49 t56 24 R
+t57 90 t56 65 R
+t57 91 t56 66 R
+t57 92 t56 67 R
+t57 93 t56 68 R
+t57 94 t56 69 R
+t57 105 t56 80 R
+t57 106 t56 81 R
+t57 107 t56 82 R
+t57 108 t56 83 R
+t57 109 t56 84 R
+t58 15 t54 3 L
+t58 16 t54 3 L
+t58 17 t54 3 L
+t58 18 t54 3 L
+t58 19 t54 3 L
+t58 20 t54 8 L
+t58 21 t54 8 L
+t58 22 t54 8 L
+t58 23 t54 8 L
+t58 24 t54 8 L
+t58 25 t54 13 L
+t58 26 t54 13 L
+t58 27 t54 13 L
+t58 28 t54 13 L
+t58 29 t54 13 L
+t58 45 t54 33 L
+t58 46 t54 33 L
+t58 47 t54 33 L
+t58 48 t54 33 L
+t58 49 t54 33 L
+t58 50 t54 38 L
+t58 51 t54 38 L
+t58 52 t54 38 L
+t58 53 t54 38 L
+t58 54 t54 38 L
+t58 55 t54 43 L
+t58 56 t54 43 L
+t58 57 t54 43 L
+t58 58 t54 43 L
+t58 59 t54 43 L
+t58 75 t54 63 L
+t58 76 t54 63 L
+t58 77 t54 63 L
+t58 78 t54 63 L
+t58 79 t54 63 L
+t58 80 t54 68 L
+t58 81 t54 68 L
+t58 82 t54 68 L
+t58 83 t54 68 L
+t58 84 t54 68 L
+t58 85 t54 73 L
+t58 86 t54 73 L
+t58 87 t54 73 L
+t58 88 t54 73 L
+t58 89 t54 73 L
+t58 105 t54 93 L
+t58 106 t54 93 L
+t58 107 t54 93 L
+t58 108 t54 93 L
+t58 109 t54 93 L
+t44 0 t44 0 L
+t44 1 t44 1 L
+t44 2 t44 2 L
+t44 3 t44 3 L
+t44 4 t44 4 L
+t44 5 t44 5 L
+t44 6 t44 6 L
+t44 7 t44 7 L
+t44 8 t44 8 L
+t44 9 t44 9 L
+t44 10 t44 10 L
+t44 11 t44 11 L
+t44 12 t44 12 L
+t44 13 t44 13 L
+t44 14 t44 14 L
+t44 15 t44 15 L
+t44 16 t44 16 L
+t44 17 t44 17 L
+t44 18 t44 18 L
+t44 19 t44 19 L
+t44 20 t44 20 L
+t44 21 t44 21 L
+t44 22 t44 22 L
+t44 23 t44 23 L
+t44 24 t44 24 L
+t44 25 t44 25 L
+t44 26 t44 26 L
+t44 27 t44 27 L
+t44 28 t44 28 L
+t44 29 t44 29 L
+t44 30 t44 30 L
+t44 31 t44 31 L
+t44 32 t44 32 L
+t44 33 t44 33 L
+t44 34 t44 34 L
+t44 35 t44 35 L
+t44 36 t44 36 L
+t44 37 t44 37 L
+t44 38 t44 38 L
+t44 39 t44 39 L
+t44 40 t44 40 L
+t44 41 t44 41 L
+t44 42 t44 42 L
+t44 43 t44 43 L
+t44 44 t44 44 L
+t44 45 t44 45 L
+t44 46 t44 46 L
+t44 47 t44 47 L
+t44 48 t44 48 L
+t44 49 t44 49 L
+t44 50 t44 50 L
+t44 51 t44 51 L
+t44 52 t44 52 L
+t44 53 t44 53 L
+t44 54 t44 54 L
+t44 55 t44 55 L
+t44 56 t44 56 L
+t44 57 t44 57 L
+t44 58 t44 58 L
+t44 59 t44 59 L
+t44 61 t59 61 S
+t44 62 t59 62 S
+t44 63 t59 63 S
+t44 64 t59 64 S
+t44 65 t59 65 S
+t44 66 t59 66 S
+t44 67 t59 67 S
+t44 68 t59 68 S
+t44 69 t59 69 S
+t44 70 t59 70 S
+t44 71 t59 71 S
+t44 72 t59 72 S
+t44 73 t59 73 S
+t44 74 t59 74 S
+t44 75 t59 75 S
+t44 76 t59 76 S
+t44 77 t59 77 S
+t44 78 t59 78 S
+t44 79 t59 79 S
+t44 80 t59 80 S
+t44 81 t59 81 S
+t44 82 t59 82 S
+t44 83 t59 83 S
+t44 84 t59 84 S
+t44 85 t59 85 S
+t44 86 t59 86 S
+t44 87 t59 87 S
+t44 88 t59 88 S
+t44 89 t59 89 S
+t44 90 t59 90 S
+t44 91 t59 91 S
+t44 92 t59 92 S
+t44 93 t59 93 S
+t44 94 t59 94 S
+t44 105 t59 105 S
+t44 106 t59 106 S
+t44 107 t59 107 S
+t44 108 t59 108 S
+t44 109 t59 109 S
+t60 0 t60 0 L
+t60 1 t60 1 L
+t60 2 t60 2 L
+t60 3 t60 3 L
+t60 4 t60 4 L
+t60 5 t60 5 L
+t60 6 t60 6 L
+t60 7 t60 7 L
+t60 8 t60 8 L
+t60 9 t60 9 L
+t60 10 t60 10 L
+t60 11 t60 11 L
+t60 12 t60 12 L
+t60 13 t60 13 L
+t60 14 t60 14 L
+t60 15 t60 15 L
+t60 16 t60 16 L
+t60 17 t60 17 L
+t60 18 t60 18 L
+t60 19 t60 19 L
+t60 20 t60 20 L
+t60 21 t60 21 L
+t60 22 t60 22 L
+t60 23 t60 23 L
+t60 24 t60 24 L
+t60 25 t60 25 L
+t60 26 t60 26 L
+t60 27 t60 27 L
+t60 28 t60 28 L
+t60 29 t60 29 L
+t60 30 t60 30 L
+t60 31 t60 31 L
+t60 32 t60 32 L
+t60 33 t60 33 L
+t60 34 t60 34 L
+t60 35 t60 35 L
+t60 36 t60 36 L
+t60 37 t60 37 L
+t60 38 t60 38 L
+t60 39 t60 39 L
+t60 40 t60 40 L
+t60 41 t60 41 L
+t60 42 t60 42 L
+t60 43 t60 43 L
+t60 44 t60 44 L
+t60 45 t60 45 L
+t60 46 t60 46 L
+t60 47 t60 47 L
+t60 48 t60 48 L
+t60 49 t60 49 L
+t60 50 t60 50 L
+t60 51 t60 51 L
+t60 52 t60 52 L
+t60 53 t60 53 L
+t60 54 t60 54 L
+t60 55 t60 55 L
+t60 56 t60 56 L
+t60 57 t60 57 L
+t60 58 t60 58 L
+t60 59 t60 59 L
+t60 61 t61 61 S
+t60 62 t61 62 S
+t60 63 t61 63 S
+t60 64 t61 64 S
+t60 65 t61 65 S
+t60 66 t61 66 S
+t60 67 t61 67 S
+t60 68 t61 68 S
+t60 69 t61 69 S
+t60 70 t61 70 S
+t60 71 t61 71 S
+t60 72 t61 72 S
+t60 73 t61 73 S
+t60 74 t61 74 S
+t60 75 t61 75 S
+t60 76 t61 76 S
+t60 77 t61 77 S
+t60 78 t61 78 S
+t60 79 t61 79 S
+t60 80 t61 80 S
+t60 81 t61 81 S
+t60 82 t61 82 S
+t60 83 t61 83 S
+t60 84 t61 84 S
+t60 85 t61 85 S
+t60 86 t61 86 S
+t60 87 t61 87 S
+t60 88 t61 88 S
+t60 89 t61 89 S
+t60 90 t61 90 S
+t60 91 t61 91 S
+t60 92 t61 92 S
+t60 93 t61 93 S
+t60 94 t61 94 S
+t60 105 t61 105 S
+t60 106 t61 106 S
+t60 107 t61 107 S
+t60 108 t61 108 S
+t60 109 t61 109 S
+t62 0 t60 30 S
+t62 1 t60 31 S
+t62 2 t60 32 S
+t62 3 t60 33 S
+t62 4 t60 34 S
+t62 5 t60 35 S
+t62 6 t60 36 S
+t62 7 t60 37 S
+t62 8 t60 38 S
+t62 9 t60 39 S
+t62 10 t60 40 S
+t62 11 t60 41 S
+t62 12 t60 42 S
+t62 13 t60 43 S
+t62 14 t60 44 S
+t62 15 t60 45 S
+t62 16 t60 46 S
+t62 17 t60 47 S
+t62 18 t60 48 S
+t62 19 t60 49 S
+t62 20 t60 50 S
+t62 21 t60 51 S
+t62 22 t60 52 S
+t62 23 t60 53 S
+t62 24 t60 54 S
+t62 25 t60 55 S
+t62 26 t60 56 S
+t62 27 t60 57 S
+t62 28 t60 58 S
+t62 29 t60 59 S
+t62 30 t60 30 S
+t62 31 t60 31 S
+t62 32 t60 32 S
+t62 33 t60 33 S
+t62 34 t60 34 S
+t62 35 t60 35 S
+t62 36 t60 36 S
+t62 37 t60 37 S
+t62 38 t60 38 S
+t62 39 t60 39 S
+t62 40 t60 40 S
+t62 41 t60 41 S
+t62 42 t60 42 S
+t62 43 t60 43 S
+t62 44 t60 44 S
+t62 45 t60 45 S
+t62 46 t60 46 S
+t62 47 t60 47 S
+t62 48 t60 48 S
+t62 49 t60 49 S
+t62 50 t60 50 S
+t62 51 t60 51 S
+t62 52 t60 52 S
+t62 53 t60 53 S
+t62 54 t60 54 S
+t62 55 t60 55 S
+t62 56 t60 56 S
+t62 57 t60 57 S
+t62 58 t60 58 S
+t62 59 t60 59 S
+t59 0 t59 0 R
+t59 1 t59 1 R
+t59 2 t59 2 R
+t59 3 t59 3 R
+t59 4 t59 4 R
+t59 5 t59 5 R
+t59 6 t59 6 R
+t59 7 t59 7 R
+t59 8 t59 8 R
+t59 9 t59 9 R
+t59 10 t59 10 R
+t59 11 t59 11 R
+t59 12 t59 12 R
+t59 13 t59 13 R
+t59 14 t59 14 R
+t59 15 t59 15 R
+t59 16 t59 16 R
+t59 17 t59 17 R
+t59 18 t59 18 R
+t59 19 t59 19 R
+t59 20 t59 20 R
+t59 21 t59 21 R
+t59 22 t59 22 R
+t59 23 t59 23 R
+t59 24 t59 24 R
+t59 25 t59 25 R
+t59 26 t59 26 R
+t59 27 t59 27 R
+t59 28 t59 28 R
+t59 29 t59 29 R
+t59 30 t63 30 S
+t59 31 t63 31 S
+t59 32 t63 32 S
+t59 33 t63 33 S
+t59 34 t63 34 S
+t59 35 t63 35 S
+t59 36 t63 36 S
+t59 37 t63 37 S
+t59 38 t63 38 S
+t59 39 t63 39 S
+t59 40 t63 40 S
+t59 41 t63 41 S
+t59 42 t63 42 S
+t59 43 t63 43 S
+t59 44 t63 44 S
+t59 45 t63 45 S
+t59 46 t63 46 S
+t59 47 t63 47 S
+t59 48 t63 48 S
+t59 49 t63 49 S
+t59 50 t63 50 S
+t59 51 t63 51 S
+t59 52 t63 52 S
+t59 53 t63 53 S
+t59 54 t63 54 S
+t59 55 t63 55 S
+t59 56 t63 56 S
+t59 57 t63 57 S
+t59 58 t63 58 S
+t59 59 t63 59 S
+t59 61 t59 61 R
+t59 62 t59 62 R
+t59 63 t59 63 R
+t59 64 t59 64 R
+t59 65 t59 65 R
+t59 66 t59 66 R
+t59 67 t59 67 R
+t59 68 t59 68 R
+t59 69 t59 69 R
+t59 70 t59 70 R
+t59 71 t59 71 R
+t59 72 t59 72 R
+t59 73 t59 73 R
+t59 74 t59 74 R
+t59 75 t59 75 R
+t59 76 t59 76 R
+t59 77 t59 77 R
+t59 78 t59 78 R
+t59 79 t59 79 R
+t59 80 t59 80 R
+t59 81 t59 81 R
+t59 82 t59 82 R
+t59 83 t59 83 R
+t59 84 t59 84 R
+t59 85 t59 85 R
+t59 86 t59 86 R
+t59 87 t59 87 R
+t59 88 t59 88 R
+t59 89 t59 89 R
+t59 90 t63 90 S
+t59 91 t63 91 S
+t59 92 t63 92 S
+t59 93 t63 93 S
+t59 94 t63 94 S
+t59 105 t63 105 S
+t59 106 t63 106 S
+t59 107 t63 107 S
+t59 108 t63 108 S
+t59 109 t63 109 S
+t61 0 t61 0 R
+t61 1 t61 1 R
+t61 2 t61 2 R
+t61 3 t61 3 R
+t61 4 t61 4 R
+t61 5 t61 5 R
+t61 6 t61 6 R
+t61 7 t61 7 R
+t61 8 t61 8 R
+t61 9 t61 9 R
+t61 10 t61 10 R
+t61 11 t61 11 R
+t61 12 t61 12 R
+t61 13 t61 13 R
+t61 14 t61 14 R
+t61 15 t64 15 S
+t61 16 t64 16 S
+t61 17 t64 17 S
+t61 18 t64 18 S
+t61 19 t64 19 S
+t61 20 t64 20 S
+t61 21 t64 21 S
+t61 22 t64 22 S
+t61 23 t64 23 S
+t61 24 t64 24 S
+t61 25 t64 25 S
+t61 26 t64 26 S
+t61 27 t64 27 S
+t61 28 t64 28 S
+t61 29 t64 29 S
+t61 30 t61 30 R
+t61 31 t61 31 R
+t61 32 t61 32 R
+t61 33 t61 33 R
+t61 34 t61 34 R
+t61 35 t61 35 R
+t61 36 t61 36 R
+t61 37 t61 37 R
+t61 38 t61 38 R
+t61 39 t61 39 R
+t61 40 t61 40 R
+t61 41 t61 41 R
+t61 42 t61 42 R
+t61 43 t61 43 R
+t61 44 t61 44 R
+t61 45 t64 45 S
+t61 46 t64 46 S
+t61 47 t64 47 S
+t61 48 t64 48 S
+t61 49 t64 49 S
+t61 50 t64 50 S
+t61 51 t64 51 S
+t61 52 t64 52 S
+t61 53 t64 53 S
+t61 54 t64 54 S
+t61 55 t64 55 S
+t61 56 t64 56 S
+t61 57 t64 57 S
+t61 58 t64 58 S
+t61 59 t64 59 S
+t61 61 t61 61 R
+t61 62 t61 62 R
+t61 63 t61 63 R
+t61 64 t61 64 R
+t61 65 t61 65 R
+t61 66 t61 66 R
+t61 67 t61 67 R
+t61 68 t61 68 R
+t61 69 t61 69 R
+t61 70 t61 70 R
+t61 71 t61 71 R
+t61 72 t61 72 R
+t61 73 t61 73 R
+t61 74 t61 74 R
+t61 75 t64 75 S
+t61 76 t64 76 S
+t61 77 t64 77 S
+t61 78 t64 78 S
+t61 79 t64 79 S
+t61 80 t64 80 S
+t61 81 t64 81 S
+t61 82 t64 82 S
+t61 83 t64 83 S
+t61 84 t64 84 S
+t61 85 t64 85 S
+t61 86 t64 86 S
+t61 87 t64 87 S
+t61 88 t64 88 S
+t61 89 t64 89 S
+t61 90 t61 90 R
+t61 91 t61 91 R
+t61 92 t61 92 R
+t61 93 t61 93 R
+t61 94 t61 94 R
+t61 105 t64 105 S
+t61 106 t64 106 S
+t61 107 t64 107 S
+t61 108 t64 108 S
+t61 109 t64 109 S
+t63 30 t62 10 R
+t63 31 t62 11 R
+t63 32 t62 12 R
+t63 33 t62 13 R
+t63 34 t62 14 R
+t63 45 t62 25 R
+t63 46 t62 26 R
+t63 47 t62 27 R
+t63 48 t62 28 R
+t63 49 t62 29 R
+t63 90 t62 70 R
+t63 91 t62 71 R
+t63 92 t62 72 R
+t63 93 t62 73 R
+t63 94 t62 74 R
+t63 105 t62 85 R
+t63 106 t62 86 R
+t63 107 t62 87 R
+t63 108 t62 88 R
+t63 109 t62 89 R
+t64 15 t54 4 L
+t64 16 t54 4 L
+t64 17 t54 4 L
+t64 18 t54 4 L
+t64 19 t54 4 L
+t64 20 t54 9 L
+t64 21 t54 9 L
+t64 22 t54 9 L
+t64 23 t54 9 L
+t64 24 t54 9 L
+t64 25 t54 14 L
+t64 26 t54 14 L
+t64 27 t54 14 L
+t64 28 t54 14 L
+t64 29 t54 14 L
+t64 45 t54 34 L
+t64 46 t54 34 L
+t64 47 t54 34 L
+t64 48 t54 34 L
+t64 49 t54 34 L
+t64 50 t54 39 L
+t64 51 t54 39 L
+t64 52 t54 39 L
+t64 53 t54 39 L
+t64 54 t54 39 L
+t64 55 t54 44 L
+t64 56 t54 44 L
+t64 57 t54 44 L
+t64 58 t54 44 L
+t64 59 t54 44 L
+t64 75 t54 64 L
+t64 76 t54 64 L
+t64 77 t54 64 L
+t64 78 t54 64 L
+t64 79 t54 64 L
+t64 80 t54 69 L
+t64 81 t54 69 L
+t64 82 t54 69 L
+t64 83 t54 69 L
+t64 84 t54 69 L
+t64 85 t54 74 L
+t64 86 t54 74 L
+t64 87 t54 74 L
+t64 88 t54 74 L
+t64 89 t54 74 L
+t64 105 t54 94 L
+t64 106 t54 94 L
+t64 107 t54 94 L
+t64 108 t54 94 L
+t64 109 t54 94 L
+t65 0 t65 0 L
+t65 1 t65 1 L
+t65 2 t65 2 L
+t65 3 t65 3 L
+t65 4 t65 4 L
+t65 15 t65 15 L
+t65 16 t65 16 L
+t65 17 t65 17 L
+t65 18 t65 18 L
+t65 19 t65 19 L
+t65 61 t66 61 S
+t65 62 t66 62 S
+t65 63 t66 63 S
+t65 64 t66 64 S
+t65 75 t66 75 S
+t65 76 t66 76 S
+t65 77 t66 77 S
+t65 78 t66 78 S
+t65 79 t66 79 S
+t65 91 t66 91 S
+t65 92 t66 92 S
+t65 93 t66 93 S
+t65 94 t66 94 S
+t65 105 t66 105 S
+t65 106 t66 106 S
+t65 107 t66 107 S
+t65 108 t66 108 S
+t65 109 t66 109 S
+t67 91 t68 91 S
+t67 92 t68 92 S
+t67 93 t68 93 S
+t67 94 t68 94 S
+t67 105 t68 105 S
+t67 106 t68 106 S
+t67 107 t68 107 S
+t67 108 t68 108 S
+t67 109 t68 109 S
+t69 61 t67 91 S
+t69 62 t67 92 S
+t69 63 t67 93 S
+t69 64 t67 94 S
+t69 75 t67 105 S
+t69 76 t67 106 S
+t69 77 t67 107 S
+t69 78 t67 108 S
+t69 79 t67 109 S
+t66 0 t66 0 R
+t66 1 t66 1 R
+t66 2 t66 2 R
+t66 3 t66 3 R
+t66 4 t66 4 R
+t66 15 t66 15 R
+t66 16 t66 16 R
+t66 17 t66 17 R
+t66 18 t66 18 R
+t66 19 t66 19 R
+t66 61 t66 61 R
+t66 62 t66 62 R
+t66 63 t66 63 R
+t66 64 t66 64 R
+t66 75 t66 75 R
+t66 76 t66 76 R
+t66 77 t66 77 R
+t66 78 t66 78 R
+t66 79 t66 79 R
+t66 91 t70 91 S
+t66 92 t70 92 S
+t66 93 t70 93 S
+t66 94 t70 94 S
+t66 105 t70 105 S
+t66 106 t70 106 S
+t66 107 t70 107 S
+t66 108 t70 108 S
+t66 109 t70 109 S
+t68 0 t68 0 R
+t68 1 t68 1 R
+t68 2 t68 2 R
+t68 3 t68 3 R
+t68 4 t68 4 R
+t68 15 t71 15 S
+t68 16 t71 16 S
+t68 17 t71 17 S
+t68 18 t71 18 S
+t68 19 t71 19 S
+t68 91 t68 91 R
+t68 92 t68 92 R
+t68 93 t68 93 R
+t68 94 t68 94 R
+t68 105 t71 105 S
+t68 106 t71 106 S
+t68 107 t71 107 S
+t68 108 t71 108 S
+t68 109 t71 109 S
+t70 91 t69 61 S
+t70 92 t69 62 S
+t70 93 t69 63 S
+t70 94 t69 64 S
+t70 105 t69 75 S
+t70 106 t69 76 S
+t70 107 t69 77 S
+t70 108 t69 78 S
+t70 109 t69 79 S
+t71 15 t37 0 L
+t71 16 t37 0 L
+t71 17 t37 0 L
+t71 18 t37 0 L
+t71 19 t37 0 L
+t71 105 t37 90 L
+t71 106 t37 90 L
+t71 107 t37 90 L
+t71 108 t37 90 L
+t71 109 t37 90 L
+t72 15 t65 15 S
+t72 16 t73 16 S
+t72 17 t74 17 S
+t72 18 t75 18 S
+t72 19 t76 19 S
+t72 108 t75 108 S
+t72 109 t76 109 S
+t73 0 t73 0 L
+t73 1 t73 1 L
+t73 2 t73 2 L
+t73 3 t73 3 L
+t73 4 t73 4 L
+t73 15 t73 15 L
+t73 16 t73 16 L
+t73 17 t73 17 L
+t73 18 t73 18 L
+t73 19 t73 19 L
+t73 61 t77 61 S
+t73 62 t77 62 S
+t73 63 t77 63 S
+t73 64 t77 64 S
+t73 75 t77 75 S
+t73 76 t77 76 S
+t73 77 t77 77 S
+t73 78 t77 78 S
+t73 79 t77 79 S
+t73 91 t77 91 S
+t73 92 t77 92 S
+t73 93 t77 93 S
+t73 94 t77 94 S
+t73 105 t77 105 S
+t73 106 t77 106 S
+t73 107 t77 107 S
+t73 108 t77 108 S
+t73 109 t77 109 S
+t78 91 t79 91 S
+t78 92 t79 92 S
+t78 93 t79 93 S
+t78 94 t79 94 S
+t78 105 t79 105 S
+t78 106 t79 106 S
+t78 107 t79 107 S
+t78 108 t79 108 S
+t78 109 t79 109 S
+t80 0 t72 15 S
+t80 1 t72 16 S
+t80 2 t72 17 S
+t80 3 t72 18 S
+t80 4 t72 19 S
+t80 15 t72 15 S
+t80 16 t72 16 S
+t80 17 t72 17 S
+t80 18 t72 18 S
+t80 19 t72 19 S
+t80 93 t72 108 S
+t80 94 t72 109 S
+t81 61 t78 91 S
+t81 62 t78 92 S
+t81 63 t78 93 S
+t81 64 t78 94 S
+t81 75 t78 105 S
+t81 76 t78 106 S
+t81 77 t78 107 S
+t81 78 t78 108 S
+t81 79 t78 109 S
+t77 0 t77 0 R
+t77 1 t77 1 R
+t77 2 t77 2 R
+t77 3 t77 3 R
+t77 4 t77 4 R
+t77 15 t77 15 R
+t77 16 t77 16 R
+t77 17 t77 17 R
+t77 18 t77 18 R
+t77 19 t77 19 R
+t77 61 t77 61 R
+t77 62 t77 62 R
+t77 63 t77 63 R
+t77 64 t77 64 R
+t77 75 t77 75 R
+t77 76 t77 76 R
+t77 77 t77 77 R
+t77 78 t77 78 R
+t77 79 t77 79 R
+t77 91 t82 91 S
+t77 92 t82 92 S
+t77 93 t82 93 S
+t77 94 t82 94 S
+t77 105 t82 105 S
+t77 106 t82 106 S
+t77 107 t82 107 S
+t77 108 t82 108 S
+t77 109 t82 109 S
+t79 0 t79 0 R
+t79 1 t79 1 R
+t79 2 t79 2 R
+t79 3 t79 3 R
+t79 4 t79 4 R
+t79 15 t83 15 S
+t79 16 t83 16 S
+t79 17 t83 17 S
+t79 18 t83 18 S
+t79 19 t83 19 S
+t79 91 t79 91 R
+t79 92 t79 92 R
+t79 93 t79 93 R
+t79 94 t79 94 R
+t79 105 t83 105 S
+t79 106 t83 106 S
+t79 107 t83 107 S
+t79 108 t83 108 S
+t79 109 t83 109 S
+t82 91 t81 61 S
+t82 92 t81 62 S
+t82 93 t81 63 S
+t82 94 t81 64 S
+t82 105 t81 75 S
+t82 106 t81 76 S
+t82 107 t81 77 S
+t82 108 t81 78 S
+t82 109 t81 79 S
+t83 15 t80 1 R
+t83 16 t80 1 R
+t83 17 t80 1 R
+t83 18 t80 1 R
+t83 19 t80 1 R
+t83 105 t80 91 R
+t83 106 t80 91 R
+t83 107 t80 91 R
+t83 108 t80 91 R
+t83 109 t80 91 R
+t74 0 t74 0 L
+t74 1 t74 1 L
+t74 2 t74 2 L
+t74 3 t74 3 L
+t74 4 t74 4 L
+t74 15 t74 15 L
+t74 16 t74 16 L
+t74 17 t74 17 L
+t74 18 t74 18 L
+t74 19 t74 19 L
+t74 61 t84 61 S
+t74 62 t84 62 S
+t74 63 t84 63 S
+t74 64 t84 64 S
+t74 75 t84 75 S
+t74 76 t84 76 S
+t74 77 t84 77 S
+t74 78 t84 78 S
+t74 79 t84 79 S
+t74 91 t84 91 S
+t74 92 t84 92 S
+t74 93 t84 93 S
+t74 94 t84 94 S
+t74 105 t84 105 S
+t74 106 t84 106 S
+t74 107 t84 107 S
+t74 108 t84 108 S
+t74 109 t84 109 S
+t85 91 t86 91 S
+t85 92 t86 92 S
+t85 93 t86 93 S
+t85 94 t86 94 S
+t85 105 t86 105 S
+t85 106 t86 106 S
+t85 107 t86 107 S
+t85 108 t86 108 S
+t85 109 t86 109 S
+t87 61 t85 91 S
+t87 62 t85 92 S
+t87 63 t85 93 S
+t87 64 t85 94 S
+t87 75 t85 105 S
+t87 76 t85 106 S
+t87 77 t85 107 S
+t87 78 t85 108 S
+t87 79 t85 109 S
+t84 0 t84 0 R
+t84 1 t84 1 R
+t84 2 t84 2 R
+t84 3 t84 3 R
+t84 4 t84 4 R
+t84 15 t84 15 R
+t84 16 t84 16 R
+t84 17 t84 17 R
+t84 18 t84 18 R
+t84 19 t84 19 R
+t84 61 t84 61 R
+t84 62 t84 62 R
+t84 63 t84 63 R
+t84 64 t84 64 R
+t84 75 t84 75 R
+t84 76 t84 76 R
+t84 77 t84 77 R
+t84 78 t84 78 R
+t84 79 t84 79 R
+t84 91 t88 91 S
+t84 92 t88 92 S
+t84 93 t88 93 S
+t84 94 t88 94 S
+t84 105 t88 105 S
+t84 106 t88 106 S
+t84 107 t88 107 S
+t84 108 t88 108 S
+t84 109 t88 109 S
+t86 0 t86 0 R
+t86 1 t86 1 R
+t86 2 t86 2 R
+t86 3 t86 3 R
+t86 4 t86 4 R
+t86 15 t89 15 S
+t86 16 t89 16 S
+t86 17 t89 17 S
+t86 18 t89 18 S
+t86 19 t89 19 S
+t86 91 t86 91 R
+t86 92 t86 92 R
+t86 93 t86 93 R
+t86 94 t86 94 R
+t86 105 t89 105 S
+t86 106 t89 106 S
+t86 107 t89 107 S
+t86 108 t89 108 S
+t86 109 t89 109 S
+t88 91 t87 61 S
+t88 92 t87 62 S
+t88 93 t87 63 S
+t88 94 t87 64 S
+t88 105 t87 75 S
+t88 106 t87 76 S
+t88 107 t87 77 S
+t88 108 t87 78 S
+t88 109 t87 79 S
+t89 15 t80 2 R
+t89 16 t80 2 R
+t89 17 t80 2 R
+t89 18 t80 2 R
+t89 19 t80 2 R
+t89 105 t80 92 R
+t89 106 t80 92 R
+t89 107 t80 92 R
+t89 108 t80 92 R
+t89 109 t80 92 R
+t75 0 t75 0 L
+t75 1 t75 1 L
+t75 2 t75 2 L
+t75 3 t75 3 L
+t75 4 t75 4 L
+t75 15 t75 15 L
+t75 16 t75 16 L
+t75 17 t75 17 L
+t75 18 t75 18 L
+t75 19 t75 19 L
+t75 61 t90 61 S
+t75 62 t90 62 S
+t75 63 t90 63 S
+t75 64 t90 64 S
+t75 75 t90 75 S
+t75 76 t90 76 S
+t75 77 t90 77 S
+t75 78 t90 78 S
+t75 79 t90 79 S
+t75 91 t90 91 S
+t75 92 t90 92 S
+t75 93 t90 93 S
+t75 94 t90 94 S
+t75 105 t90 105 S
+t75 106 t90 106 S
+t75 107 t90 107 S
+t75 108 t90 108 S
+t75 109 t90 109 S
+t91 91 t92 91 S
+t91 92 t92 92 S
+t91 93 t92 93 S
+t91 94 t92 94 S
+t91 105 t92 105 S
+t91 106 t92 106 S
+t91 107 t92 107 S
+t91 108 t92 108 S
+t91 109 t92 109 S
+t93 61 t91 91 S
+t93 62 t91 92 S
+t93 63 t91 93 S
+t93 64 t91 94 S
+t93 75 t91 105 S
+t93 76 t91 106 S
+t93 77 t91 107 S
+t93 78 t91 108 S
+t93 79 t91 109 S
+t90 0 t90 0 R
+t90 1 t90 1 R
+t90 2 t90 2 R
+t90 3 t90 3 R
+t90 4 t90 4 R
+t90 15 t90 15 R
+t90 16 t90 16 R
+t90 17 t90 17 R
+t90 18 t90 18 R
+t90 19 t90 19 R
+t90 61 t90 61 R
+t90 62 t90 62 R
+t90 63 t90 63 R
+t90 64 t90 64 R
+t90 75 t90 75 R
+t90 76 t90 76 R
+t90 77 t90 77 R
+t90 78 t90 78 R
+t90 79 t90 79 R
+t90 91 t94 91 S
+t90 92 t94 92 S
+t90 93 t94 93 S
+t90 94 t94 94 S
+t90 105 t94 105 S
+t90 106 t94 106 S
+t90 107 t94 107 S
+t90 108 t94 108 S
+t90 109 t94 109 S
+t92 0 t92 0 R
+t92 1 t92 1 R
+t92 2 t92 2 R
+t92 3 t92 3 R
+t92 4 t92 4 R
+t92 15 t95 15 S
+t92 16 t95 16 S
+t92 17 t95 17 S
+t92 18 t95 18 S
+t92 19 t95 19 S
+t92 91 t92 91 R
+t92 92 t92 92 R
+t92 93 t92 93 R
+t92 94 t92 94 R
+t92 105 t95 105 S
+t92 106 t95 106 S
+t92 107 t95 107 S
+t92 108 t95 108 S
+t92 109 t95 109 S
+t94 91 t93 61 S
+t94 92 t93 62 S
+t94 93 t93 63 S
+t94 94 t93 64 S
+t94 105 t93 75 S
+t94 106 t93 76 S
+t94 107 t93 77 S
+t94 108 t93 78 S
+t94 109 t93 79 S
+t95 15 t80 3 R
+t95 16 t80 3 R
+t95 17 t80 3 R
+t95 18 t80 3 R
+t95 19 t80 3 R
+t95 105 t80 93 R
+t95 106 t80 93 R
+t95 107 t80 93 R
+t95 108 t80 93 R
+t95 109 t80 93 R
+t76 0 t76 0 L
+t76 1 t76 1 L
+t76 2 t76 2 L
+t76 3 t76 3 L
+t76 4 t76 4 L
+t76 15 t76 15 L
+t76 16 t76 16 L
+t76 17 t76 17 L
+t76 18 t76 18 L
+t76 19 t76 19 L
+t76 61 t96 61 S
+t76 62 t96 62 S
+t76 63 t96 63 S
+t76 64 t96 64 S
+t76 75 t96 75 S
+t76 76 t96 76 S
+t76 77 t96 77 S
+t76 78 t96 78 S
+t76 79 t96 79 S
+t76 91 t96 91 S
+t76 92 t96 92 S
+t76 93 t96 93 S
+t76 94 t96 94 S
+t76 105 t96 105 S
+t76 106 t96 106 S
+t76 107 t96 107 S
+t76 108 t96 108 S
+t76 109 t96 109 S
+t97 91 t98 91 S
+t97 92 t98 92 S
+t97 93 t98 93 S
+t97 94 t98 94 S
+t97 105 t98 105 S
+t97 106 t98 106 S
+t97 107 t98 107 S
+t97 108 t98 108 S
+t97 109 t98 109 S
+t99 61 t97 91 S
+t99 62 t97 92 S
+t99 63 t97 93 S
+t99 64 t97 94 S
+t99 75 t97 105 S
+t99 76 t97 106 S
+t99 77 t97 107 S
+t99 78 t97 108 S
+t99 79 t97 109 S
+t96 0 t96 0 R
+t96 1 t96 1 R
+t96 2 t96 2 R
+t96 3 t96 3 R
+t96 4 t96 4 R
+t96 15 t96 15 R
+t96 16 t96 16 R
+t96 17 t96 17 R
+t96 18 t96 18 R
+t96 19 t96 19 R
+t96 61 t96 61 R
+t96 62 t96 62 R
+t96 63 t96 63 R
+t96 64 t96 64 R
+t96 75 t96 75 R
+t96 76 t96 76 R
+t96 77 t96 77 R
+t96 78 t96 78 R
+t96 79 t96 79 R
+t96 91 t100 91 S
+t96 92 t100 92 S
+t96 93 t100 93 S
+t96 94 t100 94 S
+t96 105 t100 105 S
+t96 106 t100 106 S
+t96 107 t100 107 S
+t96 108 t100 108 S
+t96 109 t100 109 S
+t98 0 t98 0 R
+t98 1 t98 1 R
+t98 2 t98 2 R
+t98 3 t98 3 R
+t98 4 t98 4 R
+t98 15 t101 15 S
+t98 16 t101 16 S
+t98 17 t101 17 S
+t98 18 t101 18 S
+t98 19 t101 19 S
+t98 91 t98 91 R
+t98 92 t98 92 R
+t98 93 t98 93 R
+t98 94 t98 94 R
+t98 105 t101 105 S
+t98 106 t101 106 S
+t98 107 t101 107 S
+t98 108 t101 108 S
+t98 109 t101 109 S
+t100 91 t99 61 S
+t100 92 t99 62 S
+t100 93 t99 63 S
+t100 94 t99 64 S
+t100 105 t99 75 S
+t100 106 t99 76 S
+t100 107 t99 77 S
+t100 108 t99 78 S
+t100 109 t99 79 S
+t101 15 t80 4 R
+t101 16 t80 4 R
+t101 17 t80 4 R
+t101 18 t80 4 R
+t101 19 t80 4 R
+t101 105 t80 94 R
+t101 106 t80 94 R
+t101 107 t80 94 R
+t101 108 t80 94 R
+t101 109 t80 94 R
+t102 0 t102 0 L
+t102 1 t102 1 L
+t102 2 t102 2 L
+t102 3 t102 3 L
+t102 4 t102 4 L
+t102 5 t102 5 L
+t102 6 t102 6 L
+t102 7 t102 7 L
+t102 8 t102 8 L
+t102 9 t102 9 L
+t102 10 t102 10 L
+t102 11 t102 11 L
+t102 12 t102 12 L
+t102 13 t102 13 L
+t102 14 t102 14 L
+t102 15 t102 15 L
+t102 16 t102 16 L
+t102 17 t102 17 L
+t102 18 t102 18 L
+t102 19 t102 19 L
+t102 20 t102 20 L
+t102 21 t102 21 L
+t102 22 t102 22 L
+t102 23 t102 23 L
+t102 24 t102 24 L
+t102 25 t102 25 L
+t102 26 t102 26 L
+t102 27 t102 27 L
+t102 28 t102 28 L
+t102 29 t102 29 L
+t102 30 t102 30 L
+t102 31 t102 31 L
+t102 32 t102 32 L
+t102 33 t102 33 L
+t102 34 t102 34 L
+t102 35 t102 35 L
+t102 36 t102 36 L
+t102 37 t102 37 L
+t102 38 t102 38 L
+t102 39 t102 39 L
+t102 40 t102 40 L
+t102 41 t102 41 L
+t102 42 t102 42 L
+t102 43 t102 43 L
+t102 44 t102 44 L
+t102 45 t102 45 L
+t102 46 t102 46 L
+t102 47 t102 47 L
+t102 48 t102 48 L
+t102 49 t102 49 L
+t102 50 t102 50 L
+t102 51 t102 51 L
+t102 52 t102 52 L
+t102 53 t102 53 L
+t102 54 t102 54 L
+t102 55 t102 55 L
+t102 56 t102 56 L
+t102 57 t102 57 L
+t102 58 t102 58 L
+t102 59 t102 59 L
+t102 60 t103 60 S
+t102 61 t103 61 S
+t102 62 t103 62 S
+t102 63 t103 63 S
+t102 64 t103 64 S
+t102 65 t103 65 S
+t102 66 t103 66 S
+t102 67 t103 67 S
+t102 68 t103 68 S
+t102 69 t103 69 S
+t102 70 t103 70 S
+t102 71 t103 71 S
+t102 72 t103 72 S
+t102 73 t103 73 S
+t102 74 t103 74 S
+t102 75 t103 75 S
+t102 76 t103 76 S
+t102 77 t103 77 S
+t102 78 t103 78 S
+t102 79 t103 79 S
+t102 80 t103 80 S
+t102 81 t103 81 S
+t102 82 t103 82 S
+t102 83 t103 83 S
+t102 84 t103 84 S
+t102 85 t103 85 S
+t102 86 t103 86 S
+t102 87 t103 87 S
+t102 88 t103 88 S
+t102 89 t103 89 S
+t102 90 t103 90 S
+t102 91 t103 91 S
+t102 92 t103 92 S
+t102 93 t103 93 S
+t102 94 t103 94 S
+t102 105 t103 105 S
+t102 106 t103 106 S
+t102 107 t103 107 S
+t102 108 t103 108 S
+t102 109 t103 109 S
+t104 0 t104 0 L
+t104 1 t104 1 L
+t104 2 t104 2 L
+t104 3 t104 3 L
+t104 4 t104 4 L
+t104 5 t104 5 L
+t104 6 t104 6 L
+t104 7 t104 7 L
+t104 8 t104 8 L
+t104 9 t104 9 L
+t104 10 t104 10 L
+t104 11 t104 11 L
+t104 12 t104 12 L
+t104 13 t104 13 L
+t104 14 t104 14 L
+t104 15 t104 15 L
+t104 16 t104 16 L
+t104 17 t104 17 L
+t104 18 t104 18 L
+t104 19 t104 19 L
+t104 20 t104 20 L
+t104 21 t104 21 L
+t104 22 t104 22 L
+t104 23 t104 23 L
+t104 24 t104 24 L
+t104 25 t104 25 L
+t104 26 t104 26 L
+t104 27 t104 27 L
+t104 28 t104 28 L
+t104 29 t104 29 L
+t104 30 t104 30 L
+t104 31 t104 31 L
+t104 32 t104 32 L
+t104 33 t104 33 L
+t104 34 t104 34 L
+t104 35 t104 35 L
+t104 36 t104 36 L
+t104 37 t104 37 L
+t104 38 t104 38 L
+t104 39 t104 39 L
+t104 40 t104 40 L
+t104 41 t104 41 L
+t104 42 t104 42 L
+t104 43 t104 43 L
+t104 44 t104 44 L
+t104 45 t104 45 L
+t104 46 t104 46 L
+t104 47 t104 47 L
+t104 48 t104 48 L
+t104 49 t104 49 L
+t104 50 t104 50 L
+t104 51 t104 51 L
+t104 52 t104 52 L
+t104 53 t104 53 L
+t104 54 t104 54 L
+t104 55 t104 55 L
+t104 56 t104 56 L
+t104 57 t104 57 L
+t104 58 t104 58 L
+t104 59 t104 59 L
+t104 60 t105 60 S
+t104 61 t105 61 S
+t104 62 t105 62 S
+t104 63 t105 63 S
+t104 64 t105 64 S
+t104 65 t105 65 S
+t104 66 t105 66 S
+t104 67 t105 67 S
+t104 68 t105 68 S
+t104 69 t105 69 S
+t104 70 t105 70 S
+t104 71 t105 71 S
+t104 72 t105 72 S
+t104 73 t105 73 S
+t104 74 t105 74 S
+t104 75 t105 75 S
+t104 76 t105 76 S
+t104 77 t105 77 S
+t104 78 t105 78 S
+t104 79 t105 79 S
+t104 80 t105 80 S
+t104 81 t105 81 S
+t104 82 t105 82 S
+t104 83 t105 83 S
+t104 84 t105 84 S
+t104 85 t105 85 S
+t104 86 t105 86 S
+t104 87 t105 87 S
+t104 88 t105 88 S
+t104 89 t105 89 S
+t104 90 t105 90 S
+t104 91 t105 91 S
+t104 92 t105 92 S
+t104 93 t105 93 S
+t104 94 t105 94 S
+t104 95 t105 95 S
+t104 96 t105 96 S
+t104 97 t105 97 S
+t104 98 t105 98 S
+t104 99 t105 99 S
+t104 100 t105 100 S
+t104 101 t105 101 S
+t104 102 t105 102 S
+t104 103 t105 103 S
+t104 104 t105 104 S
+t104 105 t105 105 S
+t104 106 t105 106 S
+t104 107 t105 107 S
+t104 108 t105 108 S
+t104 109 t105 109 S
+t104 110 t105 110 S
+t104 111 t105 111 S
+t104 112 t105 112 S
+t104 113 t105 113 S
+t104 114 t105 114 S
+t104 115 t105 115 S
+t104 116 t105 116 S
+t104 117 t105 117 S
+t104 118 t105 118 S
+t104 119 t105 119 S
+t106 0 t104 30 S
+t106 1 t104 31 S
+t106 2 t104 32 S
+t106 3 t104 33 S
+t106 4 t104 34 S
+t106 5 t104 35 S
+t106 6 t104 36 S
+t106 7 t104 37 S
+t106 8 t104 38 S
+t106 9 t104 39 S
+t106 10 t104 40 S
+t106 11 t104 41 S
+t106 12 t104 42 S
+t106 13 t104 43 S
+t106 14 t104 44 S
+t106 15 t104 45 S
+t106 16 t104 46 S
+t106 17 t104 47 S
+t106 18 t104 48 S
+t106 19 t104 49 S
+t106 20 t104 50 S
+t106 21 t104 51 S
+t106 22 t104 52 S
+t106 23 t104 53 S
+t106 24 t104 54 S
+t106 25 t104 55 S
+t106 26 t104 56 S
+t106 27 t104 57 S
+t106 28 t104 58 S
+t106 29 t104 59 S
+t106 30 t104 30 S
+t106 31 t104 31 S
+t106 32 t104 32 S
+t106 33 t104 33 S
+t106 34 t104 34 S
+t106 35 t104 35 S
+t106 36 t104 36 S
+t106 37 t104 37 S
+t106 38 t104 38 S
+t106 39 t104 39 S
+t106 40 t104 40 S
+t106 41 t104 41 S
+t106 42 t104 42 S
+t106 43 t104 43 S
+t106 44 t104 44 S
+t106 45 t104 45 S
+t106 46 t104 46 S
+t106 47 t104 47 S
+t106 48 t104 48 S
+t106 49 t104 49 S
+t106 50 t104 50 S
+t106 51 t104 51 S
+t106 52 t104 52 S
+t106 53 t104 53 S
+t106 54 t104 54 S
+t106 55 t104 55 S
+t106 56 t104 56 S
+t106 57 t104 57 S
+t106 58 t104 58 S
+t106 59 t104 59 S
+t106 60 t104 90 S
+t106 61 t104 91 S
+t106 62 t104 92 S
+t106 63 t104 93 S
+t106 64 t104 94 S
+t106 65 t104 95 S
+t106 66 t104 96 S
+t106 67 t104 97 S
+t106 68 t104 98 S
+t106 69 t104 99 S
+t106 70 t104 100 S
+t106 71 t104 101 S
+t106 72 t104 102 S
+t106 73 t104 103 S
+t106 74 t104 104 S
+t106 75 t104 105 S
+t106 76 t104 106 S
+t106 77 t104 107 S
+t106 78 t104 108 S
+t106 79 t104 109 S
+t106 80 t104 110 S
+t106 81 t104 111 S
+t106 82 t104 112 S
+t106 83 t104 113 S
+t106 84 t104 114 S
+t106 85 t104 115 S
+t106 86 t104 116 S
+t106 87 t104 117 S
+t106 88 t104 118 S
+t106 89 t104 119 S
+t106 90 t104 90 S
+t106 91 t104 91 S
+t106 92 t104 92 S
+t106 93 t104 93 S
+t106 94 t104 94 S
+t106 105 t104 105 S
+t106 106 t104 106 S
+t106 107 t104 107 S
+t106 108 t104 108 S
+t106 109 t104 109 S
+t103 0 t103 0 R
+t103 1 t103 1 R
+t103 2 t103 2 R
+t103 3 t103 3 R
+t103 4 t103 4 R
+t103 5 t103 5 R
+t103 6 t103 6 R
+t103 7 t103 7 R
+t103 8 t103 8 R
+t103 9 t103 9 R
+t103 10 t103 10 R
+t103 11 t103 11 R
+t103 12 t103 12 R
+t103 13 t103 13 R
+t103 14 t103 14 R
+t103 15 t103 15 R
+t103 16 t103 16 R
+t103 17 t103 17 R
+t103 18 t103 18 R
+t103 19 t103 19 R
+t103 20 t103 20 R
+t103 21 t103 21 R
+t103 22 t103 22 R
+t103 23 t103 23 R
+t103 24 t103 24 R
+t103 25 t103 25 R
+t103 26 t103 26 R
+t103 27 t103 27 R
+t103 28 t103 28 R
+t103 29 t103 29 R
+t103 30 t107 30 S
+t103 31 t107 31 S
+t103 32 t107 32 S
+t103 33 t107 33 S
+t103 34 t107 34 S
+t103 35 t107 35 S
+t103 36 t107 36 S
+t103 37 t107 37 S
+t103 38 t107 38 S
+t103 39 t107 39 S
+t103 40 t107 40 S
+t103 41 t107 41 S
+t103 42 t107 42 S
+t103 43 t107 43 S
+t103 44 t107 44 S
+t103 45 t107 45 S
+t103 46 t107 46 S
+t103 47 t107 47 S
+t103 48 t107 48 S
+t103 49 t107 49 S
+t103 50 t107 50 S
+t103 51 t107 51 S
+t103 52 t107 52 S
+t103 53 t107 53 S
+t103 54 t107 54 S
+t103 55 t107 55 S
+t103 56 t107 56 S
+t103 57 t107 57 S
+t103 58 t107 58 S
+t103 59 t107 59 S
+t103 60 t103 60 R
+t103 61 t103 61 R
+t103 62 t103 62 R
+t103 63 t103 63 R
+t103 64 t103 64 R
+t103 65 t103 65 R
+t103 66 t103 66 R
+t103 67 t103 67 R
+t103 68 t103 68 R
+t103 69 t103 69 R
+t103 70 t103 70 R
+t103 71 t103 71 R
+t103 72 t103 72 R
+t103 73 t103 73 R
+t103 74 t103 74 R
+t103 75 t103 75 R
+t103 76 t103 76 R
+t103 77 t103 77 R
+t103 78 t103 78 R
+t103 79 t103 79 R
+t103 80 t103 80 R
+t103 81 t103 81 R
+t103 82 t103 82 R
+t103 83 t103 83 R
+t103 84 t103 84 R
+t103 85 t103 85 R
+t103 86 t103 86 R
+t103 87 t103 87 R
+t103 88 t103 88 R
+t103 89 t103 89 R
+t103 90 t107 90 S
+t103 91 t107 91 S
+t103 92 t107 92 S
+t103 93 t107 93 S
+t103 94 t107 94 S
+t103 105 t107 105 S
+t103 106 t107 106 S
+t103 107 t107 107 S
+t103 108 t107 108 S
+t103 109 t107 109 S
+t105 0 t105 0 R
+t105 1 t105 1 R
+t105 2 t105 2 R
+t105 3 t105 3 R
+t105 4 t105 4 R
+t105 5 t105 5 R
+t105 6 t105 6 R
+t105 7 t105 7 R
+t105 8 t105 8 R
+t105 9 t105 9 R
+t105 10 t105 10 R
+t105 11 t105 11 R
+t105 12 t105 12 R
+t105 13 t105 13 R
+t105 14 t105 14 R
+t105 15 t108 15 S
+t105 16 t108 16 S
+t105 17 t108 17 S
+t105 18 t108 18 S
+t105 19 t108 19 S
+t105 20 t108 20 S
+t105 21 t108 21 S
+t105 22 t108 22 S
+t105 23 t108 23 S
+t105 24 t108 24 S
+t105 25 t108 25 S
+t105 26 t108 26 S
+t105 27 t108 27 S
+t105 28 t108 28 S
+t105 29 t108 29 S
+t105 30 t105 30 R
+t105 31 t105 31 R
+t105 32 t105 32 R
+t105 33 t105 33 R
+t105 34 t105 34 R
+t105 35 t105 35 R
+t105 36 t105 36 R
+t105 37 t105 37 R
+t105 38 t105 38 R
+t105 39 t105 39 R
+t105 40 t105 40 R
+t105 41 t105 41 R
+t105 42 t105 42 R
+t105 43 t105 43 R
+t105 44 t105 44 R
+t105 45 t108 45 S
+t105 46 t108 46 S
+t105 47 t108 47 S
+t105 48 t108 48 S
+t105 49 t108 49 S
+t105 50 t108 50 S
+t105 51 t108 51 S
+t105 52 t108 52 S
+t105 53 t108 53 S
+t105 54 t108 54 S
+t105 55 t108 55 S
+t105 56 t108 56 S
+t105 57 t108 57 S
+t105 58 t108 58 S
+t105 59 t108 59 S
+t105 60 t105 60 R
+t105 61 t105 61 R
+t105 62 t105 62 R
+t105 63 t105 63 R
+t105 64 t105 64 R
+t105 65 t105 65 R
+t105 66 t105 66 R
+t105 67 t105 67 R
+t105 68 t105 68 R
+t105 69 t105 69 R
+t105 70 t105 70 R
+t105 71 t105 71 R
+t105 72 t105 72 R
+t105 73 t105 73 R
+t105 74 t105 74 R
+t105 75 t108 75 S
+t105 76 t108 76 S
+t105 77 t108 77 S
+t105 78 t108 78 S
+t105 79 t108 79 S
+t105 80 t108 80 S
+t105 81 t108 81 S
+t105 82 t108 82 S
+t105 83 t108 83 S
+t105 84 t108 84 S
+t105 85 t108 85 S
+t105 86 t108 86 S
+t105 87 t108 87 S
+t105 88 t108 88 S
+t105 89 t108 89 S
+t105 90 t105 90 R
+t105 91 t105 91 R
+t105 92 t105 92 R
+t105 93 t105 93 R
+t105 94 t105 94 R
+t105 95 t105 95 R
+t105 96 t105 96 R
+t105 97 t105 97 R
+t105 98 t105 98 R
+t105 99 t105 99 R
+t105 100 t105 100 R
+t105 101 t105 101 R
+t105 102 t105 102 R
+t105 103 t105 103 R
+t105 104 t105 104 R
+t105 105 t108 105 S
+t105 106 t108 106 S
+t105 107 t108 107 S
+t105 108 t108 108 S
+t105 109 t108 109 S
+t105 110 t108 110 S
+t105 111 t108 111 S
+t105 112 t108 112 S
+t105 113 t108 113 S
+t105 114 t108 114 S
+t105 115 t108 115 S
+t105 116 t108 116 S
+t105 117 t108 117 S
+t105 118 t108 118 S
+t105 119 t108 119 S
+t107 30 t106 0 L
+t107 31 t106 1 L
+t107 32 t106 2 L
+t107 33 t106 3 L
+t107 34 t106 4 L
+t107 45 t106 15 L
+t107 46 t106 16 L
+t107 47 t106 17 L
+t107 48 t106 18 L
+t107 49 t106 19 L
+t107 90 t106 60 L
+t107 91 t106 61 L
+t107 92 t106 62 L
+t107 93 t106 63 L
+t107 94 t106 64 L
+t107 105 t106 75 L
+t107 106 t106 76 L
+t107 107 t106 77 L
+t107 108 t106 78 L
+t107 109 t106 79 L
+t108 15 t5 0 L
+t108 16 t5 0 L
+t108 17 t5 0 L
+t108 18 t5 0 L
+t108 19 t5 0 L
+t108 20 t5 5 L
+t108 21 t5 5 L
+t108 22 t5 5 L
+t108 23 t5 5 L
+t108 24 t5 5 L
+t108 25 t5 10 L
+t108 26 t5 10 L
+t108 27 t5 10 L
+t108 28 t5 10 L
+t108 29 t5 10 L
+t108 45 t5 30 L
+t108 46 t5 30 L
+t108 47 t5 30 L
+t108 48 t5 30 L
+t108 49 t5 30 L
+t108 50 t5 35 L
+t108 51 t5 35 L
+t108 52 t5 35 L
+t108 53 t5 35 L
+t108 54 t5 35 L
+t108 55 t5 40 L
+t108 56 t5 40 L
+t108 57 t5 40 L
+t108 58 t5 40 L
+t108 59 t5 40 L
+t108 75 t5 60 L
+t108 76 t5 60 L
+t108 77 t5 60 L
+t108 78 t5 60 L
+t108 79 t5 60 L
+t108 80 t5 65 L
+t108 81 t5 65 L
+t108 82 t5 65 L
+t108 83 t5 65 L
+t108 84 t5 65 L
+t108 85 t5 70 L
+t108 86 t5 70 L
+t108 87 t5 70 L
+t108 88 t5 70 L
+t108 89 t5 70 L
+t108 105 t5 90 L
+t108 106 t5 90 L
+t108 107 t5 90 L
+t108 108 t5 90 L
+t108 109 t5 90 L
+t108 110 t5 95 L
+t108 111 t5 95 L
+t108 112 t5 95 L
+t108 113 t5 95 L
+t108 114 t5 95 L
+t108 115 t5 100 L
+t108 116 t5 100 L
+t108 117 t5 100 L
+t108 118 t5 100 L
+t108 119 t5 100 L
+t55 15 t102 15 S
+t55 16 t109 16 S
+t55 17 t110 17 S
+t55 18 t111 18 S
+t55 19 t112 19 S
+t55 20 t102 20 S
+t55 21 t109 21 S
+t55 22 t110 22 S
+t55 23 t111 23 S
+t55 24 t112 24 S
+t55 25 t102 25 S
+t55 26 t109 26 S
+t55 27 t110 27 S
+t55 28 t111 28 S
+t55 29 t112 29 S
+t55 45 t102 45 S
+t55 46 t109 46 S
+t55 47 t110 47 S
+t55 48 t111 48 S
+t55 49 t112 49 S
+t55 50 t102 50 S
+t55 51 t109 51 S
+t55 52 t110 52 S
+t55 53 t111 53 S
+t55 54 t112 54 S
+t55 55 t102 55 S
+t55 56 t109 56 S
+t55 57 t110 57 S
+t55 58 t111 58 S
+t55 59 t112 59 S
+t55 75 t102 75 S
+t55 76 t109 76 S
+t55 77 t110 77 S
+t55 78 t111 78 S
+t55 79 t112 79 S
+t55 80 t102 80 S
+t55 81 t109 81 S
+t55 82 t110 82 S
+t55 83 t111 83 S
+t55 84 t112 84 S
+t55 85 t102 85 S
+t55 86 t109 86 S
+t55 87 t110 87 S
+t55 88 t111 88 S
+t55 89 t112 89 S
+t55 105 t102 105 S
+t55 106 t109 106 S
+t55 107 t110 107 S
+t55 108 t111 108 S
+t55 109 t112 109 S
+t109 0 t109 0 L
+t109 1 t109 1 L
+t109 2 t109 2 L
+t109 3 t109 3 L
+t109 4 t109 4 L
+t109 5 t109 5 L
+t109 6 t109 6 L
+t109 7 t109 7 L
+t109 8 t109 8 L
+t109 9 t109 9 L
+t109 10 t109 10 L
+t109 11 t109 11 L
+t109 12 t109 12 L
+t109 13 t109 13 L
+t109 14 t109 14 L
+t109 15 t109 15 L
+t109 16 t109 16 L
+t109 17 t109 17 L
+t109 18 t109 18 L
+t109 19 t109 19 L
+t109 20 t109 20 L
+t109 21 t109 21 L
+t109 22 t109 22 L
+t109 23 t109 23 L
+t109 24 t109 24 L
+t109 25 t109 25 L
+t109 26 t109 26 L
+t109 27 t109 27 L
+t109 28 t109 28 L
+t109 29 t109 29 L
+t109 30 t109 30 L
+t109 31 t109 31 L
+t109 32 t109 32 L
+t109 33 t109 33 L
+t109 34 t109 34 L
+t109 35 t109 35 L
+t109 36 t109 36 L
+t109 37 t109 37 L
+t109 38 t109 38 L
+t109 39 t109 39 L
+t109 40 t109 40 L
+t109 41 t109 41 L
+t109 42 t109 42 L
+t109 43 t109 43 L
+t109 44 t109 44 L
+t109 45 t109 45 L
+t109 46 t109 46 L
+t109 47 t109 47 L
+t109 48 t109 48 L
+t109 49 t109 49 L
+t109 50 t109 50 L
+t109 51 t109 51 L
+t109 52 t109 52 L
+t109 53 t109 53 L
+t109 54 t109 54 L
+t109 55 t109 55 L
+t109 56 t109 56 L
+t109 57 t109 57 L
+t109 58 t109 58 L
+t109 59 t109 59 L
+t109 60 t113 60 S
+t109 61 t113 61 S
+t109 62 t113 62 S
+t109 63 t113 63 S
+t109 64 t113 64 S
+t109 65 t113 65 S
+t109 66 t113 66 S
+t109 67 t113 67 S
+t109 68 t113 68 S
+t109 69 t113 69 S
+t109 70 t113 70 S
+t109 71 t113 71 S
+t109 72 t113 72 S
+t109 73 t113 73 S
+t109 74 t113 74 S
+t109 75 t113 75 S
+t109 76 t113 76 S
+t109 77 t113 77 S
+t109 78 t113 78 S
+t109 79 t113 79 S
+t109 80 t113 80 S
+t109 81 t113 81 S
+t109 82 t113 82 S
+t109 83 t113 83 S
+t109 84 t113 84 S
+t109 85 t113 85 S
+t109 86 t113 86 S
+t109 87 t113 87 S
+t109 88 t113 88 S
+t109 89 t113 89 S
+t109 90 t113 90 S
+t109 91 t113 91 S
+t109 92 t113 92 S
+t109 93 t113 93 S
+t109 94 t113 94 S
+t109 105 t113 105 S
+t109 106 t113 106 S
+t109 107 t113 107 S
+t109 108 t113 108 S
+t109 109 t113 109 S
+t114 0 t114 0 L
+t114 1 t114 1 L
+t114 2 t114 2 L
+t114 3 t114 3 L
+t114 4 t114 4 L
+t114 5 t114 5 L
+t114 6 t114 6 L
+t114 7 t114 7 L
+t114 8 t114 8 L
+t114 9 t114 9 L
+t114 10 t114 10 L
+t114 11 t114 11 L
+t114 12 t114 12 L
+t114 13 t114 13 L
+t114 14 t114 14 L
+t114 15 t114 15 L
+t114 16 t114 16 L
+t114 17 t114 17 L
+t114 18 t114 18 L
+t114 19 t114 19 L
+t114 20 t114 20 L
+t114 21 t114 21 L
+t114 22 t114 22 L
+t114 23 t114 23 L
+t114 24 t114 24 L
+t114 25 t114 25 L
+t114 26 t114 26 L
+t114 27 t114 27 L
+t114 28 t114 28 L
+t114 29 t114 29 L
+t114 30 t114 30 L
+t114 31 t114 31 L
+t114 32 t114 32 L
+t114 33 t114 33 L
+t114 34 t114 34 L
+t114 35 t114 35 L
+t114 36 t114 36 L
+t114 37 t114 37 L
+t114 38 t114 38 L
+t114 39 t114 39 L
+t114 40 t114 40 L
+t114 41 t114 41 L
+t114 42 t114 42 L
+t114 43 t114 43 L
+t114 44 t114 44 L
+t114 45 t114 45 L
+t114 46 t114 46 L
+t114 47 t114 47 L
+t114 48 t114 48 L
+t114 49 t114 49 L
+t114 50 t114 50 L
+t114 51 t114 51 L
+t114 52 t114 52 L
+t114 53 t114 53 L
+t114 54 t114 54 L
+t114 55 t114 55 L
+t114 56 t114 56 L
+t114 57 t114 57 L
+t114 58 t114 58 L
+t114 59 t114 59 L
+t114 60 t115 60 S
+t114 61 t115 61 S
+t114 62 t115 62 S
+t114 63 t115 63 S
+t114 64 t115 64 S
+t114 65 t115 65 S
+t114 66 t115 66 S
+t114 67 t115 67 S
+t114 68 t115 68 S
+t114 69 t115 69 S
+t114 70 t115 70 S
+t114 71 t115 71 S
+t114 72 t115 72 S
+t114 73 t115 73 S
+t114 74 t115 74 S
+t114 75 t115 75 S
+t114 76 t115 76 S
+t114 77 t115 77 S
+t114 78 t115 78 S
+t114 79 t115 79 S
+t114 80 t115 80 S
+t114 81 t115 81 S
+t114 82 t115 82 S
+t114 83 t115 83 S
+t114 84 t115 84 S
+t114 85 t115 85 S
+t114 86 t115 86 S
+t114 87 t115 87 S
+t114 88 t115 88 S
+t114 89 t115 89 S
+t114 90 t115 90 S
+t114 91 t115 91 S
+t114 92 t115 92 S
+t114 93 t115 93 S
+t114 94 t115 94 S
+t114 105 t115 105 S
+t114 106 t115 106 S
+t114 107 t115 107 S
+t114 108 t115 108 S
+t114 109 t115 109 S
+t116 0 t114 30 S
+t116 1 t114 31 S
+t116 2 t114 32 S
+t116 3 t114 33 S
+t116 4 t114 34 S
+t116 15 t114 45 S
+t116 16 t114 46 S
+t116 17 t114 47 S
+t116 18 t114 48 S
+t116 19 t114 49 S
+t116 60 t114 90 S
+t116 61 t114 91 S
+t116 62 t114 92 S
+t116 63 t114 93 S
+t116 64 t114 94 S
+t116 75 t114 105 S
+t116 76 t114 106 S
+t116 77 t114 107 S
+t116 78 t114 108 S
+t116 79 t114 109 S
+t113 0 t113 0 R
+t113 1 t113 1 R
+t113 2 t113 2 R
+t113 3 t113 3 R
+t113 4 t113 4 R
+t113 5 t113 5 R
+t113 6 t113 6 R
+t113 7 t113 7 R
+t113 8 t113 8 R
+t113 9 t113 9 R
+t113 10 t113 10 R
+t113 11 t113 11 R
+t113 12 t113 12 R
+t113 13 t113 13 R
+t113 14 t113 14 R
+t113 15 t113 15 R
+t113 16 t113 16 R
+t113 17 t113 17 R
+t113 18 t113 18 R
+t113 19 t113 19 R
+t113 20 t113 20 R
+t113 21 t113 21 R
+t113 22 t113 22 R
+t113 23 t113 23 R
+t113 24 t113 24 R
+t113 25 t113 25 R
+t113 26 t113 26 R
+t113 27 t113 27 R
+t113 28 t113 28 R
+t113 29 t113 29 R
+t113 30 t117 30 S
+t113 31 t117 31 S
+t113 32 t117 32 S
+t113 33 t117 33 S
+t113 34 t117 34 S
+t113 35 t117 35 S
+t113 36 t117 36 S
+t113 37 t117 37 S
+t113 38 t117 38 S
+t113 39 t117 39 S
+t113 40 t117 40 S
+t113 41 t117 41 S
+t113 42 t117 42 S
+t113 43 t117 43 S
+t113 44 t117 44 S
+t113 45 t117 45 S
+t113 46 t117 46 S
+t113 47 t117 47 S
+t113 48 t117 48 S
+t113 49 t117 49 S
+t113 50 t117 50 S
+t113 51 t117 51 S
+t113 52 t117 52 S
+t113 53 t117 53 S
+t113 54 t117 54 S
+t113 55 t117 55 S
+t113 56 t117 56 S
+t113 57 t117 57 S
+t113 58 t117 58 S
+t113 59 t117 59 S
+t113 60 t113 60 R
+t113 61 t113 61 R
+t113 62 t113 62 R
+t113 63 t113 63 R
+t113 64 t113 64 R
+t113 65 t113 65 R
+t113 66 t113 66 R
+t113 67 t113 67 R
+t113 68 t113 68 R
+t113 69 t113 69 R
+t113 70 t113 70 R
+t113 71 t113 71 R
+t113 72 t113 72 R
+t113 73 t113 73 R
+t113 74 t113 74 R
+t113 75 t113 75 R
+t113 76 t113 76 R
+t113 77 t113 77 R
+t113 78 t113 78 R
+t113 79 t113 79 R
+t113 80 t113 80 R
+t113 81 t113 81 R
+t113 82 t113 82 R
+t113 83 t113 83 R
+t113 84 t113 84 R
+t113 85 t113 85 R
+t113 86 t113 86 R
+t113 87 t113 87 R
+t113 88 t113 88 R
+t113 89 t113 89 R
+t113 90 t117 90 S
+t113 91 t117 91 S
+t113 92 t117 92 S
+t113 93 t117 93 S
+t113 94 t117 94 S
+t113 105 t117 105 S
+t113 106 t117 106 S
+t113 107 t117 107 S
+t113 108 t117 108 S
+t113 109 t117 109 S
+t115 0 t115 0 R
+t115 1 t115 1 R
+t115 2 t115 2 R
+t115 3 t115 3 R
+t115 4 t115 4 R
+t115 5 t115 5 R
+t115 6 t115 6 R
+t115 7 t115 7 R
+t115 8 t115 8 R
+t115 9 t115 9 R
+t115 10 t115 10 R
+t115 11 t115 11 R
+t115 12 t115 12 R
+t115 13 t115 13 R
+t115 14 t115 14 R
+t115 15 t118 15 S
+t115 16 t118 16 S
+t115 17 t118 17 S
+t115 18 t118 18 S
+t115 19 t118 19 S
+t115 20 t118 20 S
+t115 21 t118 21 S
+t115 22 t118 22 S
+t115 23 t118 23 S
+t115 24 t118 24 S
+t115 25 t118 25 S
+t115 26 t118 26 S
+t115 27 t118 27 S
+t115 28 t118 28 S
+t115 29 t118 29 S
+t115 30 t115 30 R
+t115 31 t115 31 R
+t115 32 t115 32 R
+t115 33 t115 33 R
+t115 34 t115 34 R
+t115 35 t115 35 R
+t115 36 t115 36 R
+t115 37 t115 37 R
+t115 38 t115 38 R
+t115 39 t115 39 R
+t115 40 t115 40 R
+t115 41 t115 41 R
+t115 42 t115 42 R
+t115 43 t115 43 R
+t115 44 t115 44 R
+t115 45 t118 45 S
+t115 46 t118 46 S
+t115 47 t118 47 S
+t115 48 t118 48 S
+t115 49 t118 49 S
+t115 50 t118 50 S
+t115 51 t118 51 S
+t115 52 t118 52 S
+t115 53 t118 53 S
+t115 54 t118 54 S
+t115 55 t118 55 S
+t115 56 t118 56 S
+t115 57 t118 57 S
+t115 58 t118 58 S
+t115 59 t118 59 S
+t115 60 t115 60 R
+t115 61 t115 61 R
+t115 62 t115 62 R
+t115 63 t115 63 R
+t115 64 t115 64 R
+t115 65 t115 65 R
+t115 66 t115 66 R
+t115 67 t115 67 R
+t115 68 t115 68 R
+t115 69 t115 69 R
+t115 70 t115 70 R
+t115 71 t115 71 R
+t115 72 t115 72 R
+t115 73 t115 73 R
+t115 74 t115 74 R
+t115 75 t118 75 S
+t115 76 t118 76 S
+t115 77 t118 77 S
+t115 78 t118 78 S
+t115 79 t118 79 S
+t115 80 t118 80 S
+t115 81 t118 81 S
+t115 82 t118 82 S
+t115 83 t118 83 S
+t115 84 t118 84 S
+t115 85 t118 85 S
+t115 86 t118 86 S
+t115 87 t118 87 S
+t115 88 t118 88 S
+t115 89 t118 89 S
+t115 90 t115 90 R
+t115 91 t115 91 R
+t115 92 t115 92 R
+t115 93 t115 93 R
+t115 94 t115 94 R
+t115 105 t118 105 S
+t115 106 t118 106 S
+t115 107 t118 107 S
+t115 108 t118 108 S
+t115 109 t118 109 S
+t117 30 t116 0 S
+t117 31 t116 1 S
+t117 32 t116 2 S
+t117 33 t116 3 S
+t117 34 t116 4 S
+t117 45 t116 15 S
+t117 46 t116 16 S
+t117 47 t116 17 S
+t117 48 t116 18 S
+t117 49 t116 19 S
+t117 90 t116 60 S
+t117 91 t116 61 S
+t117 92 t116 62 S
+t117 93 t116 63 S
+t117 94 t116 64 S
+t117 105 t116 75 S
+t117 106 t116 76 S
+t117 107 t116 77 S
+t117 108 t116 78 S
+t117 109 t116 79 S
+t118 15 t54 1 R
+t118 16 t54 1 R
+t118 17 t54 1 R
+t118 18 t54 1 R
+t118 19 t54 1 R
+t118 20 t54 6 R
+t118 21 t54 6 R
+t118 22 t54 6 R
+t118 23 t54 6 R
+t118 24 t54 6 R
+t118 25 t54 11 R
+t118 26 t54 11 R
+t118 27 t54 11 R
+t118 28 t54 11 R
+t118 29 t54 11 R
+t118 45 t54 31 R
+t118 46 t54 31 R
+t118 47 t54 31 R
+t118 48 t54 31 R
+t118 49 t54 31 R
+t118 50 t54 36 R
+t118 51 t54 36 R
+t118 52 t54 36 R
+t118 53 t54 36 R
+t118 54 t54 36 R
+t118 55 t54 41 R
+t118 56 t54 41 R
+t118 57 t54 41 R
+t118 58 t54 41 R
+t118 59 t54 41 R
+t118 75 t54 61 R
+t118 76 t54 61 R
+t118 77 t54 61 R
+t118 78 t54 61 R
+t118 79 t54 61 R
+t118 80 t54 66 R
+t118 81 t54 66 R
+t118 82 t54 66 R
+t118 83 t54 66 R
+t118 84 t54 66 R
+t118 85 t54 71 R
+t118 86 t54 71 R
+t118 87 t54 71 R
+t118 88 t54 71 R
+t118 89 t54 71 R
+t118 105 t54 91 R
+t118 106 t54 91 R
+t118 107 t54 91 R
+t118 108 t54 91 R
+t118 109 t54 91 R
+t110 0 t110 0 L
+t110 1 t110 1 L
+t110 2 t110 2 L
+t110 3 t110 3 L
+t110 4 t110 4 L
+t110 5 t110 5 L
+t110 6 t110 6 L
+t110 7 t110 7 L
+t110 8 t110 8 L
+t110 9 t110 9 L
+t110 10 t110 10 L
+t110 11 t110 11 L
+t110 12 t110 12 L
+t110 13 t110 13 L
+t110 14 t110 14 L
+t110 15 t110 15 L
+t110 16 t110 16 L
+t110 17 t110 17 L
+t110 18 t110 18 L
+t110 19 t110 19 L
+t110 20 t110 20 L
+t110 21 t110 21 L
+t110 22 t110 22 L
+t110 23 t110 23 L
+t110 24 t110 24 L
+t110 25 t110 25 L
+t110 26 t110 26 L
+t110 27 t110 27 L
+t110 28 t110 28 L
+t110 29 t110 29 L
+t110 30 t110 30 L
+t110 31 t110 31 L
+t110 32 t110 32 L
+t110 33 t110 33 L
+t110 34 t110 34 L
+t110 35 t110 35 L
+t110 36 t110 36 L
+t110 37 t110 37 L
+t110 38 t110 38 L
+t110 39 t110 39 L
+t110 40 t110 40 L
+t110 41 t110 41 L
+t110 42 t110 42 L
+t110 43 t110 43 L
+t110 44 t110 44 L
+t110 45 t110 45 L
+t110 46 t110 46 L
+t110 47 t110 47 L
+t110 48 t110 48 L
+t110 49 t110 49 L
+t110 50 t110 50 L
+t110 51 t110 51 L
+t110 52 t110 52 L
+t110 53 t110 53 L
+t110 54 t110 54 L
+t110 55 t110 55 L
+t110 56 t110 56 L
+t110 57 t110 57 L
+t110 58 t110 58 L
+t110 59 t110 59 L
+t110 60 t119 60 S
+t110 61 t119 61 S
+t110 62 t119 62 S
+t110 63 t119 63 S
+t110 64 t119 64 S
+t110 65 t119 65 S
+t110 66 t119 66 S
+t110 67 t119 67 S
+t110 68 t119 68 S
+t110 69 t119 69 S
+t110 70 t119 70 S
+t110 71 t119 71 S
+t110 72 t119 72 S
+t110 73 t119 73 S
+t110 74 t119 74 S
+t110 75 t119 75 S
+t110 76 t119 76 S
+t110 77 t119 77 S
+t110 78 t119 78 S
+t110 79 t119 79 S
+t110 80 t119 80 S
+t110 81 t119 81 S
+t110 82 t119 82 S
+t110 83 t119 83 S
+t110 84 t119 84 S
+t110 85 t119 85 S
+t110 86 t119 86 S
+t110 87 t119 87 S
+t110 88 t119 88 S
+t110 89 t119 89 S
+t110 90 t119 90 S
+t110 91 t119 91 S
+t110 92 t119 92 S
+t110 93 t119 93 S
+t110 94 t119 94 S
+t110 105 t119 105 S
+t110 106 t119 106 S
+t110 107 t119 107 S
+t110 108 t119 108 S
+t110 109 t119 109 S
+t120 0 t120 0 L
+t120 1 t120 1 L
+t120 2 t120 2 L
+t120 3 t120 3 L
+t120 4 t120 4 L
+t120 5 t120 5 L
+t120 6 t120 6 L
+t120 7 t120 7 L
+t120 8 t120 8 L
+t120 9 t120 9 L
+t120 10 t120 10 L
+t120 11 t120 11 L
+t120 12 t120 12 L
+t120 13 t120 13 L
+t120 14 t120 14 L
+t120 15 t120 15 L
+t120 16 t120 16 L
+t120 17 t120 17 L
+t120 18 t120 18 L
+t120 19 t120 19 L
+t120 20 t120 20 L
+t120 21 t120 21 L
+t120 22 t120 22 L
+t120 23 t120 23 L
+t120 24 t120 24 L
+t120 25 t120 25 L
+t120 26 t120 26 L
+t120 27 t120 27 L
+t120 28 t120 28 L
+t120 29 t120 29 L
+t120 30 t120 30 L
+t120 31 t120 31 L
+t120 32 t120 32 L
+t120 33 t120 33 L
+t120 34 t120 34 L
+t120 35 t120 35 L
+t120 36 t120 36 L
+t120 37 t120 37 L
+t120 38 t120 38 L
+t120 39 t120 39 L
+t120 40 t120 40 L
+t120 41 t120 41 L
+t120 42 t120 42 L
+t120 43 t120 43 L
+t120 44 t120 44 L
+t120 45 t120 45 L
+t120 46 t120 46 L
+t120 47 t120 47 L
+t120 48 t120 48 L
+t120 49 t120 49 L
+t120 50 t120 50 L
+t120 51 t120 51 L
+t120 52 t120 52 L
+t120 53 t120 53 L
+t120 54 t120 54 L
+t120 55 t120 55 L
+t120 56 t120 56 L
+t120 57 t120 57 L
+t120 58 t120 58 L
+t120 59 t120 59 L
+t120 60 t121 60 S
+t120 61 t121 61 S
+t120 62 t121 62 S
+t120 63 t121 63 S
+t120 64 t121 64 S
+t120 65 t121 65 S
+t120 66 t121 66 S
+t120 67 t121 67 S
+t120 68 t121 68 S
+t120 69 t121 69 S
+t120 70 t121 70 S
+t120 71 t121 71 S
+t120 72 t121 72 S
+t120 73 t121 73 S
+t120 74 t121 74 S
+t120 75 t121 75 S
+t120 76 t121 76 S
+t120 77 t121 77 S
+t120 78 t121 78 S
+t120 79 t121 79 S
+t120 80 t121 80 S
+t120 81 t121 81 S
+t120 82 t121 82 S
+t120 83 t121 83 S
+t120 84 t121 84 S
+t120 85 t121 85 S
+t120 86 t121 86 S
+t120 87 t121 87 S
+t120 88 t121 88 S
+t120 89 t121 89 S
+t120 90 t121 90 S
+t120 91 t121 91 S
+t120 92 t121 92 S
+t120 93 t121 93 S
+t120 94 t121 94 S
+t120 105 t121 105 S
+t120 106 t121 106 S
+t120 107 t121 107 S
+t120 108 t121 108 S
+t120 109 t121 109 S
+t122 0 t120 30 S
+t122 1 t120 31 S
+t122 2 t120 32 S
+t122 3 t120 33 S
+t122 4 t120 34 S
+t122 15 t120 45 S
+t122 16 t120 46 S
+t122 17 t120 47 S
+t122 18 t120 48 S
+t122 19 t120 49 S
+t122 60 t120 90 S
+t122 61 t120 91 S
+t122 62 t120 92 S
+t122 63 t120 93 S
+t122 64 t120 94 S
+t122 75 t120 105 S
+t122 76 t120 106 S
+t122 77 t120 107 S
+t122 78 t120 108 S
+t122 79 t120 109 S
+t119 0 t119 0 R
+t119 1 t119 1 R
+t119 2 t119 2 R
+t119 3 t119 3 R
+t119 4 t119 4 R
+t119 5 t119 5 R
+t119 6 t119 6 R
+t119 7 t119 7 R
+t119 8 t119 8 R
+t119 9 t119 9 R
+t119 10 t119 10 R
+t119 11 t119 11 R
+t119 12 t119 12 R
+t119 13 t119 13 R
+t119 14 t119 14 R
+t119 15 t119 15 R
+t119 16 t119 16 R
+t119 17 t119 17 R
+t119 18 t119 18 R
+t119 19 t119 19 R
+t119 20 t119 20 R
+t119 21 t119 21 R
+t119 22 t119 22 R
+t119 23 t119 23 R
+t119 24 t119 24 R
+t119 25 t119 25 R
+t119 26 t119 26 R
+t119 27 t119 27 R
+t119 28 t119 28 R
+t119 29 t119 29 R
+t119 30 t123 30 S
+t119 31 t123 31 S
+t119 32 t123 32 S
+t119 33 t123 33 S
+t119 34 t123 34 S
+t119 35 t123 35 S
+t119 36 t123 36 S
+t119 37 t123 37 S
+t119 38 t123 38 S
+t119 39 t123 39 S
+t119 40 t123 40 S
+t119 41 t123 41 S
+t119 42 t123 42 S
+t119 43 t123 43 S
+t119 44 t123 44 S
+t119 45 t123 45 S
+t119 46 t123 46 S
+t119 47 t123 47 S
+t119 48 t123 48 S
+t119 49 t123 49 S
+t119 50 t123 50 S
+t119 51 t123 51 S
+t119 52 t123 52 S
+t119 53 t123 53 S
+t119 54 t123 54 S
+t119 55 t123 55 S
+t119 56 t123 56 S
+t119 57 t123 57 S
+t119 58 t123 58 S
+t119 59 t123 59 S
+t119 60 t119 60 R
+t119 61 t119 61 R
+t119 62 t119 62 R
+t119 63 t119 63 R
+t119 64 t119 64 R
+t119 65 t119 65 R
+t119 66 t119 66 R
+t119 67 t119 67 R
+t119 68 t119 68 R
+t119 69 t119 69 R
+t119 70 t119 70 R
+t119 71 t119 71 R
+t119 72 t119 72 R
+t119 73 t119 73 R
+t119 74 t119 74 R
+t119 75 t119 75 R
+t119 76 t119 76 R
+t119 77 t119 77 R
+t119 78 t119 78 R
+t119 79 t119 79 R
+t119 80 t119 80 R
+t119 81 t119 81 R
+t119 82 t119 82 R
+t119 83 t119 83 R
+t119 84 t119 84 R
+t119 85 t119 85 R
+t119 86 t119 86 R
+t119 87 t119 87 R
+t119 88 t119 88 R
+t119 89 t119 89 R
+t119 90 t123 90 S
+t119 91 t123 91 S
+t119 92 t123 92 S
+t119 93 t123 93 S
+t119 94 t123 94 S
+t119 105 t123 105 S
+t119 106 t123 106 S
+t119 107 t123 107 S
+t119 108 t123 108 S
+t119 109 t123 109 S
+t121 0 t121 0 R
+t121 1 t121 1 R
+t121 2 t121 2 R
+t121 3 t121 3 R
+t121 4 t121 4 R
+t121 5 t121 5 R
+t121 6 t121 6 R
+t121 7 t121 7 R
+t121 8 t121 8 R
+t121 9 t121 9 R
+t121 10 t121 10 R
+t121 11 t121 11 R
+t121 12 t121 12 R
+t121 13 t121 13 R
+t121 14 t121 14 R
+t121 15 t124 15 S
+t121 16 t124 16 S
+t121 17 t124 17 S
+t121 18 t124 18 S
+t121 19 t124 19 S
+t121 20 t124 20 S
+t121 21 t124 21 S
+t121 22 t124 22 S
+t121 23 t124 23 S
+t121 24 t124 24 S
+t121 25 t124 25 S
+t121 26 t124 26 S
+t121 27 t124 27 S
+t121 28 t124 28 S
+t121 29 t124 29 S
+t121 30 t121 30 R
+t121 31 t121 31 R
+t121 32 t121 32 R
+t121 33 t121 33 R
+t121 34 t121 34 R
+t121 35 t121 35 R
+t121 36 t121 36 R
+t121 37 t121 37 R
+t121 38 t121 38 R
+t121 39 t121 39 R
+t121 40 t121 40 R
+t121 41 t121 41 R
+t121 42 t121 42 R
+t121 43 t121 43 R
+t121 44 t121 44 R
+t121 45 t124 45 S
+t121 46 t124 46 S
+t121 47 t124 47 S
+t121 48 t124 48 S
+t121 49 t124 49 S
+t121 50 t124 50 S
+t121 51 t124 51 S
+t121 52 t124 52 S
+t121 53 t124 53 S
+t121 54 t124 54 S
+t121 55 t124 55 S
+t121 56 t124 56 S
+t121 57 t124 57 S
+t121 58 t124 58 S
+t121 59 t124 59 S
+t121 60 t121 60 R
+t121 61 t121 61 R
+t121 62 t121 62 R
+t121 63 t121 63 R
+t121 64 t121 64 R
+t121 65 t121 65 R
+t121 66 t121 66 R
+t121 67 t121 67 R
+t121 68 t121 68 R
+t121 69 t121 69 R
+t121 70 t121 70 R
+t121 71 t121 71 R
+t121 72 t121 72 R
+t121 73 t121 73 R
+t121 74 t121 74 R
+t121 75 t124 75 S
+t121 76 t124 76 S
+t121 77 t124 77 S
+t121 78 t124 78 S
+t121 79 t124 79 S
+t121 80 t124 80 S
+t121 81 t124 81 S
+t121 82 t124 82 S
+t121 83 t124 83 S
+t121 84 t124 84 S
+t121 85 t124 85 S
+t121 86 t124 86 S
+t121 87 t124 87 S
+t121 88 t124 88 S
+t121 89 t124 89 S
+t121 90 t121 90 R
+t121 91 t121 91 R
+t121 92 t121 92 R
+t121 93 t121 93 R
+t121 94 t121 94 R
+t121 105 t124 105 S
+t121 106 t124 106 S
+t121 107 t124 107 S
+t121 108 t124 108 S
+t121 109 t124 109 S
+t123 30 t122 0 S
+t123 31 t122 1 S
+t123 32 t122 2 S
+t123 33 t122 3 S
+t123 34 t122 4 S
+t123 45 t122 15 S
+t123 46 t122 16 S
+t123 47 t122 17 S
+t123 48 t122 18 S
+t123 49 t122 19 S
+t123 90 t122 60 S
+t123 91 t122 61 S
+t123 92 t122 62 S
+t123 93 t122 63 S
+t123 94 t122 64 S
+t123 105 t122 75 S
+t123 106 t122 76 S
+t123 107 t122 77 S
+t123 108 t122 78 S
+t123 109 t122 79 S
+t124 15 t54 2 R
+t124 16 t54 2 R
+t124 17 t54 2 R
+t124 18 t54 2 R
+t124 19 t54 2 R
+t124 20 t54 7 R
+t124 21 t54 7 R
+t124 22 t54 7 R
+t124 23 t54 7 R
+t124 24 t54 7 R
+t124 25 t54 12 R
+t124 26 t54 12 R
+t124 27 t54 12 R
+t124 28 t54 12 R
+t124 29 t54 12 R
+t124 45 t54 32 R
+t124 46 t54 32 R
+t124 47 t54 32 R
+t124 48 t54 32 R
+t124 49 t54 32 R
+t124 50 t54 37 R
+t124 51 t54 37 R
+t124 52 t54 37 R
+t124 53 t54 37 R
+t124 54 t54 37 R
+t124 55 t54 42 R
+t124 56 t54 42 R
+t124 57 t54 42 R
+t124 58 t54 42 R
+t124 59 t54 42 R
+t124 75 t54 62 R
+t124 76 t54 62 R
+t124 77 t54 62 R
+t124 78 t54 62 R
+t124 79 t54 62 R
+t124 80 t54 67 R
+t124 81 t54 67 R
+t124 82 t54 67 R
+t124 83 t54 67 R
+t124 84 t54 67 R
+t124 85 t54 72 R
+t124 86 t54 72 R
+t124 87 t54 72 R
+t124 88 t54 72 R
+t124 89 t54 72 R
+t124 105 t54 92 R
+t124 106 t54 92 R
+t124 107 t54 92 R
+t124 108 t54 92 R
+t124 109 t54 92 R
+t111 0 t111 0 L
+t111 1 t111 1 L
+t111 2 t111 2 L
+t111 3 t111 3 L
+t111 4 t111 4 L
+t111 5 t111 5 L
+t111 6 t111 6 L
+t111 7 t111 7 L
+t111 8 t111 8 L
+t111 9 t111 9 L
+t111 10 t111 10 L
+t111 11 t111 11 L
+t111 12 t111 12 L
+t111 13 t111 13 L
+t111 14 t111 14 L
+t111 15 t111 15 L
+t111 16 t111 16 L
+t111 17 t111 17 L
+t111 18 t111 18 L
+t111 19 t111 19 L
+t111 20 t111 20 L
+t111 21 t111 21 L
+t111 22 t111 22 L
+t111 23 t111 23 L
+t111 24 t111 24 L
+t111 25 t111 25 L
+t111 26 t111 26 L
+t111 27 t111 27 L
+t111 28 t111 28 L
+t111 29 t111 29 L
+t111 30 t111 30 L
+t111 31 t111 31 L
+t111 32 t111 32 L
+t111 33 t111 33 L
+t111 34 t111 34 L
+t111 35 t111 35 L
+t111 36 t111 36 L
+t111 37 t111 37 L
+t111 38 t111 38 L
+t111 39 t111 39 L
+t111 40 t111 40 L
+t111 41 t111 41 L
+t111 42 t111 42 L
+t111 43 t111 43 L
+t111 44 t111 44 L
+t111 45 t111 45 L
+t111 46 t111 46 L
+t111 47 t111 47 L
+t111 48 t111 48 L
+t111 49 t111 49 L
+t111 50 t111 50 L
+t111 51 t111 51 L
+t111 52 t111 52 L
+t111 53 t111 53 L
+t111 54 t111 54 L
+t111 55 t111 55 L
+t111 56 t111 56 L
+t111 57 t111 57 L
+t111 58 t111 58 L
+t111 59 t111 59 L
+t111 60 t125 60 S
+t111 61 t125 61 S
+t111 62 t125 62 S
+t111 63 t125 63 S
+t111 64 t125 64 S
+t111 65 t125 65 S
+t111 66 t125 66 S
+t111 67 t125 67 S
+t111 68 t125 68 S
+t111 69 t125 69 S
+t111 70 t125 70 S
+t111 71 t125 71 S
+t111 72 t125 72 S
+t111 73 t125 73 S
+t111 74 t125 74 S
+t111 75 t125 75 S
+t111 76 t125 76 S
+t111 77 t125 77 S
+t111 78 t125 78 S
+t111 79 t125 79 S
+t111 80 t125 80 S
+t111 81 t125 81 S
+t111 82 t125 82 S
+t111 83 t125 83 S
+t111 84 t125 84 S
+t111 85 t125 85 S
+t111 86 t125 86 S
+t111 87 t125 87 S
+t111 88 t125 88 S
+t111 89 t125 89 S
+t111 90 t125 90 S
+t111 91 t125 91 S
+t111 92 t125 92 S
+t111 93 t125 93 S
+t111 94 t125 94 S
+t111 105 t125 105 S
+t111 106 t125 106 S
+t111 107 t125 107 S
+t111 108 t125 108 S
+t111 109 t125 109 S
+t126 0 t126 0 L
+t126 1 t126 1 L
+t126 2 t126 2 L
+t126 3 t126 3 L
+t126 4 t126 4 L
+t126 5 t126 5 L
+t126 6 t126 6 L
+t126 7 t126 7 L
+t126 8 t126 8 L
+t126 9 t126 9 L
+t126 10 t126 10 L
+t126 11 t126 11 L
+t126 12 t126 12 L
+t126 13 t126 13 L
+t126 14 t126 14 L
+t126 15 t126 15 L
+t126 16 t126 16 L
+t126 17 t126 17 L
+t126 18 t126 18 L
+t126 19 t126 19 L
+t126 20 t126 20 L
+t126 21 t126 21 L
+t126 22 t126 22 L
+t126 23 t126 23 L
+t126 24 t126 24 L
+t126 25 t126 25 L
+t126 26 t126 26 L
+t126 27 t126 27 L
+t126 28 t126 28 L
+t126 29 t126 29 L
+t126 30 t126 30 L
+t126 31 t126 31 L
+t126 32 t126 32 L
+t126 33 t126 33 L
+t126 34 t126 34 L
+t126 35 t126 35 L
+t126 36 t126 36 L
+t126 37 t126 37 L
+t126 38 t126 38 L
+t126 39 t126 39 L
+t126 40 t126 40 L
+t126 41 t126 41 L
+t126 42 t126 42 L
+t126 43 t126 43 L
+t126 44 t126 44 L
+t126 45 t126 45 L
+t126 46 t126 46 L
+t126 47 t126 47 L
+t126 48 t126 48 L
+t126 49 t126 49 L
+t126 50 t126 50 L
+t126 51 t126 51 L
+t126 52 t126 52 L
+t126 53 t126 53 L
+t126 54 t126 54 L
+t126 55 t126 55 L
+t126 56 t126 56 L
+t126 57 t126 57 L
+t126 58 t126 58 L
+t126 59 t126 59 L
+t126 60 t127 60 S
+t126 61 t127 61 S
+t126 62 t127 62 S
+t126 63 t127 63 S
+t126 64 t127 64 S
+t126 65 t127 65 S
+t126 66 t127 66 S
+t126 67 t127 67 S
+t126 68 t127 68 S
+t126 69 t127 69 S
+t126 70 t127 70 S
+t126 71 t127 71 S
+t126 72 t127 72 S
+t126 73 t127 73 S
+t126 74 t127 74 S
+t126 75 t127 75 S
+t126 76 t127 76 S
+t126 77 t127 77 S
+t126 78 t127 78 S
+t126 79 t127 79 S
+t126 80 t127 80 S
+t126 81 t127 81 S
+t126 82 t127 82 S
+t126 83 t127 83 S
+t126 84 t127 84 S
+t126 85 t127 85 S
+t126 86 t127 86 S
+t126 87 t127 87 S
+t126 88 t127 88 S
+t126 89 t127 89 S
+t126 90 t127 90 S
+t126 91 t127 91 S
+t126 92 t127 92 S
+t126 93 t127 93 S
+t126 94 t127 94 S
+t126 105 t127 105 S
+t126 106 t127 106 S
+t126 107 t127 107 S
+t126 108 t127 108 S
+t126 109 t127 109 S
+t128 0 t126 30 S
+t128 1 t126 31 S
+t128 2 t126 32 S
+t128 3 t126 33 S
+t128 4 t126 34 S
+t128 15 t126 45 S
+t128 16 t126 46 S
+t128 17 t126 47 S
+t128 18 t126 48 S
+t128 19 t126 49 S
+t128 60 t126 90 S
+t128 61 t126 91 S
+t128 62 t126 92 S
+t128 63 t126 93 S
+t128 64 t126 94 S
+t128 75 t126 105 S
+t128 76 t126 106 S
+t128 77 t126 107 S
+t128 78 t126 108 S
+t128 79 t126 109 S
+t125 0 t125 0 R
+t125 1 t125 1 R
+t125 2 t125 2 R
+t125 3 t125 3 R
+t125 4 t125 4 R
+t125 5 t125 5 R
+t125 6 t125 6 R
+t125 7 t125 7 R
+t125 8 t125 8 R
+t125 9 t125 9 R
+t125 10 t125 10 R
+t125 11 t125 11 R
+t125 12 t125 12 R
+t125 13 t125 13 R
+t125 14 t125 14 R
+t125 15 t125 15 R
+t125 16 t125 16 R
+t125 17 t125 17 R
+t125 18 t125 18 R
+t125 19 t125 19 R
+t125 20 t125 20 R
+t125 21 t125 21 R
+t125 22 t125 22 R
+t125 23 t125 23 R
+t125 24 t125 24 R
+t125 25 t125 25 R
+t125 26 t125 26 R
+t125 27 t125 27 R
+t125 28 t125 28 R
+t125 29 t125 29 R
+t125 30 t129 30 S
+t125 31 t129 31 S
+t125 32 t129 32 S
+t125 33 t129 33 S
+t125 34 t129 34 S
+t125 35 t129 35 S
+t125 36 t129 36 S
+t125 37 t129 37 S
+t125 38 t129 38 S
+t125 39 t129 39 S
+t125 40 t129 40 S
+t125 41 t129 41 S
+t125 42 t129 42 S
+t125 43 t129 43 S
+t125 44 t129 44 S
+t125 45 t129 45 S
+t125 46 t129 46 S
+t125 47 t129 47 S
+t125 48 t129 48 S
+t125 49 t129 49 S
+t125 50 t129 50 S
+t125 51 t129 51 S
+t125 52 t129 52 S
+t125 53 t129 53 S
+t125 54 t129 54 S
+t125 55 t129 55 S
+t125 56 t129 56 S
+t125 57 t129 57 S
+t125 58 t129 58 S
+t125 59 t129 59 S
+t125 60 t125 60 R
+t125 61 t125 61 R
+t125 62 t125 62 R
+t125 63 t125 63 R
+t125 64 t125 64 R
+t125 65 t125 65 R
+t125 66 t125 66 R
+t125 67 t125 67 R
+t125 68 t125 68 R
+t125 69 t125 69 R
+t125 70 t125 70 R
+t125 71 t125 71 R
+t125 72 t125 72 R
+t125 73 t125 73 R
+t125 74 t125 74 R
+t125 75 t125 75 R
+t125 76 t125 76 R
+t125 77 t125 77 R
+t125 78 t125 78 R
+t125 79 t125 79 R
+t125 80 t125 80 R
+t125 81 t125 81 R
+t125 82 t125 82 R
+t125 83 t125 83 R
+t125 84 t125 84 R
+t125 85 t125 85 R
+t125 86 t125 86 R
+t125 87 t125 87 R
+t125 88 t125 88 R
+t125 89 t125 89 R
+t125 90 t129 90 S
+t125 91 t129 91 S
+t125 92 t129 92 S
+t125 93 t129 93 S
+t125 94 t129 94 S
+t125 105 t129 105 S
+t125 106 t129 106 S
+t125 107 t129 107 S
+t125 108 t129 108 S
+t125 109 t129 109 S
+t127 0 t127 0 R
+t127 1 t127 1 R
+t127 2 t127 2 R
+t127 3 t127 3 R
+t127 4 t127 4 R
+t127 5 t127 5 R
+t127 6 t127 6 R
+t127 7 t127 7 R
+t127 8 t127 8 R
+t127 9 t127 9 R
+t127 10 t127 10 R
+t127 11 t127 11 R
+t127 12 t127 12 R
+t127 13 t127 13 R
+t127 14 t127 14 R
+t127 15 t130 15 S
+t127 16 t130 16 S
+t127 17 t130 17 S
+t127 18 t130 18 S
+t127 19 t130 19 S
+t127 20 t130 20 S
+t127 21 t130 21 S
+t127 22 t130 22 S
+t127 23 t130 23 S
+t127 24 t130 24 S
+t127 25 t130 25 S
+t127 26 t130 26 S
+t127 27 t130 27 S
+t127 28 t130 28 S
+t127 29 t130 29 S
+t127 30 t127 30 R
+t127 31 t127 31 R
+t127 32 t127 32 R
+t127 33 t127 33 R
+t127 34 t127 34 R
+t127 35 t127 35 R
+t127 36 t127 36 R
+t127 37 t127 37 R
+t127 38 t127 38 R
+t127 39 t127 39 R
+t127 40 t127 40 R
+t127 41 t127 41 R
+t127 42 t127 42 R
+t127 43 t127 43 R
+t127 44 t127 44 R
+t127 45 t130 45 S
+t127 46 t130 46 S
+t127 47 t130 47 S
+t127 48 t130 48 S
+t127 49 t130 49 S
+t127 50 t130 50 S
+t127 51 t130 51 S
+t127 52 t130 52 S
+t127 53 t130 53 S
+t127 54 t130 54 S
+t127 55 t130 55 S
+t127 56 t130 56 S
+t127 57 t130 57 S
+t127 58 t130 58 S
+t127 59 t130 59 S
+t127 60 t127 60 R
+t127 61 t127 61 R
+t127 62 t127 62 R
+t127 63 t127 63 R
+t127 64 t127 64 R
+t127 65 t127 65 R
+t127 66 t127 66 R
+t127 67 t127 67 R
+t127 68 t127 68 R
+t127 69 t127 69 R
+t127 70 t127 70 R
+t127 71 t127 71 R
+t127 72 t127 72 R
+t127 73 t127 73 R
+t127 74 t127 74 R
+t127 75 t130 75 S
+t127 76 t130 76 S
+t127 77 t130 77 S
+t127 78 t130 78 S
+t127 79 t130 79 S
+t127 80 t130 80 S
+t127 81 t130 81 S
+t127 82 t130 82 S
+t127 83 t130 83 S
+t127 84 t130 84 S
+t127 85 t130 85 S
+t127 86 t130 86 S
+t127 87 t130 87 S
+t127 88 t130 88 S
+t127 89 t130 89 S
+t127 90 t127 90 R
+t127 91 t127 91 R
+t127 92 t127 92 R
+t127 93 t127 93 R
+t127 94 t127 94 R
+t127 105 t130 105 S
+t127 106 t130 106 S
+t127 107 t130 107 S
+t127 108 t130 108 S
+t127 109 t130 109 S
+t129 30 t128 0 S
+t129 31 t128 1 S
+t129 32 t128 2 S
+t129 33 t128 3 S
+t129 34 t128 4 S
+t129 45 t128 15 S
+t129 46 t128 16 S
+t129 47 t128 17 S
+t129 48 t128 18 S
+t129 49 t128 19 S
+t129 90 t128 60 S
+t129 91 t128 61 S
+t129 92 t128 62 S
+t129 93 t128 63 S
+t129 94 t128 64 S
+t129 105 t128 75 S
+t129 106 t128 76 S
+t129 107 t128 77 S
+t129 108 t128 78 S
+t129 109 t128 79 S
+t130 15 t54 3 R
+t130 16 t54 3 R
+t130 17 t54 3 R
+t130 18 t54 3 R
+t130 19 t54 3 R
+t130 20 t54 8 R
+t130 21 t54 8 R
+t130 22 t54 8 R
+t130 23 t54 8 R
+t130 24 t54 8 R
+t130 25 t54 13 R
+t130 26 t54 13 R
+t130 27 t54 13 R
+t130 28 t54 13 R
+t130 29 t54 13 R
+t130 45 t54 33 R
+t130 46 t54 33 R
+t130 47 t54 33 R
+t130 48 t54 33 R
+t130 49 t54 33 R
+t130 50 t54 38 R
+t130 51 t54 38 R
+t130 52 t54 38 R
+t130 53 t54 38 R
+t130 54 t54 38 R
+t130 55 t54 43 R
+t130 56 t54 43 R
+t130 57 t54 43 R
+t130 58 t54 43 R
+t130 59 t54 43 R
+t130 75 t54 63 R
+t130 76 t54 63 R
+t130 77 t54 63 R
+t130 78 t54 63 R
+t130 79 t54 63 R
+t130 80 t54 68 R
+t130 81 t54 68 R
+t130 82 t54 68 R
+t130 83 t54 68 R
+t130 84 t54 68 R
+t130 85 t54 73 R
+t130 86 t54 73 R
+t130 87 t54 73 R
+t130 88 t54 73 R
+t130 89 t54 73 R
+t130 105 t54 93 R
+t130 106 t54 93 R
+t130 107 t54 93 R
+t130 108 t54 93 R
+t130 109 t54 93 R
+t112 0 t112 0 L
+t112 1 t112 1 L
+t112 2 t112 2 L
+t112 3 t112 3 L
+t112 4 t112 4 L
+t112 5 t112 5 L
+t112 6 t112 6 L
+t112 7 t112 7 L
+t112 8 t112 8 L
+t112 9 t112 9 L
+t112 10 t112 10 L
+t112 11 t112 11 L
+t112 12 t112 12 L
+t112 13 t112 13 L
+t112 14 t112 14 L
+t112 15 t112 15 L
+t112 16 t112 16 L
+t112 17 t112 17 L
+t112 18 t112 18 L
+t112 19 t112 19 L
+t112 20 t112 20 L
+t112 21 t112 21 L
+t112 22 t112 22 L
+t112 23 t112 23 L
+t112 24 t112 24 L
+t112 25 t112 25 L
+t112 26 t112 26 L
+t112 27 t112 27 L
+t112 28 t112 28 L
+t112 29 t112 29 L
+t112 30 t112 30 L
+t112 31 t112 31 L
+t112 32 t112 32 L
+t112 33 t112 33 L
+t112 34 t112 34 L
+t112 35 t112 35 L
+t112 36 t112 36 L
+t112 37 t112 37 L
+t112 38 t112 38 L
+t112 39 t112 39 L
+t112 40 t112 40 L
+t112 41 t112 41 L
+t112 42 t112 42 L
+t112 43 t112 43 L
+t112 44 t112 44 L
+t112 45 t112 45 L
+t112 46 t112 46 L
+t112 47 t112 47 L
+t112 48 t112 48 L
+t112 49 t112 49 L
+t112 50 t112 50 L
+t112 51 t112 51 L
+t112 52 t112 52 L
+t112 53 t112 53 L
+t112 54 t112 54 L
+t112 55 t112 55 L
+t112 56 t112 56 L
+t112 57 t112 57 L
+t112 58 t112 58 L
+t112 59 t112 59 L
+t112 60 t131 60 S
+t112 61 t131 61 S
+t112 62 t131 62 S
+t112 63 t131 63 S
+t112 64 t131 64 S
+t112 65 t131 65 S
+t112 66 t131 66 S
+t112 67 t131 67 S
+t112 68 t131 68 S
+t112 69 t131 69 S
+t112 70 t131 70 S
+t112 71 t131 71 S
+t112 72 t131 72 S
+t112 73 t131 73 S
+t112 74 t131 74 S
+t112 75 t131 75 S
+t112 76 t131 76 S
+t112 77 t131 77 S
+t112 78 t131 78 S
+t112 79 t131 79 S
+t112 80 t131 80 S
+t112 81 t131 81 S
+t112 82 t131 82 S
+t112 83 t131 83 S
+t112 84 t131 84 S
+t112 85 t131 85 S
+t112 86 t131 86 S
+t112 87 t131 87 S
+t112 88 t131 88 S
+t112 89 t131 89 S
+t112 90 t131 90 S
+t112 91 t131 91 S
+t112 92 t131 92 S
+t112 93 t131 93 S
+t112 94 t131 94 S
+t112 105 t131 105 S
+t112 106 t131 106 S
+t112 107 t131 107 S
+t112 108 t131 108 S
+t112 109 t131 109 S
+t132 0 t132 0 L
+t132 1 t132 1 L
+t132 2 t132 2 L
+t132 3 t132 3 L
+t132 4 t132 4 L
+t132 5 t132 5 L
+t132 6 t132 6 L
+t132 7 t132 7 L
+t132 8 t132 8 L
+t132 9 t132 9 L
+t132 10 t132 10 L
+t132 11 t132 11 L
+t132 12 t132 12 L
+t132 13 t132 13 L
+t132 14 t132 14 L
+t132 15 t132 15 L
+t132 16 t132 16 L
+t132 17 t132 17 L
+t132 18 t132 18 L
+t132 19 t132 19 L
+t132 20 t132 20 L
+t132 21 t132 21 L
+t132 22 t132 22 L
+t132 23 t132 23 L
+t132 24 t132 24 L
+t132 25 t132 25 L
+t132 26 t132 26 L
+t132 27 t132 27 L
+t132 28 t132 28 L
+t132 29 t132 29 L
+t132 30 t132 30 L
+t132 31 t132 31 L
+t132 32 t132 32 L
+t132 33 t132 33 L
+t132 34 t132 34 L
+t132 35 t132 35 L
+t132 36 t132 36 L
+t132 37 t132 37 L
+t132 38 t132 38 L
+t132 39 t132 39 L
+t132 40 t132 40 L
+t132 41 t132 41 L
+t132 42 t132 42 L
+t132 43 t132 43 L
+t132 44 t132 44 L
+t132 45 t132 45 L
+t132 46 t132 46 L
+t132 47 t132 47 L
+t132 48 t132 48 L
+t132 49 t132 49 L
+t132 50 t132 50 L
+t132 51 t132 51 L
+t132 52 t132 52 L
+t132 53 t132 53 L
+t132 54 t132 54 L
+t132 55 t132 55 L
+t132 56 t132 56 L
+t132 57 t132 57 L
+t132 58 t132 58 L
+t132 59 t132 59 L
+t132 60 t133 60 S
+t132 61 t133 61 S
+t132 62 t133 62 S
+t132 63 t133 63 S
+t132 64 t133 64 S
+t132 65 t133 65 S
+t132 66 t133 66 S
+t132 67 t133 67 S
+t132 68 t133 68 S
+t132 69 t133 69 S
+t132 70 t133 70 S
+t132 71 t133 71 S
+t132 72 t133 72 S
+t132 73 t133 73 S
+t132 74 t133 74 S
+t132 75 t133 75 S
+t132 76 t133 76 S
+t132 77 t133 77 S
+t132 78 t133 78 S
+t132 79 t133 79 S
+t132 80 t133 80 S
+t132 81 t133 81 S
+t132 82 t133 82 S
+t132 83 t133 83 S
+t132 84 t133 84 S
+t132 85 t133 85 S
+t132 86 t133 86 S
+t132 87 t133 87 S
+t132 88 t133 88 S
+t132 89 t133 89 S
+t132 90 t133 90 S
+t132 91 t133 91 S
+t132 92 t133 92 S
+t132 93 t133 93 S
+t132 94 t133 94 S
+t132 105 t133 105 S
+t132 106 t133 106 S
+t132 107 t133 107 S
+t132 108 t133 108 S
+t132 109 t133 109 S
+t134 0 t132 30 S
+t134 1 t132 31 S
+t134 2 t132 32 S
+t134 3 t132 33 S
+t134 4 t132 34 S
+t134 15 t132 45 S
+t134 16 t132 46 S
+t134 17 t132 47 S
+t134 18 t132 48 S
+t134 19 t132 49 S
+t134 60 t132 90 S
+t134 61 t132 91 S
+t134 62 t132 92 S
+t134 63 t132 93 S
+t134 64 t132 94 S
+t134 75 t132 105 S
+t134 76 t132 106 S
+t134 77 t132 107 S
+t134 78 t132 108 S
+t134 79 t132 109 S
+t131 0 t131 0 R
+t131 1 t131 1 R
+t131 2 t131 2 R
+t131 3 t131 3 R
+t131 4 t131 4 R
+t131 5 t131 5 R
+t131 6 t131 6 R
+t131 7 t131 7 R
+t131 8 t131 8 R
+t131 9 t131 9 R
+t131 10 t131 10 R
+t131 11 t131 11 R
+t131 12 t131 12 R
+t131 13 t131 13 R
+t131 14 t131 14 R
+t131 15 t131 15 R
+t131 16 t131 16 R
+t131 17 t131 17 R
+t131 18 t131 18 R
+t131 19 t131 19 R
+t131 20 t131 20 R
+t131 21 t131 21 R
+t131 22 t131 22 R
+t131 23 t131 23 R
+t131 24 t131 24 R
+t131 25 t131 25 R
+t131 26 t131 26 R
+t131 27 t131 27 R
+t131 28 t131 28 R
+t131 29 t131 29 R
+t131 30 t135 30 S
+t131 31 t135 31 S
+t131 32 t135 32 S
+t131 33 t135 33 S
+t131 34 t135 34 S
+t131 35 t135 35 S
+t131 36 t135 36 S
+t131 37 t135 37 S
+t131 38 t135 38 S
+t131 39 t135 39 S
+t131 40 t135 40 S
+t131 41 t135 41 S
+t131 42 t135 42 S
+t131 43 t135 43 S
+t131 44 t135 44 S
+t131 45 t135 45 S
+t131 46 t135 46 S
+t131 47 t135 47 S
+t131 48 t135 48 S
+t131 49 t135 49 S
+t131 50 t135 50 S
+t131 51 t135 51 S
+t131 52 t135 52 S
+t131 53 t135 53 S
+t131 54 t135 54 S
+t131 55 t135 55 S
+t131 56 t135 56 S
+t131 57 t135 57 S
+t131 58 t135 58 S
+t131 59 t135 59 S
+t131 60 t131 60 R
+t131 61 t131 61 R
+t131 62 t131 62 R
+t131 63 t131 63 R
+t131 64 t131 64 R
+t131 65 t131 65 R
+t131 66 t131 66 R
+t131 67 t131 67 R
+t131 68 t131 68 R
+t131 69 t131 69 R
+t131 70 t131 70 R
+t131 71 t131 71 R
+t131 72 t131 72 R
+t131 73 t131 73 R
+t131 74 t131 74 R
+t131 75 t131 75 R
+t131 76 t131 76 R
+t131 77 t131 77 R
+t131 78 t131 78 R
+t131 79 t131 79 R
+t131 80 t131 80 R
+t131 81 t131 81 R
+t131 82 t131 82 R
+t131 83 t131 83 R
+t131 84 t131 84 R
+t131 85 t131 85 R
+t131 86 t131 86 R
+t131 87 t131 87 R
+t131 88 t131 88 R
+t131 89 t131 89 R
+t131 90 t135 90 S
+t131 91 t135 91 S
+t131 92 t135 92 S
+t131 93 t135 93 S
+t131 94 t135 94 S
+t131 105 t135 105 S
+t131 106 t135 106 S
+t131 107 t135 107 S
+t131 108 t135 108 S
+t131 109 t135 109 S
+t133 0 t133 0 R
+t133 1 t133 1 R
+t133 2 t133 2 R
+t133 3 t133 3 R
+t133 4 t133 4 R
+t133 5 t133 5 R
+t133 6 t133 6 R
+t133 7 t133 7 R
+t133 8 t133 8 R
+t133 9 t133 9 R
+t133 10 t133 10 R
+t133 11 t133 11 R
+t133 12 t133 12 R
+t133 13 t133 13 R
+t133 14 t133 14 R
+t133 15 t136 15 S
+t133 16 t136 16 S
+t133 17 t136 17 S
+t133 18 t136 18 S
+t133 19 t136 19 S
+t133 20 t136 20 S
+t133 21 t136 21 S
+t133 22 t136 22 S
+t133 23 t136 23 S
+t133 24 t136 24 S
+t133 25 t136 25 S
+t133 26 t136 26 S
+t133 27 t136 27 S
+t133 28 t136 28 S
+t133 29 t136 29 S
+t133 30 t133 30 R
+t133 31 t133 31 R
+t133 32 t133 32 R
+t133 33 t133 33 R
+t133 34 t133 34 R
+t133 35 t133 35 R
+t133 36 t133 36 R
+t133 37 t133 37 R
+t133 38 t133 38 R
+t133 39 t133 39 R
+t133 40 t133 40 R
+t133 41 t133 41 R
+t133 42 t133 42 R
+t133 43 t133 43 R
+t133 44 t133 44 R
+t133 45 t136 45 S
+t133 46 t136 46 S
+t133 47 t136 47 S
+t133 48 t136 48 S
+t133 49 t136 49 S
+t133 50 t136 50 S
+t133 51 t136 51 S
+t133 52 t136 52 S
+t133 53 t136 53 S
+t133 54 t136 54 S
+t133 55 t136 55 S
+t133 56 t136 56 S
+t133 57 t136 57 S
+t133 58 t136 58 S
+t133 59 t136 59 S
+t133 60 t133 60 R
+t133 61 t133 61 R
+t133 62 t133 62 R
+t133 63 t133 63 R
+t133 64 t133 64 R
+t133 65 t133 65 R
+t133 66 t133 66 R
+t133 67 t133 67 R
+t133 68 t133 68 R
+t133 69 t133 69 R
+t133 70 t133 70 R
+t133 71 t133 71 R
+t133 72 t133 72 R
+t133 73 t133 73 R
+t133 74 t133 74 R
+t133 75 t136 75 S
+t133 76 t136 76 S
+t133 77 t136 77 S
+t133 78 t136 78 S
+t133 79 t136 79 S
+t133 80 t136 80 S
+t133 81 t136 81 S
+t133 82 t136 82 S
+t133 83 t136 83 S
+t133 84 t136 84 S
+t133 85 t136 85 S
+t133 86 t136 86 S
+t133 87 t136 87 S
+t133 88 t136 88 S
+t133 89 t136 89 S
+t133 90 t133 90 R
+t133 91 t133 91 R
+t133 92 t133 92 R
+t133 93 t133 93 R
+t133 94 t133 94 R
+t133 105 t136 105 S
+t133 106 t136 106 S
+t133 107 t136 107 S
+t133 108 t136 108 S
+t133 109 t136 109 S
+t135 30 t134 0 S
+t135 31 t134 1 S
+t135 32 t134 2 S
+t135 33 t134 3 S
+t135 34 t134 4 S
+t135 45 t134 15 S
+t135 46 t134 16 S
+t135 47 t134 17 S
+t135 48 t134 18 S
+t135 49 t134 19 S
+t135 90 t134 60 S
+t135 91 t134 61 S
+t135 92 t134 62 S
+t135 93 t134 63 S
+t135 94 t134 64 S
+t135 105 t134 75 S
+t135 106 t134 76 S
+t135 107 t134 77 S
+t135 108 t134 78 S
+t135 109 t134 79 S
+t136 15 t54 4 R
+t136 16 t54 4 R
+t136 17 t54 4 R
+t136 18 t54 4 R
+t136 19 t54 4 R
+t136 20 t54 9 R
+t136 21 t54 9 R
+t136 22 t54 9 R
+t136 23 t54 9 R
+t136 24 t54 9 R
+t136 25 t54 14 R
+t136 26 t54 14 R
+t136 27 t54 14 R
+t136 28 t54 14 R
+t136 29 t54 14 R
+t136 45 t54 34 R
+t136 46 t54 34 R
+t136 47 t54 34 R
+t136 48 t54 34 R
+t136 49 t54 34 R
+t136 50 t54 39 R
+t136 51 t54 39 R
+t136 52 t54 39 R
+t136 53 t54 39 R
+t136 54 t54 39 R
+t136 55 t54 44 R
+t136 56 t54 44 R
+t136 57 t54 44 R
+t136 58 t54 44 R
+t136 59 t54 44 R
+t136 75 t54 64 R
+t136 76 t54 64 R
+t136 77 t54 64 R
+t136 78 t54 64 R
+t136 79 t54 64 R
+t136 80 t54 69 R
+t136 81 t54 69 R
+t136 82 t54 69 R
+t136 83 t54 69 R
+t136 84 t54 69 R
+t136 85 t54 74 R
+t136 86 t54 74 R
+t136 87 t54 74 R
+t136 88 t54 74 R
+t136 89 t54 74 R
+t136 105 t54 94 R
+t136 106 t54 94 R
+t136 107 t54 94 R
+t136 108 t54 94 R
+t136 109 t54 94 R
+t137 105 t138 105 S
+t139 105 t140 105 S
+t141 75 t139 105 S
+t138 105 t142 105 S
+t140 105 t143 105 S
+t142 105 t141 75 S
+t143 105 t22 90 S
+t0 105 t137 105 S
+t0 106 t144 106 S
+t0 107 t145 107 S
+t144 106 t146 106 S
+t147 106 t148 106 S
+t149 76 t147 106 S
+t146 106 t150 106 S
+t148 106 t151 106 S
+t150 106 t149 76 S
+t151 106 t80 93 S
+t145 107 t152 107 S
+t153 107 t154 107 S
+t155 77 t153 107 S
+t152 107 t156 107 S
+t154 107 t157 107 S
+t156 107 t155 77 S
+t157 107 t80 94 S
+t23 18 accept 18 S
+t23 19 accept 19 S
+t23 23 accept 23 S
+t23 24 accept 24 S
+t23 28 accept 28 S
+t23 29 accept 29 S
+t23 48 accept 48 S
+t23 49 accept 49 S
+t23 53 accept 53 S
+t23 54 accept 54 S
+t23 58 accept 58 S
+t23 59 accept 59 S
+t23 78 accept 78 S
+t23 79 accept 79 S
+t23 83 accept 83 S
+t23 84 accept 84 S
+t23 88 accept 88 S
+t23 89 accept 89 S
+t23 105 accept 105 S
+t23 108 accept 108 S
+t23 109 accept 109 S
+t23 113 accept 113 S
+t23 114 accept 114 S
+t23 118 accept 118 S
+t23 119 accept 119 S
@@ -0,0 +1,65 @@
+mod common;
+
+use common::palindrome_two_tape;
+use std::path::PathBuf;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+
+/* The translations of the bundled two-tape palindrome machine, checked against the files in
+`tests/golden`. After an intended change to the translator, rerun with `UPDATE_GOLDEN=1` to
+rewrite them and review the diff. */
+
+// the translated machine with short names, followed by its legend
+fn translated(transitions: Vec<MultiTransition>, strategy: Strategy) -> (String, String) {
+    let translation = MachineTranslator::new(transitions, strategy)
+        .unwrap()
+        .translation()
+        .with_short_names();
+    let mut machine = vec![];
+    translation.write_to(&mut machine).unwrap();
+    (
+        String::from_utf8(machine).unwrap(),
+        translation.legend.to_string(),
+    )
+}
+
+fn check_golden(name: &str, actual: &str) {
+    let golden = PathBuf::from(common::path("tests/golden")).join(name);
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        std::fs::write(&golden, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&golden)
+        .unwrap_or_else(|err| panic!("can't read {}: {}", golden.display(), err));
+    assert!(
+        expected == actual,
+        "translation differs from {}, rerun with UPDATE_GOLDEN=1 if that's intended",
+        golden.display()
+    );
+}
+
+#[test]
+fn palindrome_translations_match_golden_files() {
+    for strategy in Strategy::ALL.iter() {
+        let (machine, legend) = translated(palindrome_two_tape(), *strategy);
+        check_golden(&format!("palindrome_two_tape.{}.tm", strategy), &machine);
+        check_golden(&format!("palindrome_two_tape.{}.legend", strategy), &legend);
+    }
+}
+
+#[test]
+fn translations_dont_depend_on_transition_order() {
+    let mut shuffled = palindrome_two_tape();
+    shuffled.reverse();
+    let mid = shuffled.len() / 2;
+    shuffled.swap(0, mid);
+
+    for strategy in Strategy::ALL.iter() {
+        assert_eq!(
+            translated(palindrome_two_tape(), *strategy),
+            translated(shuffled.clone(), *strategy)
+        );
+    }
+}
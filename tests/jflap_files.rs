@@ -0,0 +1,76 @@
+mod common;
+
+use common::{accepts, example, load};
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::format::jflap;
+use turinglike_oversophisticated_calculator::machine::logic::differential;
+use turinglike_oversophisticated_calculator::types::*;
+
+// two states named `q`, told apart by their ids; an empty `<read/>` is the blank
+const DUPLICATE_NAMES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<structure>
+	<type>turing</type>
+	<automaton>
+		<state id="0" name="q"><initial/></state>
+		<state id="1" name="q"></state>
+		<state id="2" name="done"><final/></state>
+		<transition><from>0</from><to>1</to><read>1</read><write>1</write><move>R</move></transition>
+		<transition><from>1</from><to>2</to><read/><write>a</write><move>S</move></transition>
+	</automaton>
+</structure>"#;
+
+#[test]
+fn one_tape_machines_survive_a_round_trip() {
+    let transitions = example("palindrome.tm");
+    let rendered = jflap::render(&transitions).unwrap();
+    assert_eq!(jflap::parse(&rendered).unwrap(), transitions);
+
+    // JFLAP has no rejecting states, so `reject` comes back as an ordinary state
+    let transitions = example("square.tm");
+    let rendered = jflap::render(&transitions).unwrap();
+    let renamed: Vec<Transition> = transitions
+        .iter()
+        .map(|trans| Transition {
+            state_after: match trans.state_after.value() {
+                std_states::REJECT => State("reject_".to_string()),
+                _ => trans.state_after.clone(),
+            },
+            ..trans.clone()
+        })
+        .collect();
+    assert_eq!(jflap::parse(&rendered).unwrap(), renamed);
+}
+
+#[test]
+fn multi_tape_machines_survive_a_round_trip() {
+    let two_tape = common::palindrome_two_tape();
+    let rendered = jflap::render_multi_tape(&two_tape).unwrap();
+    assert!(rendered.contains("<tapes>2</tapes>"));
+    assert_eq!(jflap::parse_multi_tape(&rendered).unwrap(), two_tape);
+    assert!(jflap::parse(&rendered).is_err());
+}
+
+#[test]
+fn symbols_past_letters_go_in_the_legend() {
+    let transitions = load("start 1 mark 100 R\nmark 0 back 4000 L\nback 100 accept 100 S");
+    let rendered = jflap::render(&transitions).unwrap();
+    assert!(rendered.contains("<!--symbols:"));
+    let parsed = jflap::parse(&rendered).unwrap();
+    assert_eq!(parsed, transitions);
+    for word in differential::words_up_to(&['1', '2'], 3) {
+        assert_eq!(accepts(&parsed, &word), accepts(&transitions, &word));
+    }
+}
+
+#[test]
+fn duplicate_state_names_stay_apart() {
+    let transitions = jflap::parse(DUPLICATE_NAMES).unwrap();
+    let described: Vec<String> = transitions.iter().map(|t| t.to_string()).collect();
+    assert_eq!(
+        described,
+        vec![
+            "start 1 q_1 1 R",
+            &format!("q_1 0 accept {} S", TapeEntry(10))
+        ]
+    );
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::{example, load, CONTAINS_ONE_ONE_GOING_LEFT};
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::differential;
+use turinglike_oversophisticated_calculator::machine::translation::speedup::LinearSpeedup;
+use turinglike_oversophisticated_calculator::types::*;
+
+// the length of the shortest accepting run and how much of it packed the input
+fn sped_up_run(transitions: &[Transition], word: &str) -> Option<(Number, Number)> {
+    let mut packing_steps = None;
+    let length = ClassicMachine::new(transitions.to_vec(), 10_000, word.to_string())
+        .unwrap()
+        .traced_accepting_run_length(|step, configs| {
+            if packing_steps.is_none()
+                && configs
+                    .iter()
+                    .any(|cfg| LinearSpeedup::is_block_step_start(&cfg.state))
+            {
+                packing_steps = Some(step);
+            }
+        })?;
+    Some((length, packing_steps.unwrap()))
+}
+
+fn check_speedup(transitions: Vec<Transition>, factor: usize, max_length: usize) {
+    let speedup = LinearSpeedup::new(transitions.clone(), factor, &[]).unwrap();
+    let sped_up = speedup.transitions();
+    let machine = ClassicMachine::new(transitions, 10_000, String::new()).unwrap();
+    for word in differential::words_up_to(&['1', '2'], max_length) {
+        let original = machine
+            .with_input(10_000, word.clone())
+            .accepting_run_length();
+        let result = sped_up_run(&sped_up, &word);
+        assert_eq!(
+            original.is_some(),
+            result.is_some(),
+            "disagreement on `{}` with blocks of {}",
+            word,
+            factor
+        );
+        if let (Some(original), Some((length, packing))) = (original, result) {
+            let (n, factor) = (word.len() as Number, factor as Number);
+            let block_steps = original / factor;
+            assert!(
+                length - packing <= block_steps * LinearSpeedup::steps_per_block_step() + 1,
+                "`{}` took {} steps, {} of them packing, for {} steps",
+                word,
+                length,
+                packing,
+                original
+            );
+            assert!(
+                packing <= n * n / factor + 4 * n + 1,
+                "packing `{}` took {} steps",
+                word,
+                packing
+            );
+        }
+    }
+}
+
+#[test]
+fn sped_up_palindrome_accepts_the_same_words() {
+    for factor in 1..=3 {
+        check_speedup(example("palindrome.tm"), factor, 6);
+    }
+}
+
+#[test]
+fn nondeterministic_machines_can_be_sped_up() {
+    for factor in 2..=3 {
+        check_speedup(load(CONTAINS_ONE_ONE_GOING_LEFT), factor, 5);
+    }
+}
+
+/* The palindrome machine goes back and forth over the input, so its runs take about
+`n * n / 2` steps, and the packing on top of a quarter of them is less than the rest. */
+#[test]
+fn long_runs_get_shorter() {
+    let transitions = example("palindrome.tm");
+    let sped_up = LinearSpeedup::new(transitions.clone(), 4, &[])
+        .unwrap()
+        .transitions();
+    for word in &["2112221111222112", "122112111211112111211221"] {
+        let original = ClassicMachine::new(transitions.clone(), 10_000, word.to_string())
+            .unwrap()
+            .accepting_run_length()
+            .unwrap();
+        let (length, packing) = sped_up_run(&sped_up, word).unwrap();
+        assert!(original > word.len() as Number * 8);
+        assert!(length - packing <= original / 4 * LinearSpeedup::steps_per_block_step() + 1);
+        assert!(
+            length < original,
+            "`{}` took {} steps sped up, {} of them packing, and {} before",
+            word,
+            length,
+            packing,
+            original
+        );
+    }
+}
+
+#[test]
+fn factors_too_large_to_build_are_refused() {
+    assert!(LinearSpeedup::new(example("palindrome.tm"), 4, &[]).is_ok());
+    match LinearSpeedup::new(example("palindrome.tm"), 5, &[]) {
+        Err(err) => assert!(err.to_string().contains("transitions")),
+        Ok(_) => panic!("blocks of 5 cells were accepted"),
+    }
+}
+
+#[test]
+fn letters_have_to_fit_in_a_block() {
+    assert!(LinearSpeedup::new(example("palindrome.tm"), 0, &[]).is_err());
+    let wide = load("start 1 accept 4000000 S");
+    assert!(LinearSpeedup::new(wide.clone(), 2, &[]).is_ok());
+    assert!(LinearSpeedup::new(wide, 3, &[]).is_err());
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::example;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::canonical::CanonicalForm;
+use turinglike_oversophisticated_calculator::machine::logic::diff::{self, Change};
+use turinglike_oversophisticated_calculator::machine::logic::{differential, loader};
+use turinglike_oversophisticated_calculator::types::*;
+
+fn palindrome() -> Vec<Transition> {
+    example("palindrome.tm")
+}
+
+fn description() -> String {
+    loader::description_from_transitions(&palindrome())
+}
+
+// the machine described with `golong1` renamed
+fn renamed(description: String) -> Vec<Transition> {
+    loader::transitions_from_description(description.replace("golong1", "far1")).unwrap()
+}
+
+fn canonical(transitions: &[Transition]) -> Vec<Transition> {
+    CanonicalForm::new(transitions, &[TapeEntry(1), TapeEntry(2)])
+        .transitions()
+        .to_vec()
+}
+
+fn words() -> Vec<String> {
+    differential::words_up_to(&['1', '2'], 5)
+}
+
+#[test]
+fn renamed_states_only_differ_by_name() {
+    let renamed = renamed(description());
+    let changes = diff::transition_changes(&palindrome(), &renamed);
+    assert_eq!(changes.len(), 7);
+    assert!(changes.contains(&Change::Changed {
+        before: Transition::from_description("start 1 golong1 0 R").unwrap(),
+        after: Transition::from_description("start 1 far1 0 R").unwrap(),
+    }));
+    assert!(changes.contains(&Change::Removed(
+        Transition::from_description("golong1 1 golong1 1 R").unwrap()
+    )));
+
+    assert!(diff::transition_changes(&canonical(&palindrome()), &canonical(&renamed)).is_empty());
+    let check = diff::first_disagreement(&palindrome(), &renamed, &words(), 1000).unwrap();
+    assert!(check.disagreement.is_none());
+    assert!(check.undecided.is_empty());
+}
+
+#[test]
+fn changed_transitions_show_with_a_witness() {
+    let broken =
+        renamed(description().replace("checklast2 2 goback 0 L", "checklast2 2 goback 2 L"));
+    assert_eq!(
+        diff::transition_changes(&canonical(&palindrome()), &canonical(&broken))
+            .iter()
+            .map(Change::to_string)
+            .collect::<Vec<_>>(),
+        vec!["changed `q4 2 q5 0 L` to `q4 2 q5 2 L`"]
+    );
+
+    let disagreement = diff::first_disagreement(&palindrome(), &broken, &words(), 1000)
+        .unwrap()
+        .disagreement
+        .unwrap();
+    assert_eq!(disagreement.word, "212");
+    assert!(disagreement.accepted_by_first);
+    let witness = &disagreement.witness;
+    assert_eq!(witness[0].state.value(), std_states::START);
+    assert_eq!(witness.last().unwrap().state.value(), std_states::ACCEPT);
+    // every config follows from the one before by a transition of the first machine
+    for pair in witness.windows(2) {
+        let read = pair[0].tape.read_from_head();
+        assert!(palindrome()
+            .iter()
+            .any(|trans| trans.state_before == pair[0].state
+                && &trans.tape_value_before == read
+                && trans.state_after == pair[1].state));
+    }
+}
+
+#[test]
+fn reordered_copies_have_no_canonical_changes() {
+    let twins = "start 1 a 1 R\nstart 1 b 1 R\na 1 accept 1 S\nb 2 accept 2 S";
+    let swapped = "start 1 b 1 R\nstart 1 a 1 R\na 1 accept 1 S\nb 2 accept 2 S";
+    assert!(diff::transition_changes(
+        &canonical(&common::load(twins)),
+        &canonical(&common::load(swapped))
+    )
+    .is_empty());
+
+    let mut reversed = palindrome();
+    reversed.reverse();
+    assert!(diff::transition_changes(&canonical(&palindrome()), &canonical(&reversed)).is_empty());
+}
+
+#[test]
+fn runs_out_of_steps_are_not_rejections() {
+    let walks_away = common::load("start 0 start 0 R\nstart 1 start 1 R");
+    let accepts_all = common::load("start 0 accept 0 S\nstart 1 accept 1 S");
+    let words = differential::words_up_to(&['1'], 2);
+    let check = diff::first_disagreement(&walks_away, &accepts_all, &words, 50).unwrap();
+    assert!(check.disagreement.is_none());
+    assert_eq!(check.undecided.len(), 3);
+    assert!(check.undecided[0].to_string().contains("runs out of steps"));
+
+    // a machine halting without accepting does reject
+    let halts = common::load("start 1 start 1 R");
+    let check = diff::first_disagreement(&halts, &accepts_all, &words, 50).unwrap();
+    assert!(check.undecided.is_empty());
+    let disagreement = check.disagreement.unwrap();
+    assert_eq!(disagreement.word, "");
+    assert!(!disagreement.accepted_by_first);
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::{example, load};
+use turinglike_oversophisticated_calculator::machine::logic::lint::{self, LintWarning};
+use turinglike_oversophisticated_calculator::types::*;
+
+/* A bit of everything: `orphan` can't be got to, `other` and `stuck` can't get to `accept`,
+the first transition is there twice and has a choice besides, 5 is never read and `accept`
+is left again. `reject` is a dead end on purpose. */
+const EVERY_WARNING: &str = "
+start 1 start 1 R
+start 1 start 1 R
+start 1 other 1 R
+start 2 reject 2 S
+start 0 accept 5 S
+accept 0 start 0 S
+other 1 stuck 1 R
+orphan 1 accept 1 S
+";
+
+fn state(name: &str) -> State {
+    State(name.to_string())
+}
+
+#[test]
+fn square_has_a_typo_in_a_state_name() {
+    let warnings = lint::lint(&example("square.tm"));
+    // the misspelled state gets nowhere and the one meant is never entered
+    assert!(warnings.contains(&LintWarning::DeadState(state(
+        "findSomeOtherrWordBeginningWith2"
+    ))));
+    assert!(warnings.contains(&LintWarning::UnreachableState(state(
+        "findSomeOtherWordBeginningWith2"
+    ))));
+
+    let description = std::fs::read_to_string(common::path("example/square.tm")).unwrap();
+    let fixed = load(&description.replace("Otherr", "Other"));
+    let warnings = lint::lint(&fixed);
+    assert!(!warnings
+        .iter()
+        .any(|warning| matches!(warning, LintWarning::UnreachableState(_))));
+    assert!(!warnings.contains(&LintWarning::DeadState(state(
+        "findSomeOtherWordBeginningWith2"
+    ))));
+}
+
+#[test]
+fn palindrome_is_clean() {
+    assert_eq!(lint::lint(&example("palindrome.tm")), vec![]);
+}
+
+#[test]
+fn every_kind_of_warning_is_reported() {
+    let transitions = load(EVERY_WARNING);
+    let warnings: Vec<String> = lint::lint(&transitions)
+        .iter()
+        .map(LintWarning::to_string)
+        .collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "state `orphan` is unreachable from `start`",
+            "state `other` can never reach `accept`",
+            "state `stuck` can never reach `accept`",
+            "duplicate transition `start 1 start 1 R`",
+            "transition `accept 0 start 0 S` leaves the `accept` state",
+            "symbol 5 is written but never read",
+            "state `start` reading 1 has 2 transitions:\n    start 1 start 1 R\n    start 1 other 1 R",
+        ]
+    );
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::{accepts, load};
+use std::collections::HashMap;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, differential};
+use turinglike_oversophisticated_calculator::machine::translation::restricted;
+use turinglike_oversophisticated_calculator::types::*;
+
+/* Writes `2 1` left of the input and walks back over it, accepting if the input starts with
+`1`. On our tape it would get stuck writing both on the first cell. */
+const LEFT_OF_INPUT: &str = "
+start 0 mark1 0 L
+start 1 mark1 1 L
+start 2 mark1 2 L
+mark1 0 mark2 1 L
+mark2 0 check2 2 R
+check2 1 check1 1 R
+check1 1 accept 1 S
+";
+
+// bounces between both sides of the input's first cell until it finds a `2` left of it
+const BOUNCING: &str = "
+start 1 left 1 L
+start 2 left 2 L
+left 0 right 2 R
+right 1 start 1 S
+right 2 back 2 L
+back 2 accept 2 S
+";
+
+fn corpus() -> Vec<Vec<Transition>> {
+    let mut corpus = common::corpus();
+    corpus.extend(vec![load(LEFT_OF_INPUT), load(BOUNCING)]);
+    corpus
+}
+
+fn words() -> Vec<String> {
+    differential::words_up_to(&['1', '2'], 4)
+}
+
+// runs a deterministic machine on a tape infinite in both directions
+fn accepts_on_two_way_tape(transitions: &[Transition], word: &str, limit: usize) -> bool {
+    let mut tape: HashMap<i64, TapeEntry> = word
+        .chars()
+        .enumerate()
+        .map(|(idx, ch)| (idx as i64, TapeEntry(ch.to_digit(10).unwrap().into())))
+        .collect();
+    let mut state = State(std_states::START.to_string());
+    let mut head = 0i64;
+    for _ in 0..limit {
+        if state.value() == std_states::ACCEPT {
+            return true;
+        }
+        let letter = tape.get(&head).cloned().unwrap_or(TapeEntry::BLANK);
+        let trans = match transitions
+            .iter()
+            .find(|trans| trans.state_before == state && trans.tape_value_before == letter)
+        {
+            Some(trans) => trans,
+            None => return false,
+        };
+        tape.insert(head, trans.tape_value_after.clone());
+        state = trans.state_after.clone();
+        head += match trans.tape_head_move_direction {
+            HeadMoveDirection::Left => -1,
+            HeadMoveDirection::Right => 1,
+            HeadMoveDirection::Stay => 0,
+        };
+    }
+    false
+}
+
+#[test]
+fn eliminating_stays_keeps_the_language() {
+    // folding squares the alphabet, so the translated palindrome only goes through this pass
+    for transitions in corpus()
+        .into_iter()
+        .chain(Some(common::translated_palindrome()))
+    {
+        let restricted = restricted::eliminate_stays(&transitions, &[]);
+        assert!(restricted
+            .iter()
+            .all(|trans| trans.tape_head_move_direction != HeadMoveDirection::Stay));
+        for word in words() {
+            assert_eq!(
+                accepts(&restricted, &word),
+                accepts(&transitions, &word),
+                "disagreement on `{}`",
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn folding_keeps_the_two_way_language() {
+    for transitions in corpus() {
+        if !determinism::is_deterministic(&transitions) {
+            continue;
+        }
+        let folded = restricted::fold_two_way_tape(&transitions, &[]);
+        for word in words() {
+            assert_eq!(
+                accepts(&folded, &word),
+                accepts_on_two_way_tape(&transitions, &word, 100_000),
+                "disagreement on `{}`",
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn left_of_the_input_is_really_used() {
+    let folded = restricted::fold_two_way_tape(&load(LEFT_OF_INPUT), &[]);
+    assert!(accepts(&folded, "12"));
+    assert!(!accepts(&folded, "21"));
+    assert!(!accepts(&load(LEFT_OF_INPUT), "12"));
+}
+
+#[test]
+fn passes_keep_machines_deterministic() {
+    for transitions in corpus() {
+        if !determinism::is_deterministic(&transitions) {
+            continue;
+        }
+        let folded = restricted::fold_two_way_tape(&transitions, &[]);
+        assert!(determinism::is_deterministic(&folded));
+        assert!(determinism::is_deterministic(&restricted::eliminate_stays(
+            &transitions,
+            &[]
+        )));
+        assert!(determinism::is_deterministic(&restricted::eliminate_stays(
+            &folded,
+            &[]
+        )));
+    }
+}
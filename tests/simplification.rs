@@ -0,0 +1,105 @@
+mod common;
+
+use common::{accepts, load, translated_palindrome};
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::{determinism, differential};
+use turinglike_oversophisticated_calculator::machine::translation::simplifier;
+
+/* Accepts non-empty words. `spare` can't be reached, nothing writes the `15` read in `wait`,
+`stuck` never gets anywhere, and `one` and `two` do the same thing. */
+const WITH_DEAD_PARTS: &str = "
+start 1 one 1 R
+start 2 two 2 R
+start 0 stuck 0 S
+one 0 accept 0 S
+one 1 accept 1 S
+one 2 accept 2 S
+two 0 accept 0 S
+two 1 accept 1 S
+two 2 accept 2 S
+stuck 0 stuck 0 L
+spare 1 accept 1 S
+start 15 wait 15 R
+wait 1 accept 1 S
+";
+
+// stays doing nothing, in a chain, a circle and right before accepting
+const STAY_CHAINS: &str = "
+start 1 a 1 S
+a 1 b 1 S
+b 1 c 2 R
+start 2 loop 2 S
+loop 2 start 2 S
+c 0 d 0 S
+d 0 accept 0 S
+";
+
+fn corpus() -> Vec<Vec<Transition>> {
+    let mut corpus = common::corpus();
+    corpus.extend(vec![
+        load(WITH_DEAD_PARTS),
+        load(STAY_CHAINS),
+        translated_palindrome(),
+    ]);
+    corpus
+}
+
+#[test]
+fn simplifying_keeps_the_language() {
+    for transitions in corpus() {
+        let simplified = simplifier::simplify(&transitions);
+        assert!(simplified.len() <= transitions.len());
+        if determinism::is_deterministic(&transitions) {
+            assert!(determinism::is_deterministic(&simplified));
+        }
+        for word in differential::words_up_to(&['1', '2'], 4) {
+            assert_eq!(
+                accepts(&simplified, &word),
+                accepts(&transitions, &word),
+                "disagreement on `{}`",
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn dead_parts_and_twin_states_go() {
+    let simplified = simplifier::simplify(&load(WITH_DEAD_PARTS));
+    assert_eq!(
+        simplified,
+        load(
+            "
+one 0 accept 0 S
+one 1 accept 1 S
+one 2 accept 2 S
+start 1 one 1 R
+start 2 one 2 R
+"
+        )
+    );
+}
+
+#[test]
+fn chains_of_stays_collapse() {
+    assert_eq!(
+        simplifier::collapse_stays(&load(STAY_CHAINS)),
+        load(
+            "
+a 1 c 2 R
+b 1 c 2 R
+c 0 accept 0 S
+d 0 accept 0 S
+start 1 c 2 R
+"
+        )
+    );
+}
+
+#[test]
+fn translations_get_smaller() {
+    let translated = translated_palindrome();
+    let simplified = simplifier::simplify(&translated);
+    assert!(simplified.len() < translated.len());
+    assert_eq!(simplifier::simplify(&simplified), simplified);
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use common::{accepts, load};
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::format::{
+    bbchallenge, morphett, turingmachine_io,
+};
+use turinglike_oversophisticated_calculator::machine::logic::differential;
+
+const PALINDROME: &str = include_str!("../example/palindrome.tm");
+const SQUARE: &str = include_str!("../example/square.tm");
+
+// walks right over its input and accepts once it steps back onto a 1 - no stay moves
+const ENDS_WITH_ONE: &str = "
+start 1 start 1 R
+start 2 start 2 R
+start 0 check 0 L
+check 1 accept 1 R
+";
+
+fn assert_same_language(expected: &[Transition], actual: &[Transition]) {
+    for word in differential::words_up_to(&['1', '2'], 6) {
+        assert_eq!(
+            accepts(expected, &word),
+            accepts(actual, &word),
+            "machines disagree on `{}`",
+            word
+        );
+    }
+}
+
+#[test]
+fn morphett_round_trip_keeps_transitions() {
+    for machine in [PALINDROME, SQUARE, ENDS_WITH_ONE].iter() {
+        let transitions = load(machine);
+        let rendered = morphett::render(&transitions).unwrap();
+        assert_eq!(morphett::parse(&rendered).unwrap(), transitions);
+    }
+}
+
+#[test]
+fn morphett_wildcards_lose_to_exact_rules() {
+    let transitions = morphett::parse(
+        "; skip to the end, then accept on a trailing 1
+0 * * r 0
+0 _ _ l check
+check 1 * * halt-accept",
+    )
+    .unwrap();
+    assert_same_language(&load(ENDS_WITH_ONE), &transitions);
+}
+
+#[test]
+fn morphett_digits_only_come_with_wildcards() {
+    assert_eq!(morphett::parse("0 1 1 r halt-accept").unwrap().len(), 1);
+    // `_`, `1` and the digits 2 to 9 the wildcard has to cover in the input
+    assert_eq!(morphett::parse("0 * 1 r halt-accept").unwrap().len(), 10);
+}
+
+#[test]
+fn morphett_rules_expand_in_linear_time() {
+    let mut rules: Vec<String> = (0..1000)
+        .map(|idx| format!("{} 1 1 r {}", idx, idx + 1))
+        .collect();
+    rules.push("* _ _ * halt-reject".to_string());
+    rules.push("1000 _ _ * halt-accept".to_string());
+    let transitions = morphett::parse(&rules.join("\n")).unwrap();
+    assert_eq!(transitions.len(), 1000 + 1001);
+    let accepts_ones = |count: usize| {
+        ClassicMachine::new(transitions.clone(), 2000, "1".repeat(count))
+            .unwrap()
+            .accepts()
+    };
+    assert!(accepts_ones(1000));
+    assert!(!accepts_ones(999));
+}
+
+#[test]
+fn turingmachine_io_round_trip_keeps_transitions_without_stay_moves() {
+    let transitions = load(ENDS_WITH_ONE);
+    let rendered = turingmachine_io::render(&transitions).unwrap();
+    assert_eq!(turingmachine_io::parse(&rendered).unwrap(), transitions);
+}
+
+#[test]
+fn turingmachine_io_round_trip_keeps_language() {
+    for machine in [PALINDROME, ENDS_WITH_ONE].iter() {
+        let transitions = load(machine);
+        let rendered = turingmachine_io::render(&transitions).unwrap();
+        assert_same_language(&transitions, &turingmachine_io::parse(&rendered).unwrap());
+    }
+}
+
+#[test]
+fn turingmachine_io_renames_start_and_halting_states() {
+    let transitions = turingmachine_io::parse(
+        "blank: ' '
+start state: right
+table:
+  right:
+    [1, 2]: R
+    ' '  : {L: check}
+  check:
+    1: {write: 1, R: done}
+  done:",
+    )
+    .unwrap();
+    assert_same_language(&load(ENDS_WITH_ONE), &transitions);
+}
+
+#[test]
+fn bbchallenge_round_trip_keeps_machine() {
+    for machine in ["1RB1LB_1LA1RZ", "1RB1RZ_1LB0RC_1LC1LA", "1RB---_0LA1RZ"].iter() {
+        let transitions = bbchallenge::parse(machine).unwrap();
+        assert_eq!(&bbchallenge::render(&transitions).unwrap(), machine);
+    }
+}
+
+#[test]
+fn bbchallenge_undefined_transitions_halt() {
+    let transitions = bbchallenge::parse("1RA---").unwrap();
+    assert!(accepts(&transitions, "1"));
+    // a blank sends it right forever, so it only gets a short limit
+    assert!(!ClassicMachine::new(transitions, 1000, String::new())
+        .unwrap()
+        .accepts());
+
+    let without_halting = load("start 0 start 1 R");
+    assert!(bbchallenge::render(&without_halting).is_err());
+}
+
+#[test]
+fn bbchallenge_names_at_most_26_states() {
+    assert!(bbchallenge::parse(&vec!["1RA1RA"; 26].join("_")).is_ok());
+    assert!(bbchallenge::parse(&vec!["1RA1RA"; 27].join("_")).is_err());
+}
+
+#[test]
+fn bbchallenge_halting_is_accepting() {
+    // halts on its second step
+    let transitions = bbchallenge::parse("1RB---_1LZ---").unwrap();
+    assert!(ClassicMachine::new(transitions.clone(), 2, String::new())
+        .unwrap()
+        .accepts());
+    assert!(!ClassicMachine::new(transitions, 1, String::new())
+        .unwrap()
+        .accepts());
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::palindrome_two_tape;
+
+use std::collections::HashSet;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::machine::translation::state_map::StateMap;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy, Translation,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+// copies the ones onto the second tape, halting in `t0` on a two and in `t1` at the end
+const FINAL_STATES_NAMED_LIKE_SHORT_ONES: &str = "
+start 1 0 start 1 1 R R
+start 2 0 t0 2 0 S S
+start 0 0 t1 0 0 S S
+";
+
+fn translation(transitions: Vec<MultiTransition>, strategy: Strategy) -> Translation {
+    MachineTranslator::new(transitions, strategy)
+        .unwrap()
+        .translation()
+}
+
+// in the order they first show up in the transitions
+fn states(translation: &Translation) -> Vec<State> {
+    let mut seen = HashSet::new();
+    translation
+        .transitions()
+        .flat_map(|trans| vec![trans.state_before, trans.state_after])
+        .filter(|state| seen.insert(state.clone()))
+        .collect()
+}
+
+#[test]
+fn state_maps_survive_a_round_trip() {
+    for strategy in Strategy::ALL.iter() {
+        for short_names in &[false, true] {
+            let mut translation = translation(palindrome_two_tape(), *strategy);
+            if *short_names {
+                translation = translation.with_short_names();
+            }
+            let description = translation.state_map.to_string();
+            let reloaded = StateMap::from_description(description.clone()).unwrap();
+            assert_eq!(reloaded.to_string(), description);
+        }
+    }
+}
+
+#[test]
+fn malformed_state_maps_are_rejected() {
+    for description in &["t0", "t0 a b | start 1 0 start 1 0 R R", "t0 a | start 1"] {
+        assert!(
+            StateMap::from_description(description.to_string()).is_err(),
+            "`{}` was accepted",
+            description
+        );
+    }
+}
+
+#[test]
+fn every_generated_state_has_a_meaning() {
+    for strategy in Strategy::ALL.iter() {
+        let translation = translation(palindrome_two_tape(), *strategy);
+        for state in states(&translation) {
+            let generated =
+                state.value() != "start" && !translation.halting_states.contains(&state);
+            let meaning = translation.state_map.meaning(&state);
+            assert_eq!(meaning.is_some(), generated, "{} under {}", state, strategy);
+            if let Some(meaning) = meaning {
+                assert_eq!(meaning.phase, state);
+                // only a state about to halt has no transition left to simulate
+                let (original, _) = MachineTranslator::original_state(&state).unwrap();
+                assert!(
+                    !meaning.transitions.is_empty()
+                        || translation.halting_states.contains(&original)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn short_names_follow_the_order_states_show_up_in() {
+    for strategy in Strategy::ALL.iter() {
+        let long = translation(palindrome_two_tape(), *strategy);
+        let short = translation(palindrome_two_tape(), *strategy).with_short_names();
+        let long_states = states(&long);
+        let short_states = states(&short);
+        assert_eq!(long_states.len(), short_states.len());
+
+        let mut generated = 0;
+        for (long_name, short_name) in long_states.iter().zip(short_states.iter()) {
+            if long_name.value() == "start" || long.halting_states.contains(long_name) {
+                assert_eq!(long_name, short_name);
+                continue;
+            }
+            assert_eq!(short_name.value(), format!("t{}", generated));
+            generated += 1;
+
+            // the meaning moves over to the short name, still naming the long one
+            let meaning = short.state_map.meaning(short_name).unwrap();
+            assert_eq!(&meaning.phase, long_name);
+            assert_eq!(
+                meaning.transitions,
+                long.state_map.meaning(long_name).unwrap().transitions
+            );
+        }
+    }
+}
+
+#[test]
+fn short_names_skip_the_names_of_halting_states() {
+    let transitions = loader::multi_tape_transitions_from_description(
+        FINAL_STATES_NAMED_LIKE_SHORT_ONES.to_string(),
+    )
+    .unwrap();
+    for strategy in Strategy::ALL.iter() {
+        let translation = translation(transitions.clone(), *strategy).with_short_names();
+        let names: HashSet<String> = states(&translation)
+            .iter()
+            .map(|state| state.value().to_string())
+            .collect();
+        assert!(names.contains("t0") && names.contains("t1"));
+        assert!(names.contains("t2"));
+
+        // the halting ones are still only entered, never left
+        for trans in translation.transitions() {
+            assert!(!["t0", "t1"].contains(&trans.state_before.value()));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::example;
+use turinglike_oversophisticated_calculator::machine::format::structured::MachineDocument;
+use turinglike_oversophisticated_calculator::machine::format::MachineFormat;
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+
+const FORMATS: [MachineFormat; 3] = [
+    MachineFormat::Json,
+    MachineFormat::Yaml,
+    MachineFormat::Toml,
+];
+
+// copies a run of ones onto the other two tapes
+const THREE_TAPE: &str = "
+start 1 0 0 copy 1 1 0 S R S
+copy 1 0 0 copy 1 1 1 R R R
+copy 0 0 0 accept 0 0 0 S S S
+";
+
+#[test]
+fn one_tape_machines_survive_a_round_trip() {
+    for name in ["palindrome.tm", "square.tm"].iter() {
+        let transitions = example(name);
+        for format in FORMATS.iter() {
+            let rendered = loader::transitions_to_format(&transitions, &[], *format).unwrap();
+            let parsed = MachineDocument::parse(&rendered, *format)
+                .unwrap()
+                .to_transitions()
+                .unwrap();
+            assert_eq!(parsed, transitions, "{} as {:?}", name, format);
+        }
+    }
+}
+
+#[test]
+fn two_tape_machines_survive_a_round_trip() {
+    let transitions = common::palindrome_two_tape();
+    for format in FORMATS.iter() {
+        let rendered =
+            loader::multi_tape_transitions_to_format(&transitions, &[], *format).unwrap();
+        let parsed = MachineDocument::parse(&rendered, *format)
+            .unwrap()
+            .to_multi_tape_transitions()
+            .unwrap();
+        assert_eq!(parsed, transitions, "{:?}", format);
+    }
+}
+
+#[test]
+fn multi_tape_machines_survive_a_round_trip() {
+    let transitions: Vec<MultiTransition> =
+        loader::multi_tape_transitions_from_description(THREE_TAPE.to_string()).unwrap();
+    for format in FORMATS.iter() {
+        let rendered =
+            loader::multi_tape_transitions_to_format(&transitions, &[], *format).unwrap();
+        let document = MachineDocument::parse(&rendered, *format).unwrap();
+        assert_eq!(document.tapes, 3);
+        assert_eq!(document.to_multi_tape_transitions().unwrap(), transitions);
+        assert!(document.to_transitions().is_err());
+    }
+}
+
+#[test]
+fn declarations_are_checked_and_halting_states_renamed() {
+    let document = MachineDocument::parse(
+        r#"{
+            "states": ["start", "yes"],
+            "alphabet": [0, 1],
+            "accept": ["yes"],
+            "transitions": [{"from": "start", "read": [1], "to": "yes", "write": [1], "move": ["S"]}]
+        }"#,
+        MachineFormat::Json,
+    )
+    .unwrap();
+    let transitions = document.to_transitions().unwrap();
+    assert_eq!(transitions[0].to_string(), "start 1 accept 1 S");
+
+    let undeclared = MachineDocument::parse(
+        r#"
+alphabet = [0, 1]
+
+[[transitions]]
+from = "start"
+read = [2]
+to = "accept"
+write = [2]
+move = ["S"]
+"#,
+        MachineFormat::Toml,
+    )
+    .unwrap();
+    assert!(undeclared.to_transitions().is_err());
+}
@@ -0,0 +1,115 @@
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::translation::legend::SymbolLegend;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+const TASK_SYMBOL_LIMIT: usize = 50;
+
+/* Pushes a one onto the second tape for every one read and pops one for every two, to
+accept words like `1212` or `1122` - 3 letters with the blank, like the machines in TASK.md. */
+const BALANCED: &str = "
+start 1 0 start 1 1 R R
+start 2 0 pop 2 0 S L
+pop 2 1 start 2 0 R S
+pop 2 0 reject 2 0 S S
+start 0 0 check 0 0 S L
+check 0 0 accept 0 0 S S
+check 0 1 reject 0 1 S S
+";
+
+fn translated_legend(description: &str, strategy: Strategy) -> SymbolLegend {
+    let transitions =
+        loader::multi_tape_transitions_from_description(description.to_string()).unwrap();
+    MachineTranslator::new(transitions, strategy)
+        .unwrap()
+        .translation()
+        .legend
+}
+
+fn plain(letter: Number, tapes: usize) -> SuperTapeEntry {
+    let mut letters = vec![TapeEntry::BLANK; tapes];
+    letters[0] = TapeEntry(letter);
+    SuperTapeEntry {
+        letters,
+        heads: vec![false; tapes],
+        is_start: false,
+    }
+}
+
+#[test]
+fn input_letters_keep_their_numbers() {
+    let used = [9, 3, 0, 2, 7, 1]
+        .iter()
+        .map(|&letter| plain(letter, 2).encoded());
+    let fixed = [TapeEntry(0), TapeEntry(2), TapeEntry(7)];
+    let (numbers, legend) = SymbolLegend::renumber(used, 2, &fixed);
+
+    for letter in fixed.iter() {
+        assert_eq!(&numbers[letter], letter);
+        assert_eq!(legend.decode(letter), Some(&plain(letter.0, 2)));
+    }
+    // the others fill the gaps, in the order of their encoding
+    assert_eq!(numbers[&plain(1, 2).encoded()], TapeEntry(1));
+    assert_eq!(numbers[&plain(3, 2).encoded()], TapeEntry(3));
+    assert_eq!(numbers[&plain(9, 2).encoded()], TapeEntry(4));
+    assert_eq!(legend.len(), 6);
+}
+
+#[test]
+fn translated_symbols_are_numbered_densely() {
+    for strategy in Strategy::ALL.iter() {
+        let legend = translated_legend(BALANCED, *strategy);
+        for number in 0..legend.len() {
+            assert!(legend.decode(&TapeEntry(number as Number)).is_some());
+        }
+        assert_eq!(legend.tape_count(), 2);
+    }
+}
+
+#[test]
+fn legends_survive_a_round_trip() {
+    for strategy in Strategy::ALL.iter() {
+        let legend = translated_legend(BALANCED, *strategy);
+        let reloaded = SymbolLegend::from_description(legend.to_string()).unwrap();
+        assert_eq!(reloaded.to_string(), legend.to_string());
+        assert_eq!(reloaded.len(), legend.len());
+    }
+}
+
+#[test]
+fn malformed_legends_are_rejected() {
+    for description in &[
+        "",
+        "0 0 0 . .",
+        "0 0 0 ^ x -",
+        "0 0 0 ^ . X",
+        "0 0 0 ^ . -\n1 0 0 0 ^ . . -",
+        "x 0 0 ^ . -",
+    ] {
+        assert!(
+            SymbolLegend::from_description(description.to_string()).is_err(),
+            "`{}` was accepted",
+            description
+        );
+    }
+    let mixed = SymbolLegend::from_description("0 0 0 ^ . -\n1 0 0 0 ^ . . -".to_string());
+    assert_eq!(
+        mixed.err().unwrap().to_string(),
+        "expected 2 tapes but legend line `1 0 0 0 ^ . . -` has 3"
+    );
+}
+
+/* A two-tape machine with 3 letters has 9 pairs of letters with 4 combinations of head
+flags each. Only head-to-head does without the start marker, which doubles that. */
+#[test]
+fn three_letter_machines_fit_the_task_symbol_limit() {
+    let legend = translated_legend(BALANCED, Strategy::HeadToHead);
+    assert!(legend.len() <= 36);
+    assert!(legend.len() <= TASK_SYMBOL_LIMIT);
+
+    for strategy in &[Strategy::StartMarker, Strategy::OneSweep] {
+        assert!(translated_legend(BALANCED, *strategy).len() <= 72);
+    }
+}
@@ -0,0 +1,400 @@
+mod common;
+
+use common::palindrome_two_tape;
+
+use turinglike_oversophisticated_calculator::machine::classic::ClassicMachine;
+use turinglike_oversophisticated_calculator::machine::component::transition::Transition;
+use turinglike_oversophisticated_calculator::machine::logic::differential::{
+    self, DifferentialTest,
+};
+use turinglike_oversophisticated_calculator::machine::logic::loader;
+use turinglike_oversophisticated_calculator::machine::multi_tape::MultiTapeMachine;
+use turinglike_oversophisticated_calculator::machine::translation::decoded::DecodedConfig;
+use turinglike_oversophisticated_calculator::machine::translation::multi_transition::MultiTransition;
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy, Translation,
+};
+use turinglike_oversophisticated_calculator::types::*;
+
+const TASK_STEP_LIMIT: Number = 100_000;
+
+fn source_run_length(word: &str) -> Option<Number> {
+    MultiTapeMachine::new(palindrome_two_tape(), 1000, word.to_string())
+        .unwrap()
+        .accepting_run_length()
+}
+
+fn translated_run_length(strategy: Strategy, word: &str) -> Option<Number> {
+    let transitions = MachineTranslator::new(palindrome_two_tape(), strategy)
+        .unwrap()
+        .translation()
+        .transitions()
+        .collect();
+    ClassicMachine::new(transitions, TASK_STEP_LIMIT, word.to_string())
+        .unwrap()
+        .accepting_run_length()
+}
+
+// a palindrome on which the two-tape machine runs for just under 200 steps
+fn long_palindrome() -> String {
+    let half = "12".repeat(12);
+    let word = format!("{}1{}", half, half.chars().rev().collect::<String>());
+    let steps = source_run_length(&word).unwrap();
+    assert!(
+        (190..=200).contains(&steps),
+        "source run takes {} steps",
+        steps
+    );
+    word
+}
+
+#[test]
+fn strategies_accept_the_same_words() {
+    for strategy in Strategy::ALL.iter() {
+        for word in &["", "1", "12", "121", "1221", "2112", "1211", "22122"] {
+            assert_eq!(
+                source_run_length(word).is_some(),
+                translated_run_length(*strategy, word).is_some(),
+                "{} strategy disagrees on `{}`",
+                strategy,
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn long_runs_stay_under_task_limit() {
+    let word = long_palindrome();
+    let source_steps = source_run_length(&word).unwrap();
+
+    for strategy in Strategy::ALL.iter() {
+        let steps = translated_run_length(*strategy, &word)
+            .unwrap_or_else(|| panic!("{} strategy didn't accept in time", strategy));
+        assert!(steps < TASK_STEP_LIMIT);
+        assert!(
+            steps <= strategy.run_length_bound(2, source_steps),
+            "{} strategy took {} steps",
+            strategy,
+            steps
+        );
+    }
+}
+
+#[test]
+fn one_sweep_is_faster() {
+    let word = long_palindrome();
+    let start_marker = translated_run_length(Strategy::StartMarker, &word).unwrap();
+    let one_sweep = translated_run_length(Strategy::OneSweep, &word).unwrap();
+    assert!(one_sweep < start_marker);
+}
+
+#[test]
+fn translations_match_on_short_words() {
+    let alphabet = differential::input_alphabet(&palindrome_two_tape());
+    for strategy in Strategy::ALL.iter() {
+        let test = DifferentialTest::new(palindrome_two_tape(), &[], *strategy, 1000).unwrap();
+        for word in differential::words_up_to(&alphabet, 4) {
+            assert_eq!(test.check(&word), None);
+        }
+    }
+}
+
+#[test]
+fn decoded_runs_keep_one_head_per_tape() {
+    for strategy in Strategy::ALL.iter() {
+        let translation = MachineTranslator::new(palindrome_two_tape(), *strategy)
+            .unwrap()
+            .translation()
+            .with_short_names();
+        let transitions = translation.transitions().collect();
+        let Translation {
+            legend, state_map, ..
+        } = translation;
+        let machine = ClassicMachine::new(transitions, 10_000, "1221".to_string()).unwrap();
+
+        let mut simulated_states = vec![];
+        let accepted = machine.traced_accepting_run_length(|step, configs| {
+            for cfg in configs.iter().filter(|_| step > 0) {
+                let decoded =
+                    DecodedConfig::decode(&cfg.state, &cfg.tape, &legend, Some(&state_map))
+                        .unwrap();
+                // a head is only lifted off its tape while it's being moved
+                let simulating = decoded.phase.is_none();
+                for tape in decoded.tapes.iter() {
+                    let heads = tape.heads.iter().filter(|&&head| head).count();
+                    assert!(heads == 1 || (heads == 0 && !simulating));
+                }
+                if simulating {
+                    simulated_states.push(decoded.state.unwrap().to_string());
+                }
+            }
+        });
+
+        assert!(accepted.is_some());
+        assert_eq!(simulated_states.first().map(String::as_str), Some("start"));
+        assert_eq!(simulated_states.last().map(String::as_str), Some("accept"));
+        assert!(simulated_states.iter().any(|state| state == "check"));
+    }
+}
+
+// copies its input onto the second tape and halts in `done`, or in `reject` on a 2
+const COPY_TWO_TAPE: &str = "
+start 1 0 start 1 1 R R
+start 2 0 reject 2 0 S S
+start 0 0 done 0 0 S S
+";
+
+// the halting states of the last configs reached, with what's on the second simulated tape
+fn translated_halt(strategy: Strategy, word: &str) -> Vec<(String, Vec<TapeEntry>)> {
+    let source =
+        loader::multi_tape_transitions_from_description(COPY_TWO_TAPE.to_string()).unwrap();
+    let translation = MachineTranslator::new(source, strategy)
+        .unwrap()
+        .translation()
+        .with_short_names();
+    let transitions = translation.transitions().collect();
+    let Translation {
+        legend,
+        halting_states,
+        ..
+    } = translation;
+    let machine = ClassicMachine::new(transitions, 10_000, word.to_string()).unwrap();
+
+    let mut halted = vec![];
+    machine.traced_accepting_run_length(|_, configs| {
+        for cfg in configs.iter() {
+            if halting_states.contains(&cfg.state) {
+                let decoded = DecodedConfig::decode(&cfg.state, &cfg.tape, &legend, None).unwrap();
+                let mut copy = decoded.tapes[1].letters.clone();
+                while copy.last() == Some(&TapeEntry::BLANK) {
+                    copy.pop();
+                }
+                halted.push((cfg.state.to_string(), copy));
+            }
+        }
+    });
+    halted
+}
+
+#[test]
+fn halting_states_survive_translation() {
+    for strategy in Strategy::ALL.iter() {
+        assert_eq!(
+            translated_halt(*strategy, "111"),
+            vec![("done".to_string(), vec![TapeEntry(1); 3])]
+        );
+        assert_eq!(
+            translated_halt(*strategy, "12"),
+            vec![("reject".to_string(), vec![TapeEntry(1)])]
+        );
+    }
+}
+
+// only the second tape's head moves, so the translated machine has to sweep over the whole input
+const SKIP_TWO_TAPE: &str = "
+input: 1 3
+start 1 0 a 1 0 S R
+a 1 0 b 1 0 S R
+b 1 0 accept 1 0 S S
+";
+
+#[test]
+fn declared_input_letters_get_past_the_setup() {
+    let description = SKIP_TWO_TAPE.to_string();
+    let source = loader::multi_tape_transitions_from_description(description.clone()).unwrap();
+    let alphabet = loader::input_alphabet_from_description(&description)
+        .unwrap()
+        .unwrap();
+    assert_eq!(alphabet, vec![TapeEntry(1), TapeEntry(3)]);
+
+    for strategy in Strategy::ALL.iter() {
+        let test = DifferentialTest::new(source.clone(), &alphabet, *strategy, 100).unwrap();
+        for word in differential::words_up_to(&['1', '3'], 4) {
+            assert_eq!(test.check(&word), None);
+        }
+    }
+}
+
+#[test]
+fn input_header_is_only_allowed_first() {
+    let misplaced = "start 1 0 a 1 0 S R\ninput: 1 3\na 1 0 accept 1 0 S S\n";
+    assert!(loader::multi_tape_transitions_from_description(misplaced.to_string()).is_err());
+    assert!(
+        loader::transitions_from_description("start 1 accept 1 S\ninput: 2\n".to_string()).is_err()
+    );
+}
+
+#[test]
+fn structured_documents_declare_input_letters_in_their_alphabet() {
+    let path = std::env::temp_dir().join("declared_input_letters.json");
+    let document = r#"{
+        "alphabet": [0, 1, 3],
+        "transitions": [{"from": "start", "read": [1], "to": "accept", "write": [1], "move": ["S"]}]
+    }"#;
+    std::fs::write(&path, document).unwrap();
+    let alphabet = loader::input_alphabet_from_file(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(alphabet, Some(vec![TapeEntry(1), TapeEntry(3)]));
+}
+
+// what `translate` makes of a machine file, with the input alphabet it declares
+fn translated_file(path: &str) -> Vec<Transition> {
+    let alphabet = loader::input_alphabet_from_file(path)
+        .unwrap()
+        .unwrap_or_default();
+    MachineTranslator::new(
+        loader::multi_tape_transitions_from_file(path).unwrap(),
+        Strategy::HeadToHead,
+    )
+    .unwrap()
+    .with_input_alphabet(&alphabet)
+    .unwrap()
+    .translation()
+    .transitions()
+    .collect()
+}
+
+#[test]
+fn converted_machines_keep_their_input_alphabet() {
+    let source = std::env::temp_dir().join("converted_input_letters.tm");
+    std::fs::write(&source, SKIP_TWO_TAPE).unwrap();
+    let source = source.to_str().unwrap();
+    let original = translated_file(source);
+
+    for extension in &["json", "yaml", "toml", "tm"] {
+        let converted = std::env::temp_dir().join(format!(
+            "converted_input_letters.{}.{}",
+            extension, extension
+        ));
+        let converted = converted.to_str().unwrap();
+        let status = std::process::Command::new(env!("CARGO_BIN_EXE_convert"))
+            .args(["--multi-tape", source, converted])
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let alphabet = loader::input_alphabet_from_file(converted).unwrap();
+        let translated = translated_file(converted);
+        std::fs::remove_file(converted).unwrap();
+
+        assert_eq!(
+            alphabet,
+            Some(vec![TapeEntry(1), TapeEntry(3)]),
+            "{}",
+            extension
+        );
+        assert_eq!(translated.len(), original.len(), "{}", extension);
+        let machine = ClassicMachine::new(translated, TASK_STEP_LIMIT, "13".to_string()).unwrap();
+        assert!(machine.accepts(), "{}", extension);
+    }
+    std::fs::remove_file(source).unwrap();
+}
+
+/* Sorts the input's ones onto the second tape and its twos onto the third, then erases both
+copies from their ends at once to accept words with as many ones as twos. */
+const SAME_COUNT_THREE_TAPE: &str = "
+start 1 0 0 start 1 1 0 R R S
+start 2 0 0 start 2 0 2 R S R
+start 0 0 0 back 0 0 0 S L L
+back 0 1 2 back 0 0 0 S L L
+back 0 0 0 accept 0 0 0 S S S
+back 0 1 0 reject 0 1 0 S S S
+back 0 0 2 reject 0 0 2 S S S
+";
+
+#[test]
+fn three_tape_translations_match_on_short_words() {
+    let source: Vec<MultiTransition> =
+        loader::multi_tape_transitions_from_description(SAME_COUNT_THREE_TAPE.to_string()).unwrap();
+    for strategy in Strategy::ALL.iter() {
+        let test = DifferentialTest::new(source.clone(), &[], *strategy, 1000).unwrap();
+        for word in differential::words_up_to(&['1', '2'], 5) {
+            assert_eq!(
+                test.check(&word),
+                None,
+                "{} strategy on `{}`",
+                strategy,
+                word
+            );
+            let balanced = word.matches('1').count() == word.matches('2').count();
+            let accepted = MultiTapeMachine::new(source.clone(), 1000, word.clone())
+                .unwrap()
+                .accepting_run_length()
+                .is_some();
+            assert_eq!(accepted, balanced, "`{}`", word);
+        }
+    }
+}
+
+/* A deterministic two-tape machine with 10 states and 3 letters, with a transition for every
+state and pair of letters - as big as the ones TASK.md tests on get. Targets and moves are
+picked by `seed`. */
+fn dense_machine(seed: usize) -> Vec<MultiTransition> {
+    let states: Vec<String> = std::iter::once("start".to_string())
+        .chain((1..10).map(|idx| format!("q{}", idx)))
+        .collect();
+    let mut targets = states.clone();
+    targets.extend(vec!["accept".to_string(), "reject".to_string()]);
+    let mut random = seed;
+    let mut pick = |count: usize| {
+        random = random
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        (random >> 33) % count
+    };
+
+    let mut transitions = vec![];
+    for state in states.iter() {
+        for (first, second) in (0..3).flat_map(|first| (0..3).map(move |second| (first, second))) {
+            let description = format!(
+                "{} {} {} {} {} {} {} {}",
+                state,
+                first,
+                second,
+                targets[pick(targets.len())],
+                pick(3),
+                pick(3),
+                ["L", "R", "S"][pick(3)],
+                ["L", "R", "S"][pick(3)]
+            );
+            transitions.push(MultiTransition::from_description(&description).unwrap());
+        }
+    }
+    transitions
+}
+
+#[test]
+fn translations_stay_small() {
+    // head-to-head translates the palindrome machine to about 1,250 transitions and the
+    // dense machines to about 3,900
+    const PALINDROME_BUDGET: usize = 1_500;
+    const DENSE_BUDGET: usize = 4_000;
+    const TASK_TRANSITION_LIMIT: usize = 250_000;
+    const TASK_SYMBOL_LIMIT: usize = 50;
+
+    let palindrome = MachineTranslator::new(palindrome_two_tape(), Strategy::HeadToHead)
+        .unwrap()
+        .translation();
+    assert!(
+        palindrome.len() <= PALINDROME_BUDGET,
+        "{} transitions",
+        palindrome.len()
+    );
+
+    for seed in 0..3 {
+        for strategy in Strategy::ALL.iter() {
+            let translation = MachineTranslator::new(dense_machine(seed), *strategy)
+                .unwrap()
+                .translation();
+            assert!(translation.len() <= TASK_TRANSITION_LIMIT);
+            if *strategy == Strategy::HeadToHead {
+                assert!(
+                    translation.len() <= DENSE_BUDGET,
+                    "{} transitions",
+                    translation.len()
+                );
+                assert!(translation.legend.len() <= TASK_SYMBOL_LIMIT);
+            }
+        }
+    }
+}
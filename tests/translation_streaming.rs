@@ -0,0 +1,83 @@
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turinglike_oversophisticated_calculator::machine::translation::translator::{
+    MachineTranslator, Strategy,
+};
+
+/* Keeps track of how much is allocated and of the most there was at once. The only test
+in this file, so that no other test allocates while it measures. */
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(allocated, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// counts what's written to it and forgets it
+#[derive(Default)]
+struct Counter {
+    bytes: usize,
+    lines: usize,
+}
+impl io::Write for Counter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes += buf.len();
+        self.lines += buf.iter().filter(|&&byte| byte == b'\n').count();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/* What a translation keeps is what each of its states sees, which takes less than its
+transitions as text - and writing them out doesn't take anything more. */
+#[test]
+fn translations_are_written_without_being_held_in_memory() {
+    let two_tape = common::palindrome_two_tape();
+
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    let translation = MachineTranslator::new(two_tape, Strategy::StartMarker)
+        .unwrap()
+        .translation();
+    let used_to_translate = PEAK.load(Ordering::SeqCst) - before;
+
+    let kept = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(kept, Ordering::SeqCst);
+    let mut out = Counter::default();
+    translation.write_to(&mut out).unwrap();
+    let used_to_write = PEAK.load(Ordering::SeqCst) - kept;
+
+    assert_eq!(out.lines, translation.len());
+    assert!(
+        used_to_translate * 2 < out.bytes,
+        "{} bytes allocated at once for a translation of {}",
+        used_to_translate,
+        out.bytes
+    );
+    assert!(
+        used_to_write * 100 < out.bytes,
+        "{} bytes allocated at once to write {}",
+        used_to_write,
+        out.bytes
+    );
+}